@@ -246,7 +246,7 @@ impl App {
             });
         });
 
-        self.status_message = "Loading archive...".into();
+        self.set_status("Loading archive...".into());
     }
 
     /// Handle the async archive listing result.
@@ -259,7 +259,7 @@ impl App {
                 self.status_message.clear();
             }
             Err(e) => {
-                self.status_message = format!("Failed to read archive: {e}");
+                self.set_status(format!("Failed to read archive: {e}"));
             }
         }
     }
@@ -331,9 +331,9 @@ impl App {
                     state.confirm_extract_all = true;
                     let n = state.file_count;
                     let dest = self.active_panel().path.display();
-                    self.status_message = format!(
+                    self.set_status(format!(
                         "Extract all {n} file(s) to {dest} \u{2014} press X again to confirm"
-                    );
+                    ));
                 }
                 return;
             }
@@ -391,7 +391,8 @@ impl App {
         let archive_path = state.archive_path.clone();
         let total = matching_entry_count(&state.entries, Some(&entry_path));
         let label = entry_path.clone();
-        self.start_archive_extract(archive_path, Some(entry_path), total, label);
+        let dest = self.extract_dest_beside(&archive_path);
+        self.start_archive_extract(archive_path, dest, Some(entry_path), total, label);
     }
 
     fn extract_archive_all(&mut self) {
@@ -400,28 +401,92 @@ impl App {
         };
         let archive_path = state.archive_path.clone();
         let total = state.entries.len();
-        self.start_archive_extract(archive_path, None, total, "all entries".into());
+        let dest = self.extract_dest_beside(&archive_path);
+        self.start_archive_extract(archive_path, dest, None, total, "all entries".into());
+    }
+
+    /// Extract next to the archive, into a subfolder named after it
+    /// (`data.tar.gz` → `data/`) so contents never spill loose into the
+    /// directory. Falls back to the active panel if the archive somehow has
+    /// no parent (e.g. a filesystem root).
+    fn extract_dest_beside(&self, archive_path: &std::path::Path) -> PathBuf {
+        let base = archive_path
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| self.active_panel().path.clone());
+        base.join(archive::archive_stem(archive_path))
+    }
+
+    /// `:extract`: unpack each targeted archive into a new directory in the
+    /// panel named after it (`data.tar.gz` → `data/`, or `data_1/` when that
+    /// is taken, per `copy_name`). The format goes by extension; `u` removes
+    /// the directory again.
+    pub(super) fn extract_targeted(&mut self) {
+        let panel = self.active_panel();
+        let dir = panel.path.clone();
+        let (archives, other): (Vec<PathBuf>, Vec<PathBuf>) = panel
+            .targeted_paths()
+            .into_iter()
+            .partition(|p| !p.is_dir() && ArchiveFormat::from_path(p).is_some());
+        if archives.is_empty() {
+            let what = match other.as_slice() {
+                [] => "Nothing selected to extract".to_string(),
+                [one] => format!("Not an archive: {}", file_name(one)),
+                more => format!("None of the {} selected is an archive", more.len()),
+            };
+            self.set_status(format!(
+                "{what} (.zip, .tar, .tar.gz/.tgz, .tar.bz2, .tar.xz, .tar.zst)"
+            ));
+            return;
+        }
+        let count = archives.len();
+        // `data.zip` and `data.tar.gz` must not both land in `data/`: no task
+        // has made its directory yet, so names picked here count as taken.
+        let mut dests: Vec<PathBuf> = Vec::with_capacity(count);
+        for archive_path in &archives {
+            let stem = archive::archive_stem(archive_path);
+            let dest = self
+                .config
+                .copy_name
+                .free_path_avoiding(&dir, &stem, &dests);
+            dests.push(dest);
+        }
+        let jobs: Vec<(PathBuf, PathBuf)> = archives.into_iter().zip(dests).collect();
+        if self.dry_run {
+            let plan = jobs
+                .iter()
+                .map(|(a, d)| format!("extract {} \u{2192} {}/", a.display(), d.display()))
+                .collect();
+            self.show_plan(format!("Dry run: extract ({count})"), plan);
+            return;
+        }
+        for (archive_path, dest) in jobs {
+            let label = file_name(&archive_path);
+            self.start_archive_extract(archive_path, dest, None, 0, label);
+        }
+        if !other.is_empty() {
+            self.set_status(format!(
+                "Extracting {count} (see Tasks: Space j); {} not archives, skipped",
+                other.len()
+            ));
+        }
     }
 
     /// Spawn a streaming extract task: progress flows through the task manager and
     /// per-file overwrite conflicts route through the shared conflict dialog
     /// (`conflict_rxs` / `Mode::Conflict`), exactly like `ops::paste`.
+    /// A `total` of 0 means unknown; a zip's is read from its central
+    /// directory then, which is cheap (a tar's would mean a second full pass).
     fn start_archive_extract(
         &mut self,
         archive_path: PathBuf,
+        dest: PathBuf,
         filter: Option<String>,
         total: usize,
         label: String,
     ) {
-        // Extract next to the archive, into a subfolder named after it
-        // (`data.tar.gz` → `data/`) so contents never spill loose into the
-        // directory. Falls back to the active panel if the archive somehow has
-        // no parent (e.g. a filesystem root).
-        let base = archive_path
-            .parent()
-            .map(|p| p.to_path_buf())
-            .unwrap_or_else(|| self.active_panel().path.clone());
-        let dest = base.join(archive::archive_stem(&archive_path));
+        // Only a directory the extract makes is undone with it.
+        let created = (!dest.exists()).then(|| dest.clone());
 
         let (tx, rx) = tokio::sync::mpsc::channel(64);
         let (conflict_tx, conflict_rx) = tokio::sync::mpsc::channel(4);
@@ -471,6 +536,7 @@ impl App {
                     src_modified: src_mod,
                     dst_modified: dst_meta.as_ref().and_then(|m| m.modified().ok()),
                     is_dir: false,
+                    can_rename: false,
                     response_tx: resp_tx,
                 };
                 if conflict_tx.blocking_send(info).is_err() {
@@ -484,6 +550,12 @@ impl App {
                 }
             };
 
+            let total = match total {
+                0 if ArchiveFormat::detect(&archive_path) == Some(ArchiveFormat::Zip) => {
+                    archive::list_archive(&archive_path).map_or(0, |(_, e)| e.len())
+                }
+                n => n,
+            };
             let result = archive::extract_stream(
                 &archive_path,
                 filter.as_deref(),
@@ -501,6 +573,7 @@ impl App {
                     error: None,
                     cancelled: outcome.cancelled,
                     label,
+                    created: created.filter(|d| d.exists()),
                 },
                 Err(e) => ArchiveMsg::Finished {
                     is_create: false,
@@ -509,41 +582,62 @@ impl App {
                     error: Some(e.to_string()),
                     cancelled: false,
                     label,
+                    created: created.filter(|d| d.exists()),
                 },
             };
             let _ = tx.blocking_send(msg);
         });
 
         self.task_manager.add_archive(rx, false, cancel);
-        self.status_message = "Extracting (see Tasks: Space j)...".into();
+        self.set_status("Extracting (see Tasks: Space j)...".into());
         self.archive_state = None;
         self.mode = Mode::Normal;
     }
 
-    /// Create archive from selected files. Called via `:archive <name>`.
-    /// When `force` is false, refuses to overwrite an existing file.
-    pub(super) fn create_archive(&mut self, name: &str, force: bool) {
+    /// Create archive from selected files. Called via `:archive <name>` or
+    /// `:compress <name>`. When `force` is false, refuses to overwrite an
+    /// existing file; a new archive is undone with `u`.
+    /// `cmd` is the command as typed (`archive`, `ar`, `compress`), named in
+    /// the overwrite hint.
+    pub(super) fn create_archive(&mut self, name: &str, cmd: &str, force: bool) {
         let panel = self.active_panel();
         let targeted = panel.targeted_register_entries();
         if targeted.is_empty() {
-            self.status_message = "Nothing selected to archive".into();
+            self.set_status("Nothing selected to archive".into());
             return;
         }
 
         let output = self.active_panel().path.join(name);
         if archive::ArchiveFormat::from_path(&output).is_none() {
-            self.status_message =
-                "Unknown format. Use .zip, .tar, .tar.gz, .tar.bz2, .tar.xz, .tar.zst".into();
+            self.set_status(
+                "Unknown format. Use .zip, .tar, .tar.gz, .tar.bz2, .tar.xz, .tar.zst".into(),
+            );
             return;
         }
         if !force && output.exists() {
-            self.status_message =
-                format!("{name} already exists \u{2014} use :archive! to overwrite");
+            self.set_status(format!(
+                "{name} already exists \u{2014} use :{cmd}! to overwrite"
+            ));
             return;
         }
 
         let base_dir = self.active_panel().path.clone();
         let paths: Vec<PathBuf> = targeted.into_iter().map(|e| e.path).collect();
+        if self.dry_run {
+            let verb = if output.exists() {
+                "overwrite"
+            } else {
+                "create"
+            };
+            let plan = paths
+                .iter()
+                .map(|p| format!("add {} to {}", p.display(), output.display()))
+                .collect();
+            self.show_plan(format!("Dry run: {verb} {name} ({})", paths.len()), plan);
+            return;
+        }
+        // Undoing an overwrite would delete the old archive, not restore it.
+        let created = (!output.exists()).then(|| output.clone());
         let name_owned = name.to_string();
 
         let (tx, rx) = tokio::sync::mpsc::channel(64);
@@ -581,6 +675,8 @@ impl App {
                     error: None,
                     cancelled,
                     label: name_owned,
+                    // A cancelled archive is partial; don't offer to undo it.
+                    created: created.filter(|p| !cancelled && p.exists()),
                 },
                 Err(e) => ArchiveMsg::Finished {
                     is_create: true,
@@ -589,16 +685,23 @@ impl App {
                     error: Some(e.to_string()),
                     cancelled: false,
                     label: name_owned,
+                    created: None,
                 },
             };
             let _ = tx.blocking_send(msg);
         });
 
         self.task_manager.add_archive(rx, true, cancel);
-        self.status_message = format!("Creating {name} (see Tasks: Space j)...");
+        self.set_status(format!("Creating {name} (see Tasks: Space j)..."));
     }
 }
 
+fn file_name(path: &std::path::Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
 /// Count archive entries selected by `filter` — best-effort progress denominator.
 fn matching_entry_count(entries: &[ArchiveEntry], filter: Option<&str>) -> usize {
     match filter {
@@ -611,7 +714,8 @@ fn matching_entry_count(entries: &[ArchiveEntry], filter: Option<&str>) -> usize
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::app::task_manager::TaskManager;
+    use crate::app::task_manager::{TaskEvent, TaskManager};
+    use crate::fs::ops::OpRecord;
 
     #[test]
     fn archive_tree_basic() {
@@ -740,7 +844,7 @@ mod tests {
         let mut app = App::new_for_test(crate::app::make_test_entries(&["a.txt"]));
         app.active_panel_mut().selected = 1; // a.txt
 
-        app.create_archive("out.zip", false);
+        app.create_archive("out.zip", "archive", false);
 
         assert_eq!(app.task_manager.tasks().len(), 1);
         assert_eq!(
@@ -754,11 +858,44 @@ mod tests {
     async fn create_archive_unknown_format_rejected() {
         let mut app = App::new_for_test(crate::app::make_test_entries(&["a.txt"]));
         app.active_panel_mut().selected = 1;
-        app.create_archive("out.weird", false);
+        app.create_archive("out.weird", "archive", false);
         assert!(app.task_manager.tasks().is_empty());
         assert!(app.status_message.contains("Unknown format"));
     }
 
+    #[tokio::test]
+    async fn a_new_archive_is_undoable_but_an_overwrite_is_not() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        std::fs::write(base.join("f.txt"), "hi").unwrap();
+        let mut entries = crate::app::make_test_entries(&["f.txt"]);
+        entries[1].path = base.join("f.txt");
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().path = base.to_path_buf();
+        app.active_panel_mut().selected = 1;
+
+        let finish = async |app: &mut App, force| {
+            app.create_archive("out.tar.gz", "archive", force);
+            loop {
+                if let Some(TaskEvent::ArchiveFinished { record, .. }) =
+                    app.task_manager.poll_all().pop()
+                {
+                    return record;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+        };
+        let out = base.join("out.tar.gz");
+        let record = finish(&mut app, false).await;
+        assert!(matches!(record, Some(OpRecord::Created { path }) if path == out));
+        let (_, listed) = archive::list_archive(&out).unwrap();
+        assert_eq!(listed[0].path, "f.txt");
+
+        app.create_archive("out.tar.gz", "compress", false);
+        assert!(app.status_message.contains("use :compress! to overwrite"));
+        assert!(finish(&mut app, true).await.is_none());
+    }
+
     #[test]
     fn matching_entry_count_filters() {
         let entries = vec![
@@ -813,4 +950,59 @@ mod tests {
         assert_eq!(state.tree.len(), 1);
         assert_eq!(state.tree[0].name, "hello.txt");
     }
+
+    #[tokio::test]
+    async fn extract_command_unpacks_into_a_new_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path();
+        std::fs::write(base.join("f.txt"), "hi").unwrap();
+        archive::create_archive(&[base.join("f.txt")], base, &base.join("data.zip")).unwrap();
+        std::fs::create_dir(base.join("data")).unwrap();
+
+        let mut entries = crate::app::make_test_entries(&["data.zip", "f.txt"]);
+        for e in &mut entries {
+            e.path = base.join(&e.name);
+        }
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().path = base.to_path_buf();
+        app.active_panel_mut().selected = 2;
+        app.extract_targeted();
+        assert!(app.status_message.starts_with("Not an archive: f.txt"));
+        assert_eq!(app.task_manager.tasks().len(), 0);
+
+        app.active_panel_mut().selected = 1;
+        app.extract_targeted();
+        let events = loop {
+            let events = app.task_manager.poll_all();
+            if !events.is_empty() {
+                break events;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        };
+        // `data/` was taken, so the default `copy_name` picks `data_1/`.
+        let dest = base.join("data_1");
+        assert_eq!(std::fs::read_to_string(dest.join("f.txt")).unwrap(), "hi");
+        let TaskEvent::ArchiveFinished { record, .. } = &events[0] else {
+            panic!("expected ArchiveFinished");
+        };
+        assert!(matches!(record, Some(OpRecord::Created { path }) if *path == dest));
+    }
+
+    #[tokio::test]
+    async fn archives_with_one_stem_extract_into_separate_directories() {
+        let entries = crate::app::make_test_entries(&["data.tar.gz", "data.zip"]);
+        let mut app = App::new_for_test(entries);
+        app.dry_run = true;
+        let panel = app.active_panel_mut();
+        panel.marked.insert(PathBuf::from("/test/data.tar.gz"));
+        panel.marked.insert(PathBuf::from("/test/data.zip"));
+        app.extract_targeted();
+        let mut dests: Vec<&str> = app
+            .plan_lines
+            .iter()
+            .filter_map(|l| l.rsplit(' ').next())
+            .collect();
+        dests.sort();
+        assert_eq!(dests, ["/test/data/", "/test/data_1/"]);
+    }
 }
@@ -3,7 +3,7 @@ use super::*;
 impl App {
     pub(super) fn open_bookmarks(&mut self) {
         if self.bookmarks.is_empty() {
-            self.status_message = "No bookmarks set. Use b to add one.".into();
+            self.set_status("No bookmarks set. Use b to add one.".into());
             return;
         }
         self.bookmark_cursor = 0;
@@ -15,7 +15,7 @@ impl App {
         let entry = match self.active_panel().selected_entry() {
             Some(e) if e.is_dir || e.name == ".." => e,
             _ => {
-                self.status_message = "Select a directory to bookmark".into();
+                self.set_status("Select a directory to bookmark".into());
                 return;
             }
         };
@@ -44,7 +44,7 @@ impl App {
             self.bookmarks.push((name.to_string(), path));
             self.bookmarks.sort_by(|a, b| a.0.cmp(&b.0));
         }
-        self.status_message = format!("Bookmark added: {name}");
+        self.set_status(format!("Bookmark added: {name}"));
     }
 
     pub(super) fn remove_bookmark_by_name(&mut self, name: &str) {
@@ -63,14 +63,10 @@ impl App {
         let (tx, rx) = tokio::sync::oneshot::channel();
         self.nav_check_rx = Some(rx);
         tokio::task::spawn_blocking(move || {
-            let exists = path.exists();
-            let is_dir = path.is_dir();
-            let _ = tx.send(super::NavCheckResult {
+            let _ = tx.send(super::NavCheckResult::probe(
                 path,
-                is_dir,
-                exists,
-                source: super::NavSource::Bookmark,
-            });
+                super::NavSource::Bookmark,
+            ));
         });
     }
 
@@ -105,7 +101,7 @@ impl App {
                     return;
                 };
                 self.remove_bookmark_by_name(&name);
-                self.status_message = format!("Bookmark removed: {name}");
+                self.set_status(format!("Bookmark removed: {name}"));
                 if self.bookmarks.is_empty() {
                     self.mode = Mode::Normal;
                 } else {
@@ -208,7 +204,7 @@ impl App {
 
     pub(super) fn rename_bookmark(&mut self, old_name: &str, new_name: &str) {
         if !self.bookmarks.iter().any(|(n, _)| n == old_name) {
-            self.status_message = format!("Bookmark not found: {old_name}");
+            self.set_status(format!("Bookmark not found: {old_name}"));
             return;
         }
         {
@@ -222,7 +218,7 @@ impl App {
             self.bookmarks[pos].0 = new_name.to_string();
             self.bookmarks.sort_by(|a, b| a.0.cmp(&b.0));
         }
-        self.status_message = format!("Bookmark renamed: {old_name} -> {new_name}");
+        self.set_status(format!("Bookmark renamed: {old_name} -> {new_name}"));
     }
 
     fn adjust_bookmark_scroll(&mut self) {
@@ -0,0 +1,110 @@
+use super::*;
+
+impl App {
+    /// `gp`: choose an ancestor of the active panel's directory from its title
+    /// breadcrumb, starting on the parent.
+    pub(super) fn enter_breadcrumb(&mut self) {
+        let depth = crate::util::path_segments(&self.active_panel().path).len();
+        if depth < 2 {
+            self.set_status("Already at the root".into());
+            return;
+        }
+        self.crumb_selected = depth - 2;
+        self.mode = Mode::Breadcrumb;
+    }
+
+    pub(super) fn handle_breadcrumb(&mut self, key: KeyEvent) {
+        let segments = crate::util::path_segments(&self.active_panel().path);
+        let last = segments.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('h') | KeyCode::Left => {
+                self.crumb_selected = self.crumb_selected.saturating_sub(1);
+            }
+            KeyCode::Char('l') | KeyCode::Right => {
+                self.crumb_selected = (self.crumb_selected + 1).min(last);
+            }
+            KeyCode::Home => self.crumb_selected = 0,
+            KeyCode::End => self.crumb_selected = last,
+            // A digit jumps straight up that many levels.
+            KeyCode::Char(c @ '1'..='9') => {
+                let up = c as usize - '0' as usize;
+                self.mode = Mode::Normal;
+                let target = segments[last.saturating_sub(up)].1.clone();
+                let idx = self.tab().active;
+                self.goto_ancestor(idx, target);
+            }
+            KeyCode::Enter => {
+                self.mode = Mode::Normal;
+                if let Some((_, target)) = segments.get(self.crumb_selected) {
+                    let idx = self.tab().active;
+                    self.goto_ancestor(idx, target.clone());
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') => self.mode = Mode::Normal,
+            _ => {}
+        }
+    }
+
+    /// Move panel `panel_idx` to `target`, an ancestor of its directory, with
+    /// the cursor on the child directory the jump came from.
+    pub(super) fn goto_ancestor(&mut self, panel_idx: usize, target: PathBuf) {
+        let current = &self.tab().panels[panel_idx].path;
+        if *current == target {
+            return;
+        }
+        let select = current
+            .strip_prefix(&target)
+            .ok()
+            .and_then(|rest| rest.components().next())
+            .map(|c| c.as_os_str().to_string_lossy().into_owned());
+        self.navigate_cached(target, panel_idx, select);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[tokio::test]
+    async fn breadcrumb_moves_and_jumps_to_ancestor() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().path = PathBuf::from("/home/u/src/app");
+
+        app.handle_key(key(KeyCode::Char('g')));
+        app.handle_key(key(KeyCode::Char('p')));
+        assert_eq!(app.mode, Mode::Breadcrumb);
+        // Starts on the parent; h walks towards the root and stops there.
+        assert_eq!(app.crumb_selected, 3);
+        for _ in 0..5 {
+            app.handle_key(key(KeyCode::Char('h')));
+        }
+        assert_eq!(app.crumb_selected, 0);
+        app.handle_key(key(KeyCode::Char('l')));
+        app.handle_key(key(KeyCode::Char('l')));
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.active_panel().path, PathBuf::from("/home/u"));
+    }
+
+    #[tokio::test]
+    async fn breadcrumb_digit_goes_up_levels() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().path = PathBuf::from("/home/u/src/app");
+        app.enter_breadcrumb();
+        app.handle_key(key(KeyCode::Char('2')));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.active_panel().path, PathBuf::from("/home/u"));
+
+        app.active_panel_mut().path = PathBuf::from("/");
+        app.enter_breadcrumb();
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.status_message, "Already at the root");
+    }
+}
@@ -160,7 +160,7 @@ impl App {
         let panel = self.active_panel();
         let targeted = panel.targeted_register_entries();
         if targeted.is_empty() {
-            self.status_message = "Nothing to rename".into();
+            self.set_status("Nothing to rename".into());
             return;
         }
 
@@ -178,7 +178,7 @@ impl App {
             .collect();
 
         if entries.is_empty() {
-            self.status_message = "Nothing to rename".into();
+            self.set_status("Nothing to rename".into());
             return;
         }
 
@@ -264,6 +264,10 @@ impl App {
                 state.sub_mode = BulkRenameSubMode::FindReplace;
                 state.error = None;
             }
+            KeyCode::Char('E') => {
+                self.edit_bulk_rename_in_editor();
+                return;
+            }
             KeyCode::Enter => {
                 self.execute_bulk_rename();
                 return;
@@ -361,6 +365,77 @@ impl App {
         }
     }
 
+    /// `cW`, `:bulkrename!` or `E` in the dialog: write the new names to a
+    /// buffer file, one per line, for the main loop to open in `$EDITOR`.
+    pub(super) fn edit_bulk_rename_in_editor(&mut self) {
+        if self.bulk_rename.is_none() {
+            self.enter_bulk_rename();
+        }
+        let Some(state) = self.bulk_rename.as_mut() else {
+            return;
+        };
+        let buffer: String = state
+            .entries
+            .iter()
+            .map(|e| format!("{}\n", e.new_name))
+            .collect();
+        match write_private_buffer(&buffer) {
+            Ok(file) => {
+                state.error = None;
+                self.bulk_rename_edit = Some(file);
+            }
+            Err(e) => state.error = Some(format!("Can't write the names file: {e}")),
+        }
+    }
+
+    /// Read the buffer back once the editor has exited (`edited` is false if
+    /// it failed). Line `i`, blank lines aside, is entry `i`'s new name. Any
+    /// other number of lines renames nothing; otherwise the renames go ahead
+    /// as `Enter` would, which stops at duplicate or taken names before
+    /// touching the filesystem and leaves the dialog open on them.
+    pub fn finish_bulk_rename_edit(&mut self, file: &std::path::Path, edited: bool) {
+        let text = std::fs::read_to_string(file);
+        let _ = std::fs::remove_file(file);
+        if let Some(dir) = file.parent() {
+            let _ = std::fs::remove_dir(dir);
+        }
+        let Some(state) = self.bulk_rename.as_mut() else {
+            return;
+        };
+        if !edited {
+            // `open_in_editor` has said why in the status line.
+            return;
+        }
+        let text = match text {
+            Ok(text) => text,
+            Err(e) => {
+                state.error = Some(format!("Can't read {}: {e}", file.display()));
+                return;
+            }
+        };
+        // Names may start or end with spaces, so only a CRLF editor's `\r`
+        // goes; a line of nothing but whitespace still counts as blank.
+        let names: Vec<&str> = text
+            .split('\n')
+            .map(|l| l.strip_suffix('\r').unwrap_or(l))
+            .filter(|l| !l.trim().is_empty())
+            .collect();
+        if names.len() != state.entries.len() {
+            let msg = format!(
+                "{} names for {} files \u{2014} nothing renamed",
+                names.len(),
+                state.entries.len()
+            );
+            state.error = Some(msg.clone());
+            self.set_status(msg);
+            return;
+        }
+        for (entry, name) in state.entries.iter_mut().zip(names) {
+            entry.new_name = name.to_string();
+        }
+        self.execute_bulk_rename();
+    }
+
     fn execute_bulk_rename(&mut self) {
         let state = match self.bulk_rename.take() {
             Some(s) => s,
@@ -390,10 +465,15 @@ impl App {
             .collect();
 
         if renames.is_empty() {
-            self.status_message = "No changes to apply".into();
+            self.set_status("No changes to apply".into());
             self.mode = Mode::Normal;
             return;
         }
+        if self.dry_run {
+            let plan = ops::plan_bulk_rename(&renames);
+            self.show_plan(format!("Dry run: rename ({})", plan.len()), plan);
+            return;
+        }
 
         let count = renames.len();
         let (tx, rx) = tokio::sync::oneshot::channel();
@@ -499,6 +579,56 @@ impl App {
     }
 }
 
+/// Write `contents` to a new file only this user can read, in a directory
+/// of its own under the temp dir: a name someone else took first, or a
+/// symlink planted there, is never opened. A taken name is retried with
+/// another random suffix.
+fn write_private_buffer(contents: &str) -> std::io::Result<PathBuf> {
+    use std::hash::{BuildHasher, Hasher};
+    use std::io::Write;
+    let mut last_err = None;
+    for _ in 0..16 {
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos()),
+        );
+        let dir = std::env::temp_dir().join(format!(
+            "fcmd-bulkrename-{}-{:016x}",
+            std::process::id(),
+            hasher.finish()
+        ));
+        let mut builder = std::fs::DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        match builder.create(&dir) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                last_err = Some(e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        }
+        let file = dir.join("names.txt");
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let written = options
+            .open(&file)
+            .and_then(|mut f| f.write_all(contents.as_bytes()));
+        return match written {
+            Ok(()) => Ok(file),
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&dir);
+                Err(e)
+            }
+        };
+    }
+    Err(last_err.unwrap_or_else(|| std::io::ErrorKind::AlreadyExists.into()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -729,4 +859,76 @@ mod tests {
         app.handle_bulk_rename(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
         assert_eq!(app.bulk_rename.as_ref().unwrap().entries.len(), 1);
     }
+
+    #[tokio::test]
+    async fn bulk_rename_in_editor_reads_the_buffer_back() {
+        let entries = crate::app::make_test_entries(&["a.txt", "b.txt", "c.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.select_all();
+        app.edit_bulk_rename_in_editor();
+        assert_eq!(app.mode, Mode::BulkRename);
+        let file = app.bulk_rename_edit.take().unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            "a.txt\nb.txt\nc.txt\n"
+        );
+
+        // A line gone: nothing is renamed.
+        std::fs::write(&file, "a.txt\n\nc.md\n").unwrap();
+        app.finish_bulk_rename_edit(&file, true);
+        assert!(!file.exists());
+        assert_eq!(
+            app.status_message,
+            "2 names for 3 files \u{2014} nothing renamed"
+        );
+        assert_eq!(app.bulk_rename.as_ref().unwrap().changed_count(), 0);
+        assert!(app.file_op_rx.is_none());
+
+        // Two files onto one name: held back in the dialog.
+        app.edit_bulk_rename_in_editor();
+        let file = app.bulk_rename_edit.take().unwrap();
+        std::fs::write(&file, "a.txt\nc.txt\nc.txt\n").unwrap();
+        app.finish_bulk_rename_edit(&file, true);
+        let state = app.bulk_rename.as_ref().unwrap();
+        assert_eq!(
+            state.error.as_deref(),
+            Some("Fix conflicts before applying")
+        );
+        assert!(app.file_op_rx.is_none());
+
+        app.edit_bulk_rename_in_editor();
+        let file = app.bulk_rename_edit.take().unwrap();
+        std::fs::write(&file, "a.txt\nb.md\nc.md\n").unwrap();
+        app.finish_bulk_rename_edit(&file, true);
+        assert!(app.bulk_rename.is_none());
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.file_op_rx.is_some());
+    }
+
+    #[tokio::test]
+    async fn bulk_rename_buffer_is_private_and_keeps_spaces() {
+        let entries = crate::app::make_test_entries(&[" notes.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.select_all();
+        app.edit_bulk_rename_in_editor();
+        let file = app.bulk_rename_edit.take().unwrap();
+        let dir = file.parent().unwrap().to_path_buf();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = |p: &std::path::Path| std::fs::metadata(p).unwrap().permissions().mode();
+            assert_eq!(mode(&dir) & 0o777, 0o700);
+            assert_eq!(mode(&file) & 0o777, 0o600);
+        }
+
+        // CRLF line ends and a whitespace-only line; the leading spaces stay,
+        // so both files want the same name and the dialog holds them back.
+        std::fs::write(&file, " notes.txt\r\n  \r\n notes.txt\r\n").unwrap();
+        app.finish_bulk_rename_edit(&file, true);
+        assert!(!dir.exists());
+        let state = app.bulk_rename.as_ref().unwrap();
+        assert_eq!(state.entries[0].new_name, " notes.txt");
+        assert_eq!(state.entries[1].new_name, " notes.txt");
+        assert_eq!(state.changed_count(), 1);
+    }
 }
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use super::*;
+use crate::app::messages::ChecksumMsg;
+use crate::fs::checksum::{self, SUMS_FILE};
+
+impl App {
+    /// `:genchecksums [sha256]`: hash the marked files, or every file listed in
+    /// the panel, into `SHA256SUMS` on a background task. Refuses to replace an
+    /// existing sums file unless `force` (`:genchecksums!`).
+    pub(super) fn generate_checksums(&mut self, algo: Option<&str>, force: bool) {
+        if algo.is_some_and(|a| !a.eq_ignore_ascii_case("sha256")) {
+            self.set_status("Usage: :genchecksums [sha256]".into());
+            return;
+        }
+        let panel = self.active_panel();
+        let dir = panel.path.clone();
+        let files: Vec<PathBuf> = if panel.marked.is_empty() {
+            panel
+                .entries
+                .iter()
+                .filter(|e| !e.is_dir && e.name != SUMS_FILE)
+                .map(|e| e.path.clone())
+                .collect()
+        } else {
+            panel
+                .targeted_register_entries()
+                .into_iter()
+                .filter(|e| !e.is_dir)
+                .map(|e| e.path)
+                .collect()
+        };
+        if files.is_empty() {
+            self.set_status("No files to checksum".into());
+            return;
+        }
+        let existed = dir.join(SUMS_FILE).exists();
+        if existed && !force {
+            self.set_status(format!(
+                "{SUMS_FILE} already exists \u{2014} use :genchecksums! to replace it"
+            ));
+            return;
+        }
+        if self.dry_run {
+            let plan = vec![ops::plan_create(&dir, SUMS_FILE, false)];
+            self.show_plan("Dry run: genchecksums", plan);
+            return;
+        }
+
+        let n = files.len();
+        let (tx, rx) = tokio::sync::mpsc::channel(64);
+        let cancel = Arc::new(AtomicBool::new(false));
+        let cancel_worker = Arc::clone(&cancel);
+        let workers = self.config.worker_threads();
+
+        tokio::task::spawn_blocking(move || {
+            // See start_archive_extract: throttled, lossy, non-blocking progress.
+            let mut last_report: Option<std::time::Instant> = None;
+            let mut on_progress = |done: usize, total: usize, current: &str| {
+                let now = std::time::Instant::now();
+                if last_report
+                    .is_none_or(|t| now.duration_since(t) >= crate::fs::ops::PROGRESS_INTERVAL)
+                {
+                    last_report = Some(now);
+                    let _ = tx.try_send(ChecksumMsg::Progress {
+                        done,
+                        total,
+                        current: current.to_string(),
+                    });
+                }
+            };
+            let result =
+                checksum::write_sums(&dir, &files, workers, &mut on_progress, &cancel_worker);
+            // Undo removes the sums file only if this run created it.
+            let created = (!existed).then(|| dir.join(SUMS_FILE));
+            let msg = match result {
+                Ok(Some(count)) => ChecksumMsg::Finished {
+                    count,
+                    created,
+                    error: None,
+                    cancelled: false,
+                },
+                Ok(None) => ChecksumMsg::Finished {
+                    count: 0,
+                    created: None,
+                    error: None,
+                    cancelled: true,
+                },
+                Err(e) => ChecksumMsg::Finished {
+                    count: 0,
+                    created: None,
+                    error: Some(e.to_string()),
+                    cancelled: false,
+                },
+            };
+            let _ = tx.blocking_send(msg);
+        });
+
+        self.task_manager.add_checksum(rx, cancel);
+        self.set_status(format!("Hashing {n} file(s) (see Tasks: Space j)..."));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn genchecksums_refuses_to_replace_and_rejects_other_algorithms() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(SUMS_FILE), "").unwrap();
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().path = dir.path().to_path_buf();
+
+        app.generate_checksums(Some("md5"), false);
+        assert_eq!(app.status_message, "Usage: :genchecksums [sha256]");
+        app.generate_checksums(None, false);
+        assert!(app.status_message.contains("already exists"));
+        assert_eq!(app.task_manager.active_count(), 0);
+
+        app.generate_checksums(Some("SHA256"), true);
+        assert_eq!(app.task_manager.active_count(), 1);
+    }
+}
@@ -11,6 +11,8 @@ pub struct ChownPicker {
     pub user_scroll: usize,
     pub group_scroll: usize,
     pub column: usize, // 0 = user, 1 = group
+    /// `r`: also change everything inside directories.
+    pub recursive: bool,
     pub paths: Vec<PathBuf>,
     pub current_uid: Option<u32>,
     pub current_gid: Option<u32>,
@@ -36,6 +38,7 @@ impl App {
         // Show chmod popup immediately with empty prefill, load mode async
         self.rename_input = String::new();
         self.chmod_paths = Vec::new();
+        self.chmod_recursive = false;
         self.mode = Mode::Chmod;
 
         let first_path = paths[0].clone();
@@ -64,6 +67,7 @@ impl App {
             user_scroll: 0,
             group_scroll: 0,
             column: 0,
+            recursive: false,
             paths: paths.clone(),
             current_uid: None,
             current_gid: None,
@@ -107,6 +111,8 @@ impl App {
         if self.mode != Mode::Chown {
             return;
         }
+        // `r` may already have been pressed while the lists were loading.
+        let picker_recursive = self.chown_picker.as_ref().is_some_and(|p| p.recursive);
         let user_cursor = result
             .users
             .iter()
@@ -130,6 +136,7 @@ impl App {
             user_scroll,
             group_scroll,
             column: 0,
+            recursive: picker_recursive,
             paths: result.paths,
             current_uid: Some(result.current_uid),
             current_gid: Some(result.current_gid),
@@ -149,36 +156,32 @@ impl App {
                 if !(3..=4).contains(&input.len())
                     || !input.chars().all(|c| c.is_ascii_digit() && c <= '7')
                 {
-                    self.status_message = "Invalid octal mode (e.g. 755)".into();
+                    self.set_status("Invalid octal mode (e.g. 755)".into());
                     return;
                 }
                 let mode = match u32::from_str_radix(&input, 8) {
                     Ok(m) => m,
                     Err(_) => {
-                        self.status_message = "Invalid octal mode".into();
+                        self.set_status("Invalid octal mode".into());
                         return;
                     }
                 };
                 let paths = std::mem::take(&mut self.chmod_paths);
-                let n = paths.len();
-                let input2 = input.clone();
+                let recursive = self.chmod_recursive;
+                if self.dry_run {
+                    let flag = if recursive { "-R " } else { "" };
+                    let plan = paths
+                        .iter()
+                        .map(|p| format!("chmod {flag}{input} {}", p.display()))
+                        .collect();
+                    self.show_plan("Dry run: chmod", plan);
+                    return;
+                }
                 let (tx, rx) = tokio::sync::oneshot::channel();
                 self.file_op_rx = Some(rx);
                 tokio::task::spawn_blocking(move || {
-                    let mut errors = 0;
-                    let mut last_error = None;
-                    for p in &paths {
-                        if let Err(e) = perms::chmod(p, mode) {
-                            last_error = Some(e.to_string());
-                            errors += 1;
-                        }
-                    }
-                    let _ = tx.send(super::FileOpResult::Chmod {
-                        input: input2,
-                        count: n,
-                        errors,
-                        last_error,
-                    });
+                    let outcome = perms::chmod_all(&paths, mode, recursive);
+                    let _ = tx.send(super::FileOpResult::Chmod { input, outcome });
                 });
                 self.mode = Mode::Normal;
             }
@@ -197,13 +200,14 @@ impl App {
             KeyCode::Char(c) if c.is_ascii_digit() && c <= '7' && self.rename_input.len() < 4 => {
                 self.rename_input.push(c);
             }
+            KeyCode::Char('r') => self.chmod_recursive = !self.chmod_recursive,
             // Reject out-of-range digits / overflow with feedback instead of silence.
             KeyCode::Char(c) if c.is_ascii_digit() => {
-                self.status_message = if self.rename_input.len() >= 4 {
+                self.set_status(if self.rename_input.len() >= 4 {
                     "chmod: at most 4 octal digits".into()
                 } else {
                     "chmod: octal digits 0-7 only".into()
-                };
+                });
             }
             _ => {}
         }
@@ -270,6 +274,7 @@ impl App {
             KeyCode::Char('h') | KeyCode::Left => {
                 picker.column = if picker.column == 0 { 1 } else { 0 };
             }
+            KeyCode::Char('r') => picker.recursive = !picker.recursive,
             KeyCode::Enter => {
                 let uid = picker.users.get(picker.user_cursor).map(|(_, id)| *id);
                 let gid = picker.groups.get(picker.group_cursor).map(|(_, id)| *id);
@@ -284,25 +289,25 @@ impl App {
                     .map(|(n, _)| n.clone())
                     .unwrap_or_default();
                 let paths = std::mem::take(&mut picker.paths);
-                let n = paths.len();
+                let recursive = picker.recursive;
                 self.chown_picker = None;
+                if self.dry_run {
+                    let flag = if recursive { "-R " } else { "" };
+                    let plan = paths
+                        .iter()
+                        .map(|p| format!("chown {flag}{user_name}:{group_name} {}", p.display()))
+                        .collect();
+                    self.show_plan("Dry run: chown", plan);
+                    return;
+                }
                 let (tx, rx) = tokio::sync::oneshot::channel();
                 self.file_op_rx = Some(rx);
                 tokio::task::spawn_blocking(move || {
-                    let mut errors = 0;
-                    let mut last_error = None;
-                    for p in &paths {
-                        if let Err(e) = perms::chown(p, uid, gid) {
-                            last_error = Some(e.to_string());
-                            errors += 1;
-                        }
-                    }
+                    let outcome = perms::chown_all(&paths, uid, gid, recursive);
                     let _ = tx.send(super::FileOpResult::Chown {
                         user_name,
                         group_name,
-                        count: n,
-                        errors,
-                        last_error,
+                        outcome,
                     });
                 });
                 self.mode = Mode::Normal;
@@ -354,17 +359,9 @@ fn read_uid_gid(_path: &std::path::Path) -> Option<(u32, u32)> {
     None
 }
 
-/// Global mutex to serialize access to non-thread-safe POSIX passwd/group
-/// functions: the iterators (setpwent/getpwent/endpwent, setgrent/getgrent/
-/// endgrent) AND the single-lookup variants getpwuid/getgrgid, which all
-/// return pointers into shared static libc storage. Anything calling these
-/// from a blocking thread must hold this lock (see also info.rs).
-#[cfg(unix)]
-pub(crate) static PWGRP_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
-
 #[cfg(unix)]
 fn list_system_users() -> Vec<(String, u32)> {
-    let _guard = PWGRP_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _guard = perms::PWGRP_LOCK.lock().unwrap_or_else(|e| e.into_inner());
     let mut seen = HashSet::new();
     let mut users = Vec::new();
     unsafe {
@@ -394,7 +391,7 @@ fn list_system_users() -> Vec<(String, u32)> {
 
 #[cfg(unix)]
 fn list_system_groups() -> Vec<(String, u32)> {
-    let _guard = PWGRP_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _guard = perms::PWGRP_LOCK.lock().unwrap_or_else(|e| e.into_inner());
     let mut seen = HashSet::new();
     let mut groups = Vec::new();
     unsafe {
@@ -500,6 +497,41 @@ mod tests {
         assert_eq!(app.rename_input, "7550"); // still 4
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn recursive_chmod_reports_failures_and_undoes_in_one_step() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let tree = dir.path().join("tree");
+        std::fs::create_dir(&tree).unwrap();
+        std::fs::write(tree.join("a.txt"), "").unwrap();
+        let mut app = App::new_for_test(crate::app::make_test_entries(&["a.txt"]));
+        app.mode = Mode::Chmod;
+        app.rename_input = "700".into();
+        app.chmod_paths = vec![tree.clone(), dir.path().join("gone")];
+        app.handle_chmod(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE));
+        assert!(app.chmod_recursive);
+        assert_eq!(app.rename_input, "700");
+        app.handle_chmod(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let Ok(FileOpResult::Chmod { input, outcome }) = app.file_op_rx.take().unwrap().await
+        else {
+            panic!("expected a chmod result");
+        };
+        app.finish_batch(format!("chmod {input}"), outcome);
+
+        let toast = app.toast.as_ref().unwrap();
+        assert!(toast.error);
+        let text = &toast.text;
+        assert!(text.starts_with("chmod 700: 2 changed, gone: "), "{text}");
+        let mode =
+            |p: &std::path::Path| std::fs::metadata(p).unwrap().permissions().mode() & 0o7777;
+        assert_eq!(mode(&tree.join("a.txt")), 0o700);
+        let records = app.undo_stack.pop().unwrap();
+        assert_eq!(records.len(), 2);
+        crate::fs::ops::undo(&records).unwrap();
+        assert_ne!(mode(&tree.join("a.txt")), 0o700);
+    }
+
     #[tokio::test]
     async fn handle_chmod_esc_exits_and_clears() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -1,6 +1,30 @@
 use super::*;
 use crate::util::glob_match;
 
+/// Commands whose argument names a path. `$VAR`s and a leading `~` in it are
+/// expanded before the command runs; other arguments (`:grep` patterns,
+/// `:pipe` lines, bookmark names) are left as typed.
+const PATH_COMMANDS: &[&str] = &[
+    "cd",
+    "findin",
+    "put",
+    "get",
+    "ln",
+    "link",
+    "hardlink",
+    "hln",
+    "archive",
+    "ar",
+    "compress",
+    "archive!",
+    "ar!",
+    "compress!",
+    "mount",
+    "unmount",
+    "umount",
+    "eject",
+];
+
 impl App {
     pub(super) fn handle_command(&mut self, key: KeyEvent) {
         match key.code {
@@ -8,26 +32,80 @@ impl App {
                 // Reset to Normal first so a command may override the mode
                 // (e.g. `:q` switching to ConfirmQuit when tasks are running).
                 self.mode = Mode::Normal;
+                let entry = self.command_input.trim().to_string();
+                self.record_command_history(&entry);
                 self.execute_command();
+                // A command entered from Visual mode has consumed the range.
+                if self.mode != Mode::Visual {
+                    self.active_panel_mut().visual_anchor = None;
+                }
+            }
+            KeyCode::Up => {
+                if let Some(e) = self.command_history.prev(&self.command_input) {
+                    self.command_input = e.to_string();
+                    self.line_editor.reset();
+                }
+            }
+            KeyCode::Down => {
+                if let Some(e) = self.command_history.next() {
+                    self.command_input = e.to_string();
+                    self.line_editor.reset();
+                }
             }
             KeyCode::Esc => {
                 self.mode = Mode::Normal;
                 self.command_input.clear();
+                self.active_panel_mut().visual_anchor = None;
             }
-            KeyCode::Backspace => {
-                if self.command_input.is_empty() {
-                    self.mode = Mode::Normal;
-                } else {
-                    self.command_input.pop();
-                }
+            KeyCode::Backspace if self.command_input.is_empty() => {
+                self.mode = Mode::Normal;
+                self.active_panel_mut().visual_anchor = None;
             }
-            KeyCode::Char(c) => {
-                self.command_input.push(c);
+            _ => {
+                self.line_editor.handle(&mut self.command_input, key);
             }
-            _ => {}
         }
     }
 
+    /// `:touch! [when]` — set the access and modification times of the targeted
+    /// entries to now, or to `when` (see [`ops::parse_touch_time`]).
+    fn retouch_targets(&mut self, when: Option<&str>) {
+        let time = match when {
+            None => filetime::FileTime::now(),
+            Some(w) => match ops::parse_touch_time(w) {
+                Some(t) => t,
+                None => {
+                    self.set_status(
+                        "Usage: :touch! [YYYY-MM-DD [HH:MM[:SS]] | @<unix seconds>]".into(),
+                    );
+                    return;
+                }
+            },
+        };
+        let paths = self.active_panel().targeted_paths();
+        if paths.is_empty() {
+            return;
+        }
+        let what = match when {
+            Some(w) => format!("touch {w}"),
+            None => "touch".to_string(),
+        };
+        if self.dry_run {
+            let plan = paths
+                .iter()
+                .map(|p| format!("{what} {}", p.display()))
+                .collect();
+            self.show_plan("Dry run: touch!", plan);
+            return;
+        }
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.file_op_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let outcome = ops::retouch(&paths, time);
+            let _ = tx.send(super::FileOpResult::Retouch { what, outcome });
+        });
+    }
+
     /// Create a symlink (or hard link) in the active panel's directory.
     /// `:ln <target> [name]` — name defaults to the target's basename.
     fn create_link_cmd(&mut self, arg: Option<&str>, hard: bool) {
@@ -39,7 +117,7 @@ impl App {
         let parts: Vec<&str> = match arg.filter(|a| !a.is_empty()) {
             Some(a) => a.splitn(2, ' ').collect(),
             None => {
-                self.status_message = usage.into();
+                self.set_status(usage.into());
                 return;
             }
         };
@@ -49,7 +127,7 @@ impl App {
         let name = match explicit_name.or_else(|| target.file_name().and_then(|n| n.to_str())) {
             Some(n) => n.to_string(),
             None => {
-                self.status_message = "Cannot derive link name from target".into();
+                self.set_status("Cannot derive link name from target".into());
                 return;
             }
         };
@@ -63,13 +141,24 @@ impl App {
             Ok(rec) => {
                 self.undo_stack.push(vec![rec]);
                 let kind = if hard { "hard link" } else { "symlink" };
-                self.status_message = format!("Created {kind}: {name} \u{2192} {}", parts[0]);
+                self.set_status(format!("Created {kind}: {name} \u{2192} {}", parts[0]));
                 self.refresh_panels_select(Some(name));
             }
-            Err(e) => self.status_message = format!("Link failed: {e}"),
+            Err(e) => self.set_status(format!("Link failed: {e}")),
         }
     }
 
+    /// Queue `:pipe <command>` for the main loop. The targeted entries (marks,
+    /// visual range, or the cursor) are written to the command's stdin.
+    fn request_pipe(&mut self, command: String) {
+        let paths = self.active_panel().targeted_paths();
+        if paths.is_empty() {
+            self.set_status("Nothing to pipe".into());
+            return;
+        }
+        self.pipe_request = Some(PipeRequest { command, paths });
+    }
+
     /// Path of the selected entry when it's a real file (not a directory and not
     /// the `..` parent link); `None` otherwise. Used by file-targeted commands.
     fn selected_file(&self) -> Option<PathBuf> {
@@ -90,6 +179,12 @@ impl App {
             Some((c, a)) => (c.trim(), Some(a.trim())),
             None => (input.as_str(), None),
         };
+        // Expanded here, once, so every path-taking command reads `$HOME/x`
+        // and `~/x` alike; patterns and shell lines are passed on as typed.
+        let expanded = arg
+            .filter(|_| PATH_COMMANDS.contains(&cmd))
+            .map(crate::util::expand_path_arg);
+        let arg = expanded.as_deref().or(arg);
 
         match cmd {
             // `:q!` force-quits even with running tasks; `:q`/`:quit` warn first.
@@ -100,11 +195,16 @@ impl App {
                 let name = match arg.filter(|a| !a.is_empty()) {
                     Some(n) => n.to_string(),
                     None => {
-                        self.status_message = "Usage: :mkdir <name>".into();
+                        self.set_status("Usage: :mkdir <name>".into());
                         return;
                     }
                 };
                 let dir = self.active_panel().path.clone();
+                if self.dry_run {
+                    let plan = vec![ops::plan_create(&dir, &name, true)];
+                    self.show_plan("Dry run: mkdir", plan);
+                    return;
+                }
                 let (tx, rx) = tokio::sync::oneshot::channel();
                 self.file_op_rx = Some(rx);
                 let name2 = name.clone();
@@ -121,11 +221,16 @@ impl App {
                 let name = match arg.filter(|a| !a.is_empty()) {
                     Some(n) => n.to_string(),
                     None => {
-                        self.status_message = "Usage: :touch <name>".into();
+                        self.set_status("Usage: :touch <name>".into());
                         return;
                     }
                 };
                 let dir = self.active_panel().path.clone();
+                if self.dry_run {
+                    let plan = vec![ops::plan_create(&dir, &name, false)];
+                    self.show_plan("Dry run: touch", plan);
+                    return;
+                }
                 let (tx, rx) = tokio::sync::oneshot::channel();
                 self.file_op_rx = Some(rx);
                 let name2 = name.clone();
@@ -138,17 +243,34 @@ impl App {
                 });
             }
 
+            "touch!" => self.retouch_targets(arg.filter(|a| !a.is_empty())),
+
             "ln" | "link" => self.create_link_cmd(arg, false),
             "hardlink" | "hln" => self.create_link_cmd(arg, true),
 
+            "dryrun" | "dry" => self.toggle_dry_run(),
+            "icons" => self.set_icons(arg.filter(|a| !a.is_empty())),
+
+            "put" => match arg.filter(|a| !a.is_empty()) {
+                Some(dest) => self.put_targeted(dest),
+                None => self.set_status("Usage: :put <dir|[user@]host:path>".into()),
+            },
+            "get" => match arg.filter(|a| !a.is_empty()) {
+                Some(src) => self.get_into_active(src),
+                None => self.set_status("Usage: :get <path|[user@]host:path>".into()),
+            },
+
+            "pull" => self.pull_from_other_panel(RegisterOp::Yank),
+            "pullmove" | "pullmv" => self.pull_from_other_panel(RegisterOp::Cut),
             "swap" => self.swap_panels(),
             "equalize" | "sync" => self.equalize_panels(),
+            "follow" => self.follow_other_panel(),
 
             "rename" | "rn" => {
                 let new_name = match arg.filter(|a| !a.is_empty()) {
                     Some(n) => n.to_string(),
                     None => {
-                        self.status_message = "Usage: :rename <new_name>".into();
+                        self.set_status("Usage: :rename <new_name>".into());
                         return;
                     }
                 };
@@ -159,10 +281,15 @@ impl App {
                 {
                     Some(e) => e.path.clone(),
                     None => {
-                        self.status_message = "Nothing selected to rename".into();
+                        self.set_status("Nothing selected to rename".into());
                         return;
                     }
                 };
+                if self.dry_run {
+                    let plan = vec![ops::plan_rename(&path, &new_name)];
+                    self.show_plan("Dry run: rename", plan);
+                    return;
+                }
                 let (tx, rx) = tokio::sync::oneshot::channel();
                 self.file_op_rx = Some(rx);
                 let new_name2 = new_name.clone();
@@ -179,33 +306,22 @@ impl App {
                 let path_str = match arg.filter(|a| !a.is_empty()) {
                     Some(p) => p,
                     None => {
-                        self.status_message = "Usage: :cd <path>".into();
+                        self.set_status("Usage: :cd <path>".into());
                         return;
                     }
                 };
-                let target = if path_str.starts_with('/') {
-                    PathBuf::from(path_str)
-                } else if path_str == "~" || path_str.starts_with("~/") {
-                    // Only expand bare "~" and "~/...". Leave "~user" untouched
-                    // (we don't resolve other users' home dirs).
-                    let home = dirs::home_dir()
-                        .map(|p| p.to_string_lossy().into_owned())
-                        .unwrap_or_default();
-                    PathBuf::from(path_str.replacen('~', &home, 1))
-                } else {
-                    self.active_panel().path.join(path_str)
-                };
+                let target = self.resolve_command_path(path_str);
+                // `host:path` is remote only if no local `12:30`-style name exists.
+                if crate::fs::rsync::is_remote(path_str) && target.symlink_metadata().is_err() {
+                    self.set_status(
+                        "Remote paths can't be opened in a panel \u{2014} use :put / :get".into(),
+                    );
+                    return;
+                }
                 let (tx, rx) = tokio::sync::oneshot::channel();
                 self.nav_check_rx = Some(rx);
                 tokio::task::spawn_blocking(move || {
-                    let exists = target.exists();
-                    let is_dir = target.is_dir();
-                    let _ = tx.send(super::NavCheckResult {
-                        path: target,
-                        is_dir,
-                        exists,
-                        source: super::NavSource::Cd,
-                    });
+                    let _ = tx.send(super::NavCheckResult::probe(target, super::NavSource::Cd));
                 });
             }
 
@@ -220,23 +336,37 @@ impl App {
                 self.mode = Mode::Find;
             }
 
+            "findin" => {
+                let base = self.active_panel().path.clone();
+                // A bookmark name wins over a relative path of the same name.
+                let root = match arg.filter(|a| !a.is_empty()) {
+                    None => base.clone(),
+                    Some(a) => match self.bookmarks.iter().find(|(name, _)| name == a) {
+                        Some((_, path)) => path.clone(),
+                        None => self.resolve_command_path(a),
+                    },
+                };
+                self.find_state = Some(FindState::new_global_in(&base, &root));
+                self.mode = Mode::Find;
+            }
+
             "grep" | "rg" => match arg.filter(|a| !a.is_empty()) {
                 Some(pattern) => {
                     let base = self.active_panel().path.clone();
                     self.find_state = Some(FindState::new_content(&base, pattern));
                     self.mode = Mode::Find;
                 }
-                None => self.status_message = "Usage: :grep <pattern>".into(),
+                None => self.set_status("Usage: :grep <pattern>".into()),
             },
 
             "strings" | "str" => match self.selected_file() {
                 Some(path) => self.open_viewer_strings(path),
-                None => self.status_message = "Select a file to extract strings".into(),
+                None => self.set_status("Select a file to extract strings".into()),
             },
 
             "struct" | "pe" | "elf" | "macho" => match self.selected_file() {
                 Some(path) => self.open_viewer_struct(path),
-                None => self.status_message = "Select an executable to parse".into(),
+                None => self.set_status("Select an executable to parse".into()),
             },
 
             "sort" => match arg.map(|a| a.to_lowercase()).as_deref() {
@@ -245,12 +375,14 @@ impl App {
                 Some("mod" | "modified" | "m" | "date" | "d") => self.set_sort(SortMode::Modified),
                 Some("cre" | "created" | "c") => self.set_sort(SortMode::Created),
                 Some("ext" | "e" | "extension") => self.set_sort(SortMode::Extension),
-                _ => self.status_message = "Usage: :sort name|size|mod|cre|ext".into(),
+                _ => self.set_status("Usage: :sort name|size|mod|cre|ext".into()),
             },
 
             "hidden" => {
                 self.toggle_hidden();
             }
+            "owner" => self.toggle_owner_column(),
+            "reltime" => self.toggle_relative_dates(),
 
             "tabnew" => self.new_tab(),
             "tabclose" | "tabc" => self.close_tab(),
@@ -263,7 +395,7 @@ impl App {
                     .filter(|c| c.is_ascii_lowercase());
                 match c {
                     Some(c) => self.set_mark(c),
-                    None => self.status_message = "Usage: :mark <a-z>".into(),
+                    None => self.set_status("Usage: :mark <a-z>".into()),
                 }
             }
 
@@ -292,6 +424,22 @@ impl App {
                 }
             },
 
+            "g" => match arg.filter(|a| !a.is_empty()) {
+                Some(pattern) => self.select_visual_range_matching(pattern),
+                None => self.set_status("Usage: :g <glob> (after v)".into()),
+            },
+
+            "marked" => match arg.filter(|a| !a.is_empty()) {
+                None => self.filter_visual_marks(None),
+                Some(l @ ("1" | "2" | "3")) => self.filter_visual_marks(l.parse().ok()),
+                Some(_) => self.set_status("Usage: :marked [1|2|3]".into()),
+            },
+
+            "pipe" => match arg.filter(|a| !a.is_empty()) {
+                Some(command) => self.request_pipe(command.to_string()),
+                None => self.set_status("Usage: :pipe <command>".into()),
+            },
+
             "select" | "sel" => {
                 let panel = self.active_panel_mut();
                 match arg.filter(|a| !a.is_empty()) {
@@ -303,7 +451,7 @@ impl App {
                                 count += 1;
                             }
                         }
-                        self.status_message = format!("Selected {count} items");
+                        self.set_status(format!("Selected {count} items"));
                     }
                     None => {
                         let mut count = 0;
@@ -313,7 +461,7 @@ impl App {
                                 count += 1;
                             }
                         }
-                        self.status_message = format!("Selected {count} items");
+                        self.set_status(format!("Selected {count} items"));
                     }
                 }
             }
@@ -332,42 +480,55 @@ impl App {
                         for p in &to_remove {
                             panel.marked.remove(p);
                         }
-                        self.status_message = format!("Unselected {count} items");
+                        self.set_status(format!("Unselected {count} items"));
                     }
                     None => {
                         panel.marked.clear();
-                        self.status_message = "Selection cleared".into();
+                        self.set_status("Selection cleared".into());
                     }
                 }
             }
 
             "du" => self.start_du(),
+            "autodu" => self.toggle_auto_dir_sizes(),
             "bulkrename" | "brn" => self.enter_bulk_rename(),
+            "bulkrename!" | "brn!" => self.edit_bulk_rename_in_editor(),
+
+            "extract" => self.extract_targeted(),
 
             // `:archive!` overwrites an existing archive; `:archive` refuses to clobber.
-            "archive" | "ar" | "archive!" | "ar!" => {
+            "archive" | "ar" | "compress" | "archive!" | "ar!" | "compress!" => {
                 let force = cmd.ends_with('!');
+                let cmd = cmd.trim_end_matches('!');
                 let name = match arg.filter(|a| !a.is_empty()) {
                     Some(n) => n,
                     None => {
-                        self.status_message =
-                            "Usage: :archive <name.zip|.tar.gz|.tar.bz2|.tar.xz|.tar.zst>".into();
+                        self.set_status(format!(
+                            "Usage: :{cmd} <name.zip|.tar.gz|.tar.bz2|.tar.xz|.tar.zst>"
+                        ));
                         return;
                     }
                 };
-                self.create_archive(name, force);
+                self.create_archive(name, cmd, force);
+            }
+
+            // `:genchecksums!` replaces an existing SHA256SUMS.
+            "genchecksums" | "genchecksums!" => {
+                self.generate_checksums(arg.filter(|a| !a.is_empty()), cmd.ends_with('!'));
             }
 
+            "messages" | "mes" => self.open_messages(),
+
             "marks" => {
                 if self.marks.is_empty() {
-                    self.status_message = "No marks set".into();
+                    self.set_status("No marks set".into());
                 } else {
                     let list: Vec<String> = self
                         .marks
                         .iter()
                         .map(|(k, v)| format!("'{k}={}", v.display()))
                         .collect();
-                    self.status_message = list.join("  ");
+                    self.set_status(list.join("  "));
                 }
             }
 
@@ -375,7 +536,7 @@ impl App {
                 let name = match arg.filter(|a| !a.is_empty()) {
                     Some(n) => n,
                     None => {
-                        self.status_message = "Usage: :bookmark <name>".into();
+                        self.set_status("Usage: :bookmark <name>".into());
                         return;
                     }
                 };
@@ -386,7 +547,7 @@ impl App {
                 {
                     Some(e) => e.path.clone(),
                     None => {
-                        self.status_message = "Select a directory to bookmark".into();
+                        self.set_status("Select a directory to bookmark".into());
                         return;
                     }
                 };
@@ -401,6 +562,14 @@ impl App {
                 self.open_tasks();
             }
 
+            "rm" => self.request_delete(),
+            "rm!" => self.request_permanent_delete(),
+
+            "emptytrash" => self.request_empty_trash(),
+            "mount" => self.open_mounts(arg.filter(|a| !a.is_empty())),
+            "unmount" | "umount" | "eject" => {
+                self.request_unmount(arg.filter(|a| !a.is_empty()));
+            }
             "trash" | "restore" => {
                 self.open_trash();
             }
@@ -409,12 +578,12 @@ impl App {
                 let parts: Vec<&str> = match arg.filter(|a| !a.is_empty()) {
                     Some(a) => a.splitn(2, ' ').collect(),
                     None => {
-                        self.status_message = "Usage: :brename <oldname> <newname>".into();
+                        self.set_status("Usage: :brename <oldname> <newname>".into());
                         return;
                     }
                 };
                 if parts.len() < 2 || parts[1].is_empty() {
-                    self.status_message = "Usage: :brename <oldname> <newname>".into();
+                    self.set_status("Usage: :brename <oldname> <newname>".into());
                     return;
                 }
                 let old_name = parts[0];
@@ -426,24 +595,43 @@ impl App {
                 let name = match arg.filter(|a| !a.is_empty()) {
                     Some(n) => n,
                     None => {
-                        self.status_message = "Usage: :bdel <name>".into();
+                        self.set_status("Usage: :bdel <name>".into());
                         return;
                     }
                 };
                 if self.bookmarks.iter().any(|(n, _)| n == name) {
                     let name_owned = name.to_string();
                     self.remove_bookmark_by_name(&name_owned);
-                    self.status_message = format!("Bookmark removed: {name_owned}");
+                    self.set_status(format!("Bookmark removed: {name_owned}"));
                 } else {
-                    self.status_message = format!("Bookmark not found: {name}");
+                    self.set_status(format!("Bookmark not found: {name}"));
                 }
             }
 
             _ => {
-                self.status_message = format!("Unknown command: :{cmd}");
+                self.set_status(format!("Unknown command: :{cmd}"));
             }
         }
     }
+
+    /// Resolve a path typed into a command (already through
+    /// [`crate::util::expand_path_arg`]): absolute, or relative to the active
+    /// panel, with `.` and `..` folded away. Quotes around a pasted path are
+    /// dropped.
+    pub(super) fn resolve_command_path(&self, path_str: &str) -> PathBuf {
+        let s = path_str.trim();
+        let s = ['"', '\'']
+            .iter()
+            .find_map(|&q| s.strip_prefix(q).and_then(|r| r.strip_suffix(q)))
+            .unwrap_or(s);
+        let path = PathBuf::from(s);
+        let path = if path.is_absolute() {
+            path
+        } else {
+            self.active_panel().path.join(path)
+        };
+        crate::fs::normalize_path(&path)
+    }
 }
 
 #[cfg(test)]
@@ -531,6 +719,57 @@ mod tests {
         assert_eq!(app.active_panel().sort_mode, SortMode::Size);
     }
 
+    #[tokio::test]
+    async fn execute_command_dryrun_mkdir_shows_plan() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.command_input = "dryrun".into();
+        app.execute_command();
+        assert!(app.dry_run);
+
+        app.mode = Mode::Normal;
+        app.command_input = "mkdir newdir".into();
+        app.execute_command();
+        assert_eq!(app.mode, Mode::Plan);
+        assert!(app.file_op_rx.is_none());
+        assert_eq!(app.plan_lines.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn path_arguments_are_expanded_but_patterns_are_not() {
+        let dir = tempfile::tempdir().unwrap();
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().path = dir.path().to_path_buf();
+        app.command_input = "ln ~ home".into();
+        app.execute_command();
+        let home = PathBuf::from(crate::util::home_dir_string());
+        assert_eq!(std::fs::read_link(dir.path().join("home")).unwrap(), home);
+
+        app.command_input = "grep $PATH".into();
+        app.execute_command();
+        assert_eq!(app.find_state.as_ref().unwrap().query, "$PATH");
+    }
+
+    #[tokio::test]
+    async fn execute_command_icons_switches_glyphs() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.command_input = "icons off".into();
+        app.execute_command();
+        assert!(!app.config.icons);
+        assert_eq!(crate::util::icons::file_icon("src", true), "/ ");
+
+        app.command_input = "icons".into();
+        app.execute_command();
+        assert!(app.config.icons && crate::util::icons::enabled());
+
+        app.command_input = "icons maybe".into();
+        app.execute_command();
+        assert!(app.config.icons);
+        assert!(app.status_message.starts_with("Usage: :icons"));
+    }
+
     #[tokio::test]
     async fn execute_command_hidden_toggles() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -571,6 +810,39 @@ mod tests {
         assert_eq!(app.active_panel().marked.len(), 2);
     }
 
+    #[tokio::test]
+    async fn g_marks_matches_within_visual_range() {
+        let entries = crate::app::make_test_entries(&["a.log", "b.txt", "c.log", "d.log"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 1;
+        app.enter_visual();
+        app.handle_visual(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        app.handle_visual(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        app.handle_visual(KeyEvent::new(KeyCode::Char(':'), KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Command);
+        for c in "g *.log".chars() {
+            app.handle_command(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        app.handle_command(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        let marked = &app.active_panel().marked;
+        assert_eq!(marked.len(), 2);
+        assert!(marked.contains(&PathBuf::from("/test/a.log")));
+        assert!(marked.contains(&PathBuf::from("/test/c.log")));
+        assert_eq!(app.mode, Mode::Select);
+        assert_eq!(app.active_panel().visual_anchor, None);
+    }
+
+    #[tokio::test]
+    async fn g_without_visual_range_reports() {
+        let entries = crate::app::make_test_entries(&["a.log"]);
+        let mut app = App::new_for_test(entries);
+        app.command_input = "g *.log".into();
+        app.execute_command();
+        assert!(app.active_panel().marked.is_empty());
+        assert!(app.status_message.contains("visual range"));
+    }
+
     #[tokio::test]
     async fn execute_command_unselect_clears() {
         let entries = crate::app::make_test_entries(&["a.rs", "b.py"]);
@@ -582,6 +854,27 @@ mod tests {
         assert!(app.active_panel().marked.is_empty());
     }
 
+    #[tokio::test]
+    async fn pipe_queues_targeted_paths() {
+        let entries = crate::app::make_test_entries(&["a.txt", "b.txt", "c.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.command_input = "pipe".into();
+        app.execute_command();
+        assert!(app.pipe_request.is_none());
+        assert!(app.status_message.starts_with("Usage"));
+
+        for name in ["c.txt", "a.txt"] {
+            app.active_panel_mut()
+                .marked
+                .insert(PathBuf::from("/test").join(name));
+        }
+        app.command_input = "pipe xargs tar czf bundle.tgz".into();
+        app.execute_command();
+        let req = app.pipe_request.take().unwrap();
+        assert_eq!(req.command, "xargs tar czf bundle.tgz");
+        assert_eq!(req.paths, ["/test/a.txt", "/test/c.txt"].map(PathBuf::from));
+    }
+
     #[tokio::test]
     async fn execute_command_unknown_shows_status() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -590,4 +883,109 @@ mod tests {
         app.execute_command();
         assert!(app.status_message.contains("Unknown command"));
     }
+
+    #[tokio::test]
+    async fn resolve_command_path_handles_relative_and_quotes() {
+        let entries = make_test_entries(&["a.txt"]);
+        let app = App::new_for_test(entries);
+        assert_eq!(
+            app.resolve_command_path("../etc/./x"),
+            PathBuf::from("/etc/x")
+        );
+        assert_eq!(
+            app.resolve_command_path("sub/~x"),
+            PathBuf::from("/test/sub/~x")
+        );
+        assert_eq!(
+            app.resolve_command_path("'/var/my log'"),
+            PathBuf::from("/var/my log")
+        );
+    }
+
+    #[tokio::test]
+    async fn cd_glob_navigates_or_offers_matches() {
+        let base = std::env::temp_dir().join(format!("fcmd_cd_glob_{}", std::process::id()));
+        for d in ["proj-a", "proj-b", "other", ".proj-hidden"] {
+            std::fs::create_dir_all(base.join(d)).unwrap();
+        }
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+
+        app.command_input = format!("cd {}", base.join("oth*").display());
+        app.execute_command();
+        let result = app.nav_check_rx.take().unwrap().await.unwrap();
+        app.apply_nav_check(result);
+        assert_eq!(app.active_panel().path, base.join("other"));
+
+        app.command_input = format!("cd {}", base.join("proj*").display());
+        app.execute_command();
+        let result = app.nav_check_rx.take().unwrap().await.unwrap();
+        app.apply_nav_check(result);
+        assert_eq!(app.mode, Mode::PastePicker);
+        let picker = app.paste_picker.as_ref().unwrap();
+        assert_eq!(picker.dirs, [base.join("proj-a"), base.join("proj-b")]);
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[tokio::test]
+    async fn cd_to_a_file_opens_its_directory() {
+        let base = std::env::temp_dir().join(format!("fcmd_cd_file_{}", std::process::id()));
+        std::fs::create_dir_all(&base).unwrap();
+        std::fs::write(base.join("notes.txt"), "x").unwrap();
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+
+        app.command_input = format!("cd {}", base.join("notes.txt").display());
+        app.execute_command();
+        let result = app.nav_check_rx.take().unwrap().await.unwrap();
+        app.apply_nav_check(result);
+        assert_eq!(app.active_panel().path, base);
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[tokio::test]
+    async fn cd_prefers_a_local_name_with_a_colon_over_a_remote() {
+        let base = tempfile::tempdir().unwrap();
+        std::fs::create_dir(base.path().join("12:30")).unwrap();
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().path = base.path().to_path_buf();
+
+        app.command_input = "cd 12:30".into();
+        app.execute_command();
+        let result = app.nav_check_rx.take().unwrap().await.unwrap();
+        app.apply_nav_check(result);
+        assert_eq!(app.active_panel().path, base.path().join("12:30"));
+
+        app.command_input = "cd host:backups".into();
+        app.execute_command();
+        assert!(app.nav_check_rx.is_none());
+        assert!(app.status_message.starts_with("Remote paths"));
+    }
+
+    #[tokio::test]
+    async fn findin_roots_global_search_at_bookmark_or_path() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.bookmarks = vec![("logs".into(), PathBuf::from("/var/log"))];
+
+        app.command_input = "findin logs".into();
+        app.execute_command();
+        assert_eq!(app.mode, Mode::Find);
+        let fs = app.find_state.as_ref().unwrap();
+        assert_eq!(fs.scope, FindScope::Global);
+        assert_eq!(fs.global_root(), std::path::Path::new("/var/log"));
+
+        app.command_input = "findin sub".into();
+        app.execute_command();
+        let root = app.find_state.as_ref().unwrap().global_root();
+        assert_eq!(root, std::path::Path::new("/test/sub"));
+
+        // Bare :findin searches under the panel directory.
+        app.command_input = "findin".into();
+        app.execute_command();
+        app.handle_find(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        let home = PathBuf::from(crate::util::home_dir_string());
+        assert_eq!(app.find_state.as_ref().unwrap().global_root(), home);
+    }
 }
@@ -1,4 +1,32 @@
 use super::*;
+use crate::fs::ops::ConflictChoice;
+
+/// The conflict dialog's buttons in display order, [`CONFLICT_COLS`] to a row:
+/// the answer each gives and its label.
+pub(crate) const CONFLICT_BUTTONS: [(ConflictChoice, &str); 7] = [
+    (ConflictChoice::Overwrite, "[O]verwrite"),
+    (ConflictChoice::Skip, "[S]kip"),
+    (ConflictChoice::Rename, "[R]ename"),
+    (ConflictChoice::OverwriteAll, "overwrite [A]ll"),
+    (ConflictChoice::SkipAll, "skip al[N]"),
+    (ConflictChoice::OverwriteNewer, "ne[W]er"),
+    (ConflictChoice::Abort, "[Esc] abort"),
+];
+pub(crate) const CONFLICT_COLS: usize = 4;
+
+/// Index of `choice`'s button in [`CONFLICT_BUTTONS`].
+pub(crate) fn conflict_button(choice: ConflictChoice) -> usize {
+    CONFLICT_BUTTONS
+        .iter()
+        .position(|&(c, _)| c == choice)
+        .unwrap_or(0)
+}
+
+/// Whether conflict button `idx` can be chosen. Archive extraction has no copy
+/// name to fall back on, so its dialog disables Rename.
+pub(crate) fn conflict_button_enabled(idx: usize, can_rename: bool) -> bool {
+    CONFLICT_BUTTONS[idx].0 != ConflictChoice::Rename || can_rename
+}
 
 impl App {
     /// Request application exit. If background tasks (copy/move/delete) are still
@@ -19,7 +47,7 @@ impl App {
             // Enter is intentionally not a confirm key here (safe default = cancel).
             _ => {
                 self.mode = Mode::Normal;
-                self.status_message = "Quit cancelled".into();
+                self.set_status("Quit cancelled".into());
             }
         }
     }
@@ -50,59 +78,66 @@ impl App {
                 } else {
                     self.mode = Mode::Select;
                 }
-                self.status_message = "Cancelled".into();
+                self.set_status("Cancelled".into());
             }
         }
     }
 
     pub(super) fn handle_conflict(&mut self, key: KeyEvent) {
-        use crate::fs::ops::ConflictChoice;
-        const BUTTON_COUNT: usize = 6;
+        const COLS: usize = CONFLICT_COLS;
+        let count = CONFLICT_BUTTONS.len();
+        let can_rename = self.conflict_info.as_ref().is_some_and(|i| i.can_rename);
+        let enabled = |i: usize| conflict_button_enabled(i, can_rename);
+        // Moving up or down onto a disabled button lands on the closest
+        // enabled one in that row, left first.
+        let in_row = |target: usize| {
+            let start = target - target % COLS;
+            let end = (start + COLS).min(count);
+            (start..=target)
+                .rev()
+                .chain(target + 1..end)
+                .find(|&i| enabled(i))
+        };
+        let sel = self.conflict_selected;
 
         let choice = match key.code {
             // Shortcut keys
             KeyCode::Char('o') | KeyCode::Char('O') => Some(ConflictChoice::Overwrite),
             KeyCode::Char('s') | KeyCode::Char('S') => Some(ConflictChoice::Skip),
+            KeyCode::Char('r') | KeyCode::Char('R') if can_rename => Some(ConflictChoice::Rename),
             KeyCode::Char('a') | KeyCode::Char('A') => Some(ConflictChoice::OverwriteAll),
             KeyCode::Char('n') | KeyCode::Char('N') => Some(ConflictChoice::SkipAll),
             KeyCode::Char('w') | KeyCode::Char('W') => Some(ConflictChoice::OverwriteNewer),
             KeyCode::Esc => Some(ConflictChoice::Abort),
-            // Navigation: 2 rows x 3 cols grid
+            // Navigation: a grid of COLS columns, skipping disabled buttons
             KeyCode::Left | KeyCode::Char('h') => {
-                if self.conflict_selected > 0 {
-                    self.conflict_selected -= 1;
+                if let Some(i) = (0..sel).rev().find(|&i| enabled(i)) {
+                    self.conflict_selected = i;
                 }
                 None
             }
             KeyCode::Right | KeyCode::Char('l') => {
-                if self.conflict_selected < BUTTON_COUNT - 1 {
-                    self.conflict_selected += 1;
+                if let Some(i) = (sel + 1..count).find(|&i| enabled(i)) {
+                    self.conflict_selected = i;
                 }
                 None
             }
             KeyCode::Up | KeyCode::Char('k') => {
-                if self.conflict_selected >= 3 {
-                    self.conflict_selected -= 3;
+                if let Some(i) = sel.checked_sub(COLS).and_then(in_row) {
+                    self.conflict_selected = i;
                 }
                 None
             }
             KeyCode::Down | KeyCode::Char('j') => {
-                if self.conflict_selected + 3 < BUTTON_COUNT {
-                    self.conflict_selected += 3;
+                if let Some(i) = Some(sel + COLS).filter(|&t| t < count).and_then(in_row) {
+                    self.conflict_selected = i;
                 }
                 None
             }
-            KeyCode::Enter => {
-                let c = match self.conflict_selected {
-                    0 => ConflictChoice::Overwrite,
-                    1 => ConflictChoice::Skip,
-                    2 => ConflictChoice::OverwriteAll,
-                    3 => ConflictChoice::SkipAll,
-                    4 => ConflictChoice::OverwriteNewer,
-                    _ => ConflictChoice::Abort,
-                };
-                Some(c)
-            }
+            KeyCode::Enter => CONFLICT_BUTTONS
+                .get(sel)
+                .filter(|_| enabled(sel))
+                .map(|&(choice, _)| choice),
             _ => None,
         };
 
@@ -115,11 +150,39 @@ impl App {
     }
 
     pub(super) fn handle_help(&mut self, key: KeyEvent) {
+        if self.help_filter_editing {
+            match key.code {
+                KeyCode::Esc => {
+                    self.help_filter.clear();
+                    self.help_filter_editing = false;
+                }
+                KeyCode::Enter => self.help_filter_editing = false,
+                // Backspace on an empty filter stops typing.
+                KeyCode::Backspace => self.help_filter_editing = self.help_filter.pop().is_some(),
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.help_filter.push(c);
+                }
+                _ => {}
+            }
+            self.help_scroll = 0;
+            return;
+        }
         match key.code {
-            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter | KeyCode::F(1) => {
+            // Esc drops an applied filter first, then closes.
+            KeyCode::Esc if !self.help_filter.is_empty() => {
+                self.help_filter.clear();
+                self.help_scroll = 0;
+            }
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::F(1) => {
                 self.help_scroll = 0;
+                self.help_filter.clear();
                 self.mode = Mode::Normal;
             }
+            KeyCode::Char('/') => {
+                self.help_filter.clear();
+                self.help_filter_editing = true;
+                self.help_scroll = 0;
+            }
             KeyCode::Char('j') | KeyCode::Down => {
                 self.help_scroll = self.help_scroll.saturating_add(1);
             }
@@ -280,7 +343,7 @@ impl App {
                 self.db_spawn(move |db| {
                     let _ = db.save_theme(&n);
                 });
-                self.status_message = format!("Theme: {name}");
+                self.set_status(format!("Theme: {name}"));
                 self.mode = Mode::Normal;
             }
             KeyCode::Char('t') => {
@@ -390,6 +453,45 @@ mod tests {
     use super::*;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+    #[tokio::test]
+    async fn conflict_navigation_skips_a_disabled_rename() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        let (tx, mut rx) = tokio::sync::oneshot::channel();
+        app.conflict_info = Some(crate::fs::ops::ConflictInfo {
+            src_path: PathBuf::from("/a/x"),
+            dst_path: PathBuf::from("/b/x"),
+            src_size: 1,
+            dst_size: 1,
+            src_modified: None,
+            dst_modified: None,
+            is_dir: false,
+            can_rename: false,
+            response_tx: tx,
+        });
+        app.mode = Mode::Conflict;
+        let skip = conflict_button(ConflictChoice::Skip);
+        let abort = conflict_button(ConflictChoice::Abort);
+        app.conflict_selected = skip;
+        let press =
+            |app: &mut App, code| app.handle_conflict(KeyEvent::new(code, KeyModifiers::NONE));
+
+        press(&mut app, KeyCode::Right);
+        assert_eq!(
+            app.conflict_selected,
+            conflict_button(ConflictChoice::OverwriteAll)
+        );
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.conflict_selected, skip);
+        // Up from the button under Rename lands beside it instead.
+        app.conflict_selected = abort;
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.conflict_selected, skip);
+
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(rx.try_recv(), Ok(ConflictChoice::Skip));
+    }
+
     #[tokio::test]
     async fn handle_confirm_scroll_down_up() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -514,6 +616,35 @@ mod tests {
         assert_eq!(app.mode, Mode::Normal);
     }
 
+    #[tokio::test]
+    async fn help_filter_narrows_and_esc_unwinds() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.mode = Mode::Help;
+        // Keys other than Esc/q no longer close the overlay.
+        app.handle_help(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Help);
+
+        for c in "/tab".chars() {
+            app.handle_help(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert!(app.help_filter_editing);
+        assert_eq!(app.help_filter, "tab");
+        // `q` while typing is part of the filter, not a close.
+        app.handle_help(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE));
+        app.handle_help(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+        app.handle_help(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(!app.help_filter_editing);
+        assert_eq!(app.help_filter, "tab");
+        assert_eq!(app.mode, Mode::Help);
+
+        app.handle_help(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(app.help_filter, "");
+        assert_eq!(app.mode, Mode::Help);
+        app.handle_help(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Normal);
+    }
+
     #[tokio::test]
     async fn position_theme_cursors_finds_in_group() {
         use crate::theme::ThemeGroup;
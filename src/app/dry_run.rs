@@ -0,0 +1,131 @@
+use super::*;
+
+impl App {
+    pub(super) fn toggle_dry_run(&mut self) {
+        self.dry_run = !self.dry_run;
+        self.set_status(if self.dry_run {
+            "Dry run ON \u{2014} delete, paste, rename, mkdir/touch, chmod/chown, archive, \
+             extract and empty trash only show a plan"
+                .into()
+        } else {
+            "Dry run OFF".into()
+        });
+    }
+
+    /// Show what an operation would have done instead of running it.
+    pub(super) fn show_plan(&mut self, title: impl Into<String>, lines: Vec<String>) {
+        self.plan_title = title.into();
+        self.plan_lines = lines;
+        self.plan_scroll = 0;
+        self.mode = Mode::Plan;
+    }
+
+    pub(super) fn handle_plan(&mut self, key: KeyEvent) {
+        // A held paste (`confirm_paste_conflicts`, `confirm_move`, …) goes ahead only on Enter.
+        let go = matches!(key.code, KeyCode::Enter | KeyCode::Char('y'));
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                let max = self.plan_lines.len().saturating_sub(1);
+                self.plan_scroll = (self.plan_scroll + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.plan_scroll = self.plan_scroll.saturating_sub(1);
+            }
+            _ => {
+                self.plan_lines.clear();
+                self.plan_scroll = 0;
+                self.mode = if self.active_panel().marked.is_empty() {
+                    Mode::Normal
+                } else {
+                    Mode::Select
+                };
+                if let Some((entries, op, dst_dir, from)) = self.pending_paste.take() {
+                    if go {
+                        self.start_paste(entries, op, dst_dir);
+                        if let Some(idx) = from {
+                            self.tab_mut().panels[idx].marked.clear();
+                        }
+                    } else {
+                        self.set_status("Paste cancelled".into());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[tokio::test]
+    async fn dry_run_delete_shows_plan_without_confirm() {
+        let entries = crate::app::make_test_entries(&["a.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.toggle_dry_run();
+        assert!(app.dry_run);
+
+        app.active_panel_mut().selected = 1;
+        let items = app.targeted_path_types();
+        app.request_delete_paths(items);
+        assert_eq!(app.mode, Mode::Plan);
+        assert_eq!(app.plan_lines.len(), 1);
+        assert!(app.plan_lines[0].contains("/test/a.txt"));
+        assert!(app.confirm_paths.is_empty());
+
+        app.handle_plan(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.plan_lines.is_empty());
+    }
+
+    #[tokio::test]
+    async fn dry_run_paste_keeps_register() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.dry_run = true;
+        app.active_panel_mut().selected = 1;
+        app.yank_targeted();
+        app.paste(false);
+        assert_eq!(app.mode, Mode::Plan);
+        assert!(app.register.is_some());
+        assert_eq!(app.task_manager.active_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn dry_run_covers_bulk_rename_and_chmod() {
+        let entries = crate::app::make_test_entries(&["a.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.dry_run = true;
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+
+        app.active_panel_mut().selected = 1;
+        app.enter_bulk_rename();
+        app.bulk_rename.as_mut().unwrap().entries[0].new_name = "c.txt".into();
+        app.handle_bulk_rename(enter);
+        assert_eq!(app.mode, Mode::Plan);
+        assert_eq!(app.plan_lines, ["rename /test/a.txt \u{2192} /test/c.txt"]);
+        assert!(app.file_op_rx.is_none());
+
+        app.chmod_paths = vec![PathBuf::from("/test/b.txt")];
+        app.rename_input = "640".into();
+        app.mode = Mode::Chmod;
+        app.handle_chmod(enter);
+        assert_eq!(app.mode, Mode::Plan);
+        assert_eq!(app.plan_lines, ["chmod 640 /test/b.txt"]);
+        assert!(app.file_op_rx.is_none());
+    }
+
+    #[tokio::test]
+    async fn plan_scroll_clamped() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.show_plan("Plan", vec!["one".into(), "two".into()]);
+        for _ in 0..5 {
+            app.handle_plan(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        }
+        assert_eq!(app.plan_scroll, 1);
+        app.handle_plan(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(app.plan_scroll, 0);
+    }
+}
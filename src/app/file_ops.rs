@@ -6,8 +6,18 @@ use super::*;
 impl App {
     pub(super) fn yank_targeted(&mut self) {
         let entries = self.active_panel().targeted_register_entries();
+        self.yank_entries(entries);
+    }
+
+    /// `yy` with a count: yank `count` entries from the cursor down.
+    pub(super) fn yank_count(&mut self, count: usize) {
+        let entries = self.active_panel().register_entries_from_cursor(count);
+        self.yank_entries(entries);
+    }
+
+    fn yank_entries(&mut self, entries: Vec<ops::RegisterEntry>) {
         if entries.is_empty() {
-            self.status_message = "Nothing to yank".into();
+            self.set_status("Nothing to yank".into());
             return;
         }
         let n = entries.len();
@@ -15,12 +25,26 @@ impl App {
             entries,
             op: RegisterOp::Yank,
         });
-        self.status_message = format!("Yanked {n} item(s)");
+        self.set_status(format!("Yanked {n} item(s)"));
     }
 
+    /// `dd`: trash the targets, or delete them outright when `delete_to_trash`
+    /// is off.
     pub(super) fn request_delete(&mut self) {
         let items = self.targeted_path_types();
-        self.confirm_permanent = false;
+        self.confirm_permanent = !self.config.delete_to_trash;
+        self.request_delete_paths(items);
+    }
+
+    /// `dd`/`dD` with a count: delete `count` entries from the cursor down.
+    pub(super) fn request_delete_count(&mut self, count: usize, permanent: bool) {
+        let items = self
+            .active_panel()
+            .register_entries_from_cursor(count)
+            .into_iter()
+            .map(|e| (e.path, e.is_dir))
+            .collect();
+        self.confirm_permanent = permanent || !self.config.delete_to_trash;
         self.request_delete_paths(items);
     }
 
@@ -32,11 +56,24 @@ impl App {
 
     pub(super) fn request_delete_paths(&mut self, items: Vec<(std::path::PathBuf, bool)>) {
         if items.is_empty() {
-            self.status_message = "Nothing to delete".into();
+            self.set_status("Nothing to delete".into());
+            return;
+        }
+        if self.dry_run {
+            let paths: Vec<PathBuf> = items.into_iter().map(|(p, _)| p).collect();
+            let plan = ops::plan_delete(&paths, self.confirm_permanent);
+            self.show_plan(format!("Dry run: delete ({})", plan.len()), plan);
             return;
         }
         self.confirm_paths = items;
         self.confirm_scroll = 0;
+        if !self.confirm_permanent && !self.config.confirm_delete {
+            // Trash is recoverable: with `confirm_delete` off it goes straight ahead.
+            self.execute_delete();
+            self.active_panel_mut().marked.clear();
+            self.mode = Mode::Normal;
+            return;
+        }
         self.mode = Mode::Confirm;
     }
 
@@ -128,7 +165,7 @@ impl App {
 
     pub(super) fn copy_to_other_panel(&mut self) {
         if self.layout == PanelLayout::Single {
-            self.status_message = "Cannot copy to same panel in single layout".into();
+            self.set_status("Cannot copy to same panel in single layout".into());
             return;
         }
         self.yank_targeted();
@@ -137,12 +174,12 @@ impl App {
 
     pub(super) fn move_to_other_panel(&mut self) {
         if self.layout == PanelLayout::Single {
-            self.status_message = "Cannot move to same panel in single layout".into();
+            self.set_status("Cannot move to same panel in single layout".into());
             return;
         }
         let entries = self.active_panel().targeted_register_entries();
         if entries.is_empty() {
-            self.status_message = "Nothing to move".into();
+            self.set_status("Nothing to move".into());
             return;
         }
         let n = entries.len();
@@ -150,25 +187,178 @@ impl App {
             entries,
             op: RegisterOp::Cut,
         });
-        self.status_message = format!("Moving {n} item(s)");
+        self.set_status(format!("Moving {n} item(s)"));
         self.paste(true);
     }
 
     pub(super) fn paste(&mut self, to_other_panel: bool) {
-        let (reg_entries, op) = match &self.register {
-            Some(r) => (r.entries.clone(), r.op),
-            None => {
-                self.status_message = "Register empty \u{2014} yy to yank, dd to cut".into();
-                return;
+        if self.register.is_none() {
+            self.set_status("Register empty \u{2014} yy to yank, dd to cut".into());
+            return;
+        }
+
+        if to_other_panel {
+            let dst_dir = self.inactive_panel_path();
+            if self.config.confirm_other_panel && !self.dry_run {
+                self.confirm_paste_to_other_panel(dst_dir);
+            } else {
+                self.paste_into(dst_dir);
             }
+        } else if self.config.paste_picker {
+            self.open_paste_picker();
+        } else {
+            let dst_dir = self.active_panel().path.clone();
+            self.paste_into(dst_dir);
+        }
+    }
+
+    /// Shift-F5 / Shift-F6: copy or move the other panel's marked files into
+    /// this panel's directory, leaving the register untouched.
+    pub(super) fn pull_from_other_panel(&mut self, op: RegisterOp) {
+        if self.layout == PanelLayout::Single {
+            self.set_status("No other panel to pull from in single layout".into());
+            return;
+        }
+        let src_idx = self.tab().inactive_index(self.layout);
+        let source = &self.tab().panels[src_idx];
+        // Only the marked set counts: the other panel's cursor is not a target.
+        let entries = if source.marked.is_empty() {
+            Vec::new()
+        } else {
+            source.targeted_register_entries()
+        };
+        if entries.is_empty() {
+            self.set_status("Nothing marked in the other panel".into());
+            return;
+        }
+        let side = panel_side_label(self.layout, src_idx);
+        let verb = if op == RegisterOp::Yank {
+            "Copying"
+        } else {
+            "Moving"
         };
+        let n = entries.len();
+        let dst_dir = self.active_panel().path.clone();
+        if self.paste_entries(entries, op, dst_dir, Some(src_idx)) {
+            self.tab_mut().panels[src_idx].marked.clear();
+            self.set_status(format!("{verb} {n} item(s) from the {side} panel"));
+        }
+    }
 
-        let dst_dir = if to_other_panel {
-            self.inactive_panel_path()
+    /// `confirm_other_panel`: show where the register is about to land — the
+    /// other panel's directory, how many entries it holds and which names
+    /// collide — and hold the paste until `Enter`.
+    fn confirm_paste_to_other_panel(&mut self, dst_dir: PathBuf) {
+        let Some(reg) = &self.register else {
+            return;
+        };
+        let (entries, op) = (reg.entries.clone(), reg.op);
+        let paths: Vec<PathBuf> = entries.iter().map(|e| e.path.clone()).collect();
+        let (plan, taken) = paste_plan(&paths, &dst_dir, op);
+        let other = &self.tab().panels[self.tab().inactive_index(self.layout)];
+        let held = other.entries.iter().filter(|e| e.name != "..").count();
+        let name = dst_dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| dst_dir.to_string_lossy().into_owned());
+        let verb = if op == RegisterOp::Yank {
+            "Copy"
         } else {
-            self.active_panel().path.clone()
+            "Move"
         };
+        let held = match held {
+            1 => "1 entry".to_string(),
+            n => format!("{n} entries"),
+        };
+        let mut title = format!("{verb} {} item(s) into {name}/ ({held}", plan.len());
+        if taken > 0 {
+            title.push_str(&format!(", {taken} taken"));
+        }
+        title.push(')');
+        self.show_plan(title, plan);
+        self.pending_paste = Some((entries, op, dst_dir, None));
+    }
+
+    /// Paste the register into `dst_dir` as a background task.
+    pub(super) fn paste_into(&mut self, dst_dir: PathBuf) {
+        let (reg_entries, op) = match &self.register {
+            Some(r) => (r.entries.clone(), r.op),
+            None => return,
+        };
+        self.paste_entries(reg_entries, op, dst_dir, None);
+    }
 
+    /// Copy or move `reg_entries` into `dst_dir` as a background task. Returns
+    /// false when nothing started: a dry run, or a paste held for confirmation
+    /// because names collide (`confirm_paste_conflicts`) or it is a move
+    /// (`confirm_move`, `confirm_move_cross_device`). `from` is the panel a
+    /// pull takes its marks from; a held paste names it and clears its marks
+    /// once it runs.
+    pub(super) fn paste_entries(
+        &mut self,
+        reg_entries: Vec<ops::RegisterEntry>,
+        op: RegisterOp,
+        dst_dir: PathBuf,
+        from: Option<usize>,
+    ) -> bool {
+        let paths: Vec<PathBuf> = reg_entries.iter().map(|e| e.path.clone()).collect();
+        if let Some(src) = ops::source_containing(&paths, &dst_dir) {
+            let verb = if op == RegisterOp::Yank {
+                "copy"
+            } else {
+                "move"
+            };
+            let name = src.file_name().unwrap_or(src.as_os_str()).to_string_lossy();
+            self.show_toast(format!("Cannot {verb} {name} into itself"), true);
+            return false;
+        }
+        let cross_device = op == RegisterOp::Cut
+            && self.config.confirm_move_cross_device
+            && reg_entries
+                .iter()
+                .any(|e| ops::crosses_device(&e.path, &dst_dir));
+        let confirm_move = (op == RegisterOp::Cut && self.config.confirm_move) || cross_device;
+        if self.dry_run || self.config.confirm_paste_conflicts || confirm_move {
+            let (plan, taken) = paste_plan(&paths, &dst_dir, op);
+            let source = from
+                .map(|idx| {
+                    format!(
+                        " \u{2014} from the {} panel",
+                        panel_side_label(self.layout, idx)
+                    )
+                })
+                .unwrap_or_default();
+            if self.dry_run {
+                self.show_plan(format!("Dry run: paste ({}){source}", plan.len()), plan);
+                return false;
+            }
+            let n = plan.len();
+            let title = if taken > 0 && self.config.confirm_paste_conflicts {
+                Some(format!("Paste: {taken} of {n} name(s) already exist"))
+            } else if cross_device {
+                Some(format!("Move {n} item(s) to another filesystem"))
+            } else if confirm_move {
+                Some(format!("Move {n} item(s)"))
+            } else {
+                None
+            };
+            if let Some(title) = title {
+                self.show_plan(format!("{title}{source}"), plan);
+                self.pending_paste = Some((reg_entries, op, dst_dir, from));
+                return false;
+            }
+        }
+        self.start_paste(reg_entries, op, dst_dir);
+        true
+    }
+
+    /// Launch the background copy/move, with no further checks.
+    pub(super) fn start_paste(
+        &mut self,
+        reg_entries: Vec<ops::RegisterEntry>,
+        op: RegisterOp,
+        dst_dir: PathBuf,
+    ) {
         let phantoms: Vec<PhantomEntry> = reg_entries
             .iter()
             .map(|e| PhantomEntry {
@@ -192,6 +382,10 @@ impl App {
             tx,
             conflict_tx,
             Arc::clone(&cancel),
+            ops::PasteOptions {
+                preserve: self.config.preserve_attributes,
+                copy_name: self.config.copy_name.clone(),
+            },
         );
         // Track per-task: a concurrent paste must not clobber an earlier paste's
         // conflict channel, which would make the earlier task silently skip conflicts.
@@ -213,7 +407,7 @@ impl App {
                 let _ = tx.send(super::FileOpResult::Undo { result });
             });
         } else {
-            self.status_message = "Nothing to undo".into();
+            self.set_status("Nothing to undo".into());
         }
     }
 
@@ -221,11 +415,12 @@ impl App {
         let path = self.active_panel().path.clone();
         self.dir_cache.remove(&path);
         let side = self.tab().active;
-        self.spawn_dir_load(side, None);
+        self.reload_panel(side);
     }
 
     /// Like refresh_panels, but passes `select_name` to the active panel
-    /// so the cursor moves to the named entry once the async load completes.
+    /// so the cursor moves to the named entry once the async load completes
+    /// (`None` keeps it where it is, like the other panels).
     pub(super) fn refresh_panels_select(&mut self, select_name: Option<String>) {
         let tab = &self.tabs[self.active_tab];
         let paths: Vec<PathBuf> = tab
@@ -242,14 +437,13 @@ impl App {
         for p in paths {
             self.dir_cache.remove(&p);
         }
+        let moved = self.leave_removed_dirs();
         let active = self.tab().active;
-        for i in 0..3 {
-            let sn = if i == active {
-                select_name.clone()
-            } else {
-                None
-            };
-            self.spawn_dir_load(i, sn);
+        for i in (0..3).filter(|&i| !moved[i]) {
+            match &select_name {
+                Some(name) if i == active => self.spawn_dir_load(i, Some(name.clone())),
+                _ => self.reload_panel(i),
+            }
         }
         self.tree_dirty = true;
         self.git_checked_dirs = [None, None, None];
@@ -274,18 +468,96 @@ impl App {
             self.dir_cache.remove(&p);
         }
         // Load all panels async
-        for i in 0..3 {
-            self.spawn_dir_load(i, None);
+        let moved = self.leave_removed_dirs();
+        for i in (0..3).filter(|&i| !moved[i]) {
+            self.reload_panel(i);
         }
         self.tree_dirty = true;
         self.git_checked_dirs = [None, None, None]; // force re-fetch
         self.refresh_git_status();
     }
+
+    /// Panels whose directory is gone — deleted, moved or renamed, itself or
+    /// an ancestor — walk up to the nearest surviving ancestor instead of
+    /// showing a load error. Returns which panels moved.
+    fn leave_removed_dirs(&mut self) -> [bool; 3] {
+        let mut moved = [false; 3];
+        for (i, slot) in moved.iter_mut().enumerate() {
+            let path = self.tab().panels[i].path.clone();
+            if path.is_dir() {
+                continue;
+            }
+            let Some(dir) = crate::fs::nearest_existing_dir(&path) else {
+                continue;
+            };
+            self.dir_cache.remove(&dir);
+            self.navigate_cached(dir.clone(), i, None);
+            if i < self.layout.count() {
+                self.set_status(format!(
+                    "{} no longer exists \u{2014} moved up to {}",
+                    path.display(),
+                    dir.display()
+                ));
+            }
+            *slot = true;
+        }
+        moved
+    }
+
+    /// Report a finished batch (chmod, chown, `:touch!`): how many paths
+    /// changed, and the first failure with a count of the rest. The changes
+    /// undo as one step.
+    pub(super) fn finish_batch(&mut self, what: String, outcome: ops::BatchOutcome) {
+        let changed = outcome.records.len();
+        if changed > 0 {
+            self.undo_stack.push(outcome.records);
+        }
+        let msg = match outcome.failures.as_slice() {
+            [] => format!("{what} ({changed} item(s)) \u{2014} u to undo"),
+            [(path, e), rest @ ..] => {
+                let name = path.file_name().map_or_else(
+                    || path.display().to_string(),
+                    |n| n.to_string_lossy().into_owned(),
+                );
+                let more = if rest.is_empty() {
+                    String::new()
+                } else {
+                    format!(" (+{} more)", rest.len())
+                };
+                format!("{what}: {changed} changed, {name}: {e}{more}")
+            }
+        };
+        self.show_toast(msg, !outcome.failures.is_empty());
+        self.refresh_panels();
+    }
+}
+
+/// "left" / "middle" / "right" for panel `idx` in `layout`, as shown in status
+/// messages that name a panel.
+fn panel_side_label(layout: PanelLayout, idx: usize) -> &'static str {
+    match (layout, idx) {
+        (_, 0) => "left",
+        (PanelLayout::Triple, 1) => "middle",
+        _ => "right",
+    }
+}
+
+/// The plan lines for pasting `paths` into `dst_dir`, and how many of the
+/// names are already taken there.
+fn paste_plan(
+    paths: &[PathBuf],
+    dst_dir: &std::path::Path,
+    op: RegisterOp,
+) -> (Vec<String>, usize) {
+    let steps = ops::paste_steps(paths, dst_dir);
+    let taken = steps.iter().filter(|s| s.name_taken()).count();
+    (steps.iter().map(|s| s.describe(op)).collect(), taken)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     #[tokio::test]
     async fn targeted_path_types_returns_selected() {
@@ -327,6 +599,166 @@ mod tests {
         assert_eq!(app.mode, Mode::Confirm);
     }
 
+    #[tokio::test]
+    async fn count_prefix_deletes_and_yanks_from_cursor() {
+        let entries = make_test_entries(&["a.txt", "b.txt", "c.txt", "d.txt"]);
+        let mut app = App::new_for_test(entries);
+        let press = |app: &mut App, c: char| {
+            app.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        };
+        // The cursor starts on `..`, which the count never takes.
+        for c in "3dd".chars() {
+            press(&mut app, c);
+        }
+        assert_eq!(app.mode, Mode::Confirm);
+        let paths: Vec<PathBuf> = app.confirm_paths.iter().map(|(p, _)| p.clone()).collect();
+        assert_eq!(
+            paths,
+            ["/test/a.txt", "/test/b.txt", "/test/c.txt"].map(PathBuf::from)
+        );
+        assert!(app.pending_count.is_none());
+
+        app.mode = Mode::Normal;
+        app.active_panel_mut().selected = 3;
+        for c in "12yy".chars() {
+            press(&mut app, c);
+        }
+        let reg = app.register.as_ref().unwrap();
+        assert_eq!(reg.entries.len(), 2);
+        assert_eq!(reg.entries[0].path, PathBuf::from("/test/c.txt"));
+
+        // A count followed by some other key is dropped.
+        for c in "2jyy".chars() {
+            press(&mut app, c);
+        }
+        assert_eq!(app.register.as_ref().unwrap().entries.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn paste_conflicts_wait_for_confirmation() {
+        let src = tempfile::tempdir().unwrap();
+        let dst = tempfile::tempdir().unwrap();
+        for name in ["a.txt", "b.txt"] {
+            std::fs::write(src.path().join(name), "new").unwrap();
+        }
+        std::fs::write(dst.path().join("a.txt"), "old").unwrap();
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.config.confirm_paste_conflicts = true;
+        let reg_entries: Vec<ops::RegisterEntry> = ["a.txt", "b.txt"]
+            .iter()
+            .map(|n| ops::RegisterEntry {
+                path: src.path().join(n),
+                is_dir: false,
+            })
+            .collect();
+        app.register = Some(Register {
+            entries: reg_entries,
+            op: RegisterOp::Yank,
+        });
+
+        app.paste_into(dst.path().to_path_buf());
+        assert_eq!(app.mode, Mode::Plan);
+        assert_eq!(app.plan_title, "Paste: 1 of 2 name(s) already exist");
+        assert!(app.plan_lines[0].starts_with("conflict: copy"));
+        assert_eq!(app.task_manager.active_count(), 0);
+
+        // Esc drops the paste; Enter on a fresh attempt starts it.
+        app.handle_plan(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.pending_paste.is_none());
+        assert_eq!(app.task_manager.active_count(), 0);
+        app.paste_into(dst.path().to_path_buf());
+        app.handle_plan(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.task_manager.active_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn dd_is_permanent_when_trash_disabled() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 1;
+        app.config.delete_to_trash = false;
+        app.request_delete();
+        assert_eq!(app.mode, Mode::Confirm);
+        assert!(app.confirm_permanent);
+    }
+
+    #[tokio::test]
+    async fn trash_skips_confirm_when_disabled() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.config.confirm_delete = false;
+        app.active_panel_mut().selected = 1;
+        app.request_permanent_delete();
+        // Permanent deletes still ask.
+        assert_eq!(app.mode, Mode::Confirm);
+        app.mode = Mode::Normal;
+
+        app.request_delete();
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.confirm_paths.is_empty());
+        assert_eq!(app.task_manager.active_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn unconfirmed_trash_from_select_returns_to_normal() {
+        let entries = make_test_entries(&["a.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.config.confirm_delete = false;
+        app.active_panel_mut().selected = 1;
+        app.enter_select_and_mark();
+        assert_eq!(app.mode, Mode::Select);
+
+        app.handle_select(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.active_panel().marked.is_empty());
+        assert_eq!(app.task_manager.active_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn moves_wait_for_confirmation_when_enabled() {
+        let src = tempfile::tempdir().unwrap();
+        let dst = tempfile::tempdir().unwrap();
+        std::fs::write(src.path().join("a.txt"), "x").unwrap();
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        let reg = vec![ops::RegisterEntry {
+            path: src.path().join("a.txt"),
+            is_dir: false,
+        }];
+
+        // Copies never ask; moves do once `confirm_move` is on.
+        app.config.confirm_move = true;
+        let dst_dir = dst.path().to_path_buf();
+        assert!(app.paste_entries(reg.clone(), RegisterOp::Yank, dst_dir.clone(), None));
+        assert!(!app.paste_entries(reg.clone(), RegisterOp::Cut, dst_dir.clone(), None));
+        assert_eq!(app.mode, Mode::Plan);
+        assert_eq!(app.plan_title, "Move 1 item(s)");
+        assert!(app.pending_paste.is_some());
+        app.handle_plan(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(app.pending_paste.is_none());
+
+        // Same filesystem: the cross-device check alone lets the move through.
+        app.config.confirm_move = false;
+        app.config.confirm_move_cross_device = true;
+        assert!(app.paste_entries(reg, RegisterOp::Cut, dst_dir, None));
+    }
+
+    #[tokio::test]
+    async fn rm_bang_requests_permanent_delete() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 1;
+        app.enter_command();
+        for c in "rm!".chars() {
+            app.handle_command(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        app.handle_command(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Confirm);
+        assert!(app.confirm_permanent);
+    }
+
     #[tokio::test]
     async fn execute_delete_spawns_task_and_returns_normal() {
         let entries = make_test_entries(&["a.txt"]);
@@ -386,6 +818,84 @@ mod tests {
         assert!(app.status_message.contains("Moving 1"));
     }
 
+    #[tokio::test]
+    async fn other_panel_paste_shows_target_first() {
+        let src = tempfile::tempdir().unwrap();
+        let dst = tempfile::tempdir().unwrap();
+        for name in ["a.txt", "b.txt"] {
+            std::fs::write(src.path().join(name), "x").unwrap();
+        }
+        std::fs::write(dst.path().join("b.txt"), "old").unwrap();
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.layout = PanelLayout::Dual;
+        app.config.confirm_other_panel = true;
+        let other = app.tab().inactive_index(app.layout);
+        app.tab_mut().panels[other].path = dst.path().to_path_buf();
+        app.tab_mut().panels[other].entries = Arc::new(make_test_entries(&["b.txt"]));
+        app.register = Some(Register {
+            entries: ["a.txt", "b.txt"]
+                .iter()
+                .map(|n| ops::RegisterEntry {
+                    path: src.path().join(n),
+                    is_dir: false,
+                })
+                .collect(),
+            op: RegisterOp::Yank,
+        });
+
+        app.paste(true);
+        assert_eq!(app.mode, Mode::Plan);
+        let name = dst
+            .path()
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .into_owned();
+        // The other panel lists ".." plus b.txt.
+        assert_eq!(
+            app.plan_title,
+            format!("Copy 2 item(s) into {name}/ (1 entry, 1 taken)")
+        );
+        assert_eq!(app.task_manager.active_count(), 0);
+        app.handle_plan(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.task_manager.active_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn pull_copies_other_panels_marks_here() {
+        let entries = make_test_entries(&["a.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.layout = PanelLayout::Dual;
+        app.pull_from_other_panel(RegisterOp::Yank);
+        assert_eq!(app.status_message, "Nothing marked in the other panel");
+
+        let other = &mut app.tab_mut().panels[1];
+        other.entries = std::sync::Arc::new(make_test_entries(&["a.txt", "b.txt"]));
+        other.marked.insert(PathBuf::from("/test/b.txt"));
+        app.pull_from_other_panel(RegisterOp::Cut);
+        assert_eq!(app.status_message, "Moving 1 item(s) from the right panel");
+        assert!(!app.task_manager.tasks().is_empty());
+        assert!(app.tab().panels[1].marked.is_empty());
+        // The register is not involved.
+        assert!(app.register.is_none());
+
+        // A held pull names its source and keeps the marks until it runs.
+        app.config.confirm_move = true;
+        app.tab_mut().panels[1]
+            .marked
+            .insert(PathBuf::from("/test/a.txt"));
+        app.pull_from_other_panel(RegisterOp::Cut);
+        assert_eq!(app.mode, Mode::Plan);
+        assert_eq!(
+            app.plan_title,
+            "Move 1 item(s) \u{2014} from the right panel"
+        );
+        assert_eq!(app.tab().panels[1].marked.len(), 1);
+        app.handle_plan(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert!(app.tab().panels[1].marked.is_empty());
+    }
+
     #[tokio::test]
     async fn paste_to_current_panel() {
         let entries = make_test_entries(&["a.txt"]);
@@ -451,4 +961,48 @@ mod tests {
         app.refresh_panels_select(Some("b.txt".into()));
         assert!(app.tree_dirty);
     }
+
+    #[tokio::test]
+    async fn refresh_keeps_cursor_on_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["b.txt", "c.txt", "d.txt"] {
+            std::fs::write(dir.path().join(name), "x").unwrap();
+        }
+        let mut app = App::new_for_test(make_test_entries(&["b.txt", "c.txt", "d.txt"]));
+        app.active_panel_mut().path = dir.path().to_path_buf();
+        app.active_panel_mut().selected = 2;
+
+        // A new file sorts in ahead of the cursor; it stays on c.txt.
+        std::fs::write(dir.path().join("a.txt"), "x").unwrap();
+        app.refresh_current_panel();
+        let msg = app.dir_load_rx.recv().await.unwrap();
+        app.handle_dir_load_msg(msg);
+        let panel = app.active_panel();
+        assert_eq!(panel.selected_entry().unwrap().name, "c.txt");
+
+        // Once it's gone the cursor keeps its row instead.
+        std::fs::remove_file(dir.path().join("c.txt")).unwrap();
+        app.reload_active_panel();
+        let msg = app.dir_load_rx.recv().await.unwrap();
+        app.handle_dir_load_msg(msg);
+        assert_eq!(app.active_panel().selected, 3);
+        assert_eq!(app.active_panel().selected_entry().unwrap().name, "d.txt");
+    }
+
+    #[tokio::test]
+    async fn refresh_moves_panels_out_of_removed_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let deep = dir.path().join("a/b");
+        std::fs::create_dir_all(&deep).unwrap();
+        let mut app = App::new_for_test(make_test_entries(&["x.txt"]));
+        app.tab_mut().panels[0].path = deep.clone();
+        app.tab_mut().panels[1].path = dir.path().to_path_buf();
+
+        // Deleting an ancestor of panel 0's directory strands it.
+        std::fs::remove_dir_all(dir.path().join("a")).unwrap();
+        app.refresh_panels();
+        assert_eq!(app.tab().panels[0].path, dir.path());
+        assert_eq!(app.tab().panels[1].path, dir.path());
+        assert!(app.status_message.contains("no longer exists"));
+    }
 }
@@ -3,7 +3,8 @@
 //! Unlike incremental search (`/`), which only moves the cursor, the filter
 //! hides non-matching entries. It is a persistent per-panel state (see
 //! [`crate::model::panel::Panel::set_filter`]) that clears on navigation; this
-//! module owns the interactive editing of it.
+//! module owns the interactive editing of it, and of the tree's quick-filter
+//! (`/` with the tree focused), which shares the prompt.
 
 use super::*;
 
@@ -19,6 +20,10 @@ impl App {
     }
 
     pub(super) fn handle_filter(&mut self, key: KeyEvent) {
+        if self.tree_focused {
+            self.handle_tree_filter(key);
+            return;
+        }
         match key.code {
             KeyCode::Char(c) => {
                 self.filter_input.push(c);
@@ -32,11 +37,11 @@ impl App {
                 // Keep the live-applied filter; just leave edit mode.
                 self.mode = Mode::Normal;
                 let active = !self.active_panel().filter.is_empty();
-                self.status_message = if active {
+                self.set_status(if active {
                     format!("Filter: {}", self.active_panel().filter)
                 } else {
                     String::new()
-                };
+                });
             }
             KeyCode::Esc => {
                 // Revert to whatever filter was active when editing began.
@@ -49,6 +54,35 @@ impl App {
         }
     }
 
+    /// The same prompt editing the tree's quick-filter (`/` in the tree).
+    fn handle_tree_filter(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(c) => {
+                self.filter_input.push(c);
+                self.set_tree_filter(self.filter_input.clone());
+            }
+            KeyCode::Backspace => {
+                self.filter_input.pop();
+                self.set_tree_filter(self.filter_input.clone());
+            }
+            KeyCode::Enter => {
+                self.mode = Mode::Normal;
+                self.set_status(if self.tree_filter.is_empty() {
+                    String::new()
+                } else {
+                    format!("Tree filter: {} (Esc clears)", self.tree_filter)
+                });
+            }
+            KeyCode::Esc => {
+                let prev = std::mem::take(&mut self.filter_prev);
+                self.set_tree_filter(prev);
+                self.filter_input.clear();
+                self.mode = Mode::Normal;
+            }
+            _ => {}
+        }
+    }
+
     /// Push the current input buffer into the panel as a live filter.
     fn apply_live_filter(&mut self) {
         let q = self.filter_input.clone();
@@ -13,6 +13,53 @@ impl App {
         self.mode = Mode::Find;
     }
 
+    /// `Space ;`: reopen Find with the last query and scope.
+    pub(super) fn reopen_last_find(&mut self) {
+        let Some((scope, query, root)) = self.last_find.clone() else {
+            self.set_status("No previous find".into());
+            return;
+        };
+        let base = self.active_panel().path.clone();
+        let fs = match scope {
+            FindScope::Local => {
+                let mut fs = FindState::new_local(&base);
+                fs.query = query;
+                fs.update_filter();
+                fs
+            }
+            FindScope::Global => {
+                // A `:findin` root that has since gone searches `$HOME` again.
+                let mut fs = if root.is_dir() {
+                    FindState::new_global_in(&base, &root)
+                } else {
+                    FindState::new_global(&base)
+                };
+                fs.query = query;
+                fs.trigger_search();
+                fs
+            }
+            FindScope::Content => FindState::new_content(&base, &query),
+        };
+        self.find_state = Some(fs);
+        self.mode = Mode::Find;
+    }
+
+    /// Close the overlay, remembering a non-empty query for `Space ;`.
+    fn close_find(&mut self) -> Option<FindState> {
+        self.mode = Mode::Normal;
+        let fs = self.find_state.take()?;
+        if !fs.query.is_empty() {
+            let scope = fs.scope;
+            let query = fs.query.clone();
+            let root = fs.global_root().to_path_buf();
+            self.last_find = Some((scope, query.clone(), root.clone()));
+            self.db_spawn(move |db| {
+                let _ = db.save_last_find(scope.label(), &query, &root);
+            });
+        }
+        Some(fs)
+    }
+
     pub(super) fn handle_find(&mut self, key: KeyEvent) {
         let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
 
@@ -35,10 +82,57 @@ impl App {
             return;
         }
 
+        // Bulk actions: Space and `a` are query characters here, so picking
+        // and acting on the picked set live on Ctrl.
+        if ctrl {
+            match key.code {
+                KeyCode::Char(' ') => {
+                    if let Some(ref mut fs) = self.find_state {
+                        fs.toggle_picked();
+                    }
+                    return;
+                }
+                KeyCode::Char('a') => {
+                    if let Some(ref mut fs) = self.find_state {
+                        fs.toggle_pick_all();
+                    }
+                    return;
+                }
+                KeyCode::Char('t') => {
+                    if let Some(ref mut fs) = self.find_state {
+                        fs.cycle_kind();
+                    }
+                    return;
+                }
+                KeyCode::Char('r') => {
+                    if let Some(ref mut fs) = self.find_state
+                        && fs.scope == FindScope::Global
+                    {
+                        fs.toggle_global_root();
+                    }
+                    return;
+                }
+                // Drop a pre-filled (or typed) query and start over blank.
+                KeyCode::Char('u') => {
+                    if let Some(ref mut fs) = self.find_state {
+                        fs.query.clear();
+                        if matches!(fs.scope, FindScope::Global | FindScope::Content) {
+                            fs.trigger_search();
+                        } else {
+                            fs.update_filter();
+                        }
+                    }
+                    return;
+                }
+                KeyCode::Char('y') => return self.yank_find_results(),
+                KeyCode::Char('d') => return self.delete_find_results(),
+                _ => {}
+            }
+        }
+
         match key.code {
             KeyCode::Esc => {
-                self.find_state = None;
-                self.mode = Mode::Normal;
+                self.close_find();
             }
             KeyCode::Enter => {
                 self.accept_find();
@@ -54,7 +148,7 @@ impl App {
                 if let Some(ref mut fs) = self.find_state {
                     fs.query.pop();
                     if matches!(fs.scope, FindScope::Global | FindScope::Content) {
-                        fs.trigger_search();
+                        fs.schedule_search();
                     } else {
                         fs.update_filter();
                     }
@@ -64,7 +158,7 @@ impl App {
                 if let Some(ref mut fs) = self.find_state {
                     fs.query.push(c);
                     if matches!(fs.scope, FindScope::Global | FindScope::Content) {
-                        fs.trigger_search();
+                        fs.schedule_search();
                     } else {
                         fs.update_filter();
                     }
@@ -74,21 +168,46 @@ impl App {
         }
     }
 
+    /// Close the overlay and hand its targets (picked results, else the one
+    /// under the cursor) to a bulk action.
+    fn take_find_targets(&mut self) -> Vec<(PathBuf, bool)> {
+        self.close_find()
+            .map(|fs| fs.action_targets())
+            .unwrap_or_default()
+    }
+
+    /// Ctrl-y: put the results in the register, ready for `p`/`P`.
+    fn yank_find_results(&mut self) {
+        let entries: Vec<ops::RegisterEntry> = self
+            .take_find_targets()
+            .into_iter()
+            .map(|(path, is_dir)| ops::RegisterEntry { path, is_dir })
+            .collect();
+        if entries.is_empty() {
+            self.set_status("Nothing to yank".into());
+            return;
+        }
+        let n = entries.len();
+        self.register = Some(Register {
+            entries,
+            op: RegisterOp::Yank,
+        });
+        self.set_status(format!("Yanked {n} item(s)"));
+    }
+
+    /// Ctrl-d: delete the results through the usual confirmation (trash or
+    /// permanent per `delete_to_trash`).
+    fn delete_find_results(&mut self) {
+        let items = self.take_find_targets();
+        self.confirm_permanent = !self.config.delete_to_trash;
+        self.request_delete_paths(items);
+    }
+
     fn accept_find(&mut self) {
-        let target = self
-            .find_state
-            .as_ref()
-            .and_then(|fs| fs.selected_path())
-            .map(|p| p.to_path_buf());
-        let is_dir = self
-            .find_state
-            .as_ref()
-            .map(|fs| fs.selected_is_dir())
-            .unwrap_or(false);
-        let line = self.find_state.as_ref().and_then(|fs| fs.selected_line());
-
-        self.find_state = None;
-        self.mode = Mode::Normal;
+        let Some(fs) = self.close_find() else { return };
+        let target = fs.selected_path().map(|p| p.to_path_buf());
+        let is_dir = fs.selected_is_dir();
+        let line = fs.selected_line();
 
         let Some(path) = target else { return };
 
@@ -98,6 +217,7 @@ impl App {
             return;
         }
 
+        self.reveal_in_tree(&path);
         let side = self.tab().active;
         if is_dir {
             self.navigate_cached(path, side, None);
@@ -211,6 +331,120 @@ mod tests {
         assert_eq!(new_scope, FindScope::Global);
     }
 
+    #[tokio::test]
+    async fn find_pick_and_yank_results() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        let mut fs = FindState::new_test(
+            std::path::Path::new("/test"),
+            &[("a.tmp", false), ("b.rs", false), ("sub/c.tmp", false)],
+        );
+        fs.query = "tmp".into();
+        fs.update_filter();
+        app.find_state = Some(fs);
+        app.mode = Mode::Find;
+
+        // Space is still a query character; Ctrl-Space picks.
+        app.handle_find(ctrl('a'));
+        assert_eq!(app.find_state.as_ref().unwrap().picked_count(), 2);
+        app.handle_find(ctrl(' '));
+        assert_eq!(app.find_state.as_ref().unwrap().picked_count(), 1);
+
+        app.handle_find(ctrl('y'));
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.find_state.is_none());
+        let reg = app.register.as_ref().unwrap();
+        assert_eq!(reg.entries.len(), 1);
+        assert_eq!(reg.op, RegisterOp::Yank);
+    }
+
+    #[tokio::test]
+    async fn find_delete_picked_opens_confirm() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        let fs = FindState::new_test(
+            std::path::Path::new("/test"),
+            &[("a.tmp", false), ("old/", true)],
+        );
+        app.find_state = Some(fs);
+        app.mode = Mode::Find;
+        app.handle_find(ctrl('a'));
+        app.handle_find(ctrl('d'));
+        assert_eq!(app.mode, Mode::Confirm);
+        assert!(!app.confirm_permanent);
+        assert_eq!(
+            app.confirm_paths,
+            vec![
+                (PathBuf::from("/test/a.tmp"), false),
+                (PathBuf::from("/test/old/"), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn action_targets_dedup_and_fall_back_to_cursor() {
+        let mut fs = FindState::new_test(
+            std::path::Path::new("/test"),
+            &[("a.rs", false), ("b.rs", false)],
+        );
+        assert_eq!(
+            fs.action_targets(),
+            vec![(PathBuf::from("/test/a.rs"), false)]
+        );
+        fs.toggle_pick_all();
+        assert_eq!(fs.action_targets().len(), 2);
+        assert!(fs.is_picked(1));
+        // Picking all again with everything picked clears the set.
+        fs.toggle_pick_all();
+        assert_eq!(fs.picked_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn closing_find_remembers_query_for_reopen() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.reopen_last_find();
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.status_message, "No previous find");
+
+        let mut fs = FindState::new_test(std::path::Path::new("/test"), &[("a.rs", false)]);
+        fs.query = "conf".into();
+        app.find_state = Some(fs);
+        app.mode = Mode::Find;
+        app.handle_find(key(KeyCode::Esc));
+        assert_eq!(
+            app.last_find.as_ref().map(|f| (f.0, f.1.as_str())),
+            Some((FindScope::Local, "conf"))
+        );
+
+        app.handle_key(key(KeyCode::Char(' ')));
+        app.handle_key(key(KeyCode::Char(';')));
+        assert_eq!(app.mode, Mode::Find);
+        assert_eq!(app.find_state.as_ref().unwrap().query, "conf");
+
+        // Ctrl-u resets to a blank search; closing blank keeps the old query.
+        app.handle_find(ctrl('u'));
+        assert_eq!(app.find_state.as_ref().unwrap().query, "");
+        app.handle_find(key(KeyCode::Esc));
+        assert_eq!(
+            app.last_find.as_ref().map(|f| (f.0, f.1.as_str())),
+            Some((FindScope::Local, "conf"))
+        );
+    }
+
+    #[tokio::test]
+    async fn reopened_global_find_keeps_its_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.last_find = Some((FindScope::Global, "foo".into(), dir.path().to_path_buf()));
+        app.reopen_last_find();
+        let fs = app.find_state.as_ref().unwrap();
+        assert_eq!(fs.scope, FindScope::Global);
+        assert_eq!(fs.global_root(), dir.path());
+        assert_eq!(fs.query, "foo");
+    }
+
     #[tokio::test]
     async fn accept_find_empty_is_noop() {
         let entries = make_test_entries(&["a.txt"]);
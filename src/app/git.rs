@@ -23,13 +23,11 @@ impl App {
         }
     }
 
-    /// Spawn a background task to fetch git status for visible panels.
+    /// Spawn a background task to fetch git status for visible panels. A
+    /// fetch still in flight is for directories the panels have since left
+    /// (or for a tree that has since changed), so it is dropped, which aborts
+    /// it: its result can never be applied to the wrong directory.
     pub fn refresh_git_status(&mut self) {
-        // Skip if a git fetch is already in progress
-        if self.git_progress.is_some() {
-            return;
-        }
-
         let tab = &self.tabs[self.active_tab];
         let dirs = [
             tab.panels[0].path.clone(),
@@ -45,7 +43,7 @@ impl App {
         ];
 
         let (tx, rx) = tokio::sync::oneshot::channel();
-        tokio::spawn(async move {
+        let task = tokio::spawn(async move {
             let (statuses, roots, checked_dirs) = compute_git_status(dirs).await;
             let _ = tx.send(GitMsg::Finished {
                 statuses,
@@ -54,7 +52,10 @@ impl App {
             });
         });
 
-        self.git_progress = Some(GitProgress { rx });
+        self.git_progress = Some(GitProgress {
+            rx,
+            task: Some(task),
+        });
     }
 }
 
@@ -358,15 +359,20 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn refresh_git_skips_if_already_in_progress() {
+    async fn refresh_git_supersedes_a_fetch_in_flight() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
         let mut app = App::new_for_test(entries);
+        let (stale_tx, rx) = tokio::sync::oneshot::channel();
+        app.git_progress = Some(GitProgress { rx, task: None });
         app.refresh_git_status();
         assert!(app.git_progress.is_some());
-        // Second call should be a no-op
-        app.git_checked_dirs = [None, None, None]; // reset to detect re-assignment
-        app.refresh_git_status();
-        // Should not have re-assigned checked_dirs since progress is in flight
-        assert_eq!(app.git_checked_dirs, [None, None, None]);
+        assert_eq!(app.git_checked_dirs[0], Some(PathBuf::from("/test")));
+        // The stale fetch has nowhere to deliver its result.
+        let stale = GitMsg::Finished {
+            statuses: HashMap::new(),
+            roots: [None, None, None],
+            checked_dirs: [None, None, None],
+        };
+        assert!(stale_tx.send(stale).is_err());
     }
 }
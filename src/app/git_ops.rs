@@ -25,7 +25,7 @@ impl App {
     fn run_git_stage(&mut self, stage: bool) {
         let paths = self.active_panel().targeted_paths();
         if paths.is_empty() {
-            self.status_message = "No file under cursor".into();
+            self.set_status("No file under cursor".into());
             return;
         }
         let dir = self.active_panel().path.clone();
@@ -49,7 +49,7 @@ impl App {
             .selected_entry()
             .filter(|e| e.name != "..")
         else {
-            self.status_message = "No file under cursor".into();
+            self.set_status("No file under cursor".into());
             return;
         };
         let path = entry.path.clone();
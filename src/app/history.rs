@@ -0,0 +1,168 @@
+use super::*;
+
+/// Most entries kept per history kind (in memory and in the DB).
+pub const HISTORY_CAP: usize = 200;
+
+/// Readline-style recall list for a single-line input (`:` commands, `/` search).
+/// Up walks back from the newest entry; Down walks forward and finally restores
+/// whatever was typed before recall started.
+#[derive(Default)]
+pub struct InputHistory {
+    entries: Vec<String>,
+    cursor: Option<usize>,
+    draft: String,
+}
+
+impl InputHistory {
+    /// Build from stored entries, oldest first.
+    pub fn from_entries(entries: Vec<String>) -> Self {
+        InputHistory {
+            entries,
+            ..Default::default()
+        }
+    }
+
+    /// Record a submitted entry. Empty input and repeats of the newest entry are
+    /// dropped; returns whether it was added so callers know to persist it.
+    pub fn push(&mut self, entry: &str) -> bool {
+        self.reset();
+        if entry.is_empty() || self.entries.last().is_some_and(|l| l == entry) {
+            return false;
+        }
+        self.entries.push(entry.to_string());
+        if self.entries.len() > HISTORY_CAP {
+            self.entries.remove(0);
+        }
+        true
+    }
+
+    /// Step to the previous (older) entry. `current` is remembered on the first
+    /// step so Down can bring it back.
+    pub fn prev(&mut self, current: &str) -> Option<&str> {
+        let idx = match self.cursor {
+            None => {
+                if self.entries.is_empty() {
+                    return None;
+                }
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+            Some(0) => 0,
+            Some(i) => i - 1,
+        };
+        self.cursor = Some(idx);
+        self.entries.get(idx).map(String::as_str)
+    }
+
+    /// Step to the next (newer) entry, ending on the saved draft.
+    pub fn next(&mut self) -> Option<&str> {
+        let i = self.cursor?;
+        if i + 1 < self.entries.len() {
+            self.cursor = Some(i + 1);
+            self.entries.get(i + 1).map(String::as_str)
+        } else {
+            self.cursor = None;
+            Some(self.draft.as_str())
+        }
+    }
+
+    /// Leave recall mode (a new input session starts at the newest entry again).
+    pub fn reset(&mut self) {
+        self.cursor = None;
+        self.draft.clear();
+    }
+}
+
+impl App {
+    /// Remember a submitted command line and persist it.
+    pub(super) fn record_command_history(&mut self, entry: &str) {
+        if self.command_history.push(entry) {
+            let entry = entry.to_string();
+            self.db_spawn(move |db| {
+                let _ = db.push_history("command", &entry, HISTORY_CAP);
+            });
+        }
+    }
+
+    /// Remember a submitted search pattern and persist it.
+    pub(super) fn record_search_history(&mut self, entry: &str) {
+        if self.search_history.push(entry) {
+            let entry = entry.to_string();
+            self.db_spawn(move |db| {
+                let _ = db.push_history("search", &entry, HISTORY_CAP);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[test]
+    fn push_dedupes_consecutive_and_caps() {
+        let mut h = InputHistory::default();
+        assert!(h.push("a"));
+        assert!(!h.push("a"));
+        assert!(!h.push(""));
+        assert!(h.push("b"));
+        assert!(h.push("a"));
+        assert_eq!(h.entries, vec!["a", "b", "a"]);
+
+        for i in 0..HISTORY_CAP + 5 {
+            h.push(&i.to_string());
+        }
+        assert_eq!(h.entries.len(), HISTORY_CAP);
+        assert_eq!(h.entries.last().unwrap(), &(HISTORY_CAP + 4).to_string());
+    }
+
+    #[test]
+    fn prev_next_walks_and_restores_draft() {
+        let mut h = InputHistory::from_entries(vec!["one".into(), "two".into()]);
+        assert_eq!(h.next(), None);
+        assert_eq!(h.prev("dra"), Some("two"));
+        assert_eq!(h.prev("two"), Some("one"));
+        // Clamped at the oldest entry.
+        assert_eq!(h.prev("one"), Some("one"));
+        assert_eq!(h.next(), Some("two"));
+        assert_eq!(h.next(), Some("dra"));
+        assert_eq!(h.next(), None);
+    }
+
+    #[test]
+    fn prev_on_empty_history_is_none() {
+        let mut h = InputHistory::default();
+        assert_eq!(h.prev("x"), None);
+    }
+
+    #[tokio::test]
+    async fn command_up_recalls_previous_command() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.enter_command();
+        for c in "hidden".chars() {
+            app.handle_command(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        app.handle_command(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        app.enter_command();
+        app.handle_command(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+        app.handle_command(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(app.command_input, "hidden");
+        app.handle_command(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        assert_eq!(app.command_input, "x");
+    }
+
+    #[tokio::test]
+    async fn search_up_recalls_and_jumps() {
+        let entries = crate::app::make_test_entries(&["alpha", "beta"]);
+        let mut app = App::new_for_test(entries);
+        app.search_history.push("bet");
+
+        app.enter_search();
+        app.handle_search(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(app.search_query, "bet");
+        assert_eq!(app.active_panel().selected, 2);
+    }
+}
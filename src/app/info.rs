@@ -19,6 +19,7 @@ impl App {
         let is_dir = entry.is_dir;
         let is_symlink = entry.is_symlink;
         let git_status = self.git_statuses.get(&path).copied();
+        let tree_root = self.start_dir.clone();
 
         // Show loading placeholder immediately
         self.info_lines = vec![
@@ -51,6 +52,17 @@ impl App {
                 "File".into()
             };
             lines.push(("Type".into(), type_str));
+            if is_symlink {
+                let real_root =
+                    std::fs::canonicalize(&tree_root).unwrap_or_else(|_| tree_root.clone());
+                let side = if crate::fs::link_escapes(&path, &real_root) {
+                    "Outside"
+                } else {
+                    "Inside"
+                };
+                let root = crate::search::abbreviate_home(&tree_root.to_string_lossy());
+                lines.push(("Target".into(), format!("{side} the tree root {root}")));
+            }
 
             // Full path
             let abs_path = std::fs::canonicalize(&path)
@@ -95,8 +107,8 @@ impl App {
             if let Some(ref m) = meta {
                 let uid = m.uid();
                 let gid = m.gid();
-                let user_name = get_user_name(uid).unwrap_or_else(|| uid.to_string());
-                let group_name = get_group_name(gid).unwrap_or_else(|| gid.to_string());
+                let user_name = crate::fs::perms::user_name(uid);
+                let group_name = crate::fs::perms::group_name(gid);
                 lines.push(("Owner".into(), format!("{user_name} ({uid})")));
                 lines.push(("Group".into(), format!("{group_name} ({gid})")));
             }
@@ -246,55 +258,6 @@ fn format_datetime(time: std::time::SystemTime) -> String {
     dt.format("%Y-%m-%d %H:%M:%S").to_string()
 }
 
-#[cfg(unix)]
-fn get_user_name(uid: u32) -> Option<String> {
-    // getpwuid shares static libc storage with getpwent (and other lookups),
-    // so hold the same lock to avoid a data race across blocking threads.
-    let _guard = crate::app::chmod::PWGRP_LOCK
-        .lock()
-        .unwrap_or_else(|e| e.into_inner());
-    unsafe {
-        let pw = libc::getpwuid(uid);
-        if pw.is_null() {
-            return None;
-        }
-        Some(
-            std::ffi::CStr::from_ptr((*pw).pw_name)
-                .to_string_lossy()
-                .into_owned(),
-        )
-    }
-}
-
-#[cfg(not(unix))]
-fn get_user_name(_uid: u32) -> Option<String> {
-    None
-}
-
-#[cfg(unix)]
-fn get_group_name(gid: u32) -> Option<String> {
-    // See get_user_name: getgrgid shares static libc storage; hold the lock.
-    let _guard = crate::app::chmod::PWGRP_LOCK
-        .lock()
-        .unwrap_or_else(|e| e.into_inner());
-    unsafe {
-        let gr = libc::getgrgid(gid);
-        if gr.is_null() {
-            return None;
-        }
-        Some(
-            std::ffi::CStr::from_ptr((*gr).gr_name)
-                .to_string_lossy()
-                .into_owned(),
-        )
-    }
-}
-
-#[cfg(not(unix))]
-fn get_group_name(_gid: u32) -> Option<String> {
-    None
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,8 @@
 use super::*;
 
+/// Largest count prefix accepted; more digits are ignored.
+const MAX_COUNT: usize = 9999;
+
 impl App {
     pub(super) fn handle_normal(&mut self, key: KeyEvent) {
         // Delegate to tree handler when tree is focused
@@ -16,27 +19,40 @@ impl App {
             return;
         }
 
+        if self.config.keymap == crate::config::Keymap::Mc && self.handle_mc_key(key) {
+            self.pending_count = None;
+            return;
+        }
+
         let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
         let shift = key.modifiers.contains(KeyModifiers::SHIFT);
 
+        // Count prefix for `dd`/`dD`/`yy` (`3dd`); `0` only extends a count.
+        if let KeyCode::Char(c @ '0'..='9') = key.code
+            && !ctrl
+            && (c != '0' || self.pending_count.is_some())
+        {
+            let digit = c as usize - '0' as usize;
+            let n = self.pending_count.unwrap_or(0) * 10 + digit;
+            self.pending_count = Some(n.min(MAX_COUNT));
+            self.pending_key_time = Some(Instant::now());
+            return;
+        }
+        // Only `d` and `y` carry the count on to their second key.
+        let count = self.pending_count.take();
+
         match key.code {
             KeyCode::Char('q') => self.request_quit(),
             KeyCode::F(1) => self.mode = Mode::Help,
             KeyCode::F(2) => self.enter_rename(),
+            KeyCode::F(5) if shift => self.pull_from_other_panel(RegisterOp::Yank),
+            KeyCode::F(6) if shift => self.pull_from_other_panel(RegisterOp::Cut),
             KeyCode::F(5) => self.copy_to_other_panel(),
             KeyCode::F(6) => self.move_to_other_panel(),
             KeyCode::F(7) => self.enter_create(),
             KeyCode::F(8) => self.request_delete(),
             KeyCode::F(10) => self.request_quit(),
-            KeyCode::F(4) => {
-                if let Some(entry) = self.active_panel().selected_entry()
-                    && !entry.is_dir
-                    && entry.name != ".."
-                {
-                    let path = entry.path.clone();
-                    self.request_open_editor(path);
-                }
-            }
+            KeyCode::F(4) => self.edit_targeted(),
             KeyCode::F(3) => {
                 if let Some(entry) = self.active_panel().selected_entry()
                     && !entry.is_dir
@@ -48,12 +64,12 @@ impl App {
             }
             KeyCode::Esc => {
                 if self.active_panel_mut().clear_filter() {
-                    self.status_message = "Filter cleared".into();
+                    self.set_status("Filter cleared".into());
                 } else {
                     let n = self.active_panel().marked.len();
                     if n > 0 {
                         self.active_panel_mut().marked.clear();
-                        self.status_message = format!("Selection cleared ({n} item(s))");
+                        self.set_status(format!("Selection cleared ({n} item(s))"));
                     }
                 }
             }
@@ -68,6 +84,8 @@ impl App {
 
             // Panel ops: equalize (sync other panels to this dir), MC-style `=`
             KeyCode::Char('=') => self.equalize_panels(),
+            // Swap the two panels' directories, cursors and sorts for a reverse copy
+            KeyCode::Char('\\') => self.swap_panels(),
 
             // Focus & navigation
             KeyCode::Char('l') if ctrl => self.focus_next(),
@@ -83,24 +101,14 @@ impl App {
                 let half = self.visible_height / 2;
                 self.active_panel_mut().page_up(half);
             }
+            KeyCode::Enter if self.chooser && self.choose_targeted() => {}
             KeyCode::Char('l') | KeyCode::Right | KeyCode::Enter => {
                 let entry_info = self
                     .active_panel()
                     .selected_entry()
                     .map(|e| (e.is_dir, e.name == ".."));
                 match entry_info {
-                    Some((false, _)) if key.code == KeyCode::Enter => {
-                        // Enter on archive → open archive overlay
-                        if let Some(entry) = self.active_panel().selected_entry() {
-                            if crate::archive::is_archive(&entry.path) {
-                                self.open_archive();
-                            } else {
-                                // Enter on file → open viewer
-                                let path = entry.path.clone();
-                                self.open_viewer(path);
-                            }
-                        }
-                    }
+                    Some((false, _)) => self.open_selected_file(),
                     Some((_, true)) if key.code == KeyCode::Enter => {
                         // Enter on ".." → go to parent
                         self.go_parent_async();
@@ -117,6 +125,14 @@ impl App {
             KeyCode::Char('~') => {
                 self.go_home_async();
             }
+            KeyCode::Char(']') => {
+                self.pending_key = Some(']');
+                self.pending_key_time = Some(Instant::now());
+            }
+            KeyCode::Char('[') => {
+                self.pending_key = Some('[');
+                self.pending_key_time = Some(Instant::now());
+            }
             KeyCode::Tab => {
                 let layout = self.layout;
                 self.tab_mut().cycle_panel(layout);
@@ -132,10 +148,12 @@ impl App {
             KeyCode::Char('d') => {
                 self.pending_key = Some('d');
                 self.pending_key_time = Some(Instant::now());
+                self.pending_count = count;
             }
             KeyCode::Char('y') => {
                 self.pending_key = Some('y');
                 self.pending_key_time = Some(Instant::now());
+                self.pending_count = count;
             }
             KeyCode::Char('s') => {
                 self.pending_key = Some('s');
@@ -150,6 +168,8 @@ impl App {
                 self.pending_key_time = Some(Instant::now());
             }
 
+            KeyCode::Char('p') if ctrl => self.open_palette(),
+
             // File operations
             KeyCode::Char('p') => self.paste(false),
             KeyCode::Char('P') => self.paste(true),
@@ -158,6 +178,11 @@ impl App {
                 self.pending_key = Some(' ');
                 self.pending_key_time = Some(Instant::now());
             }
+            // Overview of the everyday keys; the next key still runs as usual.
+            KeyCode::Char('?') => {
+                self.pending_key = Some('?');
+                self.pending_key_time = Some(Instant::now());
+            }
 
             // Mode switches
             KeyCode::Char('v') | KeyCode::Char('V') => self.enter_visual(),
@@ -190,17 +215,10 @@ impl App {
             // Toggles & settings
             KeyCode::Char('r') if ctrl => self.refresh_current_panel(),
             KeyCode::Char('T') => self.enter_theme_picker(),
+            KeyCode::Char('S') => self.cycle_sort(),
 
             // Open in editor
-            KeyCode::Char('o') => {
-                if let Some(entry) = self.active_panel().selected_entry()
-                    && !entry.is_dir
-                    && entry.name != ".."
-                {
-                    let path = entry.path.clone();
-                    self.request_open_editor(path);
-                }
-            }
+            KeyCode::Char('o') => self.edit_targeted(),
 
             // Preview scroll
             KeyCode::Char('J') => {
@@ -218,7 +236,9 @@ impl App {
                 if let KeyCode::Char(c) = key.code
                     && !c.is_ascii()
                 {
-                    self.status_message = "\u{f11c} Switch to English layout".into();
+                    self.set_status(crate::util::icons::label(
+                        "\u{f11c} Switch to English layout",
+                    ));
                 }
             }
         }
@@ -226,6 +246,7 @@ impl App {
 
     /// Handle two-key sequences (gg, dd, yy, etc). Returns true if consumed.
     fn handle_pending_sequence(&mut self, pending: char, key: KeyEvent) -> bool {
+        let count = self.pending_count.take();
         match (pending, key.code) {
             ('g', KeyCode::Char('g')) => self.active_panel_mut().go_top(),
             ('g', KeyCode::Char('t')) => self.next_tab(),
@@ -236,12 +257,32 @@ impl App {
             ('g', KeyCode::Char('d')) => self.git_diff(),
             // Follow the symlink under the cursor to its target.
             ('g', KeyCode::Char('f')) => self.follow_symlink(),
-            ('d', KeyCode::Char('d')) => self.request_delete(),
-            ('d', KeyCode::Char('D')) => self.request_permanent_delete(),
-            ('y', KeyCode::Char('y')) => self.yank_targeted(),
+            ('g', KeyCode::Char('p')) => self.enter_breadcrumb(),
+            ('g', KeyCode::Char('/')) => self.go_root(),
+            ('g', KeyCode::Char('.')) => self.go_launch_dir(),
+            ('g', KeyCode::Char('v')) => self.mark_range_to_anchor(),
+            ('?', KeyCode::Char('?')) => self.mode = Mode::Help,
+            ('d', KeyCode::Char('d')) => match count {
+                Some(n) => self.request_delete_count(n, false),
+                None => self.request_delete(),
+            },
+            ('d', KeyCode::Char('D')) => match count {
+                Some(n) => self.request_delete_count(n, true),
+                None => self.request_permanent_delete(),
+            },
+            ('y', KeyCode::Char('y')) => match count {
+                Some(n) => self.yank_count(n),
+                None => self.yank_targeted(),
+            },
             ('y', KeyCode::Char('p')) => self.yank_path(),
+            ('y', KeyCode::Char('r')) => self.yank_relative_path(),
             ('y', KeyCode::Char('n')) => self.yank_name(),
+            ('y', KeyCode::Char('c')) => self.yank_contents(),
             ('\'', KeyCode::Char(c)) if c.is_ascii_lowercase() => self.goto_mark(c),
+            (']', KeyCode::Char(']')) => self.goto_sibling_dir(true),
+            ('[', KeyCode::Char('[')) => self.goto_sibling_dir(false),
+            (']', KeyCode::Char('m')) => self.jump_next_visual_mark(),
+            ('[', KeyCode::Char('m')) => self.jump_prev_visual_mark(),
             ('s', KeyCode::Char('n')) => self.set_sort(SortMode::Name),
             ('s', KeyCode::Char('s')) => self.set_sort(SortMode::Size),
             ('s', KeyCode::Char('d')) | ('s', KeyCode::Char('m')) => {
@@ -254,24 +295,31 @@ impl App {
             ('c', KeyCode::Char('p')) => self.enter_chmod(),
             ('c', KeyCode::Char('o')) => self.enter_chown(),
             ('c', KeyCode::Char('w')) => self.enter_bulk_rename(),
+            ('c', KeyCode::Char('W')) => self.edit_bulk_rename_in_editor(),
             // Layout
             ('w', KeyCode::Char('1')) => self.set_layout(PanelLayout::Single),
             ('w', KeyCode::Char('2')) => self.set_layout(PanelLayout::Dual),
             ('w', KeyCode::Char('3')) => self.set_layout(PanelLayout::Triple),
             ('w', KeyCode::Char('s')) => self.swap_panels(),
             ('w', KeyCode::Char('e')) => self.equalize_panels(),
+            ('w', KeyCode::Char('o')) => self.follow_other_panel(),
             // Space as leader key
             (' ', KeyCode::Char('t')) => self.toggle_tree(),
             (' ', KeyCode::Char('h')) => self.toggle_hidden(),
+            (' ', KeyCode::Char('o')) => self.toggle_owner_column(),
+            (' ', KeyCode::Char('T')) => self.toggle_relative_dates(),
             (' ', KeyCode::Char('p')) => self.preview_mode = !self.preview_mode,
+            (' ', KeyCode::Char('P')) => self.flip_preview_side(),
             (' ', KeyCode::Char('w')) => {
                 self.pending_key = Some('w');
                 self.pending_key_time = Some(Instant::now());
             }
             (' ', KeyCode::Char('d')) => self.start_du(),
+            (' ', KeyCode::Char('D')) => self.toggle_auto_dir_sizes(),
             (' ', KeyCode::Char('f')) => self.enter_filter(),
             (' ', KeyCode::Char(',')) => self.open_find_local(),
             (' ', KeyCode::Char('.')) => self.open_find_global(),
+            (' ', KeyCode::Char(';')) => self.reopen_last_find(),
             (' ', KeyCode::Char('s')) => {
                 self.pending_key = Some('s');
                 self.pending_key_time = Some(Instant::now());
@@ -301,12 +349,16 @@ impl App {
     pub(super) fn enter_search(&mut self) {
         self.search_saved_cursor = self.active_panel().selected;
         self.search_query.clear();
+        self.search_history.reset();
+        self.line_editor.reset();
         self.mode = Mode::Search;
     }
 
     pub(super) fn enter_command(&mut self) {
         self.mode = Mode::Command;
         self.command_input.clear();
+        self.command_history.reset();
+        self.line_editor.reset();
     }
 
     pub(super) fn enter_rename(&mut self) {
@@ -319,13 +371,45 @@ impl App {
             None => return,
         };
         self.rename_input = entry.name.clone();
+        self.line_editor.reset();
         self.mode = Mode::Rename;
     }
 
     pub(super) fn enter_create(&mut self) {
         self.rename_input.clear();
+        self.line_editor.reset();
+        self.create_dir_only = false;
         self.mode = Mode::Create;
     }
+
+    /// The create prompt, but the name always becomes a directory (mc's `F7`).
+    pub(super) fn enter_mkdir(&mut self) {
+        self.enter_create();
+        self.create_dir_only = true;
+    }
+
+    /// Midnight Commander keys that differ from the default map, consulted
+    /// first when `keymap = "mc"`. Returns whether the key was handled; the
+    /// F-keys mc shares with the default map fall through to it.
+    fn handle_mc_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            // mc's F3 on a directory "views" it by entering it.
+            KeyCode::F(3) => {
+                let Some(entry) = self.active_panel().selected_entry() else {
+                    return false;
+                };
+                match (entry.is_dir, entry.name == "..") {
+                    (_, true) => self.go_parent_async(),
+                    (true, false) => self.enter_dir_async(),
+                    (false, false) => return false,
+                }
+            }
+            KeyCode::F(7) => self.enter_mkdir(),
+            KeyCode::Insert => self.active_panel_mut().toggle_mark(),
+            _ => return false,
+        }
+        true
+    }
 }
 
 #[cfg(test)]
@@ -333,6 +417,34 @@ mod tests {
     use super::*;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+    #[tokio::test]
+    async fn which_key_hints_name_keys_that_are_handled() {
+        for prefix in [' ', 'g', 's', 'w', 'u', 'c', 'd', ']', '['] {
+            let mut app = App::new_for_test(crate::app::make_test_entries(&["a.txt"]));
+            app.config.which_key = crate::config::WhichKey::Always;
+            app.pending_key = Some(prefix);
+            app.pending_key_time = Some(Instant::now());
+            let hints = app.which_key_hints().unwrap();
+            for (key, _) in hints.into_iter().filter(|(k, _)| !k.is_empty()) {
+                // "m/d" lists two keys for one action.
+                let keys: Vec<&str> = if key.len() > 1 {
+                    key.split('/').collect()
+                } else {
+                    vec![key]
+                };
+                for k in keys {
+                    let c = k.chars().next().unwrap();
+                    let event = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+                    assert!(
+                        app.handle_pending_sequence(prefix, event),
+                        "hint {prefix:?} {k} has no handler"
+                    );
+                    app.mode = Mode::Normal;
+                }
+            }
+        }
+    }
+
     #[tokio::test]
     async fn handle_normal_q_quits() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -341,6 +453,18 @@ mod tests {
         assert!(app.should_quit);
     }
 
+    #[tokio::test]
+    async fn backslash_swaps_panels() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.set_layout(PanelLayout::Dual);
+        app.tab_mut().panels[1].path = PathBuf::from("/right");
+        app.handle_normal(KeyEvent::new(KeyCode::Char('\\'), KeyModifiers::NONE));
+        assert_eq!(app.tab().panels[0].path, PathBuf::from("/right"));
+        assert_eq!(app.tab().panels[1].path, PathBuf::from("/test"));
+        assert_eq!(app.tab().active, 0);
+    }
+
     #[tokio::test]
     async fn handle_normal_f1_opens_help() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -453,6 +577,8 @@ mod tests {
             is_on_path: true,
             is_expanded: false,
             depth: 0,
+            unreadable: false,
+            link_escapes: None,
         }];
         // 'q' should reach tree handler and set should_quit
         app.handle_normal(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE));
@@ -545,6 +671,29 @@ mod tests {
         assert_eq!(app.mode, Mode::Normal); // stayed Normal
     }
 
+    #[tokio::test]
+    async fn mc_keymap_adds_insert_marking_and_f7_mkdir() {
+        let entries = crate::app::make_test_entries(&["a.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 1;
+        // The default map leaves Insert alone.
+        app.handle_key(KeyEvent::new(KeyCode::Insert, KeyModifiers::NONE));
+        assert!(app.active_panel().marked.is_empty());
+
+        app.config.keymap = crate::config::Keymap::Mc;
+        app.handle_key(KeyEvent::new(KeyCode::Insert, KeyModifiers::NONE));
+        assert!(
+            app.active_panel()
+                .marked
+                .contains(&PathBuf::from("/test/a.txt"))
+        );
+        assert_eq!(app.active_panel().selected, 2);
+
+        app.handle_key(KeyEvent::new(KeyCode::F(7), KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Create);
+        assert!(app.create_dir_only);
+    }
+
     #[tokio::test]
     async fn enter_create_clears_input() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
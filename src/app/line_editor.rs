@@ -0,0 +1,155 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Readline-style cursor editing shared by the single-line inputs (`:` command,
+/// `/` search, rename, create). Only one of them is active at a time, so App keeps
+/// a single editor and hands it whichever string is being edited.
+#[derive(Default)]
+pub struct LineEditor {
+    /// Cursor as a char index into the text; `None` pins it to the end, so code
+    /// that replaces the text wholesale (prefill, history recall) needn't touch it.
+    cursor: Option<usize>,
+}
+
+impl LineEditor {
+    /// Current cursor position (char index), clamped to `text`.
+    pub fn cursor(&self, text: &str) -> usize {
+        let len = text.chars().count();
+        self.cursor.map_or(len, |c| c.min(len))
+    }
+
+    /// Put the cursor back at the end (a new input session).
+    pub fn reset(&mut self) {
+        self.cursor = None;
+    }
+
+    /// Apply an editing key to `text`. Returns false for keys that aren't
+    /// editing keys (Enter, Esc, Up/Down, ...) so the caller can handle them.
+    pub fn handle(&mut self, text: &mut String, key: KeyEvent) -> bool {
+        let len = text.chars().count();
+        let pos = self.cursor(text);
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        let new_pos = match key.code {
+            KeyCode::Char('a') if ctrl => 0,
+            KeyCode::Char('e') if ctrl => len,
+            KeyCode::Char('u') if ctrl => {
+                text.replace_range(..byte_index(text, pos), "");
+                0
+            }
+            KeyCode::Char('w') if ctrl => {
+                let start = word_start(text, pos);
+                text.replace_range(byte_index(text, start)..byte_index(text, pos), "");
+                start
+            }
+            KeyCode::Char(_) if ctrl => return false,
+            KeyCode::Char(c) => {
+                text.insert(byte_index(text, pos), c);
+                pos + 1
+            }
+            KeyCode::Backspace => {
+                if pos > 0 {
+                    text.remove(byte_index(text, pos - 1));
+                }
+                pos.saturating_sub(1)
+            }
+            KeyCode::Delete => {
+                if pos < len {
+                    text.remove(byte_index(text, pos));
+                }
+                pos
+            }
+            KeyCode::Left => pos.saturating_sub(1),
+            KeyCode::Right => (pos + 1).min(len),
+            KeyCode::Home => 0,
+            KeyCode::End => len,
+            _ => return false,
+        };
+
+        self.cursor = (new_pos < text.chars().count()).then_some(new_pos);
+        true
+    }
+}
+
+/// Byte offset of the char at `idx` (or the end of the string).
+fn byte_index(text: &str, idx: usize) -> usize {
+    text.char_indices().nth(idx).map_or(text.len(), |(b, _)| b)
+}
+
+/// Start of the word before `pos` for Ctrl-w. `/` counts as a separator along
+/// with whitespace so a path loses one component at a time.
+fn word_start(text: &str, pos: usize) -> usize {
+    let chars: Vec<char> = text.chars().collect();
+    let is_sep = |c: char| c.is_whitespace() || c == '/';
+    let mut i = pos;
+    while i > 0 && is_sep(chars[i - 1]) {
+        i -= 1;
+    }
+    while i > 0 && !is_sep(chars[i - 1]) {
+        i -= 1;
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    #[test]
+    fn insert_at_cursor_after_moving_left() {
+        let mut ed = LineEditor::default();
+        let mut text = "ac".to_string();
+        assert!(ed.handle(&mut text, key(KeyCode::Left)));
+        ed.handle(&mut text, key(KeyCode::Char('b')));
+        assert_eq!(text, "abc");
+        assert_eq!(ed.cursor(&text), 2);
+
+        ed.handle(&mut text, ctrl('a'));
+        ed.handle(&mut text, key(KeyCode::Delete));
+        assert_eq!(text, "bc");
+        ed.handle(&mut text, ctrl('e'));
+        ed.handle(&mut text, key(KeyCode::Backspace));
+        assert_eq!(text, "b");
+    }
+
+    #[test]
+    fn ctrl_w_deletes_previous_word_or_path_component() {
+        let mut ed = LineEditor::default();
+        let mut text = "cd /usr/local/".to_string();
+        ed.handle(&mut text, ctrl('w'));
+        assert_eq!(text, "cd /usr/");
+        ed.handle(&mut text, ctrl('w'));
+        assert_eq!(text, "cd /");
+        ed.handle(&mut text, ctrl('w'));
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn ctrl_u_clears_to_start_keeping_tail() {
+        let mut ed = LineEditor::default();
+        let mut text = "héllo world".to_string();
+        for _ in 0..5 {
+            ed.handle(&mut text, key(KeyCode::Left));
+        }
+        ed.handle(&mut text, ctrl('u'));
+        assert_eq!(text, "world");
+        assert_eq!(ed.cursor(&text), 0);
+    }
+
+    #[test]
+    fn non_editing_keys_are_not_consumed() {
+        let mut ed = LineEditor::default();
+        let mut text = "x".to_string();
+        assert!(!ed.handle(&mut text, key(KeyCode::Enter)));
+        assert!(!ed.handle(&mut text, key(KeyCode::Up)));
+        assert!(!ed.handle(&mut text, ctrl('z')));
+        assert_eq!(text, "x");
+    }
+}
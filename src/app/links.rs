@@ -0,0 +1,108 @@
+use super::*;
+
+impl App {
+    /// Symlinks listed in the panels whose flag isn't known yet.
+    fn unscanned_links(&self) -> impl Iterator<Item = &PathBuf> {
+        self.tab()
+            .panels
+            .iter()
+            .flat_map(|p| p.entries.iter())
+            .filter(|e| e.is_symlink && !self.link_escapes.contains_key(&e.path))
+            .map(|e| &e.path)
+    }
+
+    pub(super) fn links_due(&self) -> bool {
+        self.link_scan_rx.is_some() || self.unscanned_links().next().is_some()
+    }
+
+    /// Find out which of the panels' symlinks point outside the tree's root
+    /// (`start_dir`). Each link is resolved once; the flags are kept until
+    /// the root moves.
+    pub fn poll_links(&mut self) {
+        self.poll_link_scan();
+        if self.link_scan_rx.is_some() {
+            return;
+        }
+        if self.link_escapes_root != self.start_dir {
+            self.link_escapes.clear();
+            self.link_escapes_root = self.start_dir.clone();
+        }
+        let links: Vec<PathBuf> = self.unscanned_links().cloned().collect();
+        if links.is_empty() {
+            return;
+        }
+        let root = self.start_dir.clone();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.link_scan_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let real_root = std::fs::canonicalize(&root).unwrap_or_else(|_| root.clone());
+            let escapes = links
+                .into_iter()
+                .map(|link| {
+                    let escapes = crate::fs::link_escapes(&link, &real_root);
+                    (link, escapes)
+                })
+                .collect();
+            let _ = tx.send(LinkScanResult { root, escapes });
+        });
+    }
+
+    fn poll_link_scan(&mut self) {
+        let Some(ref mut rx) = self.link_scan_rx else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(result) => {
+                self.link_scan_rx = None;
+                // A scan against an old root would flag the wrong links.
+                if result.root == self.start_dir {
+                    self.link_escapes.extend(result.escapes);
+                }
+            }
+            Err(tokio::sync::oneshot::error::TryRecvError::Closed) => {
+                self.link_scan_rx = None;
+            }
+            Err(tokio::sync::oneshot::error::TryRecvError::Empty) => {}
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn panel_symlinks_are_flagged_against_the_tree_root() {
+        use std::os::unix::fs::symlink;
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("root");
+        std::fs::create_dir(&root).unwrap();
+        std::fs::write(root.join("a.txt"), "").unwrap();
+        symlink("a.txt", root.join("near")).unwrap();
+        symlink("/", root.join("far")).unwrap();
+
+        let mut entries = crate::app::make_test_entries(&["near", "far"]);
+        for e in &mut entries[1..] {
+            e.path = root.join(&e.name);
+            e.is_symlink = true;
+        }
+        let mut app = App::new_for_test(entries);
+        app.start_dir = root.clone();
+        assert!(app.links_due());
+
+        app.poll_links();
+        while app.link_scan_rx.is_some() {
+            tokio::task::yield_now().await;
+            app.poll_links();
+        }
+        assert_eq!(app.link_escapes.get(&root.join("near")), Some(&false));
+        assert_eq!(app.link_escapes.get(&root.join("far")), Some(&true));
+        assert!(!app.links_due());
+
+        // Moving the root drops the flags, to be worked out again.
+        app.start_dir = dir.path().to_path_buf();
+        app.poll_links();
+        assert!(app.link_escapes.is_empty());
+        assert!(app.link_scan_rx.is_some());
+    }
+}
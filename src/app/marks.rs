@@ -23,7 +23,7 @@ impl App {
             self.db_spawn(move |db| {
                 let _ = db.remove_visual_mark(&p);
             });
-            self.status_message = format!("Unmarked: {name}");
+            self.set_status(format!("Unmarked: {name}"));
         } else {
             self.visual_marks.insert(path.clone(), next_level);
             self.db_spawn(move |db| {
@@ -35,7 +35,7 @@ impl App {
                 3 => "●3",
                 _ => "●",
             };
-            self.status_message = format!("{label} {name}");
+            self.set_status(format!("{label} {name}"));
         }
     }
 
@@ -54,26 +54,88 @@ impl App {
             self.db_spawn(move |db| {
                 let _ = db.remove_visual_mark(&path);
             });
-            self.status_message = format!("Unmarked: {name}");
+            self.set_status(format!("Unmarked: {name}"));
         } else {
-            self.status_message = "No mark here".into();
+            self.set_status("No mark here".into());
         }
     }
 
     pub(super) fn jump_next_visual_mark(&mut self) {
+        self.jump_visual_mark(true);
+    }
+
+    pub(super) fn jump_prev_visual_mark(&mut self) {
+        self.jump_visual_mark(false);
+    }
+
+    /// Move the cursor to the next (or previous) visually marked entry in the
+    /// visible listing, wrapping around the ends.
+    fn jump_visual_mark(&mut self, forward: bool) {
         let panel = self.active_panel();
         let len = panel.entries.len();
         if len == 0 {
             return;
         }
-        let start = (panel.selected + 1) % len;
-        let pos = (0..len)
-            .map(|i| (start + i) % len)
+        let step = if forward { 1 } else { len - 1 };
+        let pos = (1..=len)
+            .map(|i| (panel.selected + i * step) % len)
             .find(|&i| self.visual_marks.contains_key(&panel.entries[i].path));
         match pos {
             Some(pos) => self.active_panel_mut().selected = pos,
-            None => self.status_message = "No marks".into(),
+            None => self.set_status("No marks".into()),
+        }
+    }
+
+    /// `:marked [1-3]`: narrow the active panel to entries carrying a visual
+    /// mark, optionally only those at `level`. Esc or leaving the directory
+    /// restores the full listing.
+    pub(super) fn filter_visual_marks(&mut self, level: Option<u8>) {
+        let panel = self.active_panel();
+        let listing = if panel.filter_active() {
+            &panel.full_entries
+        } else {
+            &panel.entries
+        };
+        let paths: HashSet<PathBuf> = listing
+            .iter()
+            .filter(|e| {
+                self.visual_marks
+                    .get(&e.path)
+                    .is_some_and(|&l| level.is_none_or(|want| l == want))
+            })
+            .map(|e| e.path.clone())
+            .collect();
+        let what = match level {
+            Some(l) => format!("level {l}"),
+            None => "any level".to_string(),
+        };
+        if paths.is_empty() {
+            self.set_status(format!("No entries marked at {what} here"));
+            return;
+        }
+        let count = paths.len();
+        let flag = crate::util::icons::glyph("\u{f024}", "*");
+        let label = match level {
+            Some(l) => format!("{flag}{l}"),
+            None => flag.to_string(),
+        };
+        self.active_panel_mut()
+            .set_path_filter(Some(PathFilter { label, paths }));
+        self.set_status(format!("Showing {count} entries marked at {what}"));
+    }
+
+    /// `gv`: mark everything between the last marked entry and the cursor,
+    /// without going through Visual mode, so several ranges can pile up.
+    pub(super) fn mark_range_to_anchor(&mut self) {
+        let Some(added) = self.active_panel_mut().mark_to_anchor() else {
+            self.set_status("No marked entry here to extend from".into());
+            return;
+        };
+        let total = self.active_panel().marked.len();
+        if total > 0 {
+            self.mode = Mode::Select;
         }
+        self.set_status(format!("Selected {added} more ({total} in all)"));
     }
 
     pub(super) fn select_all(&mut self) {
@@ -85,7 +147,7 @@ impl App {
                 count += 1;
             }
         }
-        self.status_message = format!("Selected {count} items");
+        self.set_status(format!("Selected {count} items"));
     }
 
     pub(super) fn select_all_and_enter_select(&mut self) {
@@ -95,13 +157,13 @@ impl App {
 
     pub(super) fn unselect_all(&mut self) {
         self.active_panel_mut().marked.clear();
-        self.status_message = "Selection cleared".into();
+        self.set_status("Selection cleared".into());
     }
 
     pub(super) fn set_mark(&mut self, c: char) {
         let path = self.active_panel().path.clone();
         self.marks.insert(c, path);
-        self.status_message = format!("Mark '{c}' set");
+        self.set_status(format!("Mark '{c}' set"));
     }
 
     pub(super) fn goto_mark(&mut self, c: char) {
@@ -109,17 +171,13 @@ impl App {
             let (tx, rx) = tokio::sync::oneshot::channel();
             self.nav_check_rx = Some(rx);
             tokio::task::spawn_blocking(move || {
-                let exists = path.exists();
-                let is_dir = path.is_dir();
-                let _ = tx.send(super::NavCheckResult {
+                let _ = tx.send(super::NavCheckResult::probe(
                     path,
-                    is_dir,
-                    exists,
-                    source: super::NavSource::Mark(c),
-                });
+                    super::NavSource::Mark(c),
+                ));
             });
         } else {
-            self.status_message = format!("Mark '{c}' not set");
+            self.set_status(format!("Mark '{c}' not set"));
         }
     }
 }
@@ -127,6 +185,7 @@ impl App {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     #[tokio::test]
     async fn toggle_visual_mark_cycles_levels() {
@@ -217,6 +276,55 @@ mod tests {
         assert!(app.status_message.contains("No marks"));
     }
 
+    #[tokio::test]
+    async fn bracket_m_jumps_between_marks_both_ways() {
+        let entries = make_test_entries(&["a.txt", "b.txt", "c.txt", "d.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.visual_marks.insert(PathBuf::from("/test/b.txt"), 1);
+        app.visual_marks.insert(PathBuf::from("/test/d.txt"), 3);
+        app.active_panel_mut().selected = 3; // c.txt
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+        app.handle_normal(key(']'));
+        app.handle_normal(key('m'));
+        assert_eq!(app.active_panel().selected, 4); // d.txt
+        app.handle_normal(key('['));
+        app.handle_normal(key('m'));
+        assert_eq!(app.active_panel().selected, 2); // b.txt
+        app.handle_normal(key('['));
+        app.handle_normal(key('m'));
+        assert_eq!(app.active_panel().selected, 4); // wraps back to d.txt
+    }
+
+    #[tokio::test]
+    async fn filter_visual_marks_by_level() {
+        let entries = make_test_entries(&["a.txt", "b.txt", "c.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.visual_marks.insert(PathBuf::from("/test/a.txt"), 1);
+        app.visual_marks.insert(PathBuf::from("/test/c.txt"), 2);
+        let names = |app: &App| -> Vec<String> {
+            app.active_panel()
+                .entries
+                .iter()
+                .map(|e| e.name.clone())
+                .collect()
+        };
+
+        app.filter_visual_marks(Some(2));
+        assert_eq!(names(&app), ["..", "c.txt"]);
+        app.filter_visual_marks(None);
+        assert_eq!(names(&app), ["..", "a.txt", "c.txt"]);
+
+        // A level with no marks leaves the current view alone.
+        app.filter_visual_marks(Some(3));
+        assert_eq!(names(&app), ["..", "a.txt", "c.txt"]);
+        assert!(app.status_message.contains("No entries marked"));
+
+        app.handle_normal(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(names(&app).len(), 4);
+        assert!(app.active_panel().path_filter.is_none());
+    }
+
     #[tokio::test]
     async fn select_all_marks_all_non_dotdot() {
         let entries = make_test_entries(&["a.txt", "b.txt", "c.txt"]);
@@ -271,4 +379,30 @@ mod tests {
         app.goto_mark('a');
         assert!(app.nav_check_rx.is_some());
     }
+
+    #[tokio::test]
+    async fn gv_marks_from_the_last_mark_to_the_cursor() {
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        let entries = make_test_entries(&["a", "b", "c", "d", "e"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 1;
+        app.handle_key(key('g'));
+        app.handle_key(key('v'));
+        assert!(app.status_message.contains("No marked entry"));
+
+        app.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT)); // marks "a"
+        assert_eq!(app.mode, Mode::Select);
+        app.handle_key(key('j'));
+        app.handle_key(key('j'));
+        app.handle_key(key('g'));
+        app.handle_key(key('v'));
+        let mut marked: Vec<_> = app.active_panel().marked.iter().cloned().collect();
+        marked.sort();
+        let want: Vec<PathBuf> = ["a", "b", "c", "d"]
+            .iter()
+            .map(|n| PathBuf::from("/test").join(n))
+            .collect();
+        assert_eq!(marked, want);
+        assert_eq!(app.status_message, "Selected 3 more (4 in all)");
+    }
 }
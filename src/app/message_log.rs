@@ -0,0 +1,126 @@
+use super::*;
+
+/// Most messages kept for `:messages`.
+pub const MESSAGE_LOG_CAP: usize = 200;
+/// Rows the `:messages` popup shows at once (fewer on a short terminal).
+pub const MESSAGES_ROWS: usize = 16;
+
+/// One entry in the `:messages` history.
+pub struct LoggedMessage {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub text: String,
+    pub error: bool,
+}
+
+impl App {
+    /// Show `msg` in the status line and remember it for `:messages`.
+    pub fn set_status(&mut self, msg: String) {
+        self.log_message(&msg, false);
+        self.status_message = msg;
+    }
+
+    /// Append to the message history. A repeat of the newest entry (e.g. "No
+    /// match" while typing a search) only refreshes its timestamp.
+    pub(super) fn log_message(&mut self, text: &str, error: bool) {
+        if text.is_empty() {
+            return;
+        }
+        let at = chrono::Local::now();
+        if let Some(last) = self.message_log.back_mut()
+            && last.text == text
+        {
+            last.at = at;
+            return;
+        }
+        if self.message_log.len() == MESSAGE_LOG_CAP {
+            self.message_log.pop_front();
+        }
+        self.message_log.push_back(LoggedMessage {
+            at,
+            text: text.to_string(),
+            error,
+        });
+    }
+
+    pub(super) fn open_messages(&mut self) {
+        if self.message_log.is_empty() {
+            self.set_status("No messages".into());
+            return;
+        }
+        // Start at the bottom: the newest message is what the user missed.
+        self.messages_scroll = self.messages_max_scroll();
+        self.mode = Mode::Messages;
+    }
+
+    fn messages_max_scroll(&self) -> usize {
+        self.message_log.len().saturating_sub(MESSAGES_ROWS)
+    }
+
+    pub(super) fn handle_messages(&mut self, key: KeyEvent) {
+        let max = self.messages_max_scroll();
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.messages_scroll = (self.messages_scroll + 1).min(max);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.messages_scroll = self.messages_scroll.saturating_sub(1);
+            }
+            KeyCode::Char('g') => self.messages_scroll = 0,
+            KeyCode::Char('G') => self.messages_scroll = max,
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => self.mode = Mode::Normal,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[tokio::test]
+    async fn set_status_logs_and_collapses_repeats() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.set_status("one".into());
+        app.set_status("two".into());
+        app.set_status("two".into());
+        app.show_toast("rename: denied", true);
+        let texts: Vec<&str> = app.message_log.iter().map(|m| m.text.as_str()).collect();
+        assert_eq!(texts, ["one", "two", "rename: denied"]);
+        assert!(app.message_log.back().unwrap().error);
+        assert_eq!(app.status_message, "two");
+    }
+
+    #[tokio::test]
+    async fn log_is_capped() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        for i in 0..MESSAGE_LOG_CAP + 3 {
+            app.set_status(format!("m{i}"));
+        }
+        assert_eq!(app.message_log.len(), MESSAGE_LOG_CAP);
+        assert_eq!(app.message_log.front().unwrap().text, "m3");
+    }
+
+    #[tokio::test]
+    async fn messages_command_opens_popup() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.open_messages();
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.status_message, "No messages");
+
+        app.enter_command();
+        for c in "messages".chars() {
+            app.handle_command(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        app.handle_command(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Messages);
+
+        app.handle_messages(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+        assert_eq!(app.messages_scroll, 0);
+        app.handle_messages(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Normal);
+    }
+}
@@ -8,6 +8,15 @@ pub struct ArchiveLoadResult {
     )>,
 }
 
+/// A timed operation result ("Trashed 5 item(s) \u{2014} u to undo", errors) shown
+/// in the tab bar. Unlike `status_message` it survives keypresses and expires
+/// on its own.
+pub struct Toast {
+    pub text: String,
+    pub error: bool,
+    pub shown_at: Instant,
+}
+
 pub struct PhantomEntry {
     pub name: String,
     pub is_dir: bool,
@@ -16,6 +25,8 @@ pub struct PhantomEntry {
 pub struct DuProgress {
     pub rx: tokio::sync::mpsc::Receiver<DuMsg>,
     pub started_at: Instant,
+    /// Started by `auto_dir_sizes` rather than `Space d`: finishes silently.
+    pub auto: bool,
 }
 
 #[derive(Debug)]
@@ -29,6 +40,17 @@ pub enum GitMsg {
 
 pub struct GitProgress {
     pub rx: tokio::sync::oneshot::Receiver<GitMsg>,
+    /// The fetch itself. Dropping the progress aborts it, and with it any
+    /// `git` still running (they are `kill_on_drop`).
+    pub task: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl Drop for GitProgress {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
 }
 
 pub enum DeleteMsg {
@@ -49,6 +71,24 @@ pub enum DeleteMsg {
     },
 }
 
+/// Progress of `:genchecksums`.
+pub enum ChecksumMsg {
+    Progress {
+        done: usize,
+        total: usize,
+        current: String,
+    },
+    Finished {
+        /// Files listed in the sums file.
+        count: usize,
+        /// The sums file, when this run created it (rather than replaced it).
+        created: Option<PathBuf>,
+        error: Option<String>,
+        /// Cancelled before the sums file was written.
+        cancelled: bool,
+    },
+}
+
 pub enum ArchiveMsg {
     Progress {
         done: usize,
@@ -67,6 +107,9 @@ pub enum ArchiveMsg {
         cancelled: bool,
         /// Archive name (create) or extracted entry / "all entries" (extract).
         label: String,
+        /// The archive a create made or the directory an extract made, for
+        /// undo; `None` when it replaced or extracted into an existing one.
+        created: Option<PathBuf>,
     },
 }
 
@@ -85,6 +128,8 @@ pub enum DirLoadMsg {
         path: PathBuf,
         entries: Vec<FileEntry>,
         select_name: Option<String>,
+        /// Why the directory couldn't be read; `entries` then holds only `..`.
+        error: Option<String>,
     },
 }
 
@@ -128,6 +173,12 @@ pub struct TreeLoadResult {
     pub data: Vec<crate::model::tree::TreeLine>,
 }
 
+/// Symlinks resolved against the tree's `root`: whether each points outside it.
+pub struct LinkScanResult {
+    pub root: PathBuf,
+    pub escapes: Vec<(PathBuf, bool)>,
+}
+
 pub struct ChownLoadResult {
     pub users: Vec<(String, u32)>,
     pub groups: Vec<(String, u32)>,
@@ -136,18 +187,59 @@ pub struct ChownLoadResult {
     pub paths: Vec<PathBuf>,
 }
 
+/// `:pipe`: run `command` through the shell with `paths` on its stdin, one per
+/// line. Executed by the main loop with the TUI suspended.
+pub struct PipeRequest {
+    pub command: String,
+    pub paths: Vec<PathBuf>,
+}
+
 /// Result of an async path validation for navigation.
 pub struct NavCheckResult {
     pub path: PathBuf,
     pub is_dir: bool,
     pub exists: bool,
+    /// For a missing target, its closest existing ancestor directory.
+    pub nearest: Option<PathBuf>,
+    /// For a missing `:cd` target with a wildcard, the directories it matches.
+    pub matches: Vec<PathBuf>,
     pub source: NavSource,
 }
 
+impl NavCheckResult {
+    /// Stat `path` for a jump from `source`. Blocking; run it off the UI thread.
+    pub fn probe(path: PathBuf, source: NavSource) -> Self {
+        let exists = path.exists();
+        let nearest = if exists {
+            None
+        } else {
+            crate::fs::nearest_existing_dir(&path)
+        };
+        // A literal name wins over a wildcard reading of it.
+        let matches = if !exists && matches!(source, NavSource::Cd) {
+            crate::fs::glob_dirs(&path)
+        } else {
+            Vec::new()
+        };
+        NavCheckResult {
+            is_dir: path.is_dir(),
+            exists,
+            nearest,
+            matches,
+            path,
+            source,
+        }
+    }
+}
+
 pub enum NavSource {
     Cd,
     Bookmark,
     Mark(char),
+    /// `]]` / `[[`: `forward` says which way, for the "no sibling" message.
+    Sibling {
+        forward: bool,
+    },
 }
 
 /// Result of an async file operation (mkdir, touch, rename, chmod, chown, undo).
@@ -166,16 +258,17 @@ pub enum FileOpResult {
     },
     Chmod {
         input: String,
-        count: usize,
-        errors: usize,
-        last_error: Option<String>,
+        outcome: ops::BatchOutcome,
     },
     Chown {
         user_name: String,
         group_name: String,
-        count: usize,
-        errors: usize,
-        last_error: Option<String>,
+        outcome: ops::BatchOutcome,
+    },
+    /// `:touch!` set the times of existing files.
+    Retouch {
+        what: String,
+        outcome: ops::BatchOutcome,
     },
     Undo {
         result: Result<String, String>,
@@ -202,6 +295,11 @@ pub enum FileOpResult {
         label: String,
         ok: bool,
     },
+    /// `yc`: a file's text went to the clipboard (`Ok` is the status line),
+    /// or why it didn't.
+    ClipboardText {
+        result: Result<String, String>,
+    },
     BulkRename {
         total: usize,
         records: Vec<ops::OpRecord>,
@@ -213,6 +311,22 @@ pub enum FileOpResult {
         count: usize,
         result: Result<(), String>,
     },
+    /// `:mount` device listing, opening the popup.
+    MountList {
+        result: Result<Vec<crate::fs::mounts::MountDevice>, String>,
+    },
+    /// Mounted `device`; `Ok` is where it landed.
+    Mount {
+        device: String,
+        result: Result<PathBuf, String>,
+    },
+    /// Unmounted `device` from `mount_point`; `Ok(true)` if it was also
+    /// powered off.
+    Unmount {
+        device: String,
+        mount_point: Option<PathBuf>,
+        result: Result<bool, String>,
+    },
     /// `git diff` output for a single file, to be shown in the viewer.
     GitDiff {
         title: String,
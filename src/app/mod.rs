@@ -7,7 +7,8 @@ pub(crate) use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 pub(crate) use crate::fs::du::{self, DuMsg};
 pub(crate) use crate::fs::ops::{self, Register, RegisterOp, UndoStack};
 pub(crate) use crate::fs::perms;
-pub(crate) use crate::model::panel::{self, DirCache, FileEntry, Panel, SortMode};
+pub(crate) use crate::fs::trash::TrashContent;
+pub(crate) use crate::model::panel::{self, DirCache, FileEntry, Panel, PathFilter, SortMode};
 pub(crate) use crate::preview::Preview;
 pub(crate) use crate::search::{FindScope, FindState};
 pub(crate) use crate::theme::Theme;
@@ -15,22 +16,33 @@ pub(crate) use crate::viewer::Viewer;
 
 pub(crate) mod archive;
 mod bookmarks;
+mod breadcrumb;
 pub(crate) mod bulk_rename;
+mod checksum;
 pub(crate) mod chmod;
 mod command;
-mod dialogs;
+pub(crate) mod dialogs;
+mod dry_run;
 mod file_ops;
 mod filter;
 mod find;
 mod git;
 mod git_ops;
+mod history;
 mod info;
 mod input;
+mod line_editor;
+mod links;
 mod marks;
+pub(crate) mod message_log;
 pub mod messages;
+pub(crate) mod mounts;
 mod mouse;
 mod navigation;
+pub(crate) mod palette;
+mod paste_picker;
 mod polling;
+mod remote;
 mod rename;
 mod search;
 mod select_pattern;
@@ -41,8 +53,16 @@ mod tree;
 mod viewer;
 mod visual;
 
+pub use history::InputHistory;
+pub use line_editor::LineEditor;
+pub use message_log::LoggedMessage;
 pub use messages::*;
 pub use mouse::{MouseRegions, PanelRegion};
+pub use paste_picker::{PastePicker, PickAction};
+pub use polling::ANIMATION_TICK;
+
+/// Result of listing the system trash for `:emptytrash`.
+pub type TrashScan = Result<Vec<TrashContent>, String>;
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum Mode {
@@ -52,6 +72,7 @@ pub enum Mode {
     Command,
     Confirm,
     ConfirmQuit,
+    ConfirmEmptyTrash,
     Search,
     Filter,
     Find,
@@ -75,6 +96,12 @@ pub enum Mode {
     Archive,
     Tasks,
     Trash,
+    Plan,
+    Palette,
+    PastePicker,
+    Messages,
+    Breadcrumb,
+    Mounts,
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
@@ -136,9 +163,13 @@ impl Tab {
         &mut self.panels[self.active]
     }
 
+    /// Index of the "other" panel that F5/F6 target and Shift-F5/F6 pull from.
+    pub fn inactive_index(&self, layout: PanelLayout) -> usize {
+        (self.active + 1) % layout.count()
+    }
+
     pub fn inactive_panel_path(&self, layout: PanelLayout) -> PathBuf {
-        let next = (self.active + 1) % layout.count();
-        self.panels[next].path.clone()
+        self.panels[self.inactive_index(layout)].path.clone()
     }
 
     pub fn cycle_panel(&mut self, layout: PanelLayout) {
@@ -153,17 +184,62 @@ impl Tab {
     }
 }
 
+/// A copy of the session state, detached from `App` so it can be written
+/// after the app is gone (on panic).
+pub struct SessionSnapshot {
+    pub tabs: Vec<crate::storage::SavedTab>,
+    pub active_tab: usize,
+    pub layout: &'static str,
+    pub view: crate::storage::SavedView,
+}
+
+impl SessionSnapshot {
+    pub fn write(&self, db: &crate::storage::Db) {
+        if let Err(e) = db.save_session(&self.tabs, self.active_tab) {
+            eprintln!("Warning: failed to save session: {e}");
+        }
+        if let Err(e) = db.save_layout(self.layout) {
+            eprintln!("Warning: failed to save layout: {e}");
+        }
+        if let Err(e) = db.save_view(self.view) {
+            eprintln!("Warning: failed to save view: {e}");
+        }
+    }
+}
+
+/// Whether a terminal `width` columns wide leaves the tree and every panel of
+/// `layout` at least 20 columns each.
+fn tree_fits(width: u16, layout: PanelLayout) -> bool {
+    width as usize >= 20 * (layout.count() + 1)
+}
+
 pub struct App {
     pub tabs: Vec<Tab>,
     pub active_tab: usize,
     pub mode: Mode,
     pub command_input: String,
     pub rename_input: String,
+    /// The create prompt makes a directory whatever the name (mc's `F7`).
+    pub create_dir_only: bool,
     pub should_quit: bool,
-    pub open_editor: Option<PathBuf>,
+    /// `--choosefiles`: Enter on a file picks it (or the marked entries) and quits.
+    pub chooser: bool,
+    /// What the chooser picked, for `main` to write out after quitting.
+    pub chosen: Option<Vec<PathBuf>>,
+    /// Files to hand to `$EDITOR` on the next loop iteration.
+    pub open_editor: Option<Vec<PathBuf>>,
+    /// 1-based line to open `open_editor`'s file at: the viewer's top line
+    /// when `o` is pressed there.
+    pub open_editor_line: Option<usize>,
+    pub pipe_request: Option<PipeRequest>,
+    /// Bulk rename buffer to hand to `$EDITOR` on the next loop iteration;
+    /// `finish_bulk_rename_edit` reads the new names back from it.
+    pub bulk_rename_edit: Option<PathBuf>,
     pub status_message: String,
     pub pending_key: Option<char>,
     pub pending_key_time: Option<Instant>,
+    /// Count typed before `dd`/`dD`/`yy` (`3dd`).
+    pub pending_count: Option<usize>,
     pub visible_height: usize,
     pub register: Option<Register>,
     pub undo_stack: UndoStack,
@@ -171,6 +247,9 @@ pub struct App {
     pub confirm_scroll: usize,
     pub confirm_permanent: bool,
     pub help_scroll: usize,
+    /// `/` in the help overlay: only bindings matching this are shown.
+    pub help_filter: String,
+    pub help_filter_editing: bool,
     // Search
     pub search_query: String,
     pub search_saved_cursor: usize,
@@ -181,11 +260,24 @@ pub struct App {
     pub marks: HashMap<char, PathBuf>,
     // Find
     pub find_state: Option<FindState>,
+    // Scope, query and global root of the last Find closed with a query, for `Space ;`
+    pub last_find: Option<(FindScope, String, PathBuf)>,
     // Layout
     pub layout: PanelLayout,
     // Preview
     pub preview_mode: bool,
+    /// `Space P` moved the preview off `config.preview_side`; saved with the session.
+    pub preview_flipped: bool,
     pub preview: Option<Preview>,
+    /// Where the preview's image goes, set each frame by the renderer; `None`
+    /// when there is none to draw or an overlay is up.
+    pub preview_image_area: Option<ratatui::layout::Rect>,
+    /// The image id the terminal is showing and where, so it is placed again
+    /// only when either changes.
+    pub image_on_screen: Option<(u32, ratatui::layout::Rect)>,
+    /// The image id whose pixels the terminal holds, so a preview's picture
+    /// is uploaded once however often it is hidden and shown.
+    pub image_uploaded: Option<u32>,
     pub(super) preview_path: Option<PathBuf>,
     // Full-screen viewer (file/dir content)
     pub viewer: Option<Viewer>,
@@ -197,13 +289,25 @@ pub struct App {
     pub tree_selected: usize,
     pub tree_scroll: usize,
     pub start_dir: PathBuf,
+    /// The working directory fcmd was started in (`g.` goes back there);
+    /// unlike `start_dir`, the tree never moves it.
+    pub launch_dir: PathBuf,
     pub tree_data: Vec<crate::model::tree::TreeLine>,
     pub tree_collapsed: HashSet<PathBuf>,
     pub tree_expanded: HashSet<PathBuf>,
+    /// Tree quick-filter (`/` with the tree focused); empty when off.
+    pub tree_filter: String,
+    /// The whole tree behind an active `tree_filter`; `tree_data` is the view.
+    pub(super) tree_full: Vec<crate::model::tree::TreeLine>,
+    /// The tree cursor's path before filtering, restored when it clears.
+    pub(super) tree_filter_prev_selected: Option<PathBuf>,
     // Visual marks (persistent colored dots, level 1-3)
     pub visual_marks: HashMap<PathBuf, u8>,
     // Database
     pub db: Option<std::sync::Arc<std::sync::Mutex<crate::storage::Db>>>,
+    /// `--no-session`, or a PATH on the command line: quitting leaves the
+    /// saved session as it was.
+    pub no_session: bool,
     // Animation tick counter (incremented every 250ms for spinner etc.)
     pub tick_count: u32,
     // Task manager (copy/move/delete operations)
@@ -211,9 +315,11 @@ pub struct App {
     /// Cursor/scroll for the task-manager overlay (Mode::Tasks).
     pub tasks_cursor: usize,
     pub tasks_scroll: usize,
-    pub task_notification: Option<String>,
-    /// `tick_count` when the current task notification was set, used to auto-expire it.
-    pub task_notification_tick: Option<u32>,
+    /// Last operation result, kept on screen for a few seconds (see `Toast`).
+    pub toast: Option<Toast>,
+    /// Recent status/toast messages for `:messages`, oldest first.
+    pub message_log: std::collections::VecDeque<LoggedMessage>,
+    pub messages_scroll: usize,
     // Conflict resolution
     /// One conflict channel per in-flight paste task; polled round-robin so concurrent
     /// pastes don't clobber each other's conflict prompts.
@@ -225,6 +331,10 @@ pub struct App {
     pub dir_sizes: std::sync::Arc<HashMap<PathBuf, u64>>,
     pub du_progress: Option<DuProgress>,
     pub(super) dir_sizes_loaded: HashSet<PathBuf>,
+    /// `auto_dir_sizes`: the directory the active panel was last seen in, and
+    /// since when; `None` once it has been measured.
+    pub(super) auto_du_dir: PathBuf,
+    pub(super) auto_du_since: Option<Instant>,
     /// Ongoing background-work text (e.g. dir-size calculation), shown with a spinner in
     /// the tab bar. Kept separate from `status_message` so progress polls don't clobber
     /// transient user messages and aren't wiped by the per-keypress status clear.
@@ -234,6 +344,8 @@ pub struct App {
     pub tree_last_path: Option<PathBuf>,
     pub tree_last_hidden: bool,
     pub(super) tree_select_path: Option<PathBuf>,
+    /// Settings from `config.toml`.
+    pub config: crate::config::Config,
     // Theme
     pub transparent: bool,
     pub theme: Theme,
@@ -246,7 +358,9 @@ pub struct App {
     pub theme_show_light: bool,  // true = showing light themes, false = dark
     pub theme_active_name: Option<String>,
     pub theme_preview: Option<Theme>,
-    // Per-directory sort preferences
+    // Per-directory sort preferences: the sort a panel picks up when it opens
+    // a directory. Each panel keeps its own sort in memory after that, so two
+    // panels on the same directory can differ; the last one sorted is saved.
     pub dir_sorts: HashMap<PathBuf, (SortMode, bool)>,
     // Bookmarks
     pub bookmarks: Vec<(String, PathBuf)>,
@@ -254,21 +368,60 @@ pub struct App {
     pub bookmark_scroll: usize,
     pub bookmark_rename_old: Option<String>,
     pub bookmark_add_path: Option<PathBuf>,
+    // `:mount` popup
+    pub mount_devices: Vec<crate::fs::mounts::MountDevice>,
+    pub mount_cursor: usize,
     // Trash-restore overlay
     pub trash_cursor: usize,
     pub trash_scroll: usize,
+    // `:emptytrash`: background scan of the system trash, then its contents
+    // while the confirmation is up
+    pub empty_trash_rx: Option<tokio::sync::oneshot::Receiver<TrashScan>>,
+    pub empty_trash_items: Vec<TrashContent>,
+    // `gp`: index into `util::path_segments` of the highlighted title segment
+    pub crumb_selected: usize,
     // Chmod/Chown
     pub chmod_paths: Vec<PathBuf>,
+    /// `r` in the chmod popup: also change everything inside directories.
+    pub chmod_recursive: bool,
     pub chown_picker: Option<chmod::ChownPicker>,
     // Info popup
     pub info_lines: Vec<(String, String)>,
     pub info_scroll: usize,
+    // Up/Down recall for `:` and `/` input
+    pub command_history: InputHistory,
+    pub search_history: InputHistory,
+    // Cursor for whichever single-line input (command/search/rename/create) is open
+    pub line_editor: LineEditor,
+    // Command palette (Ctrl-p): query, ranked indices into PALETTE_ACTIONS, cursor
+    pub palette_query: String,
+    pub palette_matches: Vec<usize>,
+    pub palette_selected: usize,
+    // "Paste where?" picker (`p` with `paste_picker` enabled) and its
+    // background listing of sibling/child directories
+    pub paste_picker: Option<PastePicker>,
+    pub paste_picker_rx: Option<tokio::sync::oneshot::Receiver<Vec<PathBuf>>>,
+    // Dry run: destructive ops show a plan popup instead of executing
+    pub dry_run: bool,
+    pub plan_title: String,
+    pub plan_lines: Vec<String>,
+    pub plan_scroll: usize,
+    /// A paste held back by `confirm_paste_conflicts` / `confirm_move` /
+    /// `confirm_other_panel` while the plan popup lists it: `Enter` starts it,
+    /// any other key drops it. The last field is the panel a pull takes its
+    /// marks from, cleared once the paste starts.
+    pub pending_paste: Option<(Vec<ops::RegisterEntry>, RegisterOp, PathBuf, Option<usize>)>,
     pub(super) info_du_rx: Option<tokio::sync::oneshot::Receiver<(u64, usize, usize)>>,
     // Git status (tracked for panels)
     pub git_statuses: HashMap<PathBuf, char>,
     pub(super) git_roots: [Option<PathBuf>; 3],
     pub(super) git_checked_dirs: [Option<PathBuf>; 3],
     pub(super) git_progress: Option<GitProgress>,
+    /// Panel symlinks checked against `link_escapes_root` (the tree's root
+    /// when they were resolved): whether each points outside it.
+    pub link_escapes: HashMap<PathBuf, bool>,
+    pub(super) link_escapes_root: PathBuf,
+    pub(super) link_scan_rx: Option<tokio::sync::oneshot::Receiver<LinkScanResult>>,
     // Directory cache (LRU)
     pub dir_cache: DirCache,
     // Async dir loading (streaming batches + sorted final result)
@@ -276,6 +429,9 @@ pub struct App {
     pub dir_load_rx: tokio::sync::mpsc::Receiver<DirLoadMsg>,
     // Async preview loading
     pub preview_load_rx: Option<tokio::sync::oneshot::Receiver<PreviewLoadResult>>,
+    /// Set to stop the preview load in flight (an archive listing) once a
+    /// newer one replaces it.
+    pub(super) preview_load_cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
     pub viewer_load_rx: Option<tokio::sync::oneshot::Receiver<ViewerLoadResult>>,
     pub viewer_chunk_rx: Option<tokio::sync::oneshot::Receiver<ViewerChunkResult>>,
     pub viewer_hl_rx: Option<tokio::sync::oneshot::Receiver<ViewerHlResult>>,
@@ -307,10 +463,17 @@ pub struct App {
 }
 
 impl App {
-    pub fn new() -> std::io::Result<Self> {
+    /// `start` is a directory from the command line (with an entry to select
+    /// in it), which replaces the saved session for this launch only: the
+    /// session is not saved over on quit. `no_session` leaves the saved
+    /// session alone too: not restored, not saved.
+    pub fn new(
+        start: Option<(PathBuf, Option<String>)>,
+        no_session: bool,
+    ) -> std::io::Result<Self> {
         let cwd = std::env::current_dir()?;
 
-        let (db, visual_marks, dir_sorts, bookmarks, git_statuses) =
+        let (db, visual_marks, dir_sorts, bookmarks, git_statuses, histories) =
             match crate::storage::Db::init() {
                 Ok(db) => {
                     let marks = db.load_visual_marks().unwrap_or_default();
@@ -323,7 +486,20 @@ impl App {
                         .collect();
                     let bookmarks = db.load_bookmarks().unwrap_or_default();
                     let git_statuses = db.load_git_statuses().unwrap_or_default();
-                    (Some(db), marks, dir_sorts, bookmarks, git_statuses)
+                    let histories = (
+                        db.load_history("command", history::HISTORY_CAP)
+                            .unwrap_or_default(),
+                        db.load_history("search", history::HISTORY_CAP)
+                            .unwrap_or_default(),
+                    );
+                    (
+                        Some(db),
+                        marks,
+                        dir_sorts,
+                        bookmarks,
+                        git_statuses,
+                        histories,
+                    )
                 }
                 Err(e) => {
                     eprintln!("Warning: DB init failed: {e}");
@@ -333,12 +509,42 @@ impl App {
                         HashMap::new(),
                         Vec::new(),
                         HashMap::new(),
+                        (Vec::new(), Vec::new()),
                     )
                 }
             };
 
-        // Restore session from DB
-        let (tabs, active_tab, saved_layout) = if let Some(ref db) = db {
+        // Where to open when there is no session, or a saved panel path is gone:
+        // the last directory visited (even if the session wasn't saved), else cwd.
+        let fallback = db
+            .as_ref()
+            .and_then(|d| d.load_last_dir())
+            .and_then(|p| crate::fs::nearest_existing_dir(&p))
+            .unwrap_or_else(|| cwd.clone());
+
+        let home = PathBuf::from(crate::util::home_dir_string());
+        let last_find =
+            db.as_ref()
+                .and_then(|d| d.load_last_find())
+                .and_then(|(scope, query, root)| {
+                    let scope = FindScope::from_label(&scope)?;
+                    Some((scope, query, root.unwrap_or(home)))
+                });
+
+        // Restore session from DB. A saved directory that has since been removed
+        // opens at its nearest surviving ancestor; the first such case is reported.
+        let mut pruned: Option<(PathBuf, PathBuf)> = None;
+        let (start, select_name) = match start {
+            Some((dir, name)) => (Some(dir), name),
+            None => (None, None),
+        };
+        // A launch for one directory doesn't get to replace the user's tabs.
+        let no_session = no_session || start.is_some();
+        let (tabs, active_tab, saved_layout) = if no_session {
+            let dir = start.unwrap_or_else(|| cwd.clone());
+            let layout = db.as_ref().and_then(|d| d.load_layout());
+            (vec![Tab::new(dir)], 0, layout)
+        } else if let Some(ref db) = db {
             let layout = db.load_layout();
             match db.load_session() {
                 Ok((saved, at)) if !saved.is_empty() => {
@@ -347,14 +553,24 @@ impl App {
                         let paths: Vec<PathBuf> = st
                             .panel_paths
                             .iter()
-                            .map(|p| if p.is_dir() { p.clone() } else { cwd.clone() })
+                            .map(|p| {
+                                if p.is_dir() {
+                                    return p.clone();
+                                }
+                                match crate::fs::nearest_existing_dir(p) {
+                                    Some(dir) => {
+                                        pruned.get_or_insert_with(|| (p.clone(), dir.clone()));
+                                        dir
+                                    }
+                                    None => fallback.clone(),
+                                }
+                            })
                             .collect();
+                        let panel_at = |i: usize| {
+                            Panel::new(paths.get(i).cloned().unwrap_or_else(|| fallback.clone()))
+                        };
                         let tab = Tab {
-                            panels: vec![
-                                Panel::new(paths.first().cloned().unwrap_or_else(|| cwd.clone())),
-                                Panel::new(paths.get(1).cloned().unwrap_or_else(|| cwd.clone())),
-                                Panel::new(paths.get(2).cloned().unwrap_or_else(|| cwd.clone())),
-                            ],
+                            panels: vec![panel_at(0), panel_at(1), panel_at(2)],
                             active: st.active_panel.min(2),
                         };
                         tabs.push(tab);
@@ -362,7 +578,7 @@ impl App {
                     let at = at.min(tabs.len().saturating_sub(1));
                     (tabs, at, layout)
                 }
-                _ => (vec![Tab::new(cwd.clone())], 0, layout),
+                _ => (vec![Tab::new(fallback.clone())], 0, layout),
             }
         } else {
             (vec![Tab::new(cwd.clone())], 0, None)
@@ -370,6 +586,11 @@ impl App {
         let layout = saved_layout
             .and_then(|s| PanelLayout::from_label(&s))
             .unwrap_or(PanelLayout::Dual);
+        let view = db.as_ref().map(|d| d.load_view()).unwrap_or_default();
+        // The tree takes a column of its own; on a narrow terminal it would
+        // squeeze the panels, so it stays closed until asked for.
+        let show_tree =
+            view.tree && crossterm::terminal::size().is_ok_and(|(w, _)| tree_fits(w, layout));
 
         tokio::task::spawn_blocking(Theme::ensure_builtin_themes);
         let transparent = db.as_ref().is_some_and(|d| d.load_transparent());
@@ -379,6 +600,14 @@ impl App {
             None => Theme::from_config(),
         };
         let theme_active_name = saved_theme_name;
+        let (mut config, config_error) = match crate::config::Config::load() {
+            Ok(c) => (c, None),
+            Err(e) => (crate::config::Config::default(), Some(e)),
+        };
+        if view.preview_flipped {
+            config.preview_side = config.preview_side.flipped();
+        }
+        crate::util::icons::set_enabled(config.icons);
 
         let db = db.map(|d| std::sync::Arc::new(std::sync::Mutex::new(d)));
 
@@ -390,11 +619,25 @@ impl App {
             mode: Mode::Normal,
             command_input: String::new(),
             rename_input: String::new(),
+            create_dir_only: false,
             should_quit: false,
+            chooser: false,
+            chosen: None,
             open_editor: None,
-            status_message: String::new(),
+            open_editor_line: None,
+            pipe_request: None,
+            bulk_rename_edit: None,
+            status_message: config_error
+                .or_else(|| {
+                    pruned.map(|(from, to)| {
+                        let (from, to) = (from.display(), to.display());
+                        format!("{from} no longer exists \u{2014} opened {to}")
+                    })
+                })
+                .unwrap_or_default(),
             pending_key: None,
             pending_key_time: None,
+            pending_count: None,
             visible_height: 20,
             register: None,
             undo_stack: UndoStack::new(),
@@ -402,40 +645,55 @@ impl App {
             confirm_scroll: 0,
             confirm_permanent: false,
             help_scroll: 0,
+            help_filter: String::new(),
+            help_filter_editing: false,
             search_query: String::new(),
             search_saved_cursor: 0,
             filter_input: String::new(),
             filter_prev: String::new(),
             marks: HashMap::new(),
             find_state: None,
+            last_find,
             layout,
-            preview_mode: false,
+            preview_mode: view.preview,
+            preview_flipped: view.preview_flipped,
             preview: None,
+            preview_image_area: None,
+            image_on_screen: None,
+            image_uploaded: None,
             preview_path: None,
             viewer: None,
             viewer_visible_height: 20,
-            show_tree: false,
-            tree_focused: false,
+            show_tree,
+            tree_focused: show_tree && view.tree_focused,
             tree_selected: 0,
             tree_scroll: 0,
-            start_dir: cwd,
+            start_dir: cwd.clone(),
+            launch_dir: cwd,
             tree_data: Vec::new(),
             tree_collapsed: HashSet::new(),
             tree_expanded: HashSet::new(),
+            tree_filter: String::new(),
+            tree_full: Vec::new(),
+            tree_filter_prev_selected: None,
             visual_marks,
             dir_sorts,
             db,
+            no_session,
             tick_count: 0,
             task_manager: task_manager::TaskManager::new(),
             tasks_cursor: 0,
             tasks_scroll: 0,
-            task_notification: None,
-            task_notification_tick: None,
+            toast: None,
+            message_log: std::collections::VecDeque::new(),
+            messages_scroll: 0,
             conflict_rxs: Vec::new(),
             conflict_info: None,
             conflict_selected: 0,
             dir_sizes: std::sync::Arc::new(HashMap::new()),
             du_progress: None,
+            auto_du_dir: PathBuf::new(),
+            auto_du_since: None,
             background_progress: None,
             dir_sizes_loaded: HashSet::new(),
             tree_dirty: true,
@@ -443,6 +701,7 @@ impl App {
             tree_last_hidden: false,
             tree_select_path: None,
             transparent,
+            config,
             theme,
             theme_groups: Vec::new(),
             theme_group_cursor: 0,
@@ -456,24 +715,47 @@ impl App {
             bookmarks,
             bookmark_cursor: 0,
             bookmark_scroll: 0,
+            mount_devices: Vec::new(),
+            mount_cursor: 0,
             trash_cursor: 0,
             trash_scroll: 0,
+            empty_trash_rx: None,
+            empty_trash_items: Vec::new(),
+            crumb_selected: 0,
             bookmark_rename_old: None,
             bookmark_add_path: None,
             chmod_paths: Vec::new(),
+            chmod_recursive: false,
             chown_picker: None,
             info_lines: Vec::new(),
             info_scroll: 0,
+            command_history: InputHistory::from_entries(histories.0),
+            search_history: InputHistory::from_entries(histories.1),
+            line_editor: LineEditor::default(),
+            palette_query: String::new(),
+            palette_matches: Vec::new(),
+            palette_selected: 0,
+            paste_picker: None,
+            paste_picker_rx: None,
+            dry_run: false,
+            plan_title: String::new(),
+            plan_lines: Vec::new(),
+            plan_scroll: 0,
+            pending_paste: None,
             info_du_rx: None,
             git_statuses,
             git_roots: [None, None, None],
             git_checked_dirs: [None, None, None],
             git_progress: None,
+            link_escapes: HashMap::new(),
+            link_escapes_root: PathBuf::new(),
+            link_scan_rx: None,
             bulk_rename: None,
             dir_cache: DirCache::new(64),
             dir_load_tx,
             dir_load_rx,
             preview_load_rx: None,
+            preview_load_cancel: std::sync::Arc::default(),
             viewer_load_rx: None,
             viewer_chunk_rx: None,
             viewer_hl_rx: None,
@@ -501,12 +783,15 @@ impl App {
                 }
             }
         }
-        // Spawn async directory loads for all panels in all tabs
+        // Spawn async directory loads for all panels in all tabs; a file named
+        // on the command line gets the cursor in the first panel.
         let saved_active_tab = app.active_tab;
         for tab_idx in 0..app.tabs.len() {
             app.active_tab = tab_idx;
             for panel_idx in 0..3 {
-                app.spawn_dir_load(panel_idx, None);
+                let first = tab_idx == 0 && panel_idx == 0;
+                let select = select_name.clone().filter(|_| first);
+                app.spawn_dir_load(panel_idx, select);
             }
         }
         app.active_tab = saved_active_tab;
@@ -516,9 +801,18 @@ impl App {
     /// Save session and layout to DB. Synchronous — called on shutdown
     /// where fire-and-forget could lose data if the runtime exits first.
     pub fn save_session(&self) {
+        if self.no_session {
+            return;
+        }
         let Some(ref db) = self.db else { return };
         let Ok(db) = db.lock() else { return };
-        let tabs: Vec<crate::storage::SavedTab> = self
+        self.session_snapshot().write(&db);
+    }
+
+    /// Tabs, cursors and layout as `save_session` would write them. The main
+    /// loop keeps a copy after every frame for the panic hook.
+    pub fn session_snapshot(&self) -> SessionSnapshot {
+        let tabs = self
             .tabs
             .iter()
             .map(|t| crate::storage::SavedTab {
@@ -527,11 +821,16 @@ impl App {
                 active_panel: t.active,
             })
             .collect();
-        if let Err(e) = db.save_session(&tabs, self.active_tab) {
-            eprintln!("Warning: failed to save session: {e}");
-        }
-        if let Err(e) = db.save_layout(self.layout.label()) {
-            eprintln!("Warning: failed to save layout: {e}");
+        SessionSnapshot {
+            tabs,
+            active_tab: self.active_tab,
+            layout: self.layout.label(),
+            view: crate::storage::SavedView {
+                preview: self.preview_mode,
+                tree: self.show_tree,
+                tree_focused: self.tree_focused,
+                preview_flipped: self.preview_flipped,
+            },
         }
     }
 
@@ -564,6 +863,8 @@ impl App {
         let show_hidden = panel.show_hidden;
         let sort_mode = panel.sort_mode;
         let sort_reverse = panel.sort_reverse;
+        let case_sensitive = self.config.case_sensitive_sort;
+        let parent_entry = self.config.parent_entry;
         let dir_sizes = self.dir_sizes.clone();
         let tab_index = self.active_tab;
 
@@ -576,6 +877,8 @@ impl App {
                     show_hidden,
                     sort_mode,
                     sort_reverse,
+                    case_sensitive,
+                    parent_entry,
                     dir_sizes,
                     panel_idx,
                     tab_index,
@@ -586,6 +889,15 @@ impl App {
         });
     }
 
+    /// Re-list panel `panel_idx` in place. The cursor comes back on the entry
+    /// it was on, or stays at its row (clamped) if that entry is gone.
+    pub(super) fn reload_panel(&mut self, panel_idx: usize) {
+        let name = self.tab().panels[panel_idx]
+            .selected_entry()
+            .map(|e| e.name.clone());
+        self.spawn_dir_load(panel_idx, name);
+    }
+
     /// Handle a message from the streaming directory loader.
     pub fn handle_dir_load_msg(&mut self, msg: DirLoadMsg) {
         match msg {
@@ -614,6 +926,7 @@ impl App {
                 path,
                 entries,
                 select_name,
+                error,
             } => {
                 if tab_index >= self.tabs.len() {
                     return;
@@ -626,6 +939,14 @@ impl App {
                 if panel.path != path {
                     return;
                 }
+                panel.load_error = error.clone();
+                if let Some(err) = error {
+                    // Not cached: the next visit should try reading it again.
+                    panel.apply_entries(std::sync::Arc::new(entries), None);
+                    panel.loading = false;
+                    self.set_status(format!("{}: {err}", path.display()));
+                    return;
+                }
                 // Share one Arc between the cache and the panel: a refcount bump
                 // instead of deep-cloning the whole listing on every load.
                 let entries = std::sync::Arc::new(entries);
@@ -654,19 +975,39 @@ impl App {
 
     /// Handle async navigation validation result.
     pub fn apply_nav_check(&mut self, result: NavCheckResult) {
+        let side = self.tab().active;
+        match <[PathBuf; 1]>::try_from(result.matches) {
+            Ok([dir]) => {
+                self.navigate_cached(dir, side, None);
+                return;
+            }
+            Err(matches) if !matches.is_empty() => {
+                self.open_dir_picker(matches);
+                return;
+            }
+            Err(_) => {}
+        }
         if !result.exists {
             let label = match result.source {
-                NavSource::Cd => "Not a directory",
-                NavSource::Bookmark => "Bookmark directory no longer exists",
-                NavSource::Mark(c) => {
-                    self.status_message = format!("Mark '{c}' directory no longer exists");
-                    return;
+                NavSource::Cd => "No such file or directory".to_string(),
+                NavSource::Bookmark => "Bookmark directory no longer exists".to_string(),
+                NavSource::Mark(c) => format!("Mark '{c}' directory no longer exists"),
+                NavSource::Sibling { forward } => {
+                    let which = if forward { "next" } else { "previous" };
+                    format!("No {which} sibling directory")
                 }
             };
-            self.status_message = label.into();
+            // Land as close to the vanished target as possible, unless that is
+            // just where we already are (e.g. a typo in `:cd`).
+            if let Some(dir) = result.nearest.filter(|d| *d != self.active_panel().path) {
+                self.set_status(format!("{label} \u{2014} opened {}", dir.display()));
+                self.navigate_cached(dir, side, None);
+                return;
+            }
+            self.set_status(label);
             return;
         }
-        let side = self.tab().active;
+        self.reveal_in_tree(&result.path);
         if result.is_dir {
             self.navigate_cached(result.path, side, None);
         } else {
@@ -688,62 +1029,44 @@ impl App {
                 Ok(rec) => {
                     self.undo_stack.push(vec![rec]);
                     self.refresh_panels_select(Some(name.clone()));
-                    self.status_message = format!("Created directory: {name}");
+                    self.show_toast(
+                        format!("Created directory: {name} \u{2014} u to undo"),
+                        false,
+                    );
                 }
-                Err(e) => self.status_message = format!("mkdir: {e}"),
+                Err(e) => self.show_toast(format!("mkdir: {e}"), true),
             },
             FileOpResult::Touch { name, result } => match result {
                 Ok(rec) => {
                     self.undo_stack.push(vec![rec]);
                     self.refresh_panels_select(Some(name.clone()));
-                    self.status_message = format!("Created file: {name}");
+                    self.show_toast(format!("Created file: {name} \u{2014} u to undo"), false);
                 }
-                Err(e) => self.status_message = format!("touch: {e}"),
+                Err(e) => self.show_toast(format!("touch: {e}"), true),
             },
             FileOpResult::Rename { new_name, result } => match result {
                 Ok(rec) => {
                     self.undo_stack.push(vec![rec]);
                     self.refresh_panels_select(Some(new_name.clone()));
-                    self.status_message = format!("Renamed to: {new_name}");
+                    self.show_toast(format!("Renamed to: {new_name} \u{2014} u to undo"), false);
                 }
-                Err(e) => self.status_message = format!("rename: {e}"),
+                Err(e) => self.show_toast(format!("rename: {e}"), true),
             },
-            FileOpResult::Chmod {
-                input,
-                count,
-                errors,
-                last_error,
-            } => {
-                if errors > 0 {
-                    if let Some(e) = last_error {
-                        self.status_message = format!("chmod: {e}");
-                    }
-                } else {
-                    self.status_message = format!("chmod {input} ({count} item(s))");
-                }
-                self.refresh_panels();
+            FileOpResult::Chmod { input, outcome } => {
+                self.finish_batch(format!("chmod {input}"), outcome);
             }
             FileOpResult::Chown {
                 user_name,
                 group_name,
-                count,
-                errors,
-                last_error,
+                outcome,
             } => {
-                if errors > 0 {
-                    if let Some(e) = last_error {
-                        self.status_message = format!("chown: {e}");
-                    }
-                } else {
-                    self.status_message =
-                        format!("chown {user_name}:{group_name} ({count} item(s))");
-                }
-                self.refresh_panels();
+                self.finish_batch(format!("chown {user_name}:{group_name}"), outcome);
             }
+            FileOpResult::Retouch { what, outcome } => self.finish_batch(what, outcome),
             FileOpResult::Undo { result } => {
                 match result {
-                    Ok(msg) => self.status_message = msg,
-                    Err(e) => self.status_message = format!("Undo error: {e}"),
+                    Ok(msg) => self.show_toast(msg, false),
+                    Err(e) => self.show_toast(format!("Undo error: {e}"), true),
                 }
                 self.refresh_panels();
             }
@@ -757,8 +1080,8 @@ impl App {
                     self.undo_stack.remove_trashed(id);
                 }
                 match result {
-                    Ok(msg) => self.status_message = msg,
-                    Err(e) => self.status_message = format!("Restore error: {e}"),
+                    Ok(msg) => self.show_toast(msg, false),
+                    Err(e) => self.show_toast(format!("Restore error: {e}"), true),
                 }
                 if self.mode == Mode::Trash {
                     let remaining = self.undo_stack.trashed().len();
@@ -795,14 +1118,14 @@ impl App {
                     self.db_spawn(move |db| {
                         let _ = db.save_theme(&n);
                     });
-                    self.status_message = format!("Theme: {name}");
+                    self.set_status(format!("Theme: {name}"));
                 }
-                None => self.status_message = format!("Theme not found: {name}"),
+                None => self.set_status(format!("Theme not found: {name}")),
             },
             FileOpResult::ThemeList { groups } => {
                 if self.mode == Mode::ThemePicker {
                     if groups.is_empty() {
-                        self.status_message = "No themes found".into();
+                        self.set_status("No themes found".into());
                         self.mode = Mode::Normal;
                     } else {
                         self.theme_groups = groups;
@@ -817,19 +1140,23 @@ impl App {
                     all.sort();
                     all.dedup();
                     if all.is_empty() {
-                        self.status_message = "No themes found".into();
+                        self.set_status("No themes found".into());
                     } else {
-                        self.status_message = all.join(", ");
+                        self.set_status(all.join(", "));
                     }
                 }
             }
             FileOpResult::Clipboard { label, ok } => {
-                self.status_message = if ok {
+                self.set_status(if ok {
                     label
                 } else {
                     "Clipboard not available".into()
-                };
+                });
             }
+            FileOpResult::ClipboardText { result } => match result {
+                Ok(label) => self.set_status(label),
+                Err(e) => self.show_toast(format!("yc: {e}"), true),
+            },
             FileOpResult::BulkRename {
                 total,
                 records,
@@ -840,14 +1167,21 @@ impl App {
                     self.undo_stack.push(records);
                 }
                 if errors.is_empty() {
-                    self.status_message = format!("Renamed {ok}/{total} file(s)");
+                    self.show_toast(format!("Renamed {ok}/{total} file(s)"), false);
                 } else {
-                    self.status_message =
-                        format!("Renamed {ok}/{total}, errors: {}", errors.join("; "));
+                    let msg = format!("Renamed {ok}/{total}, errors: {}", errors.join("; "));
+                    self.show_toast(msg, true);
                 }
                 self.refresh_current_panel();
                 self.tree_dirty = true;
             }
+            FileOpResult::MountList { result } => self.apply_mount_list(result),
+            FileOpResult::Mount { device, result } => self.apply_mount(device, result),
+            FileOpResult::Unmount {
+                device,
+                mount_point,
+                result,
+            } => self.apply_unmount(device, mount_point, result),
             FileOpResult::GitStage {
                 staged,
                 count,
@@ -855,14 +1189,14 @@ impl App {
             } => match result {
                 Ok(()) => {
                     let verb = if staged { "Staged" } else { "Unstaged" };
-                    self.status_message = format!("{verb} {count} item(s)");
+                    self.set_status(format!("{verb} {count} item(s)"));
                     self.refresh_git_status();
                 }
-                Err(e) => self.status_message = format!("git: {e}"),
+                Err(e) => self.set_status(format!("git: {e}")),
             },
             FileOpResult::GitDiff { title, path, text } => {
                 if text.trim().is_empty() {
-                    self.status_message = format!("No changes: {title}");
+                    self.set_status(format!("No changes: {title}"));
                 } else {
                     let preview =
                         crate::preview::Preview::from_text(format!("git diff: {title}"), &text);
@@ -911,9 +1245,9 @@ impl App {
 
     pub fn handle_key(&mut self, key: KeyEvent) {
         self.status_message.clear();
-        // NOTE: task_notification is intentionally NOT cleared here. It is auto-expired
-        // by poll_tasks after a few seconds so a completion summary (incl. failures)
-        // survives the next keypress instead of vanishing in a single frame.
+        // NOTE: the toast is intentionally NOT cleared here. It is auto-expired by
+        // poll_tasks after a few seconds so an operation result (incl. failures and
+        // the undo hint) survives the next keypress instead of vanishing in a frame.
 
         match self.mode {
             Mode::Normal => self.handle_normal(key),
@@ -922,6 +1256,7 @@ impl App {
             Mode::Command => self.handle_command(key),
             Mode::Confirm => self.handle_confirm(key),
             Mode::ConfirmQuit => self.handle_confirm_quit(key),
+            Mode::ConfirmEmptyTrash => self.handle_confirm_empty_trash(key),
             Mode::Search => self.handle_search(key),
             Mode::Filter => self.handle_filter(key),
             Mode::Find => self.handle_find(key),
@@ -945,6 +1280,12 @@ impl App {
             Mode::Archive => self.handle_archive(key),
             Mode::Tasks => self.handle_tasks(key),
             Mode::Trash => self.handle_trash(key),
+            Mode::Plan => self.handle_plan(key),
+            Mode::Palette => self.handle_palette(key),
+            Mode::PastePicker => self.handle_paste_picker(key),
+            Mode::Messages => self.handle_messages(key),
+            Mode::Mounts => self.handle_mounts(key),
+            Mode::Breadcrumb => self.handle_breadcrumb(key),
         }
 
         self.update_preview();
@@ -962,6 +1303,7 @@ pub(crate) fn make_test_entries(names: &[&str]) -> Vec<FileEntry> {
         modified: None,
         created: None,
         is_symlink: false,
+        owner: None,
     }];
     for &name in names {
         let is_dir = name.ends_with('/');
@@ -974,6 +1316,7 @@ pub(crate) fn make_test_entries(names: &[&str]) -> Vec<FileEntry> {
             modified: None,
             created: None,
             is_symlink: false,
+            owner: None,
         });
     }
     entries
@@ -1006,11 +1349,18 @@ impl App {
             mode: Mode::Normal,
             command_input: String::new(),
             rename_input: String::new(),
+            create_dir_only: false,
             should_quit: false,
+            chooser: false,
+            chosen: None,
             open_editor: None,
+            open_editor_line: None,
+            pipe_request: None,
+            bulk_rename_edit: None,
             status_message: String::new(),
             pending_key: None,
             pending_key_time: None,
+            pending_count: None,
             visible_height: 20,
             register: None,
             undo_stack: UndoStack::new(),
@@ -1018,15 +1368,22 @@ impl App {
             confirm_scroll: 0,
             confirm_permanent: false,
             help_scroll: 0,
+            help_filter: String::new(),
+            help_filter_editing: false,
             search_query: String::new(),
             search_saved_cursor: 0,
             filter_input: String::new(),
             filter_prev: String::new(),
             marks: HashMap::new(),
             find_state: None,
+            last_find: None,
             layout: PanelLayout::Dual,
             preview_mode: false,
+            preview_flipped: false,
             preview: None,
+            preview_image_area: None,
+            image_on_screen: None,
+            image_uploaded: None,
             preview_path: None,
             viewer: None,
             viewer_visible_height: 20,
@@ -1035,23 +1392,31 @@ impl App {
             tree_selected: 0,
             tree_scroll: 0,
             start_dir: PathBuf::from("/test"),
+            launch_dir: PathBuf::from("/test"),
             tree_data: Vec::new(),
             tree_collapsed: HashSet::new(),
             tree_expanded: HashSet::new(),
+            tree_filter: String::new(),
+            tree_full: Vec::new(),
+            tree_filter_prev_selected: None,
             visual_marks: HashMap::new(),
             dir_sorts: HashMap::new(),
             db,
+            no_session: false,
             tick_count: 0,
             task_manager: task_manager::TaskManager::new(),
             tasks_cursor: 0,
             tasks_scroll: 0,
-            task_notification: None,
-            task_notification_tick: None,
+            toast: None,
+            message_log: std::collections::VecDeque::new(),
+            messages_scroll: 0,
             conflict_rxs: Vec::new(),
             conflict_info: None,
             conflict_selected: 0,
             dir_sizes: std::sync::Arc::new(HashMap::new()),
             du_progress: None,
+            auto_du_dir: PathBuf::new(),
+            auto_du_since: None,
             background_progress: None,
             dir_sizes_loaded: HashSet::new(),
             tree_dirty: false,
@@ -1059,6 +1424,7 @@ impl App {
             tree_last_hidden: false,
             tree_select_path: None,
             transparent: false,
+            config: crate::config::Config::default(),
             theme: Theme::default_theme(),
             theme_groups: Vec::new(),
             theme_group_cursor: 0,
@@ -1072,24 +1438,47 @@ impl App {
             bookmarks: Vec::new(),
             bookmark_cursor: 0,
             bookmark_scroll: 0,
+            mount_devices: Vec::new(),
+            mount_cursor: 0,
             trash_cursor: 0,
             trash_scroll: 0,
+            empty_trash_rx: None,
+            empty_trash_items: Vec::new(),
+            crumb_selected: 0,
             bookmark_rename_old: None,
             bookmark_add_path: None,
             chmod_paths: Vec::new(),
+            chmod_recursive: false,
             chown_picker: None,
             info_lines: Vec::new(),
             info_scroll: 0,
+            command_history: InputHistory::default(),
+            search_history: InputHistory::default(),
+            line_editor: LineEditor::default(),
+            palette_query: String::new(),
+            palette_matches: Vec::new(),
+            palette_selected: 0,
+            paste_picker: None,
+            paste_picker_rx: None,
+            dry_run: false,
+            plan_title: String::new(),
+            plan_lines: Vec::new(),
+            plan_scroll: 0,
+            pending_paste: None,
             info_du_rx: None,
             git_statuses: HashMap::new(),
             git_roots: [None, None, None],
             git_checked_dirs: [None, None, None],
             git_progress: None,
+            link_escapes: HashMap::new(),
+            link_escapes_root: PathBuf::new(),
+            link_scan_rx: None,
             bulk_rename: None,
             dir_cache: DirCache::new(64),
             dir_load_tx,
             dir_load_rx,
             preview_load_rx: None,
+            preview_load_cancel: std::sync::Arc::default(),
             viewer_load_rx: None,
             viewer_chunk_rx: None,
             viewer_hl_rx: None,
@@ -1113,6 +1502,22 @@ impl App {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn session_snapshot_writes_tabs_and_layout() {
+        let entries = make_test_entries(&["a", "b"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 2;
+        app.layout = PanelLayout::Triple;
+        let db = crate::storage::Db::init_in_memory().unwrap();
+        app.session_snapshot().write(&db);
+
+        let (tabs, active) = db.load_session().unwrap();
+        assert_eq!(active, 0);
+        assert_eq!(tabs[0].panel_paths[0], PathBuf::from("/test"));
+        assert_eq!(tabs[0].panel_cursors[0], 2);
+        assert_eq!(db.load_layout().as_deref(), Some("triple"));
+    }
+
     // ── Navigation tests ──────────────────────────────────────────
 
     #[tokio::test]
@@ -1156,6 +1561,25 @@ mod tests {
         assert!(app.tree_focused);
     }
 
+    #[tokio::test]
+    async fn session_snapshot_carries_open_panes() {
+        let entries = make_test_entries(&["a"]);
+        let mut app = App::new_for_test(entries);
+        app.preview_mode = true;
+        app.show_tree = true;
+        let view = app.session_snapshot().view;
+        assert!(view.preview && view.tree && !view.tree_focused);
+        assert!(!view.preview_flipped);
+        app.flip_preview_side();
+        assert!(app.session_snapshot().view.preview_flipped);
+        app.flip_preview_side();
+        assert!(!app.session_snapshot().view.preview_flipped);
+
+        assert!(tree_fits(80, PanelLayout::Triple));
+        assert!(tree_fits(60, PanelLayout::Dual));
+        assert!(!tree_fits(59, PanelLayout::Dual));
+    }
+
     #[tokio::test]
     async fn focus_next_exits_tree() {
         let entries = make_test_entries(&["a"]);
@@ -1687,6 +2111,30 @@ mod tests {
         assert!(app.info_du_rx.is_none());
     }
 
+    #[tokio::test]
+    async fn dir_load_error_is_kept_and_not_cached() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        let parent = app.active_panel().entries[0].clone();
+        app.handle_dir_load_msg(DirLoadMsg::Finished {
+            panel_idx: 0,
+            tab_index: 0,
+            path: PathBuf::from("/test"),
+            entries: vec![parent],
+            select_name: None,
+            error: Some("Permission denied".into()),
+        });
+        let panel = app.active_panel();
+        assert_eq!(panel.load_error.as_deref(), Some("Permission denied"));
+        assert_eq!(panel.entries.len(), 1);
+        assert_eq!(app.status_message, "/test: Permission denied");
+        assert!(app.dir_cache.get(std::path::Path::new("/test")).is_none());
+
+        // Leaving the directory clears the message.
+        app.navigate_cached(PathBuf::from("/"), 0, None);
+        assert_eq!(app.active_panel().load_error, None);
+    }
+
     // ── Tree data tests ──────────────────────────────────────────────
 
     #[tokio::test]
@@ -1703,6 +2151,8 @@ mod tests {
             is_on_path: true,
             is_expanded: false,
             depth: 0,
+            unreadable: false,
+            link_escapes: None,
         };
         let result = TreeLoadResult {
             start_dir: PathBuf::from("/test"),
@@ -2161,6 +2611,7 @@ mod tests {
             user_scroll: 0,
             group_scroll: 0,
             column: 0,
+            recursive: false,
             paths: vec![PathBuf::from("/test/a.txt")],
             current_uid: Some(0),
             current_gid: Some(0),
@@ -2204,6 +2655,7 @@ mod tests {
             user_scroll: 0,
             group_scroll: 0,
             column: 0,
+            recursive: false,
             paths: vec![],
             current_uid: None,
             current_gid: None,
@@ -0,0 +1,244 @@
+use super::*;
+use crate::fs::mounts::{self, MountDevice};
+
+/// Rows the `:mount` popup shows at once (fewer on a short terminal).
+pub const MOUNTS_ROWS: usize = 12;
+
+impl App {
+    /// `:mount` — list devices in the background; the popup opens once the
+    /// list is in. `:mount <device>` mounts that device directly.
+    pub(super) fn open_mounts(&mut self, device: Option<&str>) {
+        if let Some(device) = device {
+            self.mount_device(device.to_string());
+            return;
+        }
+        self.set_status("Listing devices...".into());
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.file_op_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let _ = tx.send(FileOpResult::MountList {
+                result: mounts::list_devices(),
+            });
+        });
+    }
+
+    pub(super) fn apply_mount_list(&mut self, result: Result<Vec<MountDevice>, String>) {
+        match result {
+            Err(e) => self.show_toast(format!("mount: {e}"), true),
+            Ok(devices) if devices.is_empty() => self.set_status("No devices found".into()),
+            Ok(devices) => {
+                self.mount_devices = devices;
+                self.mount_cursor = 0;
+                self.mode = Mode::Mounts;
+            }
+        }
+    }
+
+    pub(super) fn handle_mounts(&mut self, key: KeyEvent) {
+        let len = self.mount_devices.len();
+        if len == 0 {
+            self.mode = Mode::Normal;
+            return;
+        }
+        self.mount_cursor = self.mount_cursor.min(len - 1);
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.mount_cursor = (self.mount_cursor + 1).min(len - 1);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.mount_cursor = self.mount_cursor.saturating_sub(1);
+            }
+            KeyCode::Char('g') => self.mount_cursor = 0,
+            KeyCode::Char('G') => self.mount_cursor = len - 1,
+            KeyCode::Enter => {
+                let dev = self.mount_devices[self.mount_cursor].clone();
+                match dev.mount_point {
+                    Some(path) => {
+                        self.mode = Mode::Normal;
+                        let side = self.tab().active;
+                        self.navigate_cached(path, side, None);
+                    }
+                    None => self.mount_device(dev.device),
+                }
+            }
+            KeyCode::Char('u') => {
+                let dev = self.mount_devices[self.mount_cursor].clone();
+                if dev.mount_point.is_none() {
+                    self.set_status(format!("{} is not mounted", dev.device));
+                } else {
+                    self.unmount_device(dev);
+                }
+            }
+            KeyCode::Char('r') => self.open_mounts(None),
+            KeyCode::Esc | KeyCode::Char('q') => self.mode = Mode::Normal,
+            _ => {}
+        }
+    }
+
+    fn mount_device(&mut self, device: String) {
+        self.set_status(format!("Mounting {device}..."));
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.file_op_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let result = mounts::mount(&device);
+            let _ = tx.send(FileOpResult::Mount { device, result });
+        });
+    }
+
+    fn unmount_device(&mut self, device: MountDevice) {
+        self.set_status(format!("Unmounting {}...", device.device));
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.file_op_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let result = mounts::unmount(&device);
+            let _ = tx.send(FileOpResult::Unmount {
+                device: device.device,
+                mount_point: device.mount_point,
+                result,
+            });
+        });
+    }
+
+    /// `:unmount [device|path]` — without an argument, the removable device
+    /// holding the active panel's directory; anything else must be named.
+    pub(super) fn request_unmount(&mut self, target: Option<&str>) {
+        let path = match target {
+            Some(t) => self.resolve_command_path(t),
+            None => self.active_panel().path.clone(),
+        };
+        let device = target.map(str::to_string);
+        self.set_status("Unmounting...".into());
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.file_op_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let what = device.clone().unwrap_or_else(|| "this directory".into());
+            let fail = |e: String| FileOpResult::Unmount {
+                device: what.clone(),
+                mount_point: None,
+                result: Err(e),
+            };
+            let devices = match mounts::list_devices() {
+                Ok(d) => d,
+                Err(e) => {
+                    let _ = tx.send(fail(e));
+                    return;
+                }
+            };
+            let found = match &device {
+                Some(target) => mounts::find_mounted(&devices, target, &path),
+                None => mounts::removable_containing(&devices, &path),
+            };
+            let Some(found) = found.cloned() else {
+                let msg = if device.is_some() {
+                    format!("no mounted device found for {what}")
+                } else {
+                    "this directory is not on removable media \u{2014} name the device: \
+                     :unmount <device|path>"
+                        .into()
+                };
+                let _ = tx.send(fail(msg));
+                return;
+            };
+            let result = mounts::unmount(&found);
+            let _ = tx.send(FileOpResult::Unmount {
+                device: found.device,
+                mount_point: found.mount_point,
+                result,
+            });
+        });
+    }
+
+    pub(super) fn apply_mount(&mut self, device: String, result: Result<PathBuf, String>) {
+        match result {
+            Ok(path) => {
+                if self.mode == Mode::Mounts {
+                    self.mode = Mode::Normal;
+                }
+                self.show_toast(format!("Mounted {device} at {}", path.display()), false);
+                let side = self.tab().active;
+                self.navigate_cached(path, side, None);
+            }
+            Err(e) => self.show_toast(format!("mount: {e}"), true),
+        }
+    }
+
+    pub(super) fn apply_unmount(
+        &mut self,
+        device: String,
+        mount_point: Option<PathBuf>,
+        result: Result<bool, String>,
+    ) {
+        let powered_off = match result {
+            Ok(p) => p,
+            Err(e) => {
+                self.show_toast(format!("unmount: {e}"), true);
+                return;
+            }
+        };
+        let Some(mount_point) = mount_point else {
+            return;
+        };
+        if let Some(d) = self.mount_devices.iter_mut().find(|d| d.device == device) {
+            d.mount_point = None;
+        }
+        // Panels left inside the mount would only show errors now.
+        let fallback = mount_point.parent().unwrap_or(&mount_point).to_path_buf();
+        let count = self.layout.count();
+        for side in 0..count {
+            if self.tab().panels[side].path.starts_with(&mount_point) {
+                self.navigate_cached(fallback.clone(), side, None);
+            }
+        }
+        let msg = if powered_off {
+            format!("Unmounted {device} \u{2014} safe to remove")
+        } else {
+            format!("Unmounted {device}")
+        };
+        self.show_toast(msg, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn device(name: &str, mount_point: Option<&str>) -> MountDevice {
+        MountDevice {
+            device: name.into(),
+            label: String::new(),
+            fstype: "vfat".into(),
+            size: 0,
+            removable: true,
+            mount_point: mount_point.map(PathBuf::from),
+        }
+    }
+
+    #[tokio::test]
+    async fn mounts_popup_opens_mounted_device_and_tracks_unmount() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.apply_mount_list(Ok(Vec::new()));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.status_message, "No devices found");
+
+        let usb = device("/dev/sdb1", Some("/test"));
+        app.apply_mount_list(Ok(vec![usb.clone(), device("/dev/sdc1", None)]));
+        assert_eq!(app.mode, Mode::Mounts);
+        app.handle_mounts(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        app.handle_mounts(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::NONE));
+        assert_eq!(app.status_message, "/dev/sdc1 is not mounted");
+
+        app.apply_unmount(usb.device, usb.mount_point, Ok(true));
+        assert_eq!(app.mount_devices[0].mount_point, None);
+        let toast = app.toast.as_ref().unwrap();
+        assert_eq!(toast.text, "Unmounted /dev/sdb1 \u{2014} safe to remove");
+        // The panel sitting in the old mount point moves out of it.
+        assert_eq!(app.active_panel().path, PathBuf::from("/"));
+
+        app.apply_unmount("/dev/sdc1".into(), None, Err("busy".into()));
+        let toast = app.toast.as_ref().unwrap();
+        assert!(toast.error);
+        assert_eq!(toast.text, "unmount: busy");
+    }
+}
@@ -10,6 +10,8 @@ pub struct MouseRegions {
     pub tab_bar_row: u16,
     /// Clickable tab spans: (x_start, x_end_exclusive, tab_index).
     pub tabs: Vec<(u16, u16, usize)>,
+    /// The TASKS badge at the right end of the tab bar: (x_start, x_end_exclusive).
+    pub tasks_badge: Option<(u16, u16)>,
     /// Visible file panels of the active tab.
     pub panels: Vec<PanelRegion>,
 }
@@ -21,6 +23,12 @@ pub struct PanelRegion {
     pub index: usize,
     /// Inner content area (excludes the border).
     pub inner: Rect,
+    /// Screen row of the panel's top border, where the breadcrumb title sits.
+    pub title_row: u16,
+    /// Clickable breadcrumb segments: (x_start, x_end_exclusive, directory).
+    pub crumbs: Vec<(u16, u16, PathBuf)>,
+    /// The sort indicator on the title row: (x_start, x_end_exclusive).
+    pub sort_toggle: Option<(u16, u16)>,
 }
 
 impl PanelRegion {
@@ -95,9 +103,14 @@ impl App {
     }
 
     fn mouse_left_down(&mut self, col: u16, row: u16) {
-        // Tab bar: click a tab to switch to it.
+        // Tab bar: click a tab to switch to it, or the TASKS badge for the jobs list.
         if row == self.mouse_regions.tab_bar_row {
-            if let Some(&(_, _, ti)) = self
+            if let Some((x0, x1)) = self.mouse_regions.tasks_badge
+                && col >= x0
+                && col < x1
+            {
+                self.open_tasks();
+            } else if let Some(&(_, _, ti)) = self
                 .mouse_regions
                 .tabs
                 .iter()
@@ -112,6 +125,44 @@ impl App {
             return;
         }
 
+        // Panel title: click a breadcrumb segment to jump to that directory.
+        let crumb = self
+            .mouse_regions
+            .panels
+            .iter()
+            .filter(|p| p.title_row == row)
+            .find_map(|p| {
+                p.crumbs
+                    .iter()
+                    .find(|(x0, x1, _)| col >= *x0 && col < *x1)
+                    .map(|(_, _, dir)| (p.index, dir.clone()))
+            });
+        if let Some((pidx, dir)) = crumb {
+            if self.mode == Mode::Normal {
+                self.tree_focused = false;
+                self.tab_mut().active = pidx;
+                self.goto_ancestor(pidx, dir);
+            }
+            return;
+        }
+
+        // Panel title: click the sort indicator to cycle that panel's sort.
+        let sort_click = self
+            .mouse_regions
+            .panels
+            .iter()
+            .filter(|p| p.title_row == row)
+            .find(|p| p.sort_toggle.is_some_and(|(x0, x1)| col >= x0 && col < x1))
+            .map(|p| p.index);
+        if let Some(pidx) = sort_click {
+            if self.mode == Mode::Normal {
+                self.tree_focused = false;
+                self.tab_mut().active = pidx;
+                self.cycle_sort();
+            }
+            return;
+        }
+
         // Locate the clicked panel and the visible row within it.
         let Some((pidx, vis_row)) = self
             .mouse_regions
@@ -197,6 +248,9 @@ mod tests {
                 width: 40,
                 height: 20,
             },
+            title_row: 0,
+            crumbs: Vec::new(),
+            sort_toggle: None,
         }];
     }
 
@@ -237,6 +291,9 @@ mod tests {
                     width: 20,
                     height: 20,
                 },
+                title_row: 0,
+                crumbs: Vec::new(),
+                sort_toggle: None,
             },
             PanelRegion {
                 index: 1,
@@ -246,6 +303,9 @@ mod tests {
                     width: 20,
                     height: 20,
                 },
+                title_row: 0,
+                crumbs: Vec::new(),
+                sort_toggle: None,
             },
         ];
         assert_eq!(app.tab().active, 0);
@@ -253,6 +313,40 @@ mod tests {
         assert_eq!(app.tab().active, 1);
     }
 
+    #[tokio::test]
+    async fn click_on_breadcrumb_jumps_to_ancestor() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().path = PathBuf::from("/home/u/src");
+        // Title row 1 reads " /home/u/src": "home" spans columns 3..7.
+        app.mouse_regions.panels = vec![PanelRegion {
+            index: 0,
+            inner: Rect {
+                x: 1,
+                y: 2,
+                width: 40,
+                height: 20,
+            },
+            title_row: 1,
+            crumbs: vec![
+                (2, 3, PathBuf::from("/")),
+                (3, 7, PathBuf::from("/home")),
+                (8, 9, PathBuf::from("/home/u")),
+                (10, 13, PathBuf::from("/home/u/src")),
+            ],
+            sort_toggle: Some((33, 40)),
+        }];
+        // Clicking the current directory's own segment does nothing.
+        app.handle_mouse(ev(MouseEventKind::Down(MouseButton::Left), 11, 1));
+        assert_eq!(app.active_panel().path, PathBuf::from("/home/u/src"));
+        app.handle_mouse(ev(MouseEventKind::Down(MouseButton::Left), 4, 1));
+        assert_eq!(app.active_panel().path, PathBuf::from("/home"));
+
+        // The sort indicator at the right end of the title cycles the sort.
+        app.handle_mouse(ev(MouseEventKind::Down(MouseButton::Left), 35, 1));
+        assert_eq!(app.active_panel().sort_mode, SortMode::Size);
+    }
+
     #[tokio::test]
     async fn scroll_down_moves_cursor_of_panel_under_pointer() {
         let entries = crate::app::make_test_entries(&["a.txt", "b.txt", "c.txt"]);
@@ -291,6 +385,22 @@ mod tests {
         assert_eq!(app.active_tab, 1);
     }
 
+    #[tokio::test]
+    async fn tasks_badge_click_opens_jobs() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.mouse_regions.tab_bar_row = 0;
+        app.mouse_regions.tasks_badge = Some((70, 80));
+        let (_tx, rx) = tokio::sync::mpsc::channel(1);
+        let cancel = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        app.task_manager.add_delete(rx, false, cancel);
+
+        app.handle_mouse(ev(MouseEventKind::Down(MouseButton::Left), 69, 0));
+        assert_eq!(app.mode, Mode::Normal);
+        app.handle_mouse(ev(MouseEventKind::Down(MouseButton::Left), 75, 0));
+        assert_eq!(app.mode, Mode::Tasks);
+    }
+
     #[tokio::test]
     async fn mouse_ignored_in_overlay_modes() {
         let entries = crate::app::make_test_entries(&["a.txt", "b.txt"]);
@@ -1,6 +1,10 @@
 use super::*;
+use crate::config::FileAction;
 use crate::util::copy_to_clipboard;
 
+/// Largest file `yc` copies to the clipboard.
+pub const YANK_TEXT_MAX: u64 = 1_048_576;
+
 impl App {
     pub(super) fn focus_next(&mut self) {
         let max = self.layout.count().saturating_sub(1);
@@ -78,7 +82,10 @@ impl App {
     }
 
     pub(super) fn new_tab(&mut self) {
-        let path = self.active_panel().path.clone();
+        // Open where the user is; if that directory has since been removed, the
+        // nearest surviving ancestor.
+        let current = &self.active_panel().path;
+        let path = crate::fs::nearest_existing_dir(current).unwrap_or_else(|| current.clone());
         let mut tab = Tab::new(path);
         // Apply sort prefs before spawning loads
         for panel in tab.panels.iter_mut() {
@@ -93,12 +100,12 @@ impl App {
         for panel_idx in 0..3 {
             self.spawn_dir_load(panel_idx, None);
         }
-        self.status_message = format!("Tab {}", self.tabs.len());
+        self.set_status(format!("Tab {}", self.tabs.len()));
     }
 
     pub(super) fn close_tab(&mut self) {
         if self.tabs.len() <= 1 {
-            self.status_message = "Cannot close last tab".into();
+            self.set_status("Cannot close last tab".into());
             return;
         }
         self.tabs.remove(self.active_tab);
@@ -108,6 +115,64 @@ impl App {
         self.preview_path = None;
     }
 
+    /// `Space o`: show or hide the owner:group column for this session.
+    pub(super) fn toggle_owner_column(&mut self) {
+        if cfg!(not(unix)) {
+            self.set_status("File ownership is not available on this platform".into());
+            return;
+        }
+        self.config.show_owner = !self.config.show_owner;
+        self.set_status(if self.config.show_owner {
+            "Owner column: shown".into()
+        } else {
+            "Owner column: hidden".into()
+        });
+    }
+
+    /// `Space T`: switch the active panel's date column between calendar dates
+    /// and ages, overriding `relative_dates` for that panel only.
+    pub(super) fn toggle_relative_dates(&mut self) {
+        let default = self.config.relative_dates;
+        let panel = self.active_panel_mut();
+        let relative = !panel.relative_dates.unwrap_or(default);
+        panel.relative_dates = Some(relative);
+        self.set_status(if relative {
+            "Dates: relative in this panel".into()
+        } else {
+            "Dates: absolute in this panel".into()
+        });
+    }
+
+    /// `:icons [on|off]`: Nerd Font glyphs or plain characters for this
+    /// session; no argument toggles.
+    pub(super) fn set_icons(&mut self, arg: Option<&str>) {
+        let on = match arg {
+            None => !self.config.icons,
+            Some("on") => true,
+            Some("off") => false,
+            Some(other) => {
+                self.set_status(format!("Usage: :icons [on|off] (got {other})"));
+                return;
+            }
+        };
+        self.config.icons = on;
+        crate::util::icons::set_enabled(on);
+        self.set_status(if on {
+            "Icons: Nerd Font".into()
+        } else {
+            "Icons: off (plain characters)".into()
+        });
+    }
+
+    /// `Space P`: move the preview to the other side; the flip is saved with
+    /// the session.
+    pub(super) fn flip_preview_side(&mut self) {
+        let side = self.config.preview_side.flipped();
+        self.config.preview_side = side;
+        self.preview_flipped = !self.preview_flipped;
+        self.set_status(format!("Preview on the {}", side.label()));
+    }
+
     pub(super) fn toggle_hidden(&mut self) {
         let hidden = !self.active_panel().show_hidden;
         {
@@ -119,14 +184,14 @@ impl App {
         self.dir_cache.clear();
         // Load all panels async
         for i in 0..3 {
-            self.spawn_dir_load(i, None);
+            self.reload_panel(i);
         }
         self.tree_dirty = true;
-        self.status_message = if hidden {
+        self.set_status(if hidden {
             "Hidden files: shown".into()
         } else {
             "Hidden files: hidden".into()
-        };
+        });
     }
 
     pub(super) fn toggle_tree(&mut self) {
@@ -141,14 +206,23 @@ impl App {
         self.save_current_sort();
         let arrow = if rev { "\u{2191}" } else { "\u{2193}" };
         let mode = self.active_panel().sort_mode;
-        self.status_message = format!("Sort: {} {arrow}", mode.display_label());
+        self.set_status(format!("Sort: {} {arrow}", mode.display_label()));
     }
 
+    /// Sort the active panel only. Another panel on the same directory keeps its
+    /// own order until it next opens the directory; `dir_sorts` remembers this
+    /// one for next time.
     pub(super) fn set_sort(&mut self, mode: SortMode) {
         self.active_panel_mut().sort_mode = mode;
         self.resort_from_cache_or_reload();
         self.save_current_sort();
-        self.status_message = format!("Sort: {}", mode.display_label());
+        self.set_status(format!("Sort: {}", mode.display_label()));
+    }
+
+    /// `S` or a click on the panel's sort indicator: step to the next sort mode.
+    pub(super) fn cycle_sort(&mut self) {
+        let mode = self.active_panel().sort_mode.next();
+        self.set_sort(mode);
     }
 
     fn resort_from_cache_or_reload(&mut self) {
@@ -161,7 +235,13 @@ impl App {
             && cached.show_hidden == show_hidden
         {
             let mut entries = (*cached.entries).clone();
-            panel::resort_entries(&mut entries, sort_mode, sort_reverse, &self.dir_sizes);
+            panel::resort_entries(
+                &mut entries,
+                sort_mode,
+                sort_reverse,
+                self.config.case_sensitive_sort,
+                &self.dir_sizes,
+            );
             self.active_panel_mut()
                 .apply_entries(std::sync::Arc::new(entries), None);
             return;
@@ -188,7 +268,7 @@ impl App {
         let path = self.active_panel().path.clone();
         self.dir_cache.remove(&path);
         let idx = self.tab().active;
-        self.spawn_dir_load(idx, None);
+        self.reload_panel(idx);
         self.tree_dirty = true;
         self.git_checked_dirs = [None, None, None]; // force re-fetch
         self.refresh_git_status();
@@ -219,12 +299,18 @@ impl App {
     /// Spawn async preview load for the side panel preview.
     fn spawn_preview_load(&mut self, path: PathBuf) {
         let (tx, rx) = tokio::sync::oneshot::channel();
-        // Drop old receiver (cancels stale load)
+        // Drop old receiver (cancels stale load) and stop its archive scan
         self.preview_load_rx = Some(rx);
+        self.preview_load_cancel
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+        let cancel = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        self.preview_load_cancel = std::sync::Arc::clone(&cancel);
         let vis = self.visible_height;
+        let dark = self.theme_is_dark();
 
         tokio::task::spawn_blocking(move || {
-            let preview = Preview::load(&path, vis);
+            let mut preview = Preview::load_cancellable(&path, vis, &cancel);
+            preview.highlight_syntax(&path, dark);
             let _ = tx.send(super::PreviewLoadResult { path, preview });
         });
     }
@@ -259,6 +345,27 @@ impl App {
         self.navigate_cached(parent, idx, old_name);
     }
 
+    /// Step to the next/previous sibling of the active panel's directory (`]]` / `[[`),
+    /// wrapping at the ends when `sibling_wrap` is set.
+    pub(super) fn goto_sibling_dir(&mut self, forward: bool) {
+        let current = self.active_panel().path.clone();
+        let show_hidden = self.active_panel().show_hidden;
+        let wrap = self.config.sibling_wrap;
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.nav_check_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let target = crate::fs::sibling_dir(&current, forward, wrap, show_hidden);
+            let _ = tx.send(NavCheckResult {
+                exists: target.is_some(),
+                is_dir: true,
+                nearest: None,
+                matches: Vec::new(),
+                path: target.unwrap_or(current),
+                source: NavSource::Sibling { forward },
+            });
+        });
+    }
+
     /// Go to home directory on the active panel (async).
     pub(super) fn go_home_async(&mut self) {
         let Some(home) = dirs::home_dir() else {
@@ -268,6 +375,21 @@ impl App {
         self.navigate_cached(home, idx, None);
     }
 
+    /// `g/`: the root of the filesystem the active panel is on.
+    pub(super) fn go_root(&mut self) {
+        let path = self.active_panel().path.clone();
+        let root = path.ancestors().last().unwrap_or(&path).to_path_buf();
+        let idx = self.tab().active;
+        self.navigate_cached(root, idx, None);
+    }
+
+    /// `g.`: back to the directory fcmd was started in.
+    pub(super) fn go_launch_dir(&mut self) {
+        let dir = self.launch_dir.clone();
+        let idx = self.tab().active;
+        self.navigate_cached(dir, idx, None);
+    }
+
     /// Navigate to a directory using the cache if available, then spawn a background refresh.
     pub(super) fn navigate_cached(
         &mut self,
@@ -275,6 +397,12 @@ impl App {
         panel_idx: usize,
         select_name: Option<String>,
     ) {
+        if panel_idx == self.tab().active {
+            let dir = path.clone();
+            self.db_spawn(move |db| {
+                let _ = db.save_last_dir(&dir);
+            });
+        }
         let panel = &mut self.tabs[self.active_tab].panels[panel_idx];
         panel.path = path.clone();
         panel.selected = 0;
@@ -282,7 +410,9 @@ impl App {
         panel.marked.clear();
         // A live filter belongs to the directory it was set in; drop it on navigation.
         panel.filter.clear();
+        panel.path_filter = None;
         std::sync::Arc::make_mut(&mut panel.full_entries).clear();
+        panel.load_error = None;
         panel.loading = true;
 
         // Apply sort prefs for this directory
@@ -305,7 +435,13 @@ impl App {
             // underlying Vec when a re-sort into a different order is needed.
             let entries = if cached.sort_mode != sort_mode || cached.sort_reverse != sort_reverse {
                 let mut v = (*cached.entries).clone();
-                panel::resort_entries(&mut v, sort_mode, sort_reverse, &self.dir_sizes);
+                panel::resort_entries(
+                    &mut v,
+                    sort_mode,
+                    sort_reverse,
+                    self.config.case_sensitive_sort,
+                    &self.dir_sizes,
+                );
                 std::sync::Arc::new(v)
             } else {
                 cached.entries.clone()
@@ -319,8 +455,91 @@ impl App {
         self.spawn_dir_load(panel_idx, select_name);
     }
 
-    pub(super) fn request_open_editor(&mut self, path: PathBuf) {
-        self.open_editor = Some(path);
+    /// `o` / F4: edit the marked files in one editor session, or the file under
+    /// the cursor when nothing is marked. Directories are never passed along.
+    pub(super) fn edit_targeted(&mut self) {
+        let panel = self.active_panel();
+        if panel.marked.is_empty() {
+            if let Some(entry) = panel.selected_entry()
+                && !entry.is_dir
+                && entry.name != ".."
+            {
+                let path = entry.path.clone();
+                self.request_open_editor(vec![path]);
+            }
+            return;
+        }
+        let (dirs, files): (Vec<&FileEntry>, Vec<&FileEntry>) = panel
+            .entries
+            .iter()
+            .filter(|e| e.name != ".." && panel.marked.contains(&e.path))
+            .partition(|e| e.is_dir);
+        let paths: Vec<PathBuf> = files.iter().map(|e| e.path.clone()).collect();
+        let skipped = dirs.len();
+        if paths.is_empty() {
+            self.set_status("No files among the marked entries".into());
+            return;
+        }
+        if skipped > 0 {
+            self.set_status(format!("Skipped {skipped} marked director(y/ies)"));
+        }
+        self.request_open_editor(paths);
+    }
+
+    /// Enter on a file: archives open in the archive browser (unless the
+    /// desktop opener is asked for), anything else as `file_action` says.
+    pub(super) fn open_selected_file(&mut self) {
+        let Some(path) = self.active_panel().selected_entry().map(|e| e.path.clone()) else {
+            return;
+        };
+        let action = self.config.file_action;
+        if action != FileAction::Open && crate::archive::is_archive(&path) {
+            self.open_archive();
+            return;
+        }
+        match action {
+            FileAction::View => self.open_viewer(path),
+            FileAction::Edit => self.request_open_editor(vec![path]),
+            FileAction::Open => {
+                if let Err(e) = crate::util::open_with_system(&path) {
+                    self.show_toast(format!("open: {e}"), true);
+                }
+            }
+        }
+    }
+
+    /// `--choosefiles`: say how to pick before the first key.
+    pub fn start_chooser(&mut self) {
+        self.chooser = true;
+        self.set_status("Choose: Enter picks the file (or the marked entries), q cancels".into());
+    }
+
+    /// Enter in the chooser: pick the marked entries, or the file under the
+    /// cursor, and quit. `false` when there is nothing to pick (the cursor on
+    /// a directory), so Enter opens it as usual.
+    pub(super) fn choose_targeted(&mut self) -> bool {
+        let panel = self.active_panel();
+        let paths: Vec<PathBuf> = if panel.marked.is_empty() {
+            match panel.selected_entry() {
+                Some(e) if !e.is_dir => vec![e.path.clone()],
+                _ => return false,
+            }
+        } else {
+            panel
+                .entries
+                .iter()
+                .filter(|e| e.name != ".." && panel.marked.contains(&e.path))
+                .map(|e| e.path.clone())
+                .collect()
+        };
+        self.chosen = Some(paths);
+        self.should_quit = true;
+        true
+    }
+
+    pub(super) fn request_open_editor(&mut self, paths: Vec<PathBuf>) {
+        self.open_editor = Some(paths);
+        self.open_editor_line = None;
         // If the viewer is open, close it.
         if matches!(self.mode, Mode::Viewer | Mode::ViewerSearch) {
             self.viewer = None;
@@ -342,6 +561,34 @@ impl App {
         });
     }
 
+    /// `yr`: the selected entry's path relative to the repository root when
+    /// the panel is inside one, otherwise to the other panel's directory —
+    /// the form that pastes into code or a shell started there.
+    pub(super) fn yank_relative_path(&mut self) {
+        let Some(path) = self.active_panel().selected_entry().map(|e| e.path.clone()) else {
+            return;
+        };
+        let active = self.tab().active;
+        let (base, from) = match &self.git_roots[active] {
+            Some(root) if path.starts_with(root) => (root.clone(), "repo root".to_string()),
+            _ => {
+                let other = self.tab().inactive_panel_path(self.layout);
+                let from = crate::search::abbreviate_home(&other.to_string_lossy());
+                (other, from)
+            }
+        };
+        let rel = crate::fs::relative_path(&path, &base)
+            .to_string_lossy()
+            .into_owned();
+        let label = format!("Path from {from}: {rel}");
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.file_op_rx = Some(rx);
+        tokio::spawn(async move {
+            let ok = copy_to_clipboard(&rel).await.is_ok();
+            let _ = tx.send(super::FileOpResult::Clipboard { label, ok });
+        });
+    }
+
     pub(super) fn yank_name(&mut self) {
         let name = match self
             .active_panel()
@@ -360,10 +607,42 @@ impl App {
         });
     }
 
+    /// `yc`: the selected file's text, for pasting a config snippet or a
+    /// script. Large and binary files are refused rather than copied.
+    pub(super) fn yank_contents(&mut self) {
+        let Some(entry) = self
+            .active_panel()
+            .selected_entry()
+            .filter(|e| e.name != "..")
+        else {
+            return;
+        };
+        let (path, name) = (entry.path.clone(), entry.name.clone());
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.file_op_rx = Some(rx);
+        tokio::spawn(async move {
+            let read =
+                tokio::task::spawn_blocking(move || crate::fs::read_text(&path, YANK_TEXT_MAX));
+            let result = match read.await {
+                Ok(Ok(text)) => match copy_to_clipboard(&text).await {
+                    Ok(()) => Ok(format!(
+                        "Contents of {name}: {} lines, {}",
+                        text.lines().count(),
+                        crate::util::format_bytes(text.len() as u64)
+                    )),
+                    Err(_) => Err("Clipboard not available".into()),
+                },
+                Ok(Err(e)) => Err(e),
+                Err(e) => Err(e.to_string()),
+            };
+            let _ = tx.send(super::FileOpResult::ClipboardText { result });
+        });
+    }
+
     pub(super) fn set_layout(&mut self, layout: PanelLayout) {
         self.layout = layout;
         self.tab_mut().clamp_active(layout);
-        self.status_message = format!("Layout: {}", layout.label());
+        self.set_status(format!("Layout: {}", layout.label()));
     }
 
     pub(super) fn toggle_transparent(&mut self) {
@@ -379,11 +658,11 @@ impl App {
         self.db_spawn(move |db| {
             let _ = db.save_transparent(transparent);
         });
-        self.status_message = if self.transparent {
+        self.set_status(if self.transparent {
             "Background: transparent".into()
         } else {
             "Background: opaque".into()
-        };
+        });
     }
 
     /// Swap the active panel with its neighbour (the next visible panel). Focus
@@ -391,30 +670,46 @@ impl App {
     pub(super) fn swap_panels(&mut self) {
         let count = self.layout.count();
         if count < 2 {
-            self.status_message = "Swap needs at least two panels".into();
+            self.set_status("Swap needs at least two panels".into());
             return;
         }
         let active = self.tab().active;
         let other = (active + 1) % count;
         self.tab_mut().panels.swap(active, other);
-        self.status_message = "Panels swapped".into();
+        self.set_status("Panels swapped".into());
     }
 
-    /// Point every other visible panel at the active panel's directory.
+    /// Point every other visible panel at the active panel's directory, with
+    /// the cursor on the same entry.
     pub(super) fn equalize_panels(&mut self) {
         let count = self.layout.count();
         if count < 2 {
-            self.status_message = "Equalize needs at least two panels".into();
+            self.set_status("Equalize needs at least two panels".into());
             return;
         }
         let active = self.tab().active;
         let path = self.active_panel().path.clone();
+        let name = self.active_panel().selected_entry().map(|e| e.name.clone());
         for idx in 0..count {
             if idx != active {
-                self.navigate_cached(path.clone(), idx, None);
+                self.navigate_cached(path.clone(), idx, name.clone());
             }
         }
-        self.status_message = format!("Synced {} panel(s) to current dir", count - 1);
+        self.set_status(format!("Synced {} panel(s) to current dir", count - 1));
+    }
+
+    /// The inverse of equalize: take the active panel to the other panel's
+    /// directory, landing on the entry under the other panel's cursor.
+    pub(super) fn follow_other_panel(&mut self) {
+        if self.layout.count() < 2 {
+            self.set_status("No other panel to follow in single layout".into());
+            return;
+        }
+        let other = &self.tab().panels[self.tab().inactive_index(self.layout)];
+        let path = other.path.clone();
+        let name = other.selected_entry().map(|e| e.name.clone());
+        let active = self.tab().active;
+        self.navigate_cached(path, active, name);
     }
 
     /// Follow the symlink under the cursor: enter its target directory, or select
@@ -423,7 +718,7 @@ impl App {
         let link = match self.active_panel().selected_entry() {
             Some(e) if e.is_symlink => e.path.clone(),
             _ => {
-                self.status_message = "Not a symlink".into();
+                self.set_status("Not a symlink".into());
                 return;
             }
         };
@@ -431,7 +726,10 @@ impl App {
         let target = match std::fs::canonicalize(&link) {
             Ok(t) => t,
             Err(_) => {
-                self.status_message = "Broken symlink (target missing)".into();
+                self.set_status(match std::fs::read_link(&link) {
+                    Ok(t) => format!("Broken symlink: {} does not exist", t.display()),
+                    Err(e) => format!("Cannot read symlink: {e}"),
+                });
                 return;
             }
         };
@@ -449,11 +747,15 @@ impl App {
             ("", "Toggle"),
             ("t", "tree"),
             ("h", "hidden"),
+            ("o", "owner"),
+            ("T", "relative dates"),
             ("p", "preview"),
+            ("P", "preview side"),
             ("u", "ui"),
             ("", "Actions"),
             ("s", "sort"),
             ("d", "dir sizes"),
+            ("D", "auto dir sizes"),
             ("w", "layout"),
             ("", "Select"),
             ("a", "select all"),
@@ -463,30 +765,79 @@ impl App {
             ("f", "filter"),
             (",", "find"),
             (".", "find global"),
+            (";", "last find"),
             ("", "Other"),
             ("b", "bookmarks"),
             ("j", "tasks"),
+            ("r", "trash/restore"),
             ("?", "help"),
         ];
         const GOTO_HINTS: &[(&str, &str)] = &[
             ("g", "top"),
             ("t", "next tab"),
             ("T", "prev tab"),
+            ("p", "path segment"),
+            ("f", "follow link"),
+            ("/", "root"),
+            (".", "launch dir"),
+            ("v", "mark to last mark"),
             ("", "Git"),
             ("s", "stage"),
             ("u", "unstage"),
             ("d", "diff"),
         ];
-        const YANK_HINTS: &[(&str, &str)] =
-            &[("y", "yank"), ("p", "yank path"), ("n", "yank name")];
+        const YANK_HINTS: &[(&str, &str)] = &[
+            ("y", "yank"),
+            ("p", "yank path"),
+            ("r", "relative path"),
+            ("n", "yank name"),
+            ("c", "yank contents"),
+        ];
         const DELETE_HINTS: &[(&str, &str)] = &[("d", "trash"), ("D", "permanent")];
-        const CHANGE_HINTS: &[(&str, &str)] =
-            &[("p", "permissions"), ("o", "owner"), ("w", "bulk rename")];
+        const CHANGE_HINTS: &[(&str, &str)] = &[
+            ("p", "permissions"),
+            ("o", "owner"),
+            ("w", "bulk rename"),
+            ("W", "bulk rename in $EDITOR"),
+        ];
         const MARK_HINTS: &[(&str, &str)] = &[("a-z", "go to mark")];
+        const NEXT_HINTS: &[(&str, &str)] = &[("]", "next sibling dir"), ("m", "next mark")];
+        const PREV_HINTS: &[(&str, &str)] = &[("[", "prev sibling dir"), ("m", "prev mark")];
+        const FOLD_HINTS: &[(&str, &str)] = &[("R", "expand all"), ("M", "collapse all")];
+        // `?`: the everyday keys, asked for explicitly, so no delay applies.
+        const TOP_HINTS: &[(&str, &str)] = &[
+            ("", "Move"),
+            ("j/k", "down/up"),
+            ("h/l", "parent/open"),
+            ("gg/G", "top/bottom"),
+            ("Tab", "next panel"),
+            ("", "Files"),
+            ("yy", "yank"),
+            ("dd", "trash"),
+            ("p", "paste"),
+            ("P", "paste other"),
+            ("r", "rename"),
+            ("a", "create"),
+            ("o", "edit"),
+            ("u", "undo"),
+            ("", "Modes"),
+            ("v", "visual"),
+            ("/", "search"),
+            (":", "command"),
+            ("C-p", "palette"),
+            ("Space", "leader"),
+            ("", "Other"),
+            ("?", "full help"),
+            ("q", "quit"),
+        ];
 
         let pending = self.pending_key?;
+        if pending == '?' {
+            return Some(TOP_HINTS.to_vec());
+        }
         let time = self.pending_key_time?;
-        if time.elapsed() < std::time::Duration::from_millis(400) {
+        let delay = self.config.which_key_delay()?;
+        if time.elapsed() < delay {
             return None;
         }
         match pending {
@@ -497,8 +848,11 @@ impl App {
             'd' => Some(DELETE_HINTS.to_vec()),
             'c' => Some(CHANGE_HINTS.to_vec()),
             '\'' => Some(MARK_HINTS.to_vec()),
+            ']' => Some(NEXT_HINTS.to_vec()),
+            '[' => Some(PREV_HINTS.to_vec()),
             'w' => Some(self.build_layout_hints()),
             'u' => Some(self.build_ui_hints()),
+            'z' if self.tree_focused => Some(FOLD_HINTS.to_vec()),
             _ => None,
         }
     }
@@ -563,6 +917,7 @@ impl App {
             ("", "Panels"),
             ("s", "swap"),
             ("e", "equalize"),
+            ("o", "go to other's dir"),
         ]
     }
 }
@@ -598,6 +953,38 @@ mod tests {
         assert_eq!(app.tab().panels[1].path, active_path);
     }
 
+    #[tokio::test]
+    async fn follow_other_panel_takes_its_dir_and_cursor() {
+        let entries = crate::app::make_test_entries(&["a.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries.clone());
+        app.set_layout(PanelLayout::Dual);
+        let other = std::sync::Arc::new(entries);
+        app.tab_mut().panels[1].path = PathBuf::from("/test");
+        app.tab_mut().panels[1].entries = other.clone();
+        app.tab_mut().panels[1].selected = 2;
+        app.tab_mut().panels[0].path = PathBuf::from("/elsewhere");
+        app.dir_cache.insert(
+            PathBuf::from("/test"),
+            panel::DirCacheEntry {
+                entries: other,
+                sort_mode: SortMode::Name,
+                sort_reverse: false,
+                show_hidden: false,
+            },
+        );
+        app.follow_other_panel();
+        assert_eq!(app.active_panel().path, PathBuf::from("/test"));
+        assert_eq!(app.active_panel().selected_entry().unwrap().name, "b.txt");
+        assert_eq!(app.tab().active, 0);
+
+        app.set_layout(PanelLayout::Single);
+        app.follow_other_panel();
+        assert_eq!(
+            app.status_message,
+            "No other panel to follow in single layout"
+        );
+    }
+
     #[tokio::test]
     async fn follow_symlink_on_non_symlink_reports() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -607,6 +994,53 @@ mod tests {
         assert_eq!(app.status_message, "Not a symlink");
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn follow_symlink_goes_to_target_or_names_the_missing_one() {
+        let dir = tempfile::tempdir().unwrap();
+        let real = dir.path().canonicalize().unwrap().join("real");
+        std::fs::create_dir_all(real.join("sub")).unwrap();
+        std::fs::write(real.join("f.txt"), "").unwrap();
+        let farm = dir.path().join("farm");
+        std::fs::create_dir(&farm).unwrap();
+        std::os::unix::fs::symlink(real.join("f.txt"), farm.join("file")).unwrap();
+        std::os::unix::fs::symlink(real.join("sub"), farm.join("dir")).unwrap();
+        std::os::unix::fs::symlink("../nowhere", farm.join("broken")).unwrap();
+
+        let mut app = App::new_for_test(crate::app::make_test_entries(&[]));
+        let at_farm = |app: &mut App, name: &str| {
+            let panel = app.active_panel_mut();
+            panel.path = farm.clone();
+            let entries = panel::load_dir_entries(
+                &farm,
+                false,
+                SortMode::Name,
+                false,
+                false,
+                &HashMap::new(),
+            )
+            .unwrap();
+            panel.selected = entries.iter().position(|e| e.name == name).unwrap();
+            panel.entries = std::sync::Arc::new(entries);
+        };
+
+        at_farm(&mut app, "file");
+        app.follow_symlink();
+        assert_eq!(app.active_panel().path, real);
+
+        at_farm(&mut app, "dir");
+        app.follow_symlink();
+        assert_eq!(app.active_panel().path, real.join("sub"));
+
+        at_farm(&mut app, "broken");
+        app.follow_symlink();
+        assert_eq!(app.active_panel().path, farm);
+        assert_eq!(
+            app.status_message,
+            "Broken symlink: ../nowhere does not exist"
+        );
+    }
+
     #[tokio::test]
     async fn toggle_tree_enables_and_focuses() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -667,8 +1101,142 @@ mod tests {
     async fn request_open_editor_sets_path() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
         let mut app = App::new_for_test(entries);
-        app.request_open_editor(PathBuf::from("/test/a.txt"));
-        assert_eq!(app.open_editor, Some(PathBuf::from("/test/a.txt")));
+        app.request_open_editor(vec![PathBuf::from("/test/a.txt")]);
+        assert_eq!(app.open_editor, Some(vec![PathBuf::from("/test/a.txt")]));
+    }
+
+    #[tokio::test]
+    async fn edit_targeted_passes_marked_files_only() {
+        let entries = crate::app::make_test_entries(&["a.txt", "b.txt", "sub/"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 1;
+        app.edit_targeted();
+        assert_eq!(app.open_editor, Some(vec![PathBuf::from("/test/a.txt")]));
+
+        app.open_editor = None;
+        for name in ["b.txt", "sub", "a.txt"] {
+            app.active_panel_mut()
+                .marked
+                .insert(PathBuf::from("/test").join(name));
+        }
+        app.edit_targeted();
+        let expected = ["/test/a.txt", "/test/b.txt"].map(PathBuf::from).to_vec();
+        assert_eq!(app.open_editor, Some(expected));
+        assert!(app.status_message.contains("Skipped 1"));
+    }
+
+    #[tokio::test]
+    async fn chooser_enter_picks_file_or_marked_entries() {
+        let entries = crate::app::make_test_entries(&["sub/", "a.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.start_chooser();
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+
+        // A directory still opens.
+        app.active_panel_mut().selected = 1;
+        assert!(!app.choose_targeted());
+        assert!(!app.should_quit);
+
+        app.active_panel_mut().selected = 2;
+        app.handle_key(enter);
+        assert!(app.should_quit);
+        assert_eq!(app.chosen, Some(vec![PathBuf::from("/test/a.txt")]));
+
+        app.should_quit = false;
+        for name in ["b.txt", "sub"] {
+            app.active_panel_mut()
+                .marked
+                .insert(PathBuf::from("/test").join(name));
+        }
+        app.active_panel_mut().selected = 1;
+        app.handle_key(enter);
+        let expected = ["/test/sub", "/test/b.txt"].map(PathBuf::from).to_vec();
+        assert_eq!(app.chosen, Some(expected));
+    }
+
+    #[tokio::test]
+    async fn enter_on_a_file_follows_file_action() {
+        let entries = crate::app::make_test_entries(&["a.txt", "b.zip"]);
+        let mut app = App::new_for_test(entries);
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.active_panel_mut().selected = 1;
+        app.handle_normal(enter);
+        assert_eq!(app.mode, Mode::Viewer);
+        assert!(app.open_editor.is_none());
+
+        app.mode = Mode::Normal;
+        app.viewer = None;
+        app.config.file_action = FileAction::Edit;
+        app.handle_normal(enter);
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.open_editor, Some(vec![PathBuf::from("/test/a.txt")]));
+
+        // `l` on a file does the same instead of trying to enter it.
+        app.open_editor = None;
+        app.handle_normal(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE));
+        assert_eq!(app.open_editor, Some(vec![PathBuf::from("/test/a.txt")]));
+
+        // Archives still open in the archive browser.
+        app.open_editor = None;
+        app.active_panel_mut().selected = 2;
+        app.handle_normal(enter);
+        assert!(app.open_editor.is_none());
+    }
+
+    #[tokio::test]
+    async fn yn_copies_the_basename_but_not_dotdot() {
+        let entries = crate::app::make_test_entries(&["notes.txt"]);
+        let mut app = App::new_for_test(entries);
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        app.handle_normal(key('y'));
+        app.handle_normal(key('n'));
+        assert!(app.file_op_rx.is_none());
+
+        app.active_panel_mut().selected = 1;
+        app.handle_normal(key('y'));
+        app.handle_normal(key('n'));
+        let Ok(FileOpResult::Clipboard { label, .. }) = app.file_op_rx.take().unwrap().await else {
+            panic!("expected a clipboard result");
+        };
+        assert_eq!(label, "Name: notes.txt");
+    }
+
+    #[tokio::test]
+    async fn yc_refuses_a_binary_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let bin = dir.path().join("tool");
+        std::fs::write(&bin, b"\x7fELF\0").unwrap();
+        let mut entries = crate::app::make_test_entries(&["tool"]);
+        entries[1].path = bin;
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 1;
+
+        app.yank_contents();
+        let result = app.file_op_rx.take().unwrap().await.unwrap();
+        app.apply_file_op(result);
+        let toast = app.toast.as_ref().unwrap();
+        assert!(toast.error);
+        assert_eq!(toast.text, "yc: tool is a binary file");
+    }
+
+    #[tokio::test]
+    async fn yank_relative_path_prefers_the_repo_root() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 1;
+        app.tab_mut().panels[1].path = PathBuf::from("/other/dir");
+        app.yank_relative_path();
+        let Ok(FileOpResult::Clipboard { label, .. }) = app.file_op_rx.take().unwrap().await else {
+            panic!("expected a clipboard result");
+        };
+        assert_eq!(label, "Path from /other/dir: ../../test/a.txt");
+
+        app.git_roots[0] = Some(PathBuf::from("/"));
+        app.yank_relative_path();
+        let Ok(FileOpResult::Clipboard { label, .. }) = app.file_op_rx.take().unwrap().await else {
+            panic!("expected a clipboard result");
+        };
+        assert_eq!(label, "Path from repo root: test/a.txt");
     }
 
     #[tokio::test]
@@ -677,7 +1245,7 @@ mod tests {
         let mut app = App::new_for_test(entries);
         app.mode = Mode::Viewer;
         app.viewer = Some(crate::viewer::Viewer::loading(PathBuf::from("/x")));
-        app.request_open_editor(PathBuf::from("/test/a.txt"));
+        app.request_open_editor(vec![PathBuf::from("/test/a.txt")]);
         assert_eq!(app.mode, Mode::Normal);
         assert!(app.viewer.is_none());
     }
@@ -710,6 +1278,32 @@ mod tests {
         assert!(!app.dir_sorts.contains_key(&PathBuf::from("/test")));
     }
 
+    #[tokio::test]
+    async fn sort_is_per_panel_on_the_same_directory() {
+        let entries = crate::app::make_test_entries(&["a.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries.clone());
+        app.tab_mut().panels[1].path = PathBuf::from("/test");
+        app.tab_mut().panels[1].entries = std::sync::Arc::new(entries);
+
+        app.set_sort(SortMode::Size);
+        assert_eq!(app.tab().panels[1].sort_mode, SortMode::Name);
+        assert_eq!(
+            app.dir_sorts[&PathBuf::from("/test")],
+            (SortMode::Size, false)
+        );
+
+        // Sorting the right panel leaves the left one alone; the newest sort
+        // is the one remembered for the directory.
+        app.tab_mut().active = 1;
+        app.toggle_sort_reverse();
+        assert_eq!(app.tab().panels[0].sort_mode, SortMode::Size);
+        assert!(!app.tab().panels[0].sort_reverse);
+        assert_eq!(
+            app.dir_sorts[&PathBuf::from("/test")],
+            (SortMode::Name, true)
+        );
+    }
+
     #[tokio::test]
     async fn which_key_hints_without_pending() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -778,6 +1372,59 @@ mod tests {
         assert_eq!(app.active_tab, 0);
     }
 
+    #[tokio::test]
+    async fn g_slash_and_g_dot_go_to_root_and_launch_dir() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.launch_dir = PathBuf::from("/srv/project");
+        app.handle_key(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE));
+        assert_eq!(app.active_panel().path, PathBuf::from("/"));
+        app.handle_key(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Char('.'), KeyModifiers::NONE));
+        assert_eq!(app.active_panel().path, PathBuf::from("/srv/project"));
+    }
+
+    #[tokio::test]
+    async fn space_o_toggles_owner_column() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        assert!(!app.config.show_owner);
+        app.handle_key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE));
+        assert!(app.config.show_owner);
+        assert_eq!(app.status_message, "Owner column: shown");
+        app.toggle_owner_column();
+        assert!(!app.config.show_owner);
+    }
+
+    #[tokio::test]
+    async fn space_shift_t_toggles_relative_dates_per_panel() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.config.relative_dates = true;
+        app.handle_key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Char('T'), KeyModifiers::NONE));
+        assert_eq!(app.active_panel().relative_dates, Some(false));
+        assert_eq!(app.status_message, "Dates: absolute in this panel");
+        // The other panel keeps following the setting.
+        assert_eq!(app.tab().panels[1].relative_dates, None);
+        app.toggle_relative_dates();
+        assert_eq!(app.active_panel().relative_dates, Some(true));
+    }
+
+    #[tokio::test]
+    async fn space_shift_p_flips_preview_side() {
+        use crate::config::PreviewSide;
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        assert_eq!(app.config.preview_side, PreviewSide::Right);
+        app.handle_key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT));
+        assert_eq!(app.config.preview_side, PreviewSide::Left);
+        assert_eq!(app.status_message, "Preview on the left");
+    }
+
     #[tokio::test]
     async fn toggle_hidden_toggles_all_panels() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -945,6 +1592,81 @@ mod tests {
         assert!(!hints.is_empty());
     }
 
+    #[tokio::test]
+    async fn goto_sibling_dir_reports_missing_sibling() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        // "/test" doesn't exist, so there's no sibling to step to.
+        app.goto_sibling_dir(true);
+        let rx = app.nav_check_rx.take().expect("nav check spawned");
+        let result = rx.await.unwrap();
+        assert!(!result.exists);
+        app.apply_nav_check(result);
+        assert_eq!(app.status_message, "No next sibling directory");
+    }
+
+    #[tokio::test]
+    async fn missing_bookmark_opens_nearest_ancestor() {
+        let base = std::env::temp_dir().join(format!("fcmd_prune_{}", std::process::id()));
+        std::fs::create_dir_all(&base).unwrap();
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+
+        let gone = base.join("removed/deeper");
+        app.apply_nav_check(NavCheckResult::probe(gone, NavSource::Bookmark));
+        assert_eq!(app.active_panel().path, base);
+        assert!(app.status_message.contains("no longer exists"));
+        assert!(app.status_message.contains("opened"));
+
+        // A target whose nearest ancestor is the current directory (a `:cd`
+        // typo) just reports instead of "navigating" in place.
+        let typo = base.join("no-such-dir");
+        app.apply_nav_check(NavCheckResult::probe(typo, NavSource::Cd));
+        assert_eq!(app.status_message, "No such file or directory");
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[tokio::test]
+    async fn which_key_hints_respect_config_mode() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.pending_key = Some('g');
+        app.pending_key_time = Some(Instant::now());
+
+        app.config.which_key = crate::config::WhichKey::Always;
+        assert!(app.which_key_hints().is_some());
+
+        app.config.which_key = crate::config::WhichKey::Never;
+        app.pending_key_time = Some(Instant::now() - std::time::Duration::from_secs(5));
+        assert!(app.which_key_hints().is_none());
+
+        app.config.which_key = crate::config::WhichKey::Delay;
+        app.config.which_key_delay_ms = 10_000;
+        assert!(app.which_key_hints().is_none());
+    }
+
+    #[tokio::test]
+    async fn question_mark_shows_top_level_hints_at_once() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.config.which_key = crate::config::WhichKey::Never;
+        app.handle_key(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        let hints = app.which_key_hints().unwrap();
+        assert!(hints.contains(&("Space", "leader")));
+        // It waits for a key instead of timing out like a prefix.
+        app.pending_key_time = Some(Instant::now() - std::time::Duration::from_secs(60));
+        app.poll_pending_key();
+        assert_eq!(app.pending_key, Some('?'));
+
+        // The next key does its usual job; `?` again opens the full help.
+        app.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        assert_eq!(app.active_panel().selected, 1);
+        assert!(app.which_key_hints().is_none());
+        app.handle_key(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Help);
+    }
+
     #[tokio::test]
     async fn which_key_unknown_pending_returns_none() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -0,0 +1,260 @@
+use super::*;
+
+/// Every action the palette offers, as `(trigger, description)`.
+///
+/// The trigger is what the palette executes, so the list can never drift from
+/// the real bindings: a `:`-prefixed trigger runs that command (a trailing
+/// space opens the command line pre-filled so the user can type the
+/// argument), anything else is replayed as Normal-mode keys (`" "` is the
+/// Space leader).
+pub const PALETTE_ACTIONS: &[(&str, &str)] = &[
+    // Navigation
+    ("gg", "Go to top"),
+    ("G", "Go to bottom"),
+    ("~", "Go to home directory"),
+    ("g/", "Go to the filesystem root"),
+    ("g.", "Go back to the directory fcmd started in"),
+    ("]]", "Next sibling directory"),
+    ("[[", "Previous sibling directory"),
+    ("gf", "Follow symlink"),
+    ("gp", "Jump to an ancestor directory"),
+    ("gt", "Next tab"),
+    ("gT", "Previous tab"),
+    (":tabnew", "New tab"),
+    (":tabclose", "Close tab"),
+    (":cd ", "Change directory"),
+    // Files
+    ("yy", "Yank (copy) selection"),
+    ("yp", "Yank path to clipboard"),
+    ("yr", "Yank path relative to repo root / other panel"),
+    ("yn", "Yank name to clipboard"),
+    ("yc", "Yank file contents to clipboard"),
+    ("p", "Paste here"),
+    ("P", "Paste to other panel"),
+    ("dd", "Move to trash"),
+    ("dD", "Delete permanently"),
+    (":rm!", "Delete permanently (bypass trash)"),
+    (":emptytrash", "Empty the system trash"),
+    (":mount", "Mount a USB drive / list devices"),
+    (":unmount", "Unmount the device holding this directory"),
+    ("u", "Undo last operation"),
+    ("r", "Rename"),
+    ("a", "Create file (trailing / for directory)"),
+    (":mkdir ", "Create directory"),
+    (":touch ", "Create file"),
+    (":touch! ", "Set the targets' times to now or a date"),
+    (":ln ", "Create symlink"),
+    (":hardlink ", "Create hard link"),
+    ("cw", "Bulk rename"),
+    ("cW", "Bulk rename in $EDITOR"),
+    ("cp", "Change permissions"),
+    ("co", "Change owner"),
+    (":archive ", "Create archive"),
+    (":extract", "Extract archive into a new directory"),
+    (":genchecksums", "Write SHA256SUMS"),
+    (":dryrun", "Toggle dry run"),
+    (":icons", "Toggle Nerd Font icons (plain ASCII)"),
+    ("i", "File info"),
+    ("o", "Open in editor"),
+    // Selection
+    ("v", "Visual mode"),
+    ("A", "Select all"),
+    ("+", "Select by pattern"),
+    ("-", "Unselect by pattern"),
+    ("*", "Invert selection"),
+    (
+        "gv",
+        "Mark everything from the last marked entry to the cursor",
+    ),
+    ("m", "Toggle visual mark"),
+    (" m", "Clear visual mark"),
+    ("]m", "Next visual mark"),
+    ("[m", "Previous visual mark"),
+    (":marked", "Show only visually marked entries"),
+    (":marked ", "Show only entries marked at a level"),
+    // Search
+    ("/", "Search in panel"),
+    (" f", "Filter panel"),
+    ("f", "Find files (local)"),
+    ("F", "Find files (global)"),
+    (":findin ", "Find files (global) under a directory"),
+    (" ;", "Reopen last find"),
+    (":grep ", "Search file contents"),
+    (":pipe ", "Pipe selected paths to a command"),
+    // View
+    (" t", "Toggle tree"),
+    (" h", "Toggle hidden files"),
+    (" o", "Toggle owner column"),
+    (" T", "Toggle relative dates in this panel"),
+    (" p", "Toggle preview"),
+    (" P", "Flip preview side"),
+    (" ut", "Toggle transparent background"),
+    (" w1", "Single panel layout"),
+    (" w2", "Dual panel layout"),
+    (" w3", "Triple panel layout"),
+    (" ws", "Swap panels"),
+    (":put ", "Copy selection to a dir or host:path (rsync)"),
+    (":get ", "Copy a path or host:path here (rsync)"),
+    (":pull", "Copy other panel's marked files here"),
+    (":pullmove", "Move other panel's marked files here"),
+    (" we", "Equalize panels"),
+    (" wo", "Go to the other panel's directory"),
+    ("T", "Theme picker"),
+    // Sort
+    (" sn", "Sort by name"),
+    (" ss", "Sort by size"),
+    (" sm", "Sort by modified"),
+    (" sc", "Sort by created"),
+    (" se", "Sort by extension"),
+    (" sr", "Reverse sort"),
+    ("S", "Cycle sort mode"),
+    // Git
+    ("gs", "Git stage"),
+    ("gu", "Git unstage"),
+    ("gd", "Git diff"),
+    // Other
+    (" d", "Calculate directory sizes"),
+    (" D", "Toggle automatic directory sizes"),
+    ("b", "Add bookmark"),
+    ("B", "Bookmarks"),
+    (":marks", "List named marks"),
+    (":messages", "Message history"),
+    (" j", "Task manager"),
+    (" r", "Trash / restore"),
+    (":strings", "View printable strings"),
+    (":struct", "Parse PE/ELF/Mach-O"),
+    (" ?", "Help"),
+    ("?", "Overview of the everyday keys"),
+    ("q", "Quit"),
+];
+
+impl App {
+    pub(super) fn open_palette(&mut self) {
+        self.palette_query.clear();
+        self.palette_selected = 0;
+        self.update_palette_matches();
+        self.mode = Mode::Palette;
+    }
+
+    /// Re-rank `PALETTE_ACTIONS` against the query, matching both the
+    /// description and the trigger (so typing `dd` finds "Move to trash").
+    pub(super) fn update_palette_matches(&mut self) {
+        // Ties keep the curated order.
+        self.palette_matches = crate::search::fuzzy_rank(
+            &self.palette_query,
+            PALETTE_ACTIONS
+                .iter()
+                .map(|(keys, desc)| format!("{desc} {}", keys.trim())),
+        );
+        self.palette_selected = 0;
+    }
+
+    pub(super) fn handle_palette(&mut self, key: KeyEvent) {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let nav_up = matches!(key.code, KeyCode::Up)
+            || (ctrl && matches!(key.code, KeyCode::Char('p') | KeyCode::Char('k')));
+        let nav_down = matches!(key.code, KeyCode::Down)
+            || (ctrl && matches!(key.code, KeyCode::Char('n') | KeyCode::Char('j')));
+
+        if nav_up {
+            self.palette_selected = self.palette_selected.saturating_sub(1);
+            return;
+        }
+        if nav_down {
+            let max = self.palette_matches.len().saturating_sub(1);
+            self.palette_selected = (self.palette_selected + 1).min(max);
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.mode = Mode::Normal,
+            KeyCode::Enter => {
+                self.mode = Mode::Normal;
+                if let Some(&i) = self.palette_matches.get(self.palette_selected) {
+                    self.run_palette_action(PALETTE_ACTIONS[i].0);
+                }
+            }
+            KeyCode::Backspace => {
+                self.palette_query.pop();
+                self.update_palette_matches();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.palette_query.push(c);
+                self.update_palette_matches();
+            }
+            _ => {}
+        }
+    }
+
+    fn run_palette_action(&mut self, trigger: &str) {
+        if let Some(cmd) = trigger.strip_prefix(':') {
+            self.enter_command();
+            self.command_input = cmd.to_string();
+            if !cmd.ends_with(' ') {
+                self.handle_command(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+            }
+            return;
+        }
+        for c in trigger.chars() {
+            self.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn type_query(app: &mut App, q: &str) {
+        for c in q.chars() {
+            app.handle_palette(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    #[tokio::test]
+    async fn ctrl_p_opens_palette_with_all_actions() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.handle_key(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert_eq!(app.mode, Mode::Palette);
+        assert_eq!(app.palette_matches.len(), PALETTE_ACTIONS.len());
+    }
+
+    #[tokio::test]
+    async fn fuzzy_query_ranks_and_runs_key_action() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.open_palette();
+        type_query(&mut app, "toghid");
+        let first = app.palette_matches[0];
+        assert_eq!(PALETTE_ACTIONS[first].1, "Toggle hidden files");
+
+        let before = app.active_panel().show_hidden;
+        app.handle_palette(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_ne!(app.active_panel().show_hidden, before);
+    }
+
+    #[tokio::test]
+    async fn command_with_argument_prefills_command_line() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.open_palette();
+        type_query(&mut app, "change directory");
+        app.handle_palette(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Command);
+        assert_eq!(app.command_input, "cd ");
+    }
+
+    #[tokio::test]
+    async fn no_match_enter_is_noop() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.open_palette();
+        type_query(&mut app, "zzzzqqq");
+        assert!(app.palette_matches.is_empty());
+        app.handle_palette(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Normal);
+    }
+}
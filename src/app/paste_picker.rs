@@ -0,0 +1,228 @@
+use super::*;
+
+/// What Enter does with the directory picked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PickAction {
+    /// Paste the register there.
+    Paste,
+    /// Open it in the active panel (a `:cd` wildcard matching several).
+    Cd,
+}
+
+/// "Paste where?" picker opened by `p` when `paste_picker` is enabled; also
+/// lists the directories a `:cd` wildcard matched.
+pub struct PastePicker {
+    pub action: PickAction,
+    /// Candidate destinations, likeliest first (the current directory leads,
+    /// so `p Enter` still pastes in place).
+    pub dirs: Vec<PathBuf>,
+    pub query: String,
+    /// Indices into `dirs`, ranked against `query`.
+    pub matches: Vec<usize>,
+    pub selected: usize,
+}
+
+impl PastePicker {
+    fn new(action: PickAction, dirs: Vec<PathBuf>) -> Self {
+        let mut picker = PastePicker {
+            action,
+            dirs,
+            query: String::new(),
+            matches: Vec::new(),
+            selected: 0,
+        };
+        picker.update_matches();
+        picker
+    }
+
+    /// Re-rank `dirs` against the query with the find overlay's fuzzy scorer.
+    pub fn update_matches(&mut self) {
+        // Ties keep the seed order.
+        self.matches =
+            crate::search::fuzzy_rank(&self.query, self.dirs.iter().map(|d| d.to_string_lossy()));
+        self.selected = 0;
+    }
+
+    pub fn selected_dir(&self) -> Option<&PathBuf> {
+        self.matches.get(self.selected).map(|&i| &self.dirs[i])
+    }
+}
+
+impl App {
+    /// Open the picker with the directories known without touching the disk;
+    /// sibling and child directories are listed in the background and merged
+    /// in by `apply_paste_picker_load`.
+    pub(super) fn open_paste_picker(&mut self) {
+        let current = self.active_panel().path.clone();
+        let show_hidden = self.active_panel().show_hidden;
+
+        let mut seeds = vec![current.clone()];
+        let count = self.layout.count();
+        for tab in &self.tabs {
+            seeds.extend(tab.panels.iter().take(count).map(|p| p.path.clone()));
+        }
+        seeds.extend(self.bookmarks.iter().map(|(_, p)| p.clone()));
+        let mut marks: Vec<(&char, &PathBuf)> = self.marks.iter().collect();
+        marks.sort();
+        seeds.extend(marks.into_iter().map(|(_, p)| p.clone()));
+        if let Some(parent) = current.parent() {
+            seeds.push(parent.to_path_buf());
+        }
+        let seeds = dedup_paths(seeds);
+
+        self.paste_picker = Some(PastePicker::new(PickAction::Paste, seeds.clone()));
+        self.mode = Mode::PastePicker;
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.paste_picker_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let mut dirs: Vec<PathBuf> = seeds.into_iter().filter(|d| d.is_dir()).collect();
+            dirs.extend(crate::fs::subdirs(&current, show_hidden));
+            if let Some(parent) = current.parent() {
+                dirs.extend(crate::fs::subdirs(parent, show_hidden));
+            }
+            let _ = tx.send(dedup_paths(dirs));
+        });
+    }
+
+    /// Choose among the directories a `:cd` wildcard matched.
+    pub(super) fn open_dir_picker(&mut self, dirs: Vec<PathBuf>) {
+        self.paste_picker = Some(PastePicker::new(PickAction::Cd, dirs));
+        self.paste_picker_rx = None;
+        self.mode = Mode::PastePicker;
+    }
+
+    pub fn apply_paste_picker_load(&mut self, dirs: Vec<PathBuf>) {
+        let Some(picker) = self.paste_picker.as_mut() else {
+            return;
+        };
+        // Keep the cursor on the same directory if the user already moved it.
+        let keep = picker.selected_dir().cloned();
+        picker.dirs = dirs;
+        picker.update_matches();
+        if let Some(keep) = keep
+            && let Some(pos) = picker.matches.iter().position(|&i| picker.dirs[i] == keep)
+        {
+            picker.selected = pos;
+        }
+    }
+
+    pub(super) fn handle_paste_picker(&mut self, key: KeyEvent) {
+        let Some(picker) = self.paste_picker.as_mut() else {
+            self.mode = Mode::Normal;
+            return;
+        };
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => {
+                self.paste_picker = None;
+                self.paste_picker_rx = None;
+                self.mode = Mode::Normal;
+            }
+            KeyCode::Enter => {
+                let dst = picker.selected_dir().cloned();
+                let action = picker.action;
+                self.paste_picker = None;
+                self.paste_picker_rx = None;
+                self.mode = Mode::Normal;
+                match (dst, action) {
+                    (Some(dst), PickAction::Paste) => self.paste_into(dst),
+                    (Some(dst), PickAction::Cd) => {
+                        let side = self.tab().active;
+                        self.navigate_cached(dst, side, None);
+                    }
+                    (None, _) => {}
+                }
+            }
+            KeyCode::Up => picker.selected = picker.selected.saturating_sub(1),
+            KeyCode::Char('p') | KeyCode::Char('k') if ctrl => {
+                picker.selected = picker.selected.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                let max = picker.matches.len().saturating_sub(1);
+                picker.selected = (picker.selected + 1).min(max);
+            }
+            KeyCode::Char('n') | KeyCode::Char('j') if ctrl => {
+                let max = picker.matches.len().saturating_sub(1);
+                picker.selected = (picker.selected + 1).min(max);
+            }
+            KeyCode::Backspace => {
+                picker.query.pop();
+                picker.update_matches();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                picker.query.push(c);
+                picker.update_matches();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Drop repeated paths, keeping the first (highest-priority) occurrence.
+fn dedup_paths(paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let mut seen = HashSet::new();
+    paths
+        .into_iter()
+        .filter(|p| seen.insert(p.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[tokio::test]
+    async fn p_opens_picker_when_enabled() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.config.paste_picker = true;
+        app.active_panel_mut().selected = 1;
+        app.yank_targeted();
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::PastePicker);
+        let picker = app.paste_picker.as_ref().unwrap();
+        // Current directory first so `p Enter` behaves like a plain paste.
+        assert_eq!(picker.selected_dir(), Some(&app.active_panel().path));
+        assert_eq!(app.task_manager.active_count(), 0);
+
+        app.handle_paste_picker(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.paste_picker.is_none());
+        assert!(app.register.is_some());
+    }
+
+    #[tokio::test]
+    async fn picker_filters_and_keeps_selection_on_load() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.bookmarks = vec![
+            ("docs".into(), PathBuf::from("/home/u/docs")),
+            ("music".into(), PathBuf::from("/home/u/music")),
+        ];
+        app.open_paste_picker();
+        for c in "music".chars() {
+            app.handle_paste_picker(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        let picker = app.paste_picker.as_ref().unwrap();
+        assert_eq!(picker.selected_dir(), Some(&PathBuf::from("/home/u/music")));
+
+        app.apply_paste_picker_load(vec![
+            PathBuf::from("/tmp/music-old"),
+            PathBuf::from("/home/u/music"),
+        ]);
+        let picker = app.paste_picker.as_ref().unwrap();
+        assert_eq!(picker.selected_dir(), Some(&PathBuf::from("/home/u/music")));
+    }
+
+    #[test]
+    fn dedup_keeps_first_occurrence() {
+        let paths = ["/a", "/b", "/a", "/c", "/b"].map(PathBuf::from).to_vec();
+        assert_eq!(
+            dedup_paths(paths),
+            ["/a", "/b", "/c"].map(PathBuf::from).to_vec()
+        );
+    }
+}
@@ -1,28 +1,155 @@
 use super::task_manager::TaskEvent;
 use super::*;
 use crate::util::format_bytes;
-
-/// How many ticks (250ms each) a finished-task notification stays on screen
-/// before auto-expiring. ~5 seconds.
-const TASK_NOTIFICATION_TICKS: u32 = 20;
+use std::time::Duration;
+
+/// How long an operation result toast stays on screen.
+const TOAST_TTL: Duration = Duration::from_secs(5);
+/// Errors linger longer so they can actually be read.
+const TOAST_ERROR_TTL: Duration = Duration::from_secs(10);
+/// How long the active panel has to stay in a directory before
+/// `auto_dir_sizes` measures it, so stepping through directories doesn't
+/// start a du job for each one.
+const AUTO_DU_DELAY: Duration = Duration::from_millis(600);
+/// Tick while a spinner or progress bar moves: about the slowest rate at
+/// which the animation still looks smooth.
+pub const ANIMATION_TICK: Duration = Duration::from_millis(100);
+/// `poll_interval_ms` is stretched this many times when nothing is going on.
+const IDLE_TICK_FACTOR: u32 = 4;
+/// Floor for `poll_interval_ms`, so a typo can't turn the loop into a busy wait.
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// What the tick's `poll_*` calls can change on screen. The main loop redraws
+/// after a tick only when this differs from before it. Every background
+/// result clears its receiver as it lands, so the `*_pending` flags turning
+/// off catch results the counts would miss: a find preview arriving, an info
+/// size replacing "Calculating...", git statuses replaced at the same count.
+#[derive(PartialEq, Debug)]
+pub struct PollSnapshot {
+    status: String,
+    toast: Option<String>,
+    background_progress: Option<String>,
+    conflict: bool,
+    active_tasks: usize,
+    dir_sizes: usize,
+    git_statuses: usize,
+    info_lines: usize,
+    find_counts: (usize, usize),
+    find_loading: bool,
+    find_preview_pending: bool,
+    du_pending: bool,
+    dir_sizes_pending: bool,
+    info_du_pending: bool,
+    git_pending: bool,
+    links_pending: bool,
+}
 
 impl App {
+    pub fn poll_snapshot(&self) -> PollSnapshot {
+        let find = self.find_state.as_ref();
+        PollSnapshot {
+            status: self.status_message.clone(),
+            toast: self.toast.as_ref().map(|t| t.text.clone()),
+            background_progress: self.background_progress.clone(),
+            conflict: self.conflict_info.is_some(),
+            active_tasks: self.task_manager.active_count(),
+            dir_sizes: self.dir_sizes.len(),
+            git_statuses: self.git_statuses.len(),
+            info_lines: self.info_lines.len(),
+            find_counts: find.map_or((0, 0), |fs| (fs.total_count(), fs.filtered_count())),
+            find_loading: find.is_some_and(|fs| fs.loading),
+            find_preview_pending: find.is_some_and(|fs| fs.preview_pending()),
+            du_pending: self.du_progress.is_some(),
+            dir_sizes_pending: self.dir_sizes_load_rx.is_some(),
+            info_du_pending: self.info_du_rx.is_some(),
+            git_pending: self.git_progress.is_some(),
+            links_pending: self.link_scan_rx.is_some(),
+        }
+    }
+
+    /// How long the main loop may sleep before its next tick. Something
+    /// animating gets `ANIMATION_TICK`; something a tick has to check on (a
+    /// pending key, a toast to expire, a job's channel) gets
+    /// `poll_interval_ms`; otherwise the loop mostly sleeps, to spare a laptop
+    /// battery when fcmd sits open all day.
+    pub fn tick_interval(&self) -> Duration {
+        let base = Duration::from_millis(self.config.poll_interval_ms).max(MIN_POLL_INTERVAL);
+        if self.task_manager.active_count() > 0 || self.background_progress.is_some() {
+            return ANIMATION_TICK.min(base);
+        }
+        let auto_du_due = self.config.auto_dir_sizes
+            && (self.auto_du_since.is_some() || self.active_panel().path != self.auto_du_dir);
+        let sizes_due = self.db.is_some()
+            && self
+                .tab()
+                .panels
+                .iter()
+                .any(|p| !self.dir_sizes_loaded.contains(&p.path));
+        let waiting = self.pending_key.is_some()
+            || self.toast.is_some()
+            || !self.conflict_rxs.is_empty()
+            || self.du_progress.is_some()
+            || self.dir_sizes_load_rx.is_some()
+            || self.find_state.is_some()
+            || self.info_du_rx.is_some()
+            || self.git_progress.is_some()
+            || self.links_due()
+            || auto_du_due
+            || sizes_due;
+        if waiting {
+            base
+        } else {
+            base * IDLE_TICK_FACTOR
+        }
+    }
+
+    /// Drop a half-typed key sequence (`g`, `d`, `Space`, ...) once it has waited
+    /// longer than `key_timeout_ms`, so a forgotten prefix doesn't swallow the next
+    /// keystroke. The which-key popup stays up until this fires; the `?` key
+    /// overview waits for a key instead.
+    pub fn poll_pending_key(&mut self) {
+        let timeout = self.config.key_timeout_ms;
+        if timeout > 0
+            && self.pending_key != Some('?')
+            && self
+                .pending_key_time
+                .is_some_and(|t| t.elapsed() >= std::time::Duration::from_millis(timeout))
+        {
+            self.pending_key = None;
+            self.pending_key_time = None;
+            self.pending_count = None;
+        }
+    }
+
     pub fn poll_find(&mut self) {
+        let dark = self.theme_is_dark();
         if let Some(ref mut fs) = self.find_state {
+            fs.poll_scheduled_search();
             fs.poll_entries();
-            fs.update_find_preview(self.visible_height);
+            fs.update_find_preview(self.visible_height, dark);
             fs.poll_find_preview();
         }
     }
 
+    /// Show an operation result in the toast area for a few seconds.
+    pub fn show_toast(&mut self, text: impl Into<String>, error: bool) {
+        let text = text.into();
+        self.log_message(&text, error);
+        self.toast = Some(Toast {
+            text,
+            error,
+            shown_at: Instant::now(),
+        });
+    }
+
     pub fn poll_tasks(&mut self) {
         let events = self.task_manager.poll_all();
 
         let mut needs_refresh = false;
-        // Notification reflects the most recent task that finished this poll. Finished
+        // The toast reflects the most recent task that finished this poll. Finished
         // tasks are kept in the manager (visible in the task overlay), so the old
         // "last task once active_count==0" approach no longer applies.
-        let mut last_summary = None;
+        let mut last_result: Option<(String, bool)> = None;
 
         for event in events {
             match event {
@@ -32,44 +159,65 @@ impl App {
                     is_copy,
                     summary,
                 } => {
+                    let undoable = !records.is_empty();
                     self.undo_stack.push(records);
                     if error.is_none() && !is_copy {
                         self.register = None;
                     }
-                    last_summary = Some(summary);
+                    let failed = error.is_some();
+                    last_result = Some((with_undo_hint(summary, undoable && !failed), failed));
                     needs_refresh = true;
                 }
-                TaskEvent::DeleteFinished { summary, trashed } => {
+                TaskEvent::DeleteFinished {
+                    summary,
+                    failed,
+                    trashed,
+                } => {
                     // Record the trashed batch on the undo stack so `u` restores
                     // it and the trash overlay can list/restore individual items.
-                    if !trashed.is_empty() {
+                    let undoable = !trashed.is_empty();
+                    if undoable {
                         let records = trashed.into_iter().map(ops::OpRecord::Trashed).collect();
                         self.undo_stack.push(records);
                     }
-                    last_summary = Some(summary);
+                    last_result = Some((with_undo_hint(summary, undoable && !failed), failed));
                     needs_refresh = true;
                 }
-                TaskEvent::ArchiveFinished { summary } => {
-                    last_summary = Some(summary);
+                TaskEvent::ArchiveFinished {
+                    summary,
+                    failed,
+                    record,
+                }
+                | TaskEvent::ChecksumFinished {
+                    summary,
+                    failed,
+                    record,
+                } => {
+                    let undoable = record.is_some();
+                    self.undo_stack.push(record.into_iter().collect());
+                    last_result = Some((with_undo_hint(summary, undoable && !failed), failed));
                     needs_refresh = true;
                 }
             }
         }
 
-        if let Some(summary) = last_summary {
-            self.task_notification = Some(summary);
-            self.task_notification_tick = Some(self.tick_count);
+        if let Some((text, error)) = last_result {
+            self.show_toast(text, error);
             // Keep retained finished history bounded.
             self.task_manager.prune_finished();
         }
 
-        // Auto-expire the completion notification after a few seconds so it stays
-        // visible across keypresses but doesn't linger forever.
-        if let Some(set_tick) = self.task_notification_tick
-            && self.tick_count.wrapping_sub(set_tick) >= TASK_NOTIFICATION_TICKS
-        {
-            self.task_notification = None;
-            self.task_notification_tick = None;
+        // Auto-expire the toast so it stays visible across keypresses but
+        // doesn't linger forever. Errors get longer to be read.
+        if let Some(ref toast) = self.toast {
+            let ttl = if toast.error {
+                TOAST_ERROR_TTL
+            } else {
+                TOAST_TTL
+            };
+            if toast.shown_at.elapsed() >= ttl {
+                self.toast = None;
+            }
         }
 
         if needs_refresh {
@@ -90,9 +238,10 @@ impl App {
                 match self.conflict_rxs[i].try_recv() {
                     Ok(info) => {
                         self.conflict_info = Some(info);
-                        // Default to the safe, non-destructive option (Skip = index 1)
-                        // so a reflexive Enter never overwrites an existing file.
-                        self.conflict_selected = 1;
+                        // Default to the safe, non-destructive option (Skip) so a
+                        // reflexive Enter never overwrites an existing file.
+                        self.conflict_selected =
+                            dialogs::conflict_button(crate::fs::ops::ConflictChoice::Skip);
                         self.mode = Mode::Conflict;
                         return;
                     }
@@ -109,7 +258,7 @@ impl App {
 
     pub(super) fn start_du(&mut self) {
         if self.du_progress.is_some() {
-            self.status_message = "Directory size calculation already in progress".into();
+            self.set_status("Directory size calculation already in progress".into());
             return;
         }
         let panel = self.active_panel();
@@ -120,22 +269,79 @@ impl App {
             .map(|e| e.path.clone())
             .collect();
         if dirs.is_empty() {
-            self.status_message = "No subdirectories to measure".into();
+            self.set_status("No subdirectories to measure".into());
             return;
         }
+        self.spawn_du(dirs, false);
+    }
+
+    fn spawn_du(&mut self, dirs: Vec<PathBuf>, auto: bool) {
         let n = dirs.len();
         let (tx, rx) = tokio::sync::mpsc::channel(64);
-        du::du_in_background(dirs, tx);
+        du::du_in_background(dirs, self.config.worker_threads(), tx);
         self.du_progress = Some(DuProgress {
             rx,
             started_at: Instant::now(),
+            auto,
         });
         self.background_progress = Some(format!("Calculating sizes for {n} directories..."));
     }
 
+    /// `Space D`: measure directories automatically as the active panel
+    /// enters them, for this session.
+    pub(super) fn toggle_auto_dir_sizes(&mut self) {
+        self.config.auto_dir_sizes = !self.config.auto_dir_sizes;
+        // Count the current directory as just entered.
+        self.auto_du_dir = PathBuf::new();
+        self.set_status(if self.config.auto_dir_sizes {
+            "Automatic directory sizes: on".into()
+        } else {
+            "Automatic directory sizes: off".into()
+        });
+    }
+
+    /// `auto_dir_sizes`: once the active panel has stayed `AUTO_DU_DELAY` in a
+    /// directory, measure the subdirectories that have no size yet. Sizes
+    /// already known (from an earlier run or the DB) are not measured again;
+    /// `Space d` refreshes them.
+    fn poll_auto_du(&mut self) {
+        if !self.config.auto_dir_sizes {
+            return;
+        }
+        let path = self.active_panel().path.clone();
+        if path != self.auto_du_dir {
+            self.auto_du_dir = path;
+            self.auto_du_since = Some(Instant::now());
+            return;
+        }
+        let settled = self
+            .auto_du_since
+            .is_some_and(|t| t.elapsed() >= AUTO_DU_DELAY);
+        // Wait for the listing and for the sizes stored in the DB.
+        if !settled
+            || self.active_panel().loading
+            || self.du_progress.is_some()
+            || self.dir_sizes_load_rx.is_some()
+        {
+            return;
+        }
+        self.auto_du_since = None;
+        let dirs: Vec<PathBuf> = self
+            .active_panel()
+            .entries
+            .iter()
+            .filter(|e| e.is_dir && e.name != ".." && !self.dir_sizes.contains_key(&e.path))
+            .map(|e| e.path.clone())
+            .collect();
+        if !dirs.is_empty() {
+            self.spawn_du(dirs, true);
+        }
+    }
+
     pub fn poll_du(&mut self) {
         self.ensure_dir_sizes_loaded();
         self.poll_dir_sizes_load();
+        self.poll_auto_du();
 
         let progress = match self.du_progress.as_mut() {
             Some(p) => p,
@@ -186,15 +392,22 @@ impl App {
                 dir_sizes.insert(path.clone(), size);
             }
 
+            self.resort_size_sorted_panels();
+
             // Save to DB (fire-and-forget)
             let sizes_clone = sizes.clone();
             self.db_spawn(move |db| {
                 let _ = db.save_dir_sizes(&sizes_clone);
             });
 
-            let secs = elapsed.as_secs_f64();
-            let total_str = format_bytes(total);
-            self.status_message = format!("{count} dirs measured: {total_str} total ({secs:.1}s)");
+            let auto = self.du_progress.as_ref().is_some_and(|p| p.auto);
+            if !auto {
+                let secs = elapsed.as_secs_f64();
+                let total_str = format_bytes(total);
+                self.set_status(format!(
+                    "{count} dirs measured: {total_str} total ({secs:.1}s)"
+                ));
+            }
             self.du_progress = None;
             self.background_progress = None;
         }
@@ -268,6 +481,19 @@ impl App {
         });
     }
 
+    /// Directory sizes just came in: size-sorted panels put their directories
+    /// in order.
+    fn resort_size_sorted_panels(&mut self) {
+        let dir_sizes = std::sync::Arc::clone(&self.dir_sizes);
+        let case_sensitive = self.config.case_sensitive_sort;
+        let tab = &mut self.tabs[self.active_tab];
+        for panel in tab.panels.iter_mut() {
+            if panel.sort_mode == SortMode::Size {
+                panel.resort(case_sensitive, &dir_sizes);
+            }
+        }
+    }
+
     pub(super) fn poll_dir_sizes_load(&mut self) {
         let Some(ref mut rx) = self.dir_sizes_load_rx else {
             return;
@@ -279,6 +505,7 @@ impl App {
                     dir_sizes.extend(sizes);
                 }
                 self.dir_sizes_load_rx = None;
+                self.resort_size_sorted_panels();
             }
             Err(tokio::sync::oneshot::error::TryRecvError::Closed) => {
                 self.dir_sizes_load_rx = None;
@@ -288,9 +515,84 @@ impl App {
     }
 }
 
+/// Append the undo hint to a result the user can take back with `u`.
+fn with_undo_hint(summary: String, undoable: bool) -> String {
+    if undoable {
+        format!("{summary} \u{2014} u to undo")
+    } else {
+        summary
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    #[tokio::test]
+    async fn poll_pending_key_expires_after_timeout() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.pending_key = Some('g');
+        app.pending_key_time = Some(Instant::now());
+        app.poll_pending_key();
+        assert_eq!(app.pending_key, Some('g'));
+
+        app.pending_key_time = Some(Instant::now() - std::time::Duration::from_millis(1500));
+        app.poll_pending_key();
+        assert!(app.pending_key.is_none());
+        assert!(app.pending_key_time.is_none());
+    }
+
+    #[tokio::test]
+    async fn poll_snapshot_sees_results_that_keep_counts() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.info_lines = vec![("Size".into(), "Calculating...".into())];
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        app.info_du_rx = Some(rx);
+        tx.send((2048u64, 1usize, 0usize)).unwrap();
+
+        let before = app.poll_snapshot();
+        assert_eq!(before, app.poll_snapshot());
+        app.poll_info_du();
+        assert_eq!(app.info_lines.len(), 1);
+        assert_ne!(before, app.poll_snapshot());
+    }
+
+    #[tokio::test]
+    async fn tick_interval_slows_when_idle() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.config.poll_interval_ms = 200;
+        // The stored directory sizes of the panels are still to be read.
+        assert_eq!(app.tick_interval(), Duration::from_millis(200));
+        let dirs: Vec<PathBuf> = app.tab().panels.iter().map(|p| p.path.clone()).collect();
+        app.dir_sizes_loaded.extend(dirs);
+        assert_eq!(app.tick_interval(), Duration::from_millis(800));
+
+        app.pending_key = Some('g');
+        assert_eq!(app.tick_interval(), Duration::from_millis(200));
+
+        app.background_progress = Some("du".into());
+        assert_eq!(app.tick_interval(), ANIMATION_TICK);
+
+        app.background_progress = None;
+        app.pending_key = None;
+        app.config.poll_interval_ms = 0;
+        assert_eq!(app.tick_interval(), MIN_POLL_INTERVAL * IDLE_TICK_FACTOR);
+    }
+
+    #[tokio::test]
+    async fn poll_pending_key_zero_timeout_never_expires() {
+        let entries = make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.config.key_timeout_ms = 0;
+        app.pending_key = Some(' ');
+        app.pending_key_time = Some(Instant::now() - std::time::Duration::from_secs(60));
+        app.poll_pending_key();
+        assert_eq!(app.pending_key, Some(' '));
+    }
 
     #[tokio::test]
     async fn poll_find_noop_when_no_find_state() {
@@ -300,12 +602,41 @@ mod tests {
         app.poll_find(); // should not panic
     }
 
+    #[tokio::test]
+    async fn toast_survives_keypress_and_expires() {
+        let entries = make_test_entries(&["a.txt", "b.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.apply_file_op(FileOpResult::Rename {
+            new_name: "c.txt".into(),
+            result: Err("permission denied".into()),
+        });
+        app.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+        let toast = app.toast.as_ref().expect("toast kept across keypress");
+        assert!(toast.error);
+        assert_eq!(toast.text, "rename: permission denied");
+
+        app.poll_tasks();
+        assert!(app.toast.is_some());
+        app.toast.as_mut().unwrap().shown_at = Instant::now() - TOAST_ERROR_TTL;
+        app.poll_tasks();
+        assert!(app.toast.is_none());
+    }
+
+    #[test]
+    fn undo_hint_only_when_undoable() {
+        assert_eq!(
+            with_undo_hint("Trashed 5 item(s)".into(), true),
+            "Trashed 5 item(s) \u{2014} u to undo"
+        );
+        assert_eq!(with_undo_hint("Copied".into(), false), "Copied");
+    }
+
     #[tokio::test]
     async fn poll_tasks_noop_when_empty() {
         let entries = make_test_entries(&["a.txt"]);
         let mut app = App::new_for_test(entries);
         app.poll_tasks(); // should not panic
-        assert!(app.task_notification.is_none());
+        assert!(app.toast.is_none());
     }
 
     #[tokio::test]
@@ -330,7 +661,7 @@ mod tests {
         let mut app = App::new_for_test(entries);
         let (tx, rx) = tokio::sync::oneshot::channel::<GitMsg>();
         drop(tx); // close channel
-        app.git_progress = Some(GitProgress { rx });
+        app.git_progress = Some(GitProgress { rx, task: None });
         app.poll_git();
         assert!(app.git_progress.is_none());
     }
@@ -340,7 +671,7 @@ mod tests {
         let entries = make_test_entries(&["a.txt"]);
         let mut app = App::new_for_test(entries);
         let (tx, rx) = tokio::sync::oneshot::channel();
-        app.git_progress = Some(GitProgress { rx });
+        app.git_progress = Some(GitProgress { rx, task: None });
 
         let mut statuses = HashMap::new();
         statuses.insert(PathBuf::from("/repo/file.rs"), 'M');
@@ -399,6 +730,7 @@ mod tests {
         app.du_progress = Some(DuProgress {
             rx,
             started_at: Instant::now(),
+            auto: false,
         });
 
         tx.send(DuMsg::Finished {
@@ -427,6 +759,7 @@ mod tests {
         app.du_progress = Some(DuProgress {
             rx,
             started_at: Instant::now(),
+            auto: false,
         });
 
         tx.send(DuMsg::Progress {
@@ -450,6 +783,58 @@ mod tests {
         assert!(app.status_message.is_empty());
     }
 
+    #[tokio::test]
+    async fn auto_dir_sizes_waits_for_the_panel_to_settle() {
+        let entries = make_test_entries(&["known/", "new/", "a.txt"]);
+        let mut app = App::new_for_test(entries);
+        std::sync::Arc::make_mut(&mut app.dir_sizes).insert(PathBuf::from("/test/known"), 1);
+
+        // Off by default.
+        app.poll_du();
+        assert!(app.du_progress.is_none());
+
+        app.handle_key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        app.handle_key(KeyEvent::new(KeyCode::Char('D'), KeyModifiers::NONE));
+        assert!(app.config.auto_dir_sizes);
+        // Just entered: nothing yet.
+        app.poll_du();
+        assert!(app.du_progress.is_none());
+
+        // Sizes stored in the DB are looked up first.
+        if let Some(stored) = app.dir_sizes_load_rx.take() {
+            let _ = stored.await;
+        }
+        app.auto_du_since = Some(Instant::now() - AUTO_DU_DELAY);
+        app.poll_auto_du();
+        assert!(app.du_progress.as_ref().is_some_and(|p| p.auto));
+        // Measured once per visit.
+        assert!(app.auto_du_since.is_none());
+    }
+
+    #[tokio::test]
+    async fn auto_du_finishes_without_a_status_message() {
+        let entries = make_test_entries(&["subdir/"]);
+        let mut app = App::new_for_test(entries);
+        let (tx, rx) = tokio::sync::mpsc::channel(8);
+        app.du_progress = Some(DuProgress {
+            rx,
+            started_at: Instant::now(),
+            auto: true,
+        });
+        tx.send(DuMsg::Finished {
+            sizes: vec![(PathBuf::from("/test/subdir"), 4096)],
+        })
+        .await
+        .unwrap();
+        app.poll_du();
+        assert!(app.du_progress.is_none());
+        assert_eq!(
+            app.dir_sizes.get(&PathBuf::from("/test/subdir")),
+            Some(&4096)
+        );
+        assert!(app.status_message.is_empty());
+    }
+
     #[tokio::test]
     async fn poll_dir_sizes_load_noop_when_none() {
         let entries = make_test_entries(&["a.txt"]);
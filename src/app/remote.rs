@@ -0,0 +1,86 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use super::*;
+use crate::fs::rsync;
+
+impl App {
+    /// `:put <dest>`: copy the targeted entries to `dest`. A `[user@]host:path`
+    /// goes through rsync; a local path is an ordinary background copy.
+    pub(super) fn put_targeted(&mut self, dest: &str) {
+        let entries = self.active_panel().targeted_register_entries();
+        if entries.is_empty() {
+            self.set_status("Nothing selected to copy".into());
+            return;
+        }
+        if !rsync::is_remote(dest) {
+            let dst_dir = self.resolve_command_path(dest);
+            self.paste_entries(entries, RegisterOp::Yank, dst_dir, None);
+            return;
+        }
+        let sources = entries
+            .into_iter()
+            .map(|e| e.path.to_string_lossy().into_owned())
+            .collect();
+        self.start_rsync(sources, dest.to_string());
+    }
+
+    /// `:get <src>`: copy `src` into the active panel's directory, through
+    /// rsync when it is remote.
+    pub(super) fn get_into_active(&mut self, src: &str) {
+        let dst_dir = self.active_panel().path.clone();
+        if !rsync::is_remote(src) {
+            let path = self.resolve_command_path(src);
+            let is_dir = path.is_dir();
+            let entry = ops::RegisterEntry { path, is_dir };
+            self.paste_entries(vec![entry], RegisterOp::Yank, dst_dir, None);
+            return;
+        }
+        // Without the trailing slash rsync copies the directory itself rather
+        // than its contents, matching a local paste.
+        let src = src.trim_end_matches('/').to_string();
+        let mut dest = dst_dir.to_string_lossy().into_owned();
+        if !dest.ends_with(std::path::MAIN_SEPARATOR) {
+            dest.push(std::path::MAIN_SEPARATOR);
+        }
+        self.start_rsync(vec![src], dest);
+    }
+
+    fn start_rsync(&mut self, sources: Vec<String>, dest: String) {
+        if self.dry_run {
+            let plan = vec![format!("rsync -a {} {dest}", sources.join(" "))];
+            self.show_plan("Dry run: rsync", plan);
+            return;
+        }
+        let n = sources.len();
+        let (tx, rx) = tokio::sync::mpsc::channel(64);
+        let cancel = Arc::new(AtomicBool::new(false));
+        rsync::rsync_in_background(sources, dest.clone(), tx, Arc::clone(&cancel));
+        self.task_manager.add_rsync(rx, dest, cancel);
+        self.set_status(format!(
+            "Copying {n} item(s) with rsync (see Tasks: Space j)..."
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn put_and_get_route_remote_targets_through_rsync() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.put_targeted("host:/srv");
+        assert_eq!(app.status_message, "Nothing selected to copy");
+
+        app.dry_run = true;
+        app.active_panel_mut().selected = 1;
+        app.put_targeted("me@host:/srv/");
+        assert_eq!(app.plan_lines, ["rsync -a /test/a.txt me@host:/srv/"]);
+
+        app.get_into_active("me@host:/srv/site/");
+        assert_eq!(app.plan_lines, ["rsync -a me@host:/srv/site /test/"]);
+        assert_eq!(app.task_manager.active_count(), 0);
+    }
+}
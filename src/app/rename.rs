@@ -20,6 +20,11 @@ impl App {
                         return;
                     }
                 };
+                if self.dry_run {
+                    let plan = vec![ops::plan_rename(&path, &new_name)];
+                    self.show_plan("Dry run: rename", plan);
+                    return;
+                }
                 let (tx, rx) = tokio::sync::oneshot::channel();
                 self.file_op_rx = Some(rx);
                 let new_name2 = new_name.clone();
@@ -35,13 +40,9 @@ impl App {
             KeyCode::Esc => {
                 self.mode = Mode::Normal;
             }
-            KeyCode::Backspace => {
-                self.rename_input.pop();
-            }
-            KeyCode::Char(c) => {
-                self.rename_input.push(c);
+            _ => {
+                self.line_editor.handle(&mut self.rename_input, key);
             }
-            _ => {}
         }
     }
 
@@ -54,9 +55,15 @@ impl App {
                     return;
                 }
                 let dir = self.active_panel().path.clone();
+                let is_dir = self.create_dir_only || name.ends_with('/');
+                if self.dry_run {
+                    let plan = vec![ops::plan_create(&dir, name.trim_end_matches('/'), is_dir)];
+                    self.show_plan("Dry run: create", plan);
+                    return;
+                }
                 let (tx, rx) = tokio::sync::oneshot::channel();
                 self.file_op_rx = Some(rx);
-                if name.ends_with('/') {
+                if is_dir {
                     let dir_name = name.trim_end_matches('/').to_string();
                     tokio::task::spawn_blocking(move || {
                         let result = ops::mkdir(&dir, &dir_name).map_err(|e| e.to_string());
@@ -80,13 +87,9 @@ impl App {
             KeyCode::Esc => {
                 self.mode = Mode::Normal;
             }
-            KeyCode::Backspace => {
-                self.rename_input.pop();
+            _ => {
+                self.line_editor.handle(&mut self.rename_input, key);
             }
-            KeyCode::Char(c) => {
-                self.rename_input.push(c);
-            }
-            _ => {}
         }
     }
 }
@@ -108,6 +111,26 @@ mod tests {
         assert_eq!(app.rename_input, "hi");
     }
 
+    #[tokio::test]
+    async fn handle_rename_inserts_at_cursor() {
+        let entries = crate::app::make_test_entries(&["report.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().selected = 1;
+        app.enter_rename();
+
+        // Move in front of the extension and insert there.
+        for _ in 0..4 {
+            app.handle_rename(KeyEvent::new(KeyCode::Left, KeyModifiers::NONE));
+        }
+        for c in "-v2".chars() {
+            app.handle_rename(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        assert_eq!(app.rename_input, "report-v2.txt");
+
+        app.handle_rename(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        assert_eq!(app.rename_input, ".txt");
+    }
+
     #[tokio::test]
     async fn handle_rename_backspace_and_empty_exits() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -160,6 +183,22 @@ mod tests {
         assert_eq!(app.rename_input, "f");
     }
 
+    #[tokio::test]
+    async fn mkdir_prompt_makes_a_directory_without_slash() {
+        let tmp = std::env::temp_dir().join(format!("fcmd_mkdir_only_{}", std::process::id()));
+        std::fs::create_dir_all(&tmp).unwrap();
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.active_panel_mut().path = tmp.clone();
+        app.enter_mkdir();
+        app.rename_input = "sub".into();
+        app.handle_create(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        let result = app.file_op_rx.take().unwrap().await.unwrap();
+        assert!(matches!(result, crate::app::FileOpResult::Mkdir { .. }));
+        assert!(tmp.join("sub").is_dir());
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
     #[tokio::test]
     async fn handle_create_esc_exits() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
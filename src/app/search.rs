@@ -3,22 +3,29 @@ use super::*;
 impl App {
     pub(super) fn handle_search(&mut self, key: KeyEvent) {
         match key.code {
-            KeyCode::Char(c) => {
-                self.search_query.push(c);
-                self.search_jump_to_match();
-            }
-            KeyCode::Backspace => {
-                self.search_query.pop();
+            KeyCode::Enter => {
+                self.mode = Mode::Normal;
                 if self.search_query.is_empty() {
                     self.active_panel_mut().selected = self.search_saved_cursor;
                 } else {
-                    self.search_jump_to_match();
+                    let entry = self.search_query.clone();
+                    self.record_search_history(&entry);
                 }
             }
-            KeyCode::Enter => {
-                self.mode = Mode::Normal;
-                if self.search_query.is_empty() {
-                    self.active_panel_mut().selected = self.search_saved_cursor;
+            KeyCode::Up | KeyCode::Down => {
+                let recalled = if key.code == KeyCode::Up {
+                    self.search_history.prev(&self.search_query)
+                } else {
+                    self.search_history.next()
+                };
+                if let Some(e) = recalled {
+                    self.search_query = e.to_string();
+                    self.line_editor.reset();
+                    if self.search_query.is_empty() {
+                        self.active_panel_mut().selected = self.search_saved_cursor;
+                    } else {
+                        self.search_jump_to_match();
+                    }
                 }
             }
             KeyCode::Esc => {
@@ -26,7 +33,18 @@ impl App {
                 self.active_panel_mut().selected = self.search_saved_cursor;
                 self.search_query.clear();
             }
-            _ => {}
+            _ => {
+                let before = self.search_query.clone();
+                if self.line_editor.handle(&mut self.search_query, key)
+                    && self.search_query != before
+                {
+                    if self.search_query.is_empty() {
+                        self.active_panel_mut().selected = self.search_saved_cursor;
+                    } else {
+                        self.search_jump_to_match();
+                    }
+                }
+            }
         }
     }
 
@@ -49,14 +67,14 @@ impl App {
         match pos {
             Some(pos) => self.active_panel_mut().selected = pos,
             // Give feedback while typing instead of silently freezing the cursor.
-            None if !query.is_empty() => self.status_message = "No match".into(),
+            None if !query.is_empty() => self.set_status("No match".into()),
             None => {}
         }
     }
 
     pub(super) fn search_next(&mut self) {
         if self.search_query.is_empty() {
-            self.status_message = "No search pattern \u{2014} use / to search".into();
+            self.set_status("No search pattern \u{2014} use / to search".into());
             return;
         }
         let query = self.search_query.to_lowercase();
@@ -77,13 +95,13 @@ impl App {
         if let Some(pos) = pos {
             self.active_panel_mut().selected = pos;
         } else {
-            self.status_message = "No match".into();
+            self.set_status("No match".into());
         }
     }
 
     pub(super) fn search_prev(&mut self) {
         if self.search_query.is_empty() {
-            self.status_message = "No search pattern \u{2014} use / to search".into();
+            self.set_status("No search pattern \u{2014} use / to search".into());
             return;
         }
         let query = self.search_query.to_lowercase();
@@ -108,7 +126,7 @@ impl App {
         if let Some(pos) = pos {
             self.active_panel_mut().selected = pos;
         } else {
-            self.status_message = "No match".into();
+            self.set_status("No match".into());
         }
     }
 }
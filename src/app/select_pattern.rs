@@ -29,10 +29,10 @@ impl App {
                 }
                 if count > 0 {
                     self.mode = Mode::Select;
-                    self.status_message = format!("Selected {count} items");
+                    self.set_status(format!("Selected {count} items"));
                 } else {
                     self.mode = Mode::Normal;
-                    self.status_message = "No matches".into();
+                    self.set_status("No matches".into());
                 }
             }
             KeyCode::Esc => {
@@ -82,7 +82,7 @@ impl App {
                 } else {
                     self.mode = Mode::Select;
                 }
-                self.status_message = format!("Unselected {count} items");
+                self.set_status(format!("Unselected {count} items"));
             }
             KeyCode::Esc => {
                 self.mode = if self.active_panel().marked.is_empty() {
@@ -119,10 +119,10 @@ impl App {
         }
         if panel.marked.is_empty() {
             self.mode = Mode::Normal;
-            self.status_message = "Selection cleared".into();
+            self.set_status("Selection cleared".into());
         } else {
             self.mode = Mode::Select;
-            self.status_message = format!("Selected {selected} items");
+            self.set_status(format!("Selected {selected} items"));
         }
     }
 }
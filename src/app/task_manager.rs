@@ -7,7 +7,7 @@ use tokio::sync::mpsc;
 
 use crate::fs::ops::{OpRecord, ProgressMsg};
 
-use super::messages::{ArchiveMsg, DeleteMsg, PhantomEntry};
+use super::messages::{ArchiveMsg, ChecksumMsg, DeleteMsg, PhantomEntry};
 
 /// Cap on retained finished tasks, so completed-task history doesn't grow without
 /// bound. Oldest finished entries are pruned first.
@@ -48,6 +48,14 @@ pub enum TaskKind {
         /// True for create, false for extract — controls the displayed verb.
         is_create: bool,
     },
+    Checksum {
+        rx: mpsc::Receiver<ChecksumMsg>,
+    },
+    /// `:put` / `:get` to or from a remote host; `dest` is rsync's target.
+    Rsync {
+        rx: mpsc::Receiver<ProgressMsg>,
+        dest: String,
+    },
 }
 
 #[allow(dead_code)]
@@ -74,11 +82,22 @@ pub enum TaskEvent {
     },
     DeleteFinished {
         summary: String,
+        /// At least one item could not be deleted.
+        failed: bool,
         /// Restorable handles for items sent to the trash this operation.
         trashed: Vec<crate::fs::trash::TrashedItem>,
     },
     ArchiveFinished {
         summary: String,
+        failed: bool,
+        /// The archive or extract directory made, for undo.
+        record: Option<OpRecord>,
+    },
+    ChecksumFinished {
+        summary: String,
+        failed: bool,
+        /// The created sums file, for undo.
+        record: Option<OpRecord>,
     },
 }
 
@@ -186,6 +205,47 @@ impl TaskManager {
         id
     }
 
+    pub fn add_checksum(
+        &mut self,
+        rx: mpsc::Receiver<ChecksumMsg>,
+        cancel: Arc<AtomicBool>,
+    ) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.tasks.push(Task {
+            id,
+            kind: TaskKind::Checksum { rx },
+            started_at: Instant::now(),
+            state: TaskState::Running {
+                progress_pct: 0,
+                status_text: "Hashing...".into(),
+            },
+            cancel,
+        });
+        id
+    }
+
+    pub fn add_rsync(
+        &mut self,
+        rx: mpsc::Receiver<ProgressMsg>,
+        dest: String,
+        cancel: Arc<AtomicBool>,
+    ) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.tasks.push(Task {
+            id,
+            kind: TaskKind::Rsync { rx, dest },
+            started_at: Instant::now(),
+            state: TaskState::Running {
+                progress_pct: 0,
+                status_text: "rsync...".into(),
+            },
+            cancel,
+        });
+        id
+    }
+
     pub fn poll_all(&mut self) -> Vec<TaskEvent> {
         let mut events = Vec::new();
 
@@ -315,7 +375,11 @@ impl TaskManager {
                         cancelled,
                     }) = finished
                     {
-                        let verb = if permanent { "Deleted" } else { "Trashed" };
+                        let verb = if permanent {
+                            "Permanently deleted"
+                        } else {
+                            "Trashed"
+                        };
                         let summary = if cancelled && errors.is_empty() {
                             format!("Cancelled \u{2014} {verb} {deleted} item(s) before stop")
                         } else if errors.is_empty() {
@@ -325,12 +389,22 @@ impl TaskManager {
                         } else {
                             format!("{verb} {deleted}, {} failed: {}", errors.len(), errors[0])
                         };
+                        // Nothing goes on the undo stack for these; say so up front.
+                        let summary = if permanent && deleted > 0 {
+                            format!("{summary} (cannot be undone)")
+                        } else {
+                            summary
+                        };
                         task.state = TaskState::Finished {
                             success: errors.is_empty() && !cancelled,
                             cancelled,
                             summary: summary.clone(),
                         };
-                        events.push(TaskEvent::DeleteFinished { summary, trashed });
+                        events.push(TaskEvent::DeleteFinished {
+                            summary,
+                            failed: !errors.is_empty(),
+                            trashed,
+                        });
                     }
                 }
                 TaskKind::Archive { rx, is_create } => {
@@ -381,6 +455,7 @@ impl TaskManager {
                         error,
                         cancelled,
                         label,
+                        created,
                     }) = finished
                     {
                         let elapsed = task.started_at.elapsed();
@@ -406,7 +481,146 @@ impl TaskManager {
                             cancelled,
                             summary: summary.clone(),
                         };
-                        events.push(TaskEvent::ArchiveFinished { summary });
+                        events.push(TaskEvent::ArchiveFinished {
+                            summary,
+                            failed: error.is_some(),
+                            record: created.map(|path| OpRecord::Created { path }),
+                        });
+                    }
+                }
+                TaskKind::Checksum { rx } => {
+                    let mut last_progress = None;
+                    let mut finished = None;
+
+                    loop {
+                        match rx.try_recv() {
+                            Ok(msg @ ChecksumMsg::Progress { .. }) => {
+                                last_progress = Some(msg);
+                            }
+                            Ok(msg @ ChecksumMsg::Finished { .. }) => {
+                                finished = Some(msg);
+                                break;
+                            }
+                            Err(_) => break,
+                        }
+                    }
+
+                    if let Some(ChecksumMsg::Progress {
+                        done,
+                        total,
+                        current,
+                    }) = last_progress
+                    {
+                        let pct = if total > 0 {
+                            (done as f64 / total as f64 * 100.0) as u8
+                        } else {
+                            0
+                        };
+                        task.state = TaskState::Running {
+                            progress_pct: pct,
+                            status_text: format!("Hashing [{}/{}] {current}", done + 1, total),
+                        };
+                    }
+
+                    if let Some(ChecksumMsg::Finished {
+                        count,
+                        created,
+                        error,
+                        cancelled,
+                    }) = finished
+                    {
+                        let elapsed = task.started_at.elapsed();
+                        let sums = crate::fs::checksum::SUMS_FILE;
+                        let summary = if let Some(ref e) = error {
+                            format!("Checksum error: {e}")
+                        } else if cancelled {
+                            format!("Cancelled \u{2014} no {sums} written")
+                        } else {
+                            format!(
+                                "Wrote {sums} ({count} file(s)) in {}",
+                                crate::util::format_duration(elapsed),
+                            )
+                        };
+                        task.state = TaskState::Finished {
+                            success: error.is_none() && !cancelled,
+                            cancelled,
+                            summary: summary.clone(),
+                        };
+                        events.push(TaskEvent::ChecksumFinished {
+                            summary,
+                            failed: error.is_some(),
+                            record: created.map(|path| OpRecord::Created { path }),
+                        });
+                    }
+                }
+                TaskKind::Rsync { rx, dest } => {
+                    let mut last_progress = None;
+                    let mut finished = None;
+
+                    loop {
+                        match rx.try_recv() {
+                            Ok(msg @ ProgressMsg::Progress { .. }) => {
+                                last_progress = Some(msg);
+                            }
+                            Ok(msg @ ProgressMsg::Finished { .. }) => {
+                                finished = Some(msg);
+                                break;
+                            }
+                            Err(_) => break,
+                        }
+                    }
+
+                    if let Some(ProgressMsg::Progress {
+                        bytes_done,
+                        bytes_total,
+                        ..
+                    }) = last_progress
+                    {
+                        let pct = if bytes_total > 0 {
+                            (bytes_done as f64 / bytes_total as f64 * 100.0) as u8
+                        } else {
+                            0
+                        };
+                        task.state = TaskState::Running {
+                            progress_pct: pct,
+                            status_text: format!(
+                                "rsync \u{2192} {dest} ({})",
+                                crate::util::format_bytes(bytes_done)
+                            ),
+                        };
+                    }
+
+                    if let Some(ProgressMsg::Finished {
+                        error,
+                        bytes_total,
+                        cancelled,
+                        ..
+                    }) = finished
+                    {
+                        let elapsed = task.started_at.elapsed();
+                        let summary = if let Some(ref e) = error {
+                            format!("rsync error: {e}")
+                        } else if cancelled {
+                            "Cancelled \u{2014} rsync stopped".to_string()
+                        } else {
+                            format!(
+                                "rsync to {dest} done, {} in {}",
+                                crate::util::format_bytes(bytes_total),
+                                crate::util::format_duration(elapsed),
+                            )
+                        };
+                        task.state = TaskState::Finished {
+                            success: error.is_none() && !cancelled,
+                            cancelled,
+                            summary: summary.clone(),
+                        };
+                        // No undo records: the far side can't be rolled back.
+                        events.push(TaskEvent::PasteFinished {
+                            records: Vec::new(),
+                            error,
+                            is_copy: true,
+                            summary,
+                        });
                     }
                 }
             }
@@ -426,6 +640,23 @@ impl TaskManager {
             .count()
     }
 
+    /// Mean progress of the running tasks, for the tab bar's TASKS badge.
+    /// `None` when nothing is running.
+    pub fn aggregate_pct(&self) -> Option<u8> {
+        let pcts: Vec<u32> = self
+            .tasks
+            .iter()
+            .filter_map(|t| match t.state {
+                TaskState::Running { progress_pct, .. } => Some(progress_pct as u32),
+                TaskState::Finished { .. } => None,
+            })
+            .collect();
+        if pcts.is_empty() {
+            return None;
+        }
+        Some((pcts.iter().sum::<u32>() / pcts.len() as u32) as u8)
+    }
+
     /// Drop all finished/cancelled tasks, keeping only those still running.
     pub fn remove_finished(&mut self) {
         self.tasks
@@ -482,6 +713,8 @@ impl TaskManager {
             TaskKind::Archive {
                 is_create: false, ..
             } => "Extract",
+            TaskKind::Checksum { .. } => "Checksum",
+            TaskKind::Rsync { .. } => "Rsync",
         }
     }
 
@@ -520,6 +753,28 @@ mod tests {
         assert_eq!(tm.active_count(), 0);
     }
 
+    #[test]
+    fn aggregate_pct_averages_running_tasks() {
+        let mut tm = TaskManager::new();
+        assert_eq!(tm.aggregate_pct(), None);
+        let (_tx1, rx1) = mpsc::channel(1);
+        let (_tx2, rx2) = mpsc::channel(1);
+        tm.add_copy(rx1, PathBuf::from("/dst"), vec![], flag());
+        tm.add_move(rx2, PathBuf::from("/dst"), vec![], flag());
+        tm.tasks[0].state = TaskState::Running {
+            progress_pct: 80,
+            status_text: String::new(),
+        };
+        assert_eq!(tm.aggregate_pct(), Some(40));
+        // Finished tasks drop out of the average.
+        tm.tasks[1].state = TaskState::Finished {
+            success: true,
+            cancelled: false,
+            summary: String::new(),
+        };
+        assert_eq!(tm.aggregate_pct(), Some(80));
+    }
+
     #[test]
     fn add_copy_returns_unique_ids() {
         let mut tm = TaskManager::new();
@@ -680,6 +935,7 @@ mod tests {
             error: None,
             cancelled: false,
             label: "all entries".into(),
+            created: None,
         })
         .await
         .unwrap();
@@ -710,6 +966,7 @@ mod tests {
             error: Some("boom".into()),
             cancelled: false,
             label: "out.zip".into(),
+            created: None,
         })
         .await
         .unwrap();
@@ -4,7 +4,7 @@ use super::*;
 impl App {
     pub(super) fn open_tasks(&mut self) {
         if self.task_manager.tasks().is_empty() {
-            self.status_message = "No tasks".into();
+            self.set_status("No tasks".into());
             return;
         }
         self.tasks_cursor = 0;
@@ -49,12 +49,12 @@ impl App {
             return;
         };
         if !matches!(task.state, TaskState::Running { .. }) {
-            self.status_message = "Task already finished".into();
+            self.set_status("Task already finished".into());
             return;
         }
         let id = task.id;
         self.task_manager.cancel(id);
-        self.status_message = "Cancelling task...".into();
+        self.set_status("Cancelling task...".into());
     }
 
     fn clear_finished_tasks(&mut self) {
@@ -69,9 +69,9 @@ impl App {
             self.adjust_tasks_scroll();
         }
         if cleared > 0 {
-            self.status_message = format!("Cleared {cleared} finished task(s)");
+            self.set_status(format!("Cleared {cleared} finished task(s)"));
         } else {
-            self.status_message = "No finished tasks to clear".into();
+            self.set_status("No finished tasks to clear".into());
         }
     }
 
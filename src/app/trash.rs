@@ -1,3 +1,6 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use super::*;
 use crate::fs::trash::TrashedItem;
 
@@ -6,7 +9,7 @@ impl App {
     /// that is still recoverable.
     pub(super) fn open_trash(&mut self) {
         if self.undo_stack.trashed().is_empty() {
-            self.status_message = "Nothing to restore (nothing trashed this session)".into();
+            self.set_status("Nothing to restore (nothing trashed this session)".into());
             return;
         }
         self.trash_cursor = 0;
@@ -91,6 +94,116 @@ impl App {
         });
     }
 
+    /// `:emptytrash` — list the whole system trash in the background; the
+    /// confirmation opens once the item count and size are known.
+    pub(super) fn request_empty_trash(&mut self) {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.empty_trash_rx = Some(rx);
+        self.set_status("Measuring trash...".into());
+        tokio::task::spawn_blocking(move || {
+            let _ = tx.send(crate::fs::trash::list_contents().map_err(|e| e.to_string()));
+        });
+    }
+
+    pub fn apply_empty_trash_scan(&mut self, scan: TrashScan) {
+        match scan {
+            Err(e) => self.show_toast(format!("Trash: {e}"), true),
+            Ok(items) if items.is_empty() => self.set_status("Trash is already empty".into()),
+            // Don't pull the user out of whatever they opened while it scanned.
+            Ok(items) if self.mode != Mode::Normal => self.set_status(format!(
+                "Trash scan done ({} item(s)) \u{2014} run :emptytrash again to empty it",
+                items.len()
+            )),
+            Ok(items) if self.dry_run => {
+                let size: u64 = items.iter().map(|c| c.size).sum();
+                let title = format!(
+                    "Dry run: empty trash ({}, {})",
+                    items.len(),
+                    crate::util::format_bytes(size)
+                );
+                let plan = items
+                    .iter()
+                    .map(|c| format!("purge {} ({})", c.name, crate::util::format_bytes(c.size)))
+                    .collect();
+                self.show_plan(title, plan);
+            }
+            Ok(items) => {
+                self.empty_trash_items = items;
+                self.mode = Mode::ConfirmEmptyTrash;
+            }
+        }
+    }
+
+    /// Total size of the entries awaiting confirmation.
+    pub fn empty_trash_size(&self) -> u64 {
+        self.empty_trash_items.iter().map(|c| c.size).sum()
+    }
+
+    pub(super) fn handle_confirm_empty_trash(&mut self, key: KeyEvent) {
+        match key.code {
+            // Same rule as the delete confirmation: only an explicit 'y' purges.
+            KeyCode::Char('y') | KeyCode::Char('Y') => self.execute_empty_trash(),
+            _ => {
+                self.empty_trash_items.clear();
+                self.mode = Mode::Normal;
+                self.set_status("Empty trash cancelled".into());
+            }
+        }
+    }
+
+    /// Purge the confirmed entries as a background delete task, reporting
+    /// through the same progress channel and summary as `dD`.
+    fn execute_empty_trash(&mut self) {
+        let items = std::mem::take(&mut self.empty_trash_items);
+        self.mode = Mode::Normal;
+        // Session handles now point at nothing; drop them so `u` and the
+        // restore overlay don't offer items that are gone.
+        let ids: Vec<u64> = self.undo_stack.trashed().iter().map(|it| it.id).collect();
+        for id in ids {
+            self.undo_stack.remove_trashed(id);
+        }
+
+        let total = items.len();
+        let (tx, rx) = tokio::sync::mpsc::channel(64);
+        let cancel = Arc::new(AtomicBool::new(false));
+        let cancel_worker = Arc::clone(&cancel);
+        tokio::task::spawn_blocking(move || {
+            let mut deleted = 0usize;
+            let mut errors = Vec::new();
+            let mut cancelled = false;
+            let mut last_report: Option<std::time::Instant> = None;
+            for (i, content) in items.iter().enumerate() {
+                if cancel_worker.load(Ordering::Relaxed) {
+                    cancelled = true;
+                    break;
+                }
+                let now = std::time::Instant::now();
+                if last_report
+                    .is_none_or(|t| now.duration_since(t) >= crate::fs::ops::PROGRESS_INTERVAL)
+                {
+                    last_report = Some(now);
+                    let _ = tx.try_send(DeleteMsg::Progress {
+                        done: i,
+                        total,
+                        current: content.name.clone(),
+                    });
+                }
+                match crate::fs::trash::purge(content) {
+                    Ok(()) => deleted += 1,
+                    Err(e) => errors.push(format!("{}: {e}", content.name)),
+                }
+            }
+            let _ = tx.blocking_send(DeleteMsg::Finished {
+                deleted,
+                errors,
+                trashed: Vec::new(),
+                permanent: true,
+                cancelled,
+            });
+        });
+        self.task_manager.add_delete(rx, true, cancel);
+    }
+
     pub(super) fn adjust_trash_scroll(&mut self) {
         let max_h = (self.visible_height * 70 / 100).max(2);
         let list_h = max_h.saturating_sub(4).max(1);
@@ -127,6 +240,50 @@ mod tests {
         app
     }
 
+    #[tokio::test]
+    async fn empty_trash_scan_opens_confirm_with_totals() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.apply_empty_trash_scan(Ok(Vec::new()));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.status_message, "Trash is already empty");
+
+        app.apply_empty_trash_scan(Ok(vec![
+            TrashContent::new_for_test("old.log", 1024),
+            TrashContent::new_for_test("build", 4096),
+        ]));
+        assert_eq!(app.mode, Mode::ConfirmEmptyTrash);
+        assert_eq!(app.empty_trash_items.len(), 2);
+        assert_eq!(app.empty_trash_size(), 5120);
+
+        // Enter is not a confirm key.
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(app.mode, Mode::Normal);
+        assert!(app.empty_trash_items.is_empty());
+        assert_eq!(app.task_manager.active_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn empty_trash_scan_leaves_another_mode_alone() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.mode = Mode::Help;
+        app.apply_empty_trash_scan(Ok(vec![TrashContent::new_for_test("x", 1)]));
+        assert_eq!(app.mode, Mode::Help);
+        assert!(app.empty_trash_items.is_empty());
+        assert!(app.status_message.contains("run :emptytrash again"));
+    }
+
+    #[tokio::test]
+    async fn empty_trash_confirm_starts_task_and_drops_handles() {
+        let mut app = app_with_trash(2);
+        app.apply_empty_trash_scan(Ok(vec![TrashContent::new_for_test("x", 1)]));
+        app.handle_key(key('y'));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.task_manager.tasks().len(), 1);
+        assert!(app.undo_stack.trashed().is_empty());
+    }
+
     #[tokio::test]
     async fn open_trash_empty_stays_normal() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -1,16 +1,31 @@
 use super::*;
 
+/// `zR` opens directories down to this depth below the tree root and no
+/// further, so one key can't walk a whole disk into `tree_data`.
+const EXPAND_ALL_MAX_DEPTH: usize = 4;
+
 impl App {
     pub(super) fn handle_tree_input(&mut self, key: KeyEvent) {
         // Handle pending key
         if let Some(pending) = {
             self.pending_key_time = None;
             self.pending_key.take()
-        } && pending == 'g'
-            && key.code == KeyCode::Char('g')
-        {
-            self.tree_selected = 0;
-            return;
+        } {
+            match (pending, key.code) {
+                ('g', KeyCode::Char('g')) => {
+                    self.tree_selected = 0;
+                    return;
+                }
+                ('z', KeyCode::Char('M')) => {
+                    self.tree_collapse_all();
+                    return;
+                }
+                ('z', KeyCode::Char('R')) => {
+                    self.tree_expand_all();
+                    return;
+                }
+                _ => {}
+            }
         }
 
         let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
@@ -40,8 +55,8 @@ impl App {
             KeyCode::Char('k') | KeyCode::Up => {
                 self.tree_selected = self.tree_selected.saturating_sub(1);
             }
-            KeyCode::Char('g') => {
-                self.pending_key = Some('g');
+            KeyCode::Char(c @ ('g' | 'z')) => {
+                self.pending_key = Some(c);
                 self.pending_key_time = Some(Instant::now());
             }
             KeyCode::Char('G') => {
@@ -89,15 +104,57 @@ impl App {
                 self.tree_focused = false;
             }
             KeyCode::Char('?') => self.mode = Mode::Help,
-            KeyCode::Char(':') => {
-                self.mode = Mode::Command;
-                self.command_input.clear();
-            }
+            KeyCode::Char(':') => self.enter_command(),
+
+            KeyCode::Char('/') => self.enter_tree_filter(),
+            KeyCode::Esc if !self.tree_filter.is_empty() => self.set_tree_filter(String::new()),
 
             _ => {}
         }
     }
 
+    /// `/` with the tree focused: edit the tree's quick-filter in the filter
+    /// prompt, pre-filled with the one in force.
+    fn enter_tree_filter(&mut self) {
+        self.filter_prev = self.tree_filter.clone();
+        self.filter_input = self.tree_filter.clone();
+        self.mode = Mode::Filter;
+    }
+
+    /// Narrow the tree to lines matching `query` and the directories above
+    /// them, or with an empty `query` bring back the whole tree and the cursor
+    /// it had before filtering.
+    pub(super) fn set_tree_filter(&mut self, query: String) {
+        let was_active = !self.tree_filter.is_empty();
+        self.tree_filter = query;
+        if self.tree_filter.is_empty() {
+            if was_active {
+                self.tree_data = std::mem::take(&mut self.tree_full);
+                let prev = self.tree_filter_prev_selected.take();
+                self.tree_selected = prev
+                    .and_then(|p| self.tree_data.iter().position(|l| l.path == p))
+                    .unwrap_or(0);
+            }
+            return;
+        }
+        if !was_active {
+            self.tree_full = std::mem::take(&mut self.tree_data);
+            self.tree_filter_prev_selected = self
+                .tree_full
+                .get(self.tree_selected)
+                .map(|l| l.path.clone());
+        }
+        self.tree_data = crate::model::tree::filter_tree(&self.tree_full, &self.tree_filter);
+        // Land on the first line that matches itself, not just an ancestor.
+        let query: Vec<char> = self.tree_filter.to_lowercase().chars().collect();
+        self.tree_selected = self
+            .tree_data
+            .iter()
+            .position(|l| l.depth > 0 && crate::model::tree::name_matches(&l.name, &query))
+            .unwrap_or(0);
+        self.tree_scroll = 0;
+    }
+
     fn tree_enter_selected(&mut self) {
         if let Some(line) = self.tree_data.get(self.tree_selected) {
             let path = line.path.clone();
@@ -134,6 +191,39 @@ impl App {
         self.spawn_rebuild_tree();
     }
 
+    /// `zM`: close every directory but the ones leading to the panel's.
+    fn tree_collapse_all(&mut self) {
+        self.tree_expanded.clear();
+        self.tree_collapsed.clear();
+        self.tree_select_path = self
+            .tree_data
+            .get(self.tree_selected)
+            .map(|l| l.path.clone());
+        self.spawn_rebuild_tree();
+        self.set_status("Tree: collapsed to the current path".into());
+    }
+
+    /// `zR`: open every directory the tree shows, down to
+    /// `EXPAND_ALL_MAX_DEPTH`. Pressing it again opens the level it revealed.
+    fn tree_expand_all(&mut self) {
+        let dirs: Vec<PathBuf> = self
+            .tree_data
+            .iter()
+            .filter(|l| l.is_dir && !l.unreadable && l.depth < EXPAND_ALL_MAX_DEPTH)
+            .map(|l| l.path.clone())
+            .collect();
+        for dir in &dirs {
+            self.tree_collapsed.remove(dir);
+        }
+        self.tree_expanded.extend(dirs);
+        self.tree_select_path = self
+            .tree_data
+            .get(self.tree_selected)
+            .map(|l| l.path.clone());
+        self.spawn_rebuild_tree();
+        self.set_status(format!("Tree: expanded to depth {EXPAND_ALL_MAX_DEPTH}"));
+    }
+
     fn tree_go_parent(&mut self) {
         if let Some(line) = self.tree_data.get(self.tree_selected) {
             if line.depth == 0 {
@@ -156,6 +246,19 @@ impl App {
         }
     }
 
+    /// Make a jump target (find result, bookmark, mark, `:cd`) show up in the
+    /// tree. The rebuild already follows the panel's directory, but a collapsed
+    /// ancestor would still hide it, so those are reopened; the cursor lands on
+    /// the target itself, including a file, once the rebuild comes in.
+    pub(super) fn reveal_in_tree(&mut self, target: &std::path::Path) {
+        if !self.show_tree {
+            return;
+        }
+        self.tree_collapsed.retain(|p| !target.starts_with(p));
+        self.tree_select_path = Some(target.to_path_buf());
+        self.tree_dirty = true;
+    }
+
     pub fn spawn_rebuild_tree(&mut self) {
         let current = self.active_panel().path.clone();
         let show_hidden = self.active_panel().show_hidden;
@@ -194,7 +297,12 @@ impl App {
         if result.start_dir != self.start_dir {
             return;
         }
-        self.tree_data = result.data;
+        if self.tree_filter.is_empty() {
+            self.tree_data = result.data;
+        } else {
+            self.tree_data = crate::model::tree::filter_tree(&result.data, &self.tree_filter);
+            self.tree_full = result.data;
+        }
         self.tree_dirty = false;
         self.tree_last_path = Some(result.current_path);
         self.tree_last_hidden = self.active_panel().show_hidden;
@@ -233,6 +341,8 @@ mod tests {
                 is_on_path: false,
                 is_expanded: false,
                 depth: 0,
+                unreadable: false,
+                link_escapes: None,
             })
             .collect()
     }
@@ -278,6 +388,77 @@ mod tests {
         assert_eq!(app.tree_selected, 4);
     }
 
+    #[tokio::test]
+    async fn tree_z_keys_expand_to_a_depth_and_collapse_all() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        let mut lines = make_tree_lines(3);
+        lines[1].depth = 1;
+        lines[2].depth = EXPAND_ALL_MAX_DEPTH;
+        app.tree_data = lines;
+        app.tree_collapsed.insert(PathBuf::from("/test/dir1"));
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+
+        app.handle_tree_input(key('z'));
+        app.handle_tree_input(key('R'));
+        assert_eq!(
+            app.tree_expanded,
+            HashSet::from([PathBuf::from("/test/dir0"), PathBuf::from("/test/dir1")])
+        );
+        assert!(app.tree_collapsed.is_empty());
+
+        app.tree_collapsed.insert(PathBuf::from("/test/dir0"));
+        app.handle_tree_input(key('z'));
+        app.handle_tree_input(key('M'));
+        assert!(app.tree_expanded.is_empty() && app.tree_collapsed.is_empty());
+        assert_eq!(app.tree_select_path, Some(PathBuf::from("/test/dir0")));
+    }
+
+    #[tokio::test]
+    async fn tree_filter_narrows_and_esc_restores_the_cursor() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.tree_focused = true;
+        // dir0 ┬ dir1 ┬ dir2
+        //      │      └ dir3
+        //      └ dir4
+        let mut lines = make_tree_lines(5);
+        for (line, depth) in lines.iter_mut().zip([0, 1, 2, 2, 1]) {
+            line.depth = depth;
+        }
+        app.tree_data = lines;
+        app.tree_selected = 4;
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        app.handle_tree_input(key(KeyCode::Char('/')));
+        assert_eq!(app.mode, Mode::Filter);
+        app.handle_key(key(KeyCode::Char('3')));
+        let paths: Vec<_> = app.tree_data.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(paths, ["dir0", "dir1", "dir3"]);
+        assert_eq!(app.tree_selected, 2);
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.mode, Mode::Normal);
+        assert_eq!(app.tree_filter, "3");
+
+        // A rebuild while filtered stays filtered.
+        let mut rebuilt = make_tree_lines(5);
+        for line in &mut rebuilt[1..] {
+            line.depth = 1;
+        }
+        app.apply_tree_data(crate::app::TreeLoadResult {
+            start_dir: app.start_dir.clone(),
+            current_path: PathBuf::from("/test"),
+            data: rebuilt,
+        });
+        assert_eq!(app.tree_data.len(), 2);
+        assert_eq!(app.tree_full.len(), 5);
+
+        app.handle_tree_input(key(KeyCode::Esc));
+        assert!(app.tree_filter.is_empty());
+        assert_eq!(app.tree_data.len(), 5);
+        assert_eq!(app.tree_selected, 4);
+    }
+
     #[tokio::test]
     async fn tree_tab_unfocuses() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
@@ -289,6 +470,34 @@ mod tests {
         assert!(!app.tree_focused);
     }
 
+    #[tokio::test]
+    async fn jump_reopens_collapsed_ancestors_and_selects_target() {
+        let entries = crate::app::make_test_entries(&["a.txt"]);
+        let mut app = App::new_for_test(entries);
+        app.show_tree = true;
+        app.tree_dirty = false;
+        for p in ["/test/dir1", "/test/dir1/deep", "/test/dir2"] {
+            app.tree_collapsed.insert(PathBuf::from(p));
+        }
+        let target = PathBuf::from("/test/dir1/deep/file.rs");
+        app.reveal_in_tree(&target);
+        assert_eq!(
+            app.tree_collapsed,
+            HashSet::from([PathBuf::from("/test/dir2")])
+        );
+        assert!(app.tree_dirty);
+
+        let mut lines = make_tree_lines(3);
+        lines[2].path = target.clone();
+        lines[2].is_dir = false;
+        app.apply_tree_data(crate::app::TreeLoadResult {
+            start_dir: app.start_dir.clone(),
+            current_path: PathBuf::from("/test/dir1/deep"),
+            data: lines,
+        });
+        assert_eq!(app.tree_selected, 2);
+    }
+
     #[tokio::test]
     async fn tree_q_quits() {
         let entries = crate::app::make_test_entries(&["a.txt"]);
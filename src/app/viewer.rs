@@ -231,7 +231,7 @@ impl App {
     }
 
     /// Whether the active theme has a dark background (drives syntect theme choice).
-    fn theme_is_dark(&self) -> bool {
+    pub(super) fn theme_is_dark(&self) -> bool {
         match self.theme.bg {
             ratatui::style::Color::Rgb(r, g, b) => {
                 // Rec. 601 luma; below mid-grey counts as dark.
@@ -268,7 +268,12 @@ impl App {
             KeyCode::Char('o') => {
                 if let Some(v) = self.viewer.as_ref() {
                     let path = v.path.clone();
-                    self.request_open_editor(path);
+                    // Open the editor at the line at the top of the view.
+                    let line = (v.mode == ViewMode::Text && !v.content.is_binary)
+                        .then(|| v.layout.rows.get(v.content.scroll).map(|r| r.logical + 1))
+                        .flatten();
+                    self.request_open_editor(vec![path]);
+                    self.open_editor_line = line;
                 }
             }
             KeyCode::Char('/') => {
@@ -394,6 +399,17 @@ mod tests {
         app
     }
 
+    #[tokio::test]
+    async fn o_opens_editor_at_top_line() {
+        let lines: Vec<&str> = (0..20).map(|_| "line").collect();
+        let mut app = open_with_lines(lines);
+        app.viewer.as_mut().unwrap().content.scroll = 7;
+        app.handle_viewer(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE));
+        assert_eq!(app.open_editor, Some(vec![PathBuf::from("/test/a.txt")]));
+        assert_eq!(app.open_editor_line, Some(8));
+        assert!(app.viewer.is_none());
+    }
+
     #[tokio::test]
     async fn scroll_down_up() {
         let lines: Vec<&str> = (0..20).map(|_| "line").collect();
@@ -1,4 +1,5 @@
 use super::*;
+use crate::util::glob_match;
 
 impl App {
     pub(super) fn handle_visual(&mut self, key: KeyEvent) {
@@ -26,6 +27,11 @@ impl App {
                     self.enter_bulk_rename();
                     return;
                 }
+                ('c', KeyCode::Char('W')) => {
+                    self.exit_visual();
+                    self.edit_bulk_rename_in_editor();
+                    return;
+                }
                 _ => {}
             }
         }
@@ -51,14 +57,14 @@ impl App {
                 let entries = self.active_panel().targeted_register_entries();
                 self.exit_visual();
                 if entries.is_empty() {
-                    self.status_message = "Nothing to yank".into();
+                    self.set_status("Nothing to yank".into());
                 } else {
                     let n = entries.len();
                     self.register = Some(Register {
                         entries,
                         op: RegisterOp::Yank,
                     });
-                    self.status_message = format!("Yanked {n} item(s)");
+                    self.set_status(format!("Yanked {n} item(s)"));
                 }
             }
             KeyCode::Char('d') => {
@@ -90,6 +96,10 @@ impl App {
 
             KeyCode::Char('v') | KeyCode::Char('V') | KeyCode::Esc => self.exit_visual(),
 
+            // Commands see the range as their target (e.g. `:g *.log`); the
+            // anchor is dropped once the command line closes.
+            KeyCode::Char(':') => self.enter_command(),
+
             KeyCode::Tab => {
                 self.exit_visual();
                 {
@@ -107,6 +117,36 @@ impl App {
         self.mode = Mode::Normal;
     }
 
+    /// `:g <glob>` from Visual mode: add the entries inside the visual range whose
+    /// names match to the marked set, then continue in Select mode so the refined
+    /// set can be acted on.
+    pub(super) fn select_visual_range_matching(&mut self, pattern: &str) {
+        let panel = self.active_panel_mut();
+        let Some((lo, hi)) = panel.visual_range() else {
+            self.set_status(":g needs a visual range \u{2014} press v first".into());
+            return;
+        };
+        let matched: Vec<PathBuf> = panel.entries[lo..=hi]
+            .iter()
+            .filter(|e| e.name != ".." && glob_match(pattern, &e.name))
+            .map(|e| e.path.clone())
+            .collect();
+        panel.visual_anchor = None;
+        let count = matched.len();
+        panel.marked.extend(matched);
+        if count > 0 {
+            self.mode = Mode::Select;
+            self.set_status(format!("Selected {count} matching item(s) in range"));
+        } else {
+            self.mode = if self.active_panel().marked.is_empty() {
+                Mode::Normal
+            } else {
+                Mode::Select
+            };
+            self.set_status(format!("No matches for {pattern} in range"));
+        }
+    }
+
     // ── Select mode ─────────────────────────────────────────────────
 
     pub(super) fn enter_select_and_mark(&mut self) {
@@ -133,6 +173,10 @@ impl App {
                     self.active_panel_mut().go_top();
                     return;
                 }
+                ('g', KeyCode::Char('v')) => {
+                    self.mark_range_to_anchor();
+                    return;
+                }
                 ('c', KeyCode::Char('p')) => {
                     self.exit_select();
                     self.enter_chmod();
@@ -148,6 +192,11 @@ impl App {
                     self.enter_bulk_rename();
                     return;
                 }
+                ('c', KeyCode::Char('W')) => {
+                    self.exit_select();
+                    self.edit_bulk_rename_in_editor();
+                    return;
+                }
                 _ => {}
             }
         }
@@ -179,14 +228,14 @@ impl App {
                 self.active_panel_mut().marked.clear();
                 self.exit_select();
                 if entries.is_empty() {
-                    self.status_message = "Nothing to yank".into();
+                    self.set_status("Nothing to yank".into());
                 } else {
                     let n = entries.len();
                     self.register = Some(Register {
                         entries,
                         op: RegisterOp::Yank,
                     });
-                    self.status_message = format!("Yanked {n} item(s)");
+                    self.set_status(format!("Yanked {n} item(s)"));
                 }
             }
             KeyCode::Char('d') => {
@@ -214,7 +263,7 @@ impl App {
                 self.active_panel_mut().marked.clear();
                 self.exit_select();
                 if n > 0 {
-                    self.status_message = format!("Selection cleared ({n} item(s))");
+                    self.set_status(format!("Selection cleared ({n} item(s))"));
                 }
             }
 
@@ -12,18 +12,44 @@ use super::{
     copy_with_cancel, never_cancel,
 };
 
+/// How much of an archive a listing may read. A preview stops early rather
+/// than decompressing a multi-GB tarball to its end for every cursor move.
+pub struct ListLimit<'a> {
+    pub max_entries: usize,
+    /// Tar stream to scan at most, in unpacked bytes.
+    pub max_bytes: u64,
+    /// Set when the listing is no longer wanted; checked between entries.
+    pub cancel: &'a AtomicBool,
+}
+
 /// List all entries in an archive. Returns a flat list sorted by path.
 pub fn list_archive(path: &Path) -> io::Result<(ArchiveFormat, Vec<ArchiveEntry>)> {
+    let cancel = never_cancel();
+    let limit = ListLimit {
+        max_entries: usize::MAX,
+        max_bytes: u64::MAX,
+        cancel: &cancel,
+    };
+    peek_archive(path, &limit).map(|(format, entries, _)| (format, entries))
+}
+
+/// List an archive's entries up to `limit`, sorted by path. The flag is
+/// whether the listing is complete: false when the limit or a cancel cut
+/// it short.
+pub fn peek_archive(
+    path: &Path,
+    limit: &ListLimit,
+) -> io::Result<(ArchiveFormat, Vec<ArchiveEntry>, bool)> {
     let format = ArchiveFormat::detect(path)
         .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Unknown archive format"))?;
 
-    let mut entries = match format {
-        ArchiveFormat::Zip => list_zip(path)?,
-        ArchiveFormat::Tar => list_tar(path, TarCompression::None)?,
-        ArchiveFormat::TarGz => list_tar(path, TarCompression::Gz)?,
-        ArchiveFormat::TarBz2 => list_tar(path, TarCompression::Bz2)?,
-        ArchiveFormat::TarXz => list_tar(path, TarCompression::Xz)?,
-        ArchiveFormat::TarZst => list_tar(path, TarCompression::Zst)?,
+    let (mut entries, complete) = match format {
+        ArchiveFormat::Zip => list_zip(path, limit)?,
+        ArchiveFormat::Tar => list_tar(path, TarCompression::None, limit)?,
+        ArchiveFormat::TarGz => list_tar(path, TarCompression::Gz, limit)?,
+        ArchiveFormat::TarBz2 => list_tar(path, TarCompression::Bz2, limit)?,
+        ArchiveFormat::TarXz => list_tar(path, TarCompression::Xz, limit)?,
+        ArchiveFormat::TarZst => list_tar(path, TarCompression::Zst, limit)?,
     };
 
     // Synthesize missing directory entries
@@ -60,7 +86,7 @@ pub fn list_archive(path: &Path) -> io::Result<(ArchiveFormat, Vec<ArchiveEntry>
     }
 
     entries.sort_by(|a, b| a.path.cmp(&b.path));
-    Ok((format, entries))
+    Ok((format, entries, complete))
 }
 
 /// Extract a single entry (file or directory) from an archive.
@@ -285,14 +311,17 @@ fn write_file_entry<R: Read + ?Sized>(
 
 // ── Zip ──────────────────────────────────────────────────────────────
 
-fn list_zip(path: &Path) -> io::Result<Vec<ArchiveEntry>> {
+fn list_zip(path: &Path, limit: &ListLimit) -> io::Result<(Vec<ArchiveEntry>, bool)> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
     let mut archive =
         zip::ZipArchive::new(reader).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
-    let mut entries = Vec::with_capacity(archive.len());
+    let mut entries = Vec::with_capacity(archive.len().min(limit.max_entries));
     for i in 0..archive.len() {
+        if entries.len() >= limit.max_entries || limit.cancel.load(Ordering::Relaxed) {
+            return Ok((entries, false));
+        }
         let entry = archive
             .by_index(i)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
@@ -307,7 +336,7 @@ fn list_zip(path: &Path) -> io::Result<Vec<ArchiveEntry>> {
             modified,
         });
     }
-    Ok(entries)
+    Ok((entries, true))
 }
 
 /// Convert a zip `DateTime` (stored in local time, but treated as UTC here —
@@ -503,13 +532,25 @@ fn open_tar_reader(path: &Path, compression: TarCompression) -> io::Result<Box<d
     }
 }
 
-fn list_tar(path: &Path, compression: TarCompression) -> io::Result<Vec<ArchiveEntry>> {
+fn list_tar(
+    path: &Path,
+    compression: TarCompression,
+    limit: &ListLimit,
+) -> io::Result<(Vec<ArchiveEntry>, bool)> {
     let reader = open_tar_reader(path, compression)?;
     let mut archive = tar::Archive::new(reader);
     let mut entries = Vec::new();
 
     for entry_result in archive.entries()? {
+        // Each step unpacks the previous entry's data to skip it, so the cut
+        // goes by how far into the stream the next header is.
+        if entries.len() >= limit.max_entries || limit.cancel.load(Ordering::Relaxed) {
+            return Ok((entries, false));
+        }
         let entry = entry_result?;
+        if entry.raw_header_position() > limit.max_bytes {
+            return Ok((entries, false));
+        }
         let path_str = entry.path()?.to_string_lossy().into_owned();
         let is_dir = entry.header().entry_type().is_dir();
         let size = entry.size();
@@ -525,7 +566,7 @@ fn list_tar(path: &Path, compression: TarCompression) -> io::Result<Vec<ArchiveE
             modified,
         });
     }
-    Ok(entries)
+    Ok((entries, true))
 }
 
 fn extract_tar_stream(
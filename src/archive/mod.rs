@@ -108,7 +108,6 @@ impl ArchiveFormat {
         Self::from_path(path).or_else(|| Self::sniff(path))
     }
 
-    #[allow(dead_code)]
     pub fn label(self) -> &'static str {
         match self {
             ArchiveFormat::Zip => "zip",
@@ -0,0 +1,116 @@
+//! `fcmd du`, `fcmd find` and `fcmd trash`: panel operations for scripts and
+//! cron jobs, without the TUI. Each runs the code its interactive counterpart
+//! runs (`Space d`, local Find, `dd`), so the results are the same.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::cli::Batch;
+use crate::fs::du::{DuMsg, du_in_background};
+use crate::fs::normalize_path;
+use crate::fs::ops::path_size;
+use crate::search::FindState;
+
+/// Run `batch`, with relative paths taken from `cwd`. Results go to stdout,
+/// problems to stderr; the return value is the exit status.
+pub async fn run(batch: Batch, cwd: &Path) -> i32 {
+    match batch {
+        Batch::Du(dir) => {
+            let workers = crate::config::Config::load()
+                .unwrap_or_else(|e| {
+                    eprintln!("fcmd: {e}");
+                    crate::config::Config::default()
+                })
+                .worker_threads();
+            let dir = normalize_path(&cwd.join(dir));
+            match du(&dir, workers).await {
+                Ok((sizes, total)) => {
+                    let mut out = std::io::stdout().lock();
+                    for (path, size) in sizes {
+                        let _ = writeln!(out, "{size}\t{}", path.display());
+                    }
+                    let _ = writeln!(out, "{total}\t{}", dir.display());
+                    0
+                }
+                Err(e) => {
+                    eprintln!("fcmd: du: {}: {e}", dir.display());
+                    1
+                }
+            }
+        }
+        Batch::Find { pattern, root } => {
+            let root = normalize_path(&cwd.join(root));
+            if !root.is_dir() {
+                eprintln!("fcmd: find: {} is not a directory", root.display());
+                return 1;
+            }
+            let hits = FindState::local_matches(&root, &pattern);
+            let mut out = std::io::stdout().lock();
+            for path in &hits {
+                let _ = writeln!(out, "{}", path.display());
+            }
+            i32::from(hits.is_empty())
+        }
+        Batch::Trash(paths) => {
+            let mut status = 0;
+            for path in paths {
+                let path = normalize_path(&cwd.join(path));
+                // The trash backend's own error for a missing file is hard to read.
+                if let Err(e) = path
+                    .symlink_metadata()
+                    .and_then(|_| crate::fs::trash::trash(&path))
+                {
+                    eprintln!("fcmd: trash: {}: {e}", path.display());
+                    status = 1;
+                }
+            }
+            status
+        }
+    }
+}
+
+/// The size of every directory in `dir`, in name order, measured on the
+/// `Space d` worker pool, and the total of `dir` itself.
+async fn du(dir: &Path, workers: usize) -> std::io::Result<(Vec<(PathBuf, u64)>, u64)> {
+    let mut dirs = Vec::new();
+    let mut other = 0;
+    for entry in std::fs::read_dir(dir)?.flatten() {
+        if entry.file_type().is_ok_and(|t| t.is_dir()) {
+            dirs.push(entry.path());
+        } else {
+            other += path_size(&entry.path());
+        }
+    }
+    dirs.sort();
+    let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+    du_in_background(dirs, workers, tx);
+    let mut sizes = Vec::new();
+    while let Some(msg) = rx.recv().await {
+        if let DuMsg::Finished { sizes: done } = msg {
+            sizes = done;
+        }
+    }
+    let total = other + sizes.iter().map(|(_, size)| size).sum::<u64>();
+    Ok((sizes, total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn du_lists_each_directory_then_the_total() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("b/deep")).unwrap();
+        std::fs::create_dir(root.join("a")).unwrap();
+        std::fs::write(root.join("a/x"), [0; 10]).unwrap();
+        std::fs::write(root.join("b/deep/y"), [0; 20]).unwrap();
+        std::fs::write(root.join("top"), [0; 5]).unwrap();
+
+        let (sizes, total) = du(root, 2).await.unwrap();
+        assert_eq!(sizes, vec![(root.join("a"), 10), (root.join("b"), 20)]);
+        assert_eq!(total, 35);
+        assert!(du(&root.join("missing"), 2).await.is_err());
+    }
+}
@@ -0,0 +1,282 @@
+//! Command-line arguments.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+pub const USAGE: &str = "\
+Usage: fcmd [OPTIONS] [PATH]
+       fcmd du [DIR]
+       fcmd find PATTERN [ROOT]
+       fcmd trash FILE...
+
+Opens PATH (a directory, or a file to select in its directory) instead of
+restoring the last session, which is then kept as it was for next time.
+
+Commands (print the result and exit, without the TUI):
+  du [DIR]            Print the size in bytes of each directory in DIR
+                      (default .), then DIR's total
+  find PATTERN [ROOT] Print the paths under ROOT (default .) that PATTERN
+                      fuzzy-matches, best first, as Find does. Exits with
+                      status 1 if nothing matches
+  trash FILE...       Move the files to the system trash
+
+A directory named like a command opens as ./du, ./find or ./trash.
+
+Options:
+      --no-session    Start in the current directory; don't restore or save
+                      the session
+      --config <DIR>  Read config.toml and themes from DIR and keep the
+                      database there (default ~/.config/fcmd)
+      --cd-file <FILE>
+                      On quit, write the active panel's directory to FILE
+                      (for a shell function that cds there)
+      --choosefiles <FILE>
+                      Pick files: Enter on a file (or with entries marked)
+                      writes their paths to FILE, one per line, and quits.
+                      Exits with status 1 if fcmd is quit without a choice
+  -h, --help          Print this help
+  -V, --version       Print the version
+";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Args {
+    /// Directory to open, or a file to select in its directory.
+    pub path: Option<PathBuf>,
+    pub no_session: bool,
+    pub config_dir: Option<PathBuf>,
+    /// File to write the active directory to on quit.
+    pub cd_file: Option<PathBuf>,
+    /// Chooser mode: file to write the picked paths to.
+    pub choose_files: Option<PathBuf>,
+}
+
+/// A command that runs without the TUI (`fcmd du`, `fcmd find`, `fcmd trash`).
+#[derive(Debug, PartialEq)]
+pub enum Batch {
+    /// Sizes of the directories in a directory.
+    Du(PathBuf),
+    /// Paths under `root` that `pattern` fuzzy-matches.
+    Find { pattern: String, root: PathBuf },
+    /// Files to move to the trash.
+    Trash(Vec<PathBuf>),
+}
+
+/// What the command line asks for.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(Args),
+    Batch(Batch),
+    Help,
+    Version,
+}
+
+/// Parse the arguments after the program name. `--` ends the options, so a
+/// path starting with `-` can still be given. A command name (`du`, `find`,
+/// `trash`) counts as one only in first place.
+pub fn parse(args: impl IntoIterator<Item = OsString>) -> Result<Command, String> {
+    let mut out = Args::default();
+    let mut args = args.into_iter().peekable();
+    if let Some(command) = args
+        .peek()
+        .and_then(|a| a.to_str())
+        .filter(|a| ["du", "find", "trash"].contains(a))
+        .map(str::to_string)
+    {
+        args.next();
+        return parse_batch(&command, args.collect()).map(Command::Batch);
+    }
+    let mut options_done = false;
+    while let Some(arg) = args.next() {
+        let s = arg.to_string_lossy().into_owned();
+        if options_done || !s.starts_with('-') || s == "-" {
+            if out.path.is_some() {
+                return Err(format!("unexpected argument '{s}'"));
+            }
+            out.path = Some(arg.into());
+            continue;
+        }
+        match s.as_str() {
+            "--" => options_done = true,
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "--no-session" => out.no_session = true,
+            "--config" => {
+                let dir = args.next().ok_or("--config needs a directory")?;
+                out.config_dir = Some(dir.into());
+            }
+            "--cd-file" => {
+                let file = args.next().ok_or("--cd-file needs a file")?;
+                out.cd_file = Some(file.into());
+            }
+            "--choosefiles" => {
+                let file = args.next().ok_or("--choosefiles needs a file")?;
+                out.choose_files = Some(file.into());
+            }
+            _ => {
+                if let Some(dir) = s.strip_prefix("--config=") {
+                    out.config_dir = Some(dir.into());
+                } else if let Some(file) = s.strip_prefix("--cd-file=") {
+                    out.cd_file = Some(file.into());
+                } else if let Some(file) = s.strip_prefix("--choosefiles=") {
+                    out.choose_files = Some(file.into());
+                } else {
+                    return Err(format!("unknown option '{s}'"));
+                }
+            }
+        }
+    }
+    Ok(Command::Run(out))
+}
+
+/// The arguments of `du [DIR]`, `find PATTERN [ROOT]` and `trash FILE...`,
+/// after an optional `--`.
+fn parse_batch(command: &str, mut args: Vec<OsString>) -> Result<Batch, String> {
+    if args.first().is_some_and(|a| a == "--") {
+        args.remove(0);
+    }
+    let too_many = |max: usize| {
+        args.get(max)
+            .map(|a| format!("{command}: unexpected argument '{}'", a.to_string_lossy()))
+    };
+    match command {
+        "du" => {
+            if let Some(e) = too_many(1) {
+                return Err(e);
+            }
+            let dir = args
+                .into_iter()
+                .next()
+                .map_or_else(|| ".".into(), PathBuf::from);
+            Ok(Batch::Du(dir))
+        }
+        "find" => {
+            if let Some(e) = too_many(2) {
+                return Err(e);
+            }
+            let mut args = args.into_iter();
+            let pattern = args.next().ok_or("find needs a pattern")?;
+            Ok(Batch::Find {
+                pattern: pattern.to_string_lossy().into_owned(),
+                root: args.next().map_or_else(|| ".".into(), PathBuf::from),
+            })
+        }
+        _ => {
+            if args.is_empty() {
+                return Err("trash needs at least one file".into());
+            }
+            Ok(Batch::Trash(args.into_iter().map(PathBuf::from).collect()))
+        }
+    }
+}
+
+/// Where `path` (relative to `cwd`) opens: the directory itself, or a file's
+/// directory with the file's name to select.
+pub fn start_location(path: &Path, cwd: &Path) -> Result<(PathBuf, Option<String>), String> {
+    let path = crate::fs::normalize_path(&cwd.join(path));
+    match std::fs::metadata(&path) {
+        Ok(meta) if meta.is_dir() => Ok((path, None)),
+        Ok(_) => {
+            let name = path.file_name().map(|n| n.to_string_lossy().into_owned());
+            let dir = path
+                .parent()
+                .map_or_else(|| path.clone(), Path::to_path_buf);
+            Ok((dir, name))
+        }
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_strs(args: &[&str]) -> Result<Command, String> {
+        parse(args.iter().map(OsString::from))
+    }
+
+    #[test]
+    fn parse_path_and_options() {
+        assert_eq!(parse_strs(&[]), Ok(Command::Run(Args::default())));
+        let Ok(Command::Run(args)) = parse_strs(&["--no-session", "--config", "/c", "src"]) else {
+            panic!("expected Run");
+        };
+        assert_eq!(args.path, Some(PathBuf::from("src")));
+        assert!(args.no_session);
+        assert_eq!(args.config_dir, Some(PathBuf::from("/c")));
+
+        let Ok(Command::Run(args)) = parse_strs(&["--config=/d", "--", "-dash"]) else {
+            panic!("expected Run");
+        };
+        assert_eq!(args.config_dir, Some(PathBuf::from("/d")));
+        assert_eq!(args.path, Some(PathBuf::from("-dash")));
+
+        let Ok(Command::Run(args)) = parse_strs(&["--cd-file", "/tmp/dir"]) else {
+            panic!("expected Run");
+        };
+        assert_eq!(args.cd_file, Some(PathBuf::from("/tmp/dir")));
+        assert!(parse_strs(&["--cd-file"]).is_err());
+
+        let Ok(Command::Run(args)) = parse_strs(&["--choosefiles=/tmp/picked"]) else {
+            panic!("expected Run");
+        };
+        assert_eq!(args.choose_files, Some(PathBuf::from("/tmp/picked")));
+
+        assert_eq!(parse_strs(&["-V"]), Ok(Command::Version));
+        assert_eq!(parse_strs(&["a", "--help"]), Ok(Command::Help));
+    }
+
+    #[test]
+    fn parse_rejects_bad_arguments() {
+        assert!(parse_strs(&["--bogus"]).unwrap_err().contains("--bogus"));
+        assert!(parse_strs(&["--config"]).is_err());
+        assert!(parse_strs(&["a", "b"]).unwrap_err().contains("'b'"));
+    }
+
+    #[test]
+    fn parse_batch_commands() {
+        assert_eq!(
+            parse_strs(&["du"]),
+            Ok(Command::Batch(Batch::Du(PathBuf::from("."))))
+        );
+        assert_eq!(
+            parse_strs(&["find", "cfg", "src"]),
+            Ok(Command::Batch(Batch::Find {
+                pattern: "cfg".into(),
+                root: PathBuf::from("src"),
+            }))
+        );
+        assert_eq!(
+            parse_strs(&["trash", "--", "-a", "b"]),
+            Ok(Command::Batch(Batch::Trash(vec![
+                PathBuf::from("-a"),
+                PathBuf::from("b"),
+            ])))
+        );
+        assert!(parse_strs(&["find"]).is_err());
+        assert!(parse_strs(&["trash"]).is_err());
+        assert!(parse_strs(&["du", "a", "b"]).unwrap_err().contains("'b'"));
+
+        // Only in first place: elsewhere it is a directory to open.
+        let Ok(Command::Run(args)) = parse_strs(&["--no-session", "du"]) else {
+            panic!("expected Run");
+        };
+        assert_eq!(args.path, Some(PathBuf::from("du")));
+    }
+
+    #[test]
+    fn start_location_selects_a_file_in_its_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::fs::write(dir.path().join("sub/notes.txt"), "x").unwrap();
+
+        let (at, select) = start_location(Path::new("sub"), dir.path()).unwrap();
+        assert_eq!(at, dir.path().join("sub"));
+        assert_eq!(select, None);
+
+        let (at, select) = start_location(Path::new("sub/../sub/notes.txt"), dir.path()).unwrap();
+        assert_eq!(at, dir.path().join("sub"));
+        assert_eq!(select.as_deref(), Some("notes.txt"));
+
+        assert!(start_location(Path::new("missing"), dir.path()).is_err());
+    }
+}
@@ -0,0 +1,467 @@
+//! User settings read from `~/.config/fcmd/config.toml`.
+//!
+//! Every key is optional: a missing file or a missing key falls back to the
+//! built-in default, so the file only needs to list what the user changes.
+//! The `theme` key in the same file is read separately by [`crate::theme`].
+
+use serde::Deserialize;
+
+use crate::fs::copy_name::NameTemplate;
+
+/// Upper bound for `workers`: past this, more threads only add contention.
+pub const MAX_WORKERS: usize = 64;
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct Config {
+    /// Mirror the source's ownership, full permission bits (setuid/setgid/sticky
+    /// included) and timestamps onto pasted copies, `cp -a` style — including
+    /// symlinks themselves and directories after their contents are written.
+    pub preserve_attributes: bool,
+    /// Name a pasted copy takes when the user keeps both files on a conflict:
+    /// `{stem}`, `{n}` and `{ext}` are filled in, e.g. `{stem} ({n}){ext}`.
+    pub copy_name: NameTemplate,
+    /// How long (ms) a half-typed key sequence like `g` or `Space` waits for
+    /// its second key before being dropped. `0` waits forever.
+    pub key_timeout_ms: u64,
+    /// When the which-key hint popup appears for a pending key sequence.
+    pub which_key: WhichKey,
+    /// Delay (ms) before the popup appears in `WhichKey::Delay` mode.
+    pub which_key_delay_ms: u64,
+    /// How often (ms) the main loop checks on background work that is under
+    /// way. With nothing going on it checks four times less often; while a
+    /// spinner or progress bar is moving, every 100 ms.
+    pub poll_interval_ms: u64,
+    /// Threads a background `du` or `:genchecksums` may keep busy at once.
+    /// Unset, one per CPU; at least 1, and more than 64 counts as 64.
+    pub workers: Option<usize>,
+    /// `]]` / `[[` wrap from the last sibling directory to the first and back.
+    pub sibling_wrap: bool,
+    /// `p` asks where to paste (a fuzzy picker over open panels, bookmarks,
+    /// marks, sibling and child directories) instead of pasting in place.
+    pub paste_picker: bool,
+    /// Before a paste where names already exist in the destination, list every
+    /// source with its destination and wait for `Enter` to go ahead.
+    #[serde(alias = "confirm_overwrite")]
+    pub confirm_paste_conflicts: bool,
+    /// Ask before `dd` trashes anything. Trash is recoverable, so this can go;
+    /// permanent deletes always ask.
+    pub confirm_delete: bool,
+    /// List every move (cut + paste, `F6`) and wait for `Enter` to go ahead.
+    pub confirm_move: bool,
+    /// Like `confirm_move`, but only for moves onto another filesystem, which
+    /// copy and then delete instead of renaming.
+    pub confirm_move_cross_device: bool,
+    /// `F5` / `F6` / `P` first show the other panel's directory, its entry count
+    /// and what would collide there, and wait for `Enter`.
+    pub confirm_other_panel: bool,
+    /// `dd` moves to the trash. When off, `dd` deletes permanently like `dD`
+    /// (still behind the red confirmation), e.g. where trashing is wasteful.
+    pub delete_to_trash: bool,
+    /// Show an owner:group column in the panels (Unix only; `Space o` toggles
+    /// it for the session).
+    pub show_owner: bool,
+    /// Show the date column as the age of the entry ("5m", "2h", "3d")
+    /// instead of the calendar date. `Space T` flips it for one panel.
+    pub relative_dates: bool,
+    /// Sort names with uppercase before lowercase (`Cherry` before `apple`)
+    /// instead of ignoring case. Numbers in names sort numerically either way.
+    pub case_sensitive_sort: bool,
+    /// Measure the subdirectories of each directory the active panel settles
+    /// in, as `Space d` would (`Space D` toggles it for the session).
+    pub auto_dir_sizes: bool,
+    /// Which side of the screen the `Space p` preview takes (`Space P` flips
+    /// it for the session).
+    pub preview_side: PreviewSide,
+    /// Percentage of the panel area the preview gets; clamped to 20–80.
+    pub preview_ratio: u16,
+    /// Whether the preview stays on `preview_side` or takes the place of the
+    /// panel opposite the active one.
+    pub preview_placement: PreviewPlacement,
+    /// Key preset layered on top of the vim bindings.
+    pub keymap: Keymap,
+    /// What `Enter` does on a file that is not an archive.
+    pub file_action: FileAction,
+    /// `r` edits the name in place on the panel row instead of in a popup.
+    pub inline_rename: bool,
+    /// Nerd Font glyphs for file icons and the powerline status bar. Off, the
+    /// UI sticks to plain characters for terminals without a patched font.
+    pub icons: bool,
+    /// Where the `..` row goes in a listing: `top` (default), `bottom`, or
+    /// `hidden`. `h` / `Backspace` go up either way.
+    pub parent_entry: ParentEntry,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Keymap {
+    /// Vim bindings with the orthodox F-keys (default).
+    Vim,
+    /// Midnight Commander habits on top: `F3` also enters directories, `F7`
+    /// always makes a directory and `Insert` marks and moves down.
+    Mc,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FileAction {
+    /// Open the built-in viewer (default).
+    View,
+    /// Open `$VISUAL` / `$EDITOR`, as `o` does.
+    Edit,
+    /// Hand the file to the desktop's opener (`xdg-open`, `open` on macOS).
+    Open,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewPlacement {
+    /// Always on `preview_side` (default); the active panel keeps the other
+    /// columns, so it does not move as `Tab` switches panels.
+    Fixed,
+    /// Replace the panel across from the active one, leaving every panel in
+    /// its own column; the preview moves instead.
+    Opposite,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewSide {
+    Left,
+    Right,
+}
+
+impl PreviewSide {
+    pub fn flipped(self) -> Self {
+        match self {
+            PreviewSide::Left => PreviewSide::Right,
+            PreviewSide::Right => PreviewSide::Left,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PreviewSide::Left => "left",
+            PreviewSide::Right => "right",
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ParentEntry {
+    /// First row, before the directories (default).
+    Top,
+    /// Last row, after the files.
+    Bottom,
+    /// Not listed at all.
+    Hidden,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WhichKey {
+    /// Show after `which_key_delay_ms` (default): experts who type fast never see it.
+    Delay,
+    /// Show as soon as a prefix key is pressed.
+    Always,
+    /// Never show.
+    Never,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            preserve_attributes: false,
+            copy_name: NameTemplate::default(),
+            key_timeout_ms: 1000,
+            which_key: WhichKey::Delay,
+            which_key_delay_ms: 400,
+            poll_interval_ms: 250,
+            workers: None,
+            sibling_wrap: true,
+            paste_picker: false,
+            confirm_paste_conflicts: false,
+            confirm_delete: true,
+            confirm_move: false,
+            confirm_move_cross_device: false,
+            confirm_other_panel: false,
+            delete_to_trash: true,
+            show_owner: false,
+            relative_dates: false,
+            case_sensitive_sort: false,
+            auto_dir_sizes: false,
+            preview_side: PreviewSide::Right,
+            preview_ratio: 50,
+            preview_placement: PreviewPlacement::Fixed,
+            keymap: Keymap::Vim,
+            file_action: FileAction::View,
+            inline_rename: false,
+            icons: true,
+            parent_entry: ParentEntry::Top,
+        }
+    }
+}
+
+impl Config {
+    /// Load `config.toml`. A missing file yields the defaults; a file that
+    /// exists but fails to parse is reported so the user can fix it.
+    pub fn load() -> Result<Self, String> {
+        let Some(dir) = crate::util::config_dir() else {
+            return Ok(Self::default());
+        };
+        match std::fs::read_to_string(dir.join("config.toml")) {
+            Ok(content) => Self::parse(&content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("config.toml: {e}")),
+        }
+    }
+
+    /// How long a pending key must wait before which-key hints show, or `None`
+    /// when they are disabled.
+    pub fn which_key_delay(&self) -> Option<std::time::Duration> {
+        match self.which_key {
+            WhichKey::Delay => Some(std::time::Duration::from_millis(self.which_key_delay_ms)),
+            WhichKey::Always => Some(std::time::Duration::ZERO),
+            WhichKey::Never => None,
+        }
+    }
+
+    /// `preview_ratio` kept within a range where neither side collapses.
+    pub fn preview_percent(&self) -> u16 {
+        self.preview_ratio.clamp(20, 80)
+    }
+
+    /// How many threads background jobs use: `workers`, or the CPU count.
+    pub fn worker_threads(&self) -> usize {
+        let n = self.workers.unwrap_or_else(|| {
+            std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+        });
+        n.clamp(1, MAX_WORKERS)
+    }
+
+    /// The side the preview takes with `count` panels and panel `active`
+    /// focused. An opposite preview has no side to take from a middle panel,
+    /// so it falls back to `preview_side` there.
+    pub fn preview_side_for(&self, count: usize, active: usize) -> PreviewSide {
+        match self.preview_placement {
+            PreviewPlacement::Opposite if active == 0 => PreviewSide::Right,
+            PreviewPlacement::Opposite if active + 1 == count => PreviewSide::Left,
+            _ => self.preview_side,
+        }
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let config: Self =
+            toml::from_str(content).map_err(|e| format!("config.toml: {}", e.message()))?;
+        if config.workers == Some(0) {
+            return Err("config.toml: workers must be at least 1".into());
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_file_is_default() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn unknown_keys_are_ignored() {
+        // `theme` lives in the same file but is owned by the theme loader.
+        let cfg = Config::parse("theme = \"nord\"\n").unwrap();
+        assert_eq!(cfg, Config::default());
+    }
+
+    #[test]
+    fn preserve_attributes_parses() {
+        let cfg = Config::parse("preserve_attributes = true\n").unwrap();
+        assert!(cfg.preserve_attributes);
+    }
+
+    #[test]
+    fn copy_name_parses_and_needs_a_number() {
+        let cfg = Config::parse("copy_name = \"{stem} copy {n}{ext}\"\n").unwrap();
+        assert_eq!(cfg.copy_name.render("a.txt", 2), "a copy 2.txt");
+        let err = Config::parse("copy_name = \"{stem} copy{ext}\"\n").unwrap_err();
+        assert!(err.contains("{n}"), "{err}");
+    }
+
+    #[test]
+    fn poll_interval_parses() {
+        assert_eq!(Config::default().poll_interval_ms, 250);
+        let cfg = Config::parse("poll_interval_ms = 500\n").unwrap();
+        assert_eq!(cfg.poll_interval_ms, 500);
+    }
+
+    #[test]
+    fn workers_are_validated_and_clamped() {
+        assert!(Config::default().worker_threads() >= 1);
+        let cfg = Config::parse("workers = 2\n").unwrap();
+        assert_eq!(cfg.worker_threads(), 2);
+        let cfg = Config::parse("workers = 100000\n").unwrap();
+        assert_eq!(cfg.worker_threads(), MAX_WORKERS);
+        let err = Config::parse("workers = 0\n").unwrap_err();
+        assert!(err.contains("at least 1"), "{err}");
+        assert!(Config::parse("workers = -1\n").is_err());
+    }
+
+    #[test]
+    fn file_action_parses() {
+        assert_eq!(Config::default().file_action, FileAction::View);
+        let cfg = Config::parse("file_action = \"edit\"\n").unwrap();
+        assert_eq!(cfg.file_action, FileAction::Edit);
+        assert!(Config::parse("file_action = \"run\"\n").is_err());
+    }
+
+    #[test]
+    fn key_timeout_overrides_default() {
+        assert_eq!(Config::default().key_timeout_ms, 1000);
+        let cfg = Config::parse("key_timeout_ms = 2500\n").unwrap();
+        assert_eq!(cfg.key_timeout_ms, 2500);
+        assert!(!cfg.preserve_attributes);
+    }
+
+    #[test]
+    fn which_key_modes_parse() {
+        use std::time::Duration;
+        let cfg = Config::default();
+        assert_eq!(cfg.which_key_delay(), Some(Duration::from_millis(400)));
+        let cfg = Config::parse("which_key_delay_ms = 150\n").unwrap();
+        assert_eq!(cfg.which_key_delay(), Some(Duration::from_millis(150)));
+        let cfg = Config::parse("which_key = \"always\"\n").unwrap();
+        assert_eq!(cfg.which_key_delay(), Some(Duration::ZERO));
+        let cfg = Config::parse("which_key = \"never\"\n").unwrap();
+        assert_eq!(cfg.which_key_delay(), None);
+        assert!(Config::parse("which_key = \"sometimes\"\n").is_err());
+    }
+
+    #[test]
+    fn delete_to_trash_can_be_disabled() {
+        assert!(Config::default().delete_to_trash);
+        let cfg = Config::parse("delete_to_trash = false\n").unwrap();
+        assert!(!cfg.delete_to_trash);
+    }
+
+    #[test]
+    fn confirm_flags_parse() {
+        let d = Config::default();
+        assert!(d.confirm_delete && !d.confirm_move && !d.confirm_move_cross_device);
+        let cfg = Config::parse(
+            "confirm_delete = false\nconfirm_move = true\nconfirm_move_cross_device = true\n",
+        )
+        .unwrap();
+        assert!(!cfg.confirm_delete && cfg.confirm_move && cfg.confirm_move_cross_device);
+        assert!(!d.confirm_other_panel);
+        let cfg = Config::parse("confirm_other_panel = true\n").unwrap();
+        assert!(cfg.confirm_other_panel);
+    }
+
+    #[test]
+    fn auto_dir_sizes_parses() {
+        assert!(!Config::default().auto_dir_sizes);
+        let cfg = Config::parse("auto_dir_sizes = true\n").unwrap();
+        assert!(cfg.auto_dir_sizes);
+    }
+
+    #[test]
+    fn relative_dates_parses() {
+        assert!(!Config::default().relative_dates);
+        assert!(
+            Config::parse("relative_dates = true\n")
+                .unwrap()
+                .relative_dates
+        );
+    }
+
+    #[test]
+    fn case_sensitive_sort_parses() {
+        assert!(!Config::default().case_sensitive_sort);
+        let cfg = Config::parse("case_sensitive_sort = true\n").unwrap();
+        assert!(cfg.case_sensitive_sort);
+    }
+
+    #[test]
+    fn parent_entry_parses() {
+        assert_eq!(Config::default().parent_entry, ParentEntry::Top);
+        let cfg = Config::parse("parent_entry = \"hidden\"\n").unwrap();
+        assert_eq!(cfg.parent_entry, ParentEntry::Hidden);
+        assert!(Config::parse("parent_entry = \"left\"\n").is_err());
+    }
+
+    #[test]
+    fn show_owner_parses() {
+        assert!(!Config::default().show_owner);
+        assert!(Config::parse("show_owner = true\n").unwrap().show_owner);
+    }
+
+    #[test]
+    fn preview_placement_parses() {
+        let cfg = Config::parse("preview_side = \"left\"\npreview_ratio = 65\n").unwrap();
+        assert_eq!(cfg.preview_side, PreviewSide::Left);
+        assert_eq!(cfg.preview_percent(), 65);
+        assert_eq!(
+            Config::parse("preview_ratio = 95\n")
+                .unwrap()
+                .preview_percent(),
+            80
+        );
+        assert!(Config::parse("preview_side = \"top\"\n").is_err());
+    }
+
+    #[test]
+    fn opposite_preview_follows_the_active_panel() {
+        let fixed = Config::default();
+        assert_eq!(fixed.preview_side_for(2, 1), PreviewSide::Right);
+        let cfg = Config::parse("preview_placement = \"opposite\"\n").unwrap();
+        assert_eq!(cfg.preview_side_for(2, 0), PreviewSide::Right);
+        assert_eq!(cfg.preview_side_for(2, 1), PreviewSide::Left);
+        // The middle of three has no opposite side.
+        assert_eq!(cfg.preview_side_for(3, 1), cfg.preview_side);
+    }
+
+    #[test]
+    fn keymap_preset_parses() {
+        assert_eq!(Config::default().keymap, Keymap::Vim);
+        assert_eq!(
+            Config::parse("keymap = \"mc\"\n").unwrap().keymap,
+            Keymap::Mc
+        );
+        assert!(Config::parse("keymap = \"emacs\"\n").is_err());
+    }
+
+    #[test]
+    fn confirm_paste_conflicts_parses() {
+        assert!(!Config::default().confirm_paste_conflicts);
+        let cfg = Config::parse("confirm_paste_conflicts = true\n").unwrap();
+        assert!(cfg.confirm_paste_conflicts);
+        let cfg = Config::parse("confirm_overwrite = true\n").unwrap();
+        assert!(cfg.confirm_paste_conflicts);
+    }
+
+    #[test]
+    fn icons_parses() {
+        assert!(Config::default().icons);
+        assert!(!Config::parse("icons = false\n").unwrap().icons);
+    }
+
+    #[test]
+    fn inline_rename_parses() {
+        assert!(!Config::default().inline_rename);
+        assert!(
+            Config::parse("inline_rename = true\n")
+                .unwrap()
+                .inline_rename
+        );
+    }
+
+    #[test]
+    fn wrong_type_is_an_error() {
+        let err = Config::parse("preserve_attributes = \"yes\"\n").unwrap_err();
+        assert!(err.starts_with("config.toml:"));
+    }
+}
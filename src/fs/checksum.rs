@@ -0,0 +1,118 @@
+//! `SHA256SUMS` generation in the `sha256sum` format (`<hash>  <name>`), so
+//! `sha256sum -c SHA256SUMS` verifies the directory later.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use sha2::{Digest, Sha256};
+
+use crate::util::pool::for_each_parallel;
+
+/// File written by `:genchecksums`.
+pub const SUMS_FILE: &str = "SHA256SUMS";
+
+/// Lowercase hex SHA-256 of the file at `path`, read in chunks. `None` when
+/// `cancel` was set part-way through.
+pub fn sha256_file(path: &Path, cancel: &AtomicBool) -> std::io::Result<Option<String>> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(Some(format!("{:x}", hasher.finalize())))
+}
+
+/// Hash `files` on up to `workers` threads and write their sums to
+/// `dir/SHA256SUMS`, sorted by name. `on_progress` gets the count finished so
+/// far and the latest file. Nothing is written when cancelled or on an error.
+/// Returns how many files were listed, or `None` on cancel.
+pub fn write_sums(
+    dir: &Path,
+    files: &[PathBuf],
+    workers: usize,
+    on_progress: &mut dyn FnMut(usize, usize, &str),
+    cancel: &AtomicBool,
+) -> std::io::Result<Option<usize>> {
+    let mut named: Vec<(String, &PathBuf)> = files
+        .iter()
+        .map(|p| {
+            (
+                p.file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned(),
+                p,
+            )
+        })
+        .collect();
+    named.sort();
+    let mut hashes: Vec<String> = vec![String::new(); named.len()];
+    let mut done = 0;
+    let mut stopped: Option<std::io::Result<Option<usize>>> = None;
+    for_each_parallel(
+        &named,
+        workers,
+        |(_, path)| sha256_file(path, cancel),
+        |i, hash| {
+            match hash {
+                Ok(Some(hash)) => hashes[i] = hash,
+                Ok(None) => stopped = Some(Ok(None)),
+                Err(e) => stopped = Some(Err(e)),
+            }
+            done += 1;
+            on_progress(done, named.len(), &named[i].0);
+            stopped.is_none()
+        },
+    );
+    if let Some(stopped) = stopped {
+        return stopped;
+    }
+    let mut out = String::new();
+    for ((name, _), hash) in named.iter().zip(&hashes) {
+        out.push_str(&format!("{hash}  {name}\n"));
+    }
+    std::fs::write(dir.join(SUMS_FILE), out)?;
+    Ok(Some(named.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_sha256sum_format_sorted_by_name() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("b.txt"), "abc").unwrap();
+        std::fs::write(dir.path().join("a.txt"), "").unwrap();
+        let files = [dir.path().join("b.txt"), dir.path().join("a.txt")];
+        let cancel = AtomicBool::new(false);
+        let n = write_sums(dir.path(), &files, 2, &mut |_, _, _| {}, &cancel).unwrap();
+        assert_eq!(n, Some(2));
+        let sums = std::fs::read_to_string(dir.path().join(SUMS_FILE)).unwrap();
+        assert_eq!(
+            sums,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  a.txt\n\
+             ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  b.txt\n"
+        );
+    }
+
+    #[test]
+    fn cancel_writes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.txt"), "x").unwrap();
+        let cancel = AtomicBool::new(true);
+        let files = [dir.path().join("a.txt")];
+        let n = write_sums(dir.path(), &files, 2, &mut |_, _, _| {}, &cancel).unwrap();
+        assert_eq!(n, None);
+        assert!(!dir.path().join(SUMS_FILE).exists());
+    }
+}
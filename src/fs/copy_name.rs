@@ -0,0 +1,180 @@
+//! Names for a pasted copy that must not replace what is already there.
+//!
+//! The `copy_name` setting is a template such as `{stem}_{n}{ext}` (the
+//! default, `notes_1.txt`) or `{stem} ({n}){ext}` (`notes (1).txt`). It is
+//! parsed once, when the config is read.
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+pub const DEFAULT_TEMPLATE: &str = "{stem}_{n}{ext}";
+
+#[derive(Clone, Debug, PartialEq)]
+enum Part {
+    Text(String),
+    Stem,
+    Number,
+    Ext,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(try_from = "String")]
+pub struct NameTemplate {
+    parts: Vec<Part>,
+}
+
+impl NameTemplate {
+    /// Parse a template. `{stem}`, `{n}` and `{ext}` are the placeholders;
+    /// `{n}` is required, since without it every copy gets the same name.
+    pub fn parse(template: &str) -> Result<Self, String> {
+        if template.contains('/') {
+            return Err(format!("copy_name '{template}' must not contain '/'"));
+        }
+        let mut parts = Vec::new();
+        let mut rest = template;
+        while let Some(open) = rest.find('{') {
+            if open > 0 {
+                parts.push(Part::Text(rest[..open].to_string()));
+            }
+            let Some(close) = rest[open..].find('}') else {
+                return Err(format!("copy_name '{template}' has an unclosed '{{'"));
+            };
+            parts.push(match &rest[open + 1..open + close] {
+                "stem" => Part::Stem,
+                "n" => Part::Number,
+                "ext" => Part::Ext,
+                other => {
+                    return Err(format!("copy_name: unknown placeholder {{{other}}}"));
+                }
+            });
+            rest = &rest[open + close + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_string()));
+        }
+        if !parts.contains(&Part::Number) {
+            return Err(format!("copy_name '{template}' must contain {{n}}"));
+        }
+        Ok(NameTemplate { parts })
+    }
+
+    /// The `n`th copy's name for `name`. The extension keeps its dot, so a
+    /// name without one (`Makefile`) gets no trailing dot.
+    pub fn render(&self, name: &str, n: u64) -> String {
+        let path = Path::new(name);
+        let stem = path
+            .file_stem()
+            .map_or_else(|| name.to_string(), |s| s.to_string_lossy().into_owned());
+        let ext = path
+            .extension()
+            .map(|e| format!(".{}", e.to_string_lossy()))
+            .unwrap_or_default();
+        let mut out = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => out.push_str(text),
+                Part::Stem => out.push_str(&stem),
+                Part::Number => out.push_str(&n.to_string()),
+                Part::Ext => out.push_str(&ext),
+            }
+        }
+        out
+    }
+
+    /// `dir/name` if that is free, otherwise the first free numbered name.
+    pub fn free_path(&self, dir: &Path, name: &str) -> PathBuf {
+        self.free_path_avoiding(dir, name, &[])
+    }
+
+    /// Like [`free_path`](Self::free_path), also treating `claimed` as taken:
+    /// paths handed out already but not created yet.
+    pub fn free_path_avoiding(&self, dir: &Path, name: &str, claimed: &[PathBuf]) -> PathBuf {
+        let taken = |p: &Path| claimed.iter().any(|c| c == p) || p.symlink_metadata().is_ok();
+        let candidate = dir.join(name);
+        if !taken(&candidate) {
+            return candidate;
+        }
+        for n in 1..=99999 {
+            let p = dir.join(self.render(name, n));
+            if !taken(&p) {
+                return p;
+            }
+        }
+        // Fallback with timestamp
+        let ts = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64);
+        dir.join(self.render(name, ts))
+    }
+}
+
+impl Default for NameTemplate {
+    fn default() -> Self {
+        Self::parse(DEFAULT_TEMPLATE).expect("default template is valid")
+    }
+}
+
+impl TryFrom<String> for NameTemplate {
+    type Error = String;
+
+    fn try_from(template: String) -> Result<Self, String> {
+        Self::parse(&template)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_common_templates() {
+        let underscore = NameTemplate::default();
+        assert_eq!(underscore.render("notes.txt", 1), "notes_1.txt");
+        assert_eq!(underscore.render("Makefile", 2), "Makefile_2");
+
+        let finder = NameTemplate::parse("{stem} copy {n}{ext}").unwrap();
+        assert_eq!(finder.render("notes.txt", 3), "notes copy 3.txt");
+
+        let windows = NameTemplate::parse("{stem} ({n}){ext}").unwrap();
+        assert_eq!(windows.render("archive.tar.gz", 1), "archive.tar (1).gz");
+        assert_eq!(windows.render(".bashrc", 1), ".bashrc (1)");
+    }
+
+    #[test]
+    fn rejects_bad_templates() {
+        assert!(
+            NameTemplate::parse("{stem} copy{ext}")
+                .unwrap_err()
+                .contains("{n}")
+        );
+        assert!(
+            NameTemplate::parse("{stem}_{i}{ext}")
+                .unwrap_err()
+                .contains("{i}")
+        );
+        assert!(NameTemplate::parse("{stem}_{n").is_err());
+        assert!(NameTemplate::parse("old/{n}").is_err());
+    }
+
+    #[test]
+    fn free_path_skips_taken_numbers() {
+        let dir = tempfile::tempdir().unwrap();
+        let windows = NameTemplate::parse("{stem} ({n}){ext}").unwrap();
+        assert_eq!(
+            windows.free_path(dir.path(), "a.txt"),
+            dir.path().join("a.txt")
+        );
+        std::fs::write(dir.path().join("a.txt"), "").unwrap();
+        std::fs::write(dir.path().join("a (1).txt"), "").unwrap();
+        assert_eq!(
+            windows.free_path(dir.path(), "a.txt"),
+            dir.path().join("a (2).txt")
+        );
+        let claimed = [dir.path().join("a (2).txt")];
+        assert_eq!(
+            windows.free_path_avoiding(dir.path(), "a.txt", &claimed),
+            dir.path().join("a (3).txt")
+        );
+    }
+}
@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use crate::fs::ops::{PROGRESS_INTERVAL, path_size};
+use crate::util::pool::for_each_parallel;
 
 pub enum DuMsg {
     Progress {
@@ -17,31 +18,41 @@ pub enum DuMsg {
     },
 }
 
-pub fn du_in_background(dirs: Vec<PathBuf>, tx: tokio::sync::mpsc::Sender<DuMsg>) {
+/// Measure `dirs` on up to `workers` threads. Sizes come back in the order
+/// of `dirs`.
+pub fn du_in_background(dirs: Vec<PathBuf>, workers: usize, tx: tokio::sync::mpsc::Sender<DuMsg>) {
     tokio::task::spawn_blocking(move || {
         let total = dirs.len();
-        let mut sizes = Vec::new();
+        let mut sizes: Vec<u64> = vec![0; total];
+        let mut done = 0;
         let mut last_report: Option<Instant> = None;
-        for (i, dir) in dirs.iter().enumerate() {
-            let name = dir
-                .file_name()
-                .map(|n| n.to_string_lossy().into_owned())
-                .unwrap_or_default();
-            // Progress is cosmetic and the receiver only keeps the latest message,
-            // so use a throttled, non-blocking send: a full channel must never stall
-            // the size calculation (see ProgressCtx::report for the same rationale).
-            let now = Instant::now();
-            if last_report.is_none_or(|t| now.duration_since(t) >= PROGRESS_INTERVAL) {
-                last_report = Some(now);
-                let _ = tx.try_send(DuMsg::Progress {
-                    done: i,
-                    total,
-                    current: name,
-                });
-            }
-            let size = path_size(dir);
-            sizes.push((dir.clone(), size));
-        }
+        for_each_parallel(
+            &dirs,
+            workers,
+            |dir| path_size(dir),
+            |i, size| {
+                sizes[i] = size;
+                done += 1;
+                // Progress is cosmetic and the receiver only keeps the latest message,
+                // so use a throttled, non-blocking send: a full channel must never stall
+                // the size calculation (see ProgressCtx::report for the same rationale).
+                let now = Instant::now();
+                if last_report.is_none_or(|t| now.duration_since(t) >= PROGRESS_INTERVAL) {
+                    last_report = Some(now);
+                    let name = dirs[i]
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    let _ = tx.try_send(DuMsg::Progress {
+                        done,
+                        total,
+                        current: name,
+                    });
+                }
+                true
+            },
+        );
+        let sizes = dirs.into_iter().zip(sizes).collect();
         // Final result must be delivered reliably.
         let _ = tx.blocking_send(DuMsg::Finished { sizes });
     });
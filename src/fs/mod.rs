@@ -2,12 +2,20 @@
 //!
 //! - [`ops`]: copy / move / delete / rename / mkdir / touch, the yank-register
 //!   and the undo stack, plus conflict-resolution and progress reporting.
+//! - [`copy_name`]: the numbered name a pasted copy takes to keep both files.
 //! - [`du`]: recursive directory-size calculation.
 //! - [`perms`]: `chmod` / `chown`.
+//! - [`mounts`]: removable media for `:mount` / `:unmount`.
+//!
+//! Small standalone queries (free space, sibling directories) live here.
 
+pub mod checksum;
+pub mod copy_name;
 pub mod du;
+pub mod mounts;
 pub mod ops;
 pub mod perms;
+pub mod rsync;
 pub mod trash;
 
 /// Free and total bytes of the filesystem containing `path`.
@@ -42,6 +50,182 @@ pub fn disk_free(_path: &std::path::Path) -> Option<(u64, u64)> {
     None
 }
 
+/// Names of `dir`'s subdirectories that pass `keep`, in natural name order — the
+/// panel's default sort. `Path::is_dir` follows symlinks, so linked directories
+/// count too.
+fn subdir_names(
+    dir: &std::path::Path,
+    keep: impl Fn(&std::ffi::OsStr) -> bool,
+) -> Option<Vec<std::ffi::OsString>> {
+    let mut names: Vec<std::ffi::OsString> = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name())
+        .filter(|n| keep(n))
+        .collect();
+    names.sort_by(|a, b| crate::util::natsort::natsort(a.as_encoded_bytes(), b.as_encoded_bytes()));
+    Some(names)
+}
+
+/// Subdirectories of `dir` in natural order, hidden ones only with `show_hidden`.
+/// Empty when `dir` can't be read.
+pub fn subdirs(dir: &std::path::Path, show_hidden: bool) -> Vec<std::path::PathBuf> {
+    subdir_names(dir, |n| {
+        show_hidden || !n.to_string_lossy().starts_with('.')
+    })
+    .unwrap_or_default()
+    .into_iter()
+    .map(|n| dir.join(n))
+    .collect()
+}
+
+/// Directories matching `pattern`'s last component as a glob (`*`, `?`), in
+/// natural order. Only that component may hold wildcards; `*` skips hidden
+/// directories unless the pattern itself starts with `.`, as in a shell.
+/// Empty when the last component has no wildcard or nothing matches.
+pub fn glob_dirs(pattern: &std::path::Path) -> Vec<std::path::PathBuf> {
+    let (Some(parent), Some(glob)) = (pattern.parent(), pattern.file_name()) else {
+        return Vec::new();
+    };
+    let glob = glob.to_string_lossy();
+    if !glob.contains(['*', '?']) {
+        return Vec::new();
+    }
+    let dot = glob.starts_with('.');
+    subdir_names(parent, |n| {
+        let n = n.to_string_lossy();
+        (dot || !n.starts_with('.')) && crate::util::glob_match(&glob, &n)
+    })
+    .unwrap_or_default()
+    .into_iter()
+    .map(|n| parent.join(n))
+    .collect()
+}
+
+/// `path` if it is a directory, otherwise its closest ancestor that is one.
+/// `None` only when nothing up to the root exists (e.g. an unmounted drive).
+pub fn nearest_existing_dir(path: &std::path::Path) -> Option<std::path::PathBuf> {
+    path.ancestors()
+        .find(|p| p.is_dir())
+        .map(|p| p.to_path_buf())
+}
+
+/// `path` with `.` and `..` folded away lexically (symlinks are not resolved),
+/// so a typed `../src` does not leave `..` in a panel path. `..` at the root
+/// stays at the root.
+pub fn normalize_path(path: &std::path::Path) -> std::path::PathBuf {
+    use std::path::Component;
+    let mut out = std::path::PathBuf::new();
+    for comp in path.components() {
+        match comp {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if out.file_name().is_some() {
+                    out.pop();
+                } else if !out.has_root() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// `path` as seen from the directory `base`, `../` included where the two
+/// part ways. Both are taken lexically, as absolute paths; `path == base`
+/// gives `.`.
+pub fn relative_path(path: &std::path::Path, base: &std::path::Path) -> std::path::PathBuf {
+    let path: Vec<_> = path.components().collect();
+    let base: Vec<_> = base.components().collect();
+    let common = path.iter().zip(&base).take_while(|(a, b)| a == b).count();
+    let mut out: std::path::PathBuf = base[common..].iter().map(|_| "..").collect();
+    out.extend(&path[common..]);
+    if out.as_os_str().is_empty() {
+        out.push(".");
+    }
+    out
+}
+
+/// Whether the symlink at `link` points outside `root`, which must already be
+/// canonical. The target is resolved fully, so `..` hops and links further
+/// along count; a dangling link is judged by where it would point.
+pub fn link_escapes(link: &std::path::Path, root: &std::path::Path) -> bool {
+    let target = std::fs::canonicalize(link).unwrap_or_else(|_| {
+        let raw = std::fs::read_link(link).unwrap_or_default();
+        let parent = link.parent().unwrap_or(link);
+        let parent = std::fs::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf());
+        normalize_path(&parent.join(raw))
+    });
+    !target.starts_with(root)
+}
+
+/// `path`'s contents as text, for the clipboard. Refused when the file is
+/// larger than `max` bytes, or holds NUL bytes or invalid UTF-8 (a binary).
+pub fn read_text(path: &std::path::Path, max: u64) -> Result<String, String> {
+    use std::io::Read;
+    let name = path
+        .file_name()
+        .map_or_else(|| path.to_string_lossy(), |n| n.to_string_lossy());
+    let too_big = |len: u64| {
+        format!(
+            "{name} is {}, over the {} limit",
+            crate::util::format_bytes(len),
+            crate::util::format_bytes(max)
+        )
+    };
+    let meta = std::fs::metadata(path).map_err(|e| format!("{name}: {e}"))?;
+    if meta.is_dir() {
+        return Err(format!("{name} is a directory"));
+    }
+    if meta.len() > max {
+        return Err(too_big(meta.len()));
+    }
+    // The file may have grown since the stat; read one byte past the cap.
+    let mut bytes = Vec::new();
+    std::fs::File::open(path)
+        .and_then(|f| f.take(max + 1).read_to_end(&mut bytes))
+        .map_err(|e| format!("{name}: {e}"))?;
+    if bytes.len() as u64 > max {
+        return Err(too_big(bytes.len() as u64));
+    }
+    if bytes.contains(&0) {
+        return Err(format!("{name} is a binary file"));
+    }
+    String::from_utf8(bytes).map_err(|_| format!("{name} is not UTF-8 text"))
+}
+
+/// The directory next to (`forward`) or before `current` among its parent's
+/// subdirectories, in natural name order. Hidden siblings are skipped unless
+/// `show_hidden`. At either end this wraps around when `wrap` is set, otherwise
+/// returns `None`; also `None` when there is no other sibling at all.
+pub fn sibling_dir(
+    current: &std::path::Path,
+    forward: bool,
+    wrap: bool,
+    show_hidden: bool,
+) -> Option<std::path::PathBuf> {
+    let parent = current.parent()?;
+    let own = current.file_name()?;
+    let names = subdir_names(parent, |n| {
+        n == own || show_hidden || !n.to_string_lossy().starts_with('.')
+    })?;
+    let pos = names.iter().position(|n| n == own)?;
+    let len = names.len();
+    if len < 2 {
+        return None;
+    }
+    let next = match (forward, pos) {
+        (true, p) if p + 1 < len => p + 1,
+        (true, _) if wrap => 0,
+        (false, 0) if wrap => len - 1,
+        (false, p) if p > 0 => p - 1,
+        _ => return None,
+    };
+    Some(parent.join(&names[next]))
+}
+
 #[cfg(all(test, unix))]
 mod tests {
     use super::*;
@@ -53,6 +237,132 @@ mod tests {
         assert!(free <= total, "free ({free}) must not exceed total ({total})");
     }
 
+    #[test]
+    fn normalize_path_folds_dots() {
+        use std::path::{Path, PathBuf};
+        assert_eq!(
+            normalize_path(Path::new("/a/b/../c/./d")),
+            PathBuf::from("/a/c/d")
+        );
+        assert_eq!(normalize_path(Path::new("/../..")), PathBuf::from("/"));
+        assert_eq!(
+            normalize_path(Path::new("../../x")),
+            PathBuf::from("../../x")
+        );
+    }
+
+    #[test]
+    fn relative_path_climbs_out_where_paths_part() {
+        use std::path::{Path, PathBuf};
+        let rel = |p, b| relative_path(Path::new(p), Path::new(b));
+        assert_eq!(
+            rel("/repo/src/app/mod.rs", "/repo"),
+            PathBuf::from("src/app/mod.rs")
+        );
+        assert_eq!(
+            rel("/repo/src/main.rs", "/repo/docs/api"),
+            PathBuf::from("../../src/main.rs")
+        );
+        assert_eq!(rel("/a", "/b/c"), PathBuf::from("../../a"));
+        assert_eq!(rel("/repo", "/repo"), PathBuf::from("."));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn link_escapes_resolves_the_target() {
+        use std::os::unix::fs::symlink;
+        let dir = tempfile::tempdir().unwrap();
+        let root = std::fs::canonicalize(dir.path()).unwrap().join("root");
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::write(root.join("sub/a.txt"), "").unwrap();
+        symlink("sub/a.txt", root.join("inside")).unwrap();
+        symlink("../root/sub", root.join("roundabout")).unwrap();
+        symlink("..", root.join("up")).unwrap();
+        symlink("/nowhere/at/all", root.join("dangling_out")).unwrap();
+        symlink("sub/missing", root.join("dangling_in")).unwrap();
+
+        assert!(!link_escapes(&root.join("inside"), &root));
+        assert!(!link_escapes(&root.join("roundabout"), &root));
+        assert!(link_escapes(&root.join("up"), &root));
+        assert!(link_escapes(&root.join("dangling_out"), &root));
+        assert!(!link_escapes(&root.join("dangling_in"), &root));
+    }
+
+    #[test]
+    fn read_text_refuses_large_and_binary_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let text = dir.path().join("notes.txt");
+        std::fs::write(&text, "one\ntwo\n").unwrap();
+        assert_eq!(read_text(&text, 64).unwrap(), "one\ntwo\n");
+        assert_eq!(
+            read_text(&text, 4).unwrap_err(),
+            "notes.txt is 8B, over the 4B limit"
+        );
+
+        let bin = dir.path().join("a.out");
+        std::fs::write(&bin, b"\x7fELF\0\x02").unwrap();
+        assert_eq!(read_text(&bin, 64).unwrap_err(), "a.out is a binary file");
+        std::fs::write(&bin, b"caf\xe9").unwrap();
+        assert_eq!(read_text(&bin, 64).unwrap_err(), "a.out is not UTF-8 text");
+        assert!(
+            read_text(dir.path(), 64)
+                .unwrap_err()
+                .ends_with("is a directory")
+        );
+    }
+
+    #[test]
+    fn nearest_existing_dir_walks_up() {
+        let base = std::env::temp_dir().join(format!("fcmd_nearest_{}", std::process::id()));
+        std::fs::create_dir_all(base.join("kept")).unwrap();
+        let gone = base.join("kept/removed/deeper");
+        assert_eq!(nearest_existing_dir(&gone), Some(base.join("kept")));
+        assert_eq!(nearest_existing_dir(&base), Some(base.clone()));
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn sibling_dir_steps_in_natural_order() {
+        let base = std::env::temp_dir().join(format!("fcmd_sibling_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+        for d in ["ch2", "ch10", "ch1", ".hidden"] {
+            std::fs::create_dir_all(base.join(d)).unwrap();
+        }
+        std::fs::write(base.join("ch3"), "not a dir").unwrap();
+
+        let ch2 = base.join("ch2");
+        assert_eq!(
+            sibling_dir(&ch2, true, false, false),
+            Some(base.join("ch10"))
+        );
+        assert_eq!(
+            sibling_dir(&ch2, false, false, false),
+            Some(base.join("ch1"))
+        );
+        let ch10 = base.join("ch10");
+        assert_eq!(sibling_dir(&ch10, true, false, false), None);
+        assert_eq!(
+            sibling_dir(&ch10, true, true, false),
+            Some(base.join("ch1"))
+        );
+        // Hidden dirs sort first ('.' < 'c') and only appear when shown.
+        let ch1 = base.join("ch1");
+        assert_eq!(
+            sibling_dir(&ch1, false, true, false),
+            Some(base.join("ch10"))
+        );
+        assert_eq!(
+            sibling_dir(&ch1, false, false, true),
+            Some(base.join(".hidden"))
+        );
+        assert_eq!(
+            subdirs(&base, false),
+            vec![ch1.clone(), ch2.clone(), ch10.clone()]
+        );
+        assert_eq!(subdirs(&base, true).len(), 4);
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
     #[test]
     fn disk_free_nonexistent_is_none() {
         assert!(disk_free(std::path::Path::new("/no/such/path/here/xyz")).is_none());
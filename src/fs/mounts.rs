@@ -0,0 +1,478 @@
+//! Removable media for `:mount` / `:unmount`.
+//!
+//! Devices come from `lsblk` on Linux (falling back to `/proc/mounts`) and
+//! from `diskutil` + `mount` on macOS. Mounting goes through `udisksctl` or
+//! `diskutil`, so no root is needed when the desktop's polkit rules allow it.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+/// A block device with a filesystem, mounted or not.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MountDevice {
+    /// `/dev/sdb1` on Linux, `disk4s1` on macOS.
+    pub device: String,
+    pub label: String,
+    pub fstype: String,
+    /// Bytes; 0 when unknown.
+    pub size: u64,
+    pub removable: bool,
+    pub mount_point: Option<PathBuf>,
+}
+
+/// Undo the octal escapes (`\040` for a space) used in `/proc/mounts`.
+fn unescape_octal(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\'
+            && let Some(oct) = s.get(i + 1..i + 4)
+            && let Ok(b) = u8::from_str_radix(oct, 8)
+        {
+            out.push(b);
+            i += 4;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Device-backed mounts from `/proc/mounts` (pseudo filesystems skipped).
+pub fn parse_proc_mounts(text: &str) -> Vec<MountDevice> {
+    text.lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let device = fields.next()?;
+            let mount_point = fields.next()?;
+            let fstype = fields.next()?;
+            device.starts_with("/dev/").then(|| MountDevice {
+                device: device.to_string(),
+                label: String::new(),
+                fstype: fstype.to_string(),
+                size: 0,
+                removable: false,
+                mount_point: Some(PathBuf::from(unescape_octal(mount_point))),
+            })
+        })
+        .collect()
+}
+
+/// `KEY="value"` pairs of one `lsblk -P` line; `\xNN` escapes are decoded.
+fn parse_pairs(line: &str) -> Vec<(&str, String)> {
+    let mut pairs = Vec::new();
+    let mut rest = line.trim_start();
+    while let Some((key, after)) = rest.split_once("=\"") {
+        let Some(end) = after.find('"') else { break };
+        pairs.push((key.trim(), unescape_hex(&after[..end])));
+        rest = after[end + 1..].trim_start();
+    }
+    pairs
+}
+
+fn unescape_hex(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if s[i..].starts_with("\\x")
+            && let Some(hex) = s.get(i + 2..i + 4)
+            && let Ok(b) = u8::from_str_radix(hex, 16)
+        {
+            out.push(b);
+            i += 4;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Devices carrying a filesystem from
+/// `lsblk -P -p -b -o NAME,SIZE,RM,TYPE,FSTYPE,MOUNTPOINT,LABEL`.
+pub fn parse_lsblk(text: &str) -> Vec<MountDevice> {
+    text.lines()
+        .filter_map(|line| {
+            let pairs = parse_pairs(line);
+            let get = |k: &str| {
+                pairs
+                    .iter()
+                    .find(|(key, _)| *key == k)
+                    .map_or("", |(_, v)| v.as_str())
+            };
+            let fstype = get("FSTYPE");
+            if fstype.is_empty() || fstype == "swap" || get("NAME").is_empty() {
+                return None;
+            }
+            let mount_point = get("MOUNTPOINT");
+            Some(MountDevice {
+                device: get("NAME").to_string(),
+                label: get("LABEL").to_string(),
+                fstype: fstype.to_string(),
+                size: get("SIZE").parse().unwrap_or(0),
+                removable: get("RM") == "1",
+                mount_point: (!mount_point.is_empty()).then(|| PathBuf::from(mount_point)),
+            })
+        })
+        .collect()
+}
+
+/// Partitions of external disks from `diskutil list external physical`, e.g.
+/// `   1:  DOS_FAT_32 USB   15.5 GB   disk4s1`. Mount points come separately.
+pub fn parse_diskutil_list(text: &str) -> Vec<MountDevice> {
+    text.lines()
+        .filter_map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let index = tokens.first()?.strip_suffix(':')?;
+            // Index 0 is the partition scheme of the whole disk.
+            if index.parse::<u32>().ok()? == 0 || tokens.len() < 5 {
+                return None;
+            }
+            let n = tokens.len();
+            let amount: f64 = tokens[n - 3].trim_start_matches('*').parse().ok()?;
+            let unit = match tokens[n - 2] {
+                "KB" => 1e3,
+                "MB" => 1e6,
+                "GB" => 1e9,
+                "TB" => 1e12,
+                _ => 1.0,
+            };
+            Some(MountDevice {
+                device: tokens[n - 1].to_string(),
+                label: tokens[2..n - 3].join(" "),
+                fstype: tokens[1].to_string(),
+                size: (amount * unit) as u64,
+                removable: true,
+                mount_point: None,
+            })
+        })
+        .collect()
+}
+
+/// Device-backed mounts from the BSD `mount` listing, e.g.
+/// `/dev/disk4s1 on /Volumes/USB (msdos, local, nodev)`.
+pub fn parse_bsd_mount(text: &str) -> Vec<MountDevice> {
+    text.lines()
+        .filter_map(|line| {
+            let (device, rest) = line.split_once(" on ")?;
+            let (mount_point, opts) = rest.rsplit_once(" (")?;
+            let fstype = opts.split(',').next().unwrap_or("").trim_end_matches(')');
+            device.starts_with("/dev/").then(|| MountDevice {
+                device: device.trim_start_matches("/dev/").to_string(),
+                label: String::new(),
+                fstype: fstype.to_string(),
+                size: 0,
+                removable: false,
+                mount_point: Some(PathBuf::from(mount_point)),
+            })
+        })
+        .collect()
+}
+
+/// Run `tool args...`, turning a missing binary and a non-zero exit into
+/// readable errors. Authorization failures say so plainly.
+fn run(tool: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(tool)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => format!("{tool} is not installed"),
+            _ => format!("{tool}: {e}"),
+        })?;
+    if output.status.success() {
+        return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+    }
+    Err(tool_error(tool, &output))
+}
+
+fn tool_error(tool: &str, output: &Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let msg = stdout
+        .lines()
+        .chain(stderr.lines())
+        .map(str::trim)
+        .rfind(|l| !l.is_empty())
+        .unwrap_or("")
+        .to_string();
+    let lower = msg.to_lowercase();
+    if [
+        "not authorized",
+        "permission denied",
+        "not permitted",
+        "must be root",
+    ]
+    .iter()
+    .any(|p| lower.contains(p))
+    {
+        format!("Permission denied \u{2014} {tool} needs elevated rights here ({msg})")
+    } else if msg.is_empty() {
+        format!("{tool} failed ({})", output.status)
+    } else {
+        format!("{tool}: {msg}")
+    }
+}
+
+/// Everything mountable plus what is already mounted, removable media first.
+pub fn list_devices() -> Result<Vec<MountDevice>, String> {
+    let mut devices = if cfg!(target_os = "macos") {
+        let list = run("diskutil", &["list", "external", "physical"])?;
+        let mut external = parse_diskutil_list(&list);
+        let mounted = parse_bsd_mount(&run("mount", &[])?);
+        for dev in &mut external {
+            dev.mount_point = mounted
+                .iter()
+                .find(|m| m.device == dev.device)
+                .and_then(|m| m.mount_point.clone());
+        }
+        let known: Vec<String> = external.iter().map(|d| d.device.clone()).collect();
+        external.extend(mounted.into_iter().filter(|m| !known.contains(&m.device)));
+        external
+    } else {
+        let columns = "NAME,SIZE,RM,TYPE,FSTYPE,MOUNTPOINT,LABEL";
+        match run("lsblk", &["-P", "-p", "-b", "-o", columns]) {
+            Ok(text) => parse_lsblk(&text),
+            Err(_) => std::fs::read_to_string("/proc/mounts")
+                .map(|text| parse_proc_mounts(&text))
+                .map_err(|e| format!("/proc/mounts: {e}"))?,
+        }
+    };
+    devices.sort_by(|a, b| b.removable.cmp(&a.removable).then(a.device.cmp(&b.device)));
+    Ok(devices)
+}
+
+/// Mount `device` and return where it landed.
+pub fn mount(device: &str) -> Result<PathBuf, String> {
+    if cfg!(target_os = "macos") {
+        run("diskutil", &["mount", device])?;
+    } else {
+        // No polkit prompt: it would fight the TUI for the terminal.
+        let out = run(
+            "udisksctl",
+            &["mount", "-b", device, "--no-user-interaction"],
+        )?;
+        if let Some(path) = parse_udisks_mounted(&out) {
+            return Ok(path);
+        }
+    }
+    list_devices()?
+        .into_iter()
+        .find(|d| d.device == device)
+        .and_then(|d| d.mount_point)
+        .ok_or_else(|| format!("{device} mounted, but its mount point wasn't found"))
+}
+
+/// Path from udisksctl's `Mounted /dev/sdb1 at /media/u/USB` (older versions
+/// add a trailing period).
+fn parse_udisks_mounted(out: &str) -> Option<PathBuf> {
+    let (_, path) = out.trim().split_once(" at ")?;
+    Some(PathBuf::from(path.strip_suffix('.').unwrap_or(path)))
+}
+
+/// Unmount `device`; on Linux a removable drive is also powered off so it
+/// can be pulled safely. Returns whether it was powered off.
+pub fn unmount(device: &MountDevice) -> Result<bool, String> {
+    let dev = device.device.as_str();
+    if cfg!(target_os = "macos") {
+        run("diskutil", &["unmount", dev])?;
+        return Ok(false);
+    }
+    run(
+        "udisksctl",
+        &["unmount", "-b", dev, "--no-user-interaction"],
+    )?;
+    if !device.removable {
+        return Ok(false);
+    }
+    // Unmounted is already safe for data; a failed power-off only means the
+    // drive stays visible.
+    Ok(run(
+        "udisksctl",
+        &["power-off", "-b", dev, "--no-user-interaction"],
+    )
+    .is_ok())
+}
+
+/// A device name without `/dev/`, so `sdb1` matches `/dev/sdb1` on Linux and
+/// `/dev/disk4s1` matches `disk4s1` on macOS.
+fn bare_device(name: &str) -> &str {
+    name.strip_prefix("/dev/").unwrap_or(name)
+}
+
+/// The mounted device `target` (an `:unmount` argument, `resolved` to a path)
+/// names: a mount point first, then a device name with or without `/dev/`,
+/// then for an argument that is clearly a path, the device holding it.
+pub fn find_mounted<'a>(
+    devices: &'a [MountDevice],
+    target: &str,
+    resolved: &Path,
+) -> Option<&'a MountDevice> {
+    let mut mounted = devices.iter().filter(|d| d.mount_point.is_some());
+    if let Some(d) = mounted
+        .clone()
+        .find(|d| d.mount_point.as_deref() == Some(resolved))
+    {
+        return Some(d);
+    }
+    if let Some(d) = mounted.find(|d| bare_device(&d.device) == bare_device(target)) {
+        return Some(d);
+    }
+    let is_path = target.contains('/') && !target.starts_with("/dev/");
+    is_path
+        .then(|| device_containing(devices, resolved))
+        .flatten()
+}
+
+/// The mounted device whose mount point most closely contains `path`.
+pub fn device_containing<'a>(devices: &'a [MountDevice], path: &Path) -> Option<&'a MountDevice> {
+    devices
+        .iter()
+        .filter(|d| {
+            d.mount_point
+                .as_deref()
+                .is_some_and(|m| path.starts_with(m))
+        })
+        .max_by_key(|d| d.mount_point.as_ref().map_or(0, |m| m.as_os_str().len()))
+}
+
+/// The device a bare `:unmount` may take: the one holding `path`, but only if
+/// it is removable media, so the root or home filesystem is never picked.
+pub fn removable_containing<'a>(
+    devices: &'a [MountDevice],
+    path: &Path,
+) -> Option<&'a MountDevice> {
+    device_containing(devices, path)
+        .filter(|d| d.removable && d.mount_point.as_deref() != Some(Path::new("/")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proc_mounts_keeps_devices_and_decodes_spaces() {
+        let text = "proc /proc proc rw 0 0\n\
+                    /dev/sdb1 /media/u/My\\040Stick vfat rw 0 0\n\
+                    tmpfs /tmp tmpfs rw 0 0\n";
+        let devices = parse_proc_mounts(text);
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].device, "/dev/sdb1");
+        assert_eq!(devices[0].fstype, "vfat");
+        assert_eq!(
+            devices[0].mount_point.as_deref(),
+            Some(Path::new("/media/u/My Stick"))
+        );
+    }
+
+    #[test]
+    fn lsblk_pairs_skip_disks_without_a_filesystem() {
+        let text = "NAME=\"/dev/sdb\" SIZE=\"16000000000\" RM=\"1\" TYPE=\"disk\" FSTYPE=\"\" \
+                    MOUNTPOINT=\"\" LABEL=\"\"\n\
+                    NAME=\"/dev/sdb1\" SIZE=\"15999000000\" RM=\"1\" TYPE=\"part\" \
+                    FSTYPE=\"exfat\" MOUNTPOINT=\"\" LABEL=\"MY\\x20USB\"\n\
+                    NAME=\"/dev/sda2\" SIZE=\"8000\" RM=\"0\" TYPE=\"part\" FSTYPE=\"swap\" \
+                    MOUNTPOINT=\"[SWAP]\" LABEL=\"\"\n\
+                    NAME=\"/dev/sda1\" SIZE=\"500000\" RM=\"0\" TYPE=\"part\" FSTYPE=\"ext4\" \
+                    MOUNTPOINT=\"/\" LABEL=\"\"\n";
+        let devices = parse_lsblk(text);
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].label, "MY USB");
+        assert!(devices[0].removable);
+        assert_eq!(devices[0].mount_point, None);
+        assert_eq!(devices[0].size, 15_999_000_000);
+        assert_eq!(devices[1].mount_point.as_deref(), Some(Path::new("/")));
+    }
+
+    #[test]
+    fn diskutil_and_mount_listings() {
+        let list = "/dev/disk4 (external, physical):\n\
+                    \x20  #:                       TYPE NAME          SIZE       IDENTIFIER\n\
+                    \x20  0:     FDisk_partition_scheme              *15.5 GB    disk4\n\
+                    \x20  1:             DOS_FAT_32 MY USB            15.5 GB    disk4s1\n";
+        let devices = parse_diskutil_list(list);
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].device, "disk4s1");
+        assert_eq!(devices[0].label, "MY USB");
+        assert_eq!(devices[0].size, 15_500_000_000);
+
+        let mounts = parse_bsd_mount("/dev/disk4s1 on /Volumes/MY USB (msdos, local, nodev)\n");
+        assert_eq!(mounts[0].device, "disk4s1");
+        assert_eq!(mounts[0].fstype, "msdos");
+        assert_eq!(
+            mounts[0].mount_point.as_deref(),
+            Some(Path::new("/Volumes/MY USB"))
+        );
+    }
+
+    #[test]
+    fn udisks_output_and_containing_device() {
+        assert_eq!(
+            parse_udisks_mounted("Mounted /dev/sdb1 at /media/u/USB.\n"),
+            Some(PathBuf::from("/media/u/USB"))
+        );
+        let text = "/dev/sda1 / ext4 rw 0 0\n/dev/sdb1 /media/u/USB vfat rw 0 0\n";
+        let devices = parse_proc_mounts(text);
+        let found = device_containing(&devices, Path::new("/media/u/USB/photos")).unwrap();
+        assert_eq!(found.device, "/dev/sdb1");
+        let root = device_containing(&devices, Path::new("/home/u")).unwrap();
+        assert_eq!(root.device, "/dev/sda1");
+    }
+
+    #[test]
+    fn find_mounted_tries_mount_points_then_device_names() {
+        let device = |dev: &str, at: Option<&str>| MountDevice {
+            device: dev.into(),
+            label: String::new(),
+            fstype: "ext4".into(),
+            size: 0,
+            removable: true,
+            mount_point: at.map(PathBuf::from),
+        };
+        let devices = [
+            device("/dev/sda1", Some("/")),
+            device("/dev/sdb1", Some("/media/usb")),
+            device("disk4s1", Some("/Volumes/Stick")),
+            device("/dev/sdc1", None),
+        ];
+        let find = |target: &str, resolved: &str| {
+            find_mounted(&devices, target, Path::new(resolved)).map(|d| d.device.as_str())
+        };
+        assert_eq!(find("sdb1", "/home/u/sdb1"), Some("/dev/sdb1"));
+        assert_eq!(find("/dev/sdb1", "/dev/sdb1"), Some("/dev/sdb1"));
+        assert_eq!(find("/dev/disk4s1", "/dev/disk4s1"), Some("disk4s1"));
+        // A mount point, by name from its parent or by path.
+        assert_eq!(find("usb", "/media/usb"), Some("/dev/sdb1"));
+        assert_eq!(
+            find("/media/usb/docs", "/media/usb/docs"),
+            Some("/dev/sdb1")
+        );
+        // A bare name that is neither never falls back to the root device.
+        assert_eq!(find("stick", "/home/u/stick"), None);
+        assert_eq!(find("sdc1", "/home/u/sdc1"), None);
+    }
+
+    #[test]
+    fn bare_unmount_only_takes_removable_media() {
+        let device = |dev: &str, at: &str, removable| MountDevice {
+            device: dev.into(),
+            label: String::new(),
+            fstype: "ext4".into(),
+            size: 0,
+            removable,
+            mount_point: Some(PathBuf::from(at)),
+        };
+        let devices = [
+            device("/dev/sda1", "/", false),
+            device("/dev/sda2", "/home", false),
+            device("/dev/sdb1", "/media/u/USB", true),
+        ];
+        let found = removable_containing(&devices, Path::new("/media/u/USB/photos")).unwrap();
+        assert_eq!(found.device, "/dev/sdb1");
+        assert!(removable_containing(&devices, Path::new("/home/u")).is_none());
+        assert!(removable_containing(&devices, Path::new("/etc")).is_none());
+    }
+}
@@ -5,6 +5,8 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Instant, SystemTime};
 
+use crate::fs::copy_name::NameTemplate;
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum RegisterOp {
     Yank,
@@ -29,10 +31,35 @@ pub enum OpRecord {
     Moved { src: PathBuf, dst: PathBuf },
     Created { path: PathBuf },
     Renamed { from: PathBuf, to: PathBuf },
+    /// Permissions changed; `mode` is the one to restore.
+    Chmod {
+        path: PathBuf,
+        mode: u32,
+    },
+    /// Owner changed; `uid` / `gid` are the ones to restore.
+    Chown {
+        path: PathBuf,
+        uid: u32,
+        gid: u32,
+    },
+    /// Access and modification times set by `:touch!`; the old ones to restore.
+    Touched {
+        path: PathBuf,
+        old_atime: filetime::FileTime,
+        old_mtime: filetime::FileTime,
+    },
     /// An item moved to the system trash; undo restores it to its origin.
     Trashed(crate::fs::trash::TrashedItem),
 }
 
+/// What an operation over several paths did: one undo record per changed
+/// path, and the paths that failed with why.
+#[derive(Default)]
+pub struct BatchOutcome {
+    pub records: Vec<OpRecord>,
+    pub failures: Vec<(PathBuf, String)>,
+}
+
 const MAX_UNDO: usize = 50;
 
 pub struct UndoStack {
@@ -124,6 +151,8 @@ pub struct ConflictInfo {
     pub src_modified: Option<SystemTime>,
     pub dst_modified: Option<SystemTime>,
     pub is_dir: bool,
+    /// Whether the dialog offers `Rename` (keep both).
+    pub can_rename: bool,
     pub response_tx: tokio::sync::oneshot::Sender<ConflictChoice>,
 }
 
@@ -134,6 +163,8 @@ pub enum ConflictChoice {
     OverwriteAll,
     SkipAll,
     OverwriteNewer,
+    /// Keep both: paste under the next free `copy_name` name.
+    Rename,
     Abort,
 }
 
@@ -143,9 +174,18 @@ pub enum ConflictChoice {
 pub(crate) struct ConflictPolicy {
     overwrite_all: bool,
     skip_all: bool,
+    /// Naming for `Rename`.
+    copy_name: NameTemplate,
 }
 
 impl ConflictPolicy {
+    fn with_copy_name(copy_name: NameTemplate) -> Self {
+        ConflictPolicy {
+            copy_name,
+            ..Self::default()
+        }
+    }
+
     /// If a sticky choice is already in effect, return it (`true` = overwrite,
     /// `false` = skip) without prompting. `None` means the caller must ask.
     pub(crate) fn preempt(&self) -> Option<bool> {
@@ -182,6 +222,8 @@ impl ConflictPolicy {
                 (Some(s), Some(d)) => s > d,
                 _ => true, // if we can't determine, overwrite
             }),
+            // Resolved by the caller, which knows where the copy goes.
+            ConflictChoice::Rename => Ok(false),
             ConflictChoice::Abort => Err(abort_error()),
         }
     }
@@ -215,6 +257,8 @@ struct ProgressCtx {
     item_index: usize,
     item_total: usize,
     last_report: Option<Instant>,
+    /// Mirror ownership, mode bits and symlink times (`preserve_attributes`).
+    preserve: bool,
 }
 
 /// Minimum interval between progress messages. Progress is purely cosmetic
@@ -279,6 +323,18 @@ fn is_self_or_descendant(src: &Path, dst: &Path) -> bool {
     dst_canon == src_canon || dst_canon.starts_with(&src_canon)
 }
 
+/// The first of `paths` that is `dst_dir` itself or one of its ancestors:
+/// pasting it there would copy a directory into its own subtree. Checked
+/// before a paste starts, so a bad source fails the whole paste instead of
+/// surfacing after the items ahead of it were already copied.
+pub fn source_containing<'a>(paths: &'a [PathBuf], dst_dir: &Path) -> Option<&'a Path> {
+    let dst_canon = dst_dir.canonicalize().ok()?;
+    paths
+        .iter()
+        .find(|src| src.canonicalize().is_ok_and(|s| dst_canon.starts_with(s)))
+        .map(PathBuf::as_path)
+}
+
 /// Ask the UI for a conflict resolution. Returns the user's choice.
 /// If the channel is closed (e.g. app quit), returns Abort.
 fn ask_conflict(
@@ -304,6 +360,7 @@ fn ask_conflict(
         src_modified: src_meta.as_ref().and_then(|m| m.modified().ok()),
         dst_modified: dst_meta.as_ref().and_then(|m| m.modified().ok()),
         is_dir,
+        can_rename: true,
         response_tx,
     };
     if conflict_tx.blocking_send(info).is_err() {
@@ -312,25 +369,32 @@ fn ask_conflict(
     response_rx.blocking_recv().unwrap_or(ConflictChoice::Abort)
 }
 
-/// Resolve a conflict for a single file/symlink, returning true if we should proceed (overwrite).
+/// Resolve a conflict for a single file/symlink: `Some(dst)` to overwrite it,
+/// another path to keep both under a numbered name, `None` to skip.
 fn resolve_file_conflict(
     src: &Path,
     dst: &Path,
     is_dir: bool,
     conflict_tx: &tokio::sync::mpsc::Sender<ConflictInfo>,
     policy: &mut ConflictPolicy,
-) -> Result<bool, std::io::Error> {
+) -> Result<Option<PathBuf>, std::io::Error> {
     if let Some(proceed) = policy.preempt() {
-        return Ok(proceed);
+        return Ok(proceed.then(|| dst.to_path_buf()));
     }
     let choice = ask_conflict(conflict_tx, src, dst, is_dir);
+    if choice == ConflictChoice::Rename {
+        let dir = dst.parent().unwrap_or(Path::new("."));
+        return Ok(Some(policy.copy_name.free_path(dir, &filename(dst)?)));
+    }
     let src_mod = fs::symlink_metadata(src)
         .ok()
         .and_then(|m| m.modified().ok());
     let dst_mod = fs::symlink_metadata(dst)
         .ok()
         .and_then(|m| m.modified().ok());
-    policy.decide(choice, src_mod, dst_mod)
+    Ok(policy
+        .decide(choice, src_mod, dst_mod)?
+        .then(|| dst.to_path_buf()))
 }
 
 fn copy_dir_progress(
@@ -348,17 +412,25 @@ fn copy_dir_progress(
     }
     for entry in fs::read_dir(src)? {
         let entry = entry?;
-        let target = dst.join(entry.file_name());
+        let mut target = dst.join(entry.file_name());
         let ft = entry.file_type()?;
         if ft.is_symlink() {
             if target.exists() || target.symlink_metadata().is_ok() {
-                if !resolve_file_conflict(&entry.path(), &target, false, conflict_tx, policy)? {
+                let Some(to) =
+                    resolve_file_conflict(&entry.path(), &target, false, conflict_tx, policy)?
+                else {
                     // Skipped: leave the source entry in place.
                     continue;
+                };
+                if to == target {
+                    remove_overwrite_target(&target)?;
                 }
-                remove_overwrite_target(&target)?;
+                target = to;
             }
             copy_symlink(&entry.path(), &target)?;
+            if ctx.preserve {
+                preserve_attributes(&entry.path(), &target);
+            }
             if move_src {
                 fs::remove_file(entry.path())?;
             }
@@ -386,13 +458,19 @@ fn copy_dir_progress(
                         format!("Cannot copy file over directory: {}", target.display()),
                     ));
                 }
-                if !resolve_file_conflict(&entry.path(), &target, false, conflict_tx, policy)? {
+                let Some(to) =
+                    resolve_file_conflict(&entry.path(), &target, false, conflict_tx, policy)?
+                else {
                     // Skipped: leave the source entry in place.
                     continue;
-                }
+                };
+                target = to;
             }
             fs::copy(entry.path(), &target)?;
             copy_timestamps(&entry.path(), &target);
+            if ctx.preserve {
+                preserve_attributes(&entry.path(), &target);
+            }
             ctx.bytes_done += size;
             ctx.report();
             if move_src {
@@ -402,6 +480,9 @@ fn copy_dir_progress(
     }
     // Preserve directory timestamps (after all contents are copied)
     copy_timestamps(src, dst);
+    if ctx.preserve {
+        preserve_attributes(src, dst);
+    }
     // In move mode remove the now-empty source directory. If any entry was
     // skipped due to a conflict the directory is not empty and remove_dir
     // fails with NotEmpty, which we deliberately ignore to preserve the
@@ -424,6 +505,9 @@ fn copy_dir_progress_simple(src: &Path, dst: &Path, ctx: &mut ProgressCtx) -> st
         let ft = entry.file_type()?;
         if ft.is_symlink() {
             copy_symlink(&entry.path(), &target)?;
+            if ctx.preserve {
+                preserve_attributes(&entry.path(), &target);
+            }
         } else if ft.is_dir() {
             copy_dir_progress_simple(&entry.path(), &target, ctx)?;
         } else {
@@ -446,7 +530,7 @@ fn copy_path_progress(
     policy: &mut ConflictPolicy,
 ) -> std::io::Result<Option<OpRecord>> {
     let name = filename(src)?;
-    let dst = dst_dir.join(&name);
+    let mut dst = dst_dir.join(&name);
     let meta = fs::symlink_metadata(src)?;
 
     if is_self_or_descendant(src, &dst) {
@@ -458,12 +542,18 @@ fn copy_path_progress(
 
     if meta.is_symlink() {
         if dst.exists() || dst.symlink_metadata().is_ok() {
-            if !resolve_file_conflict(src, &dst, false, conflict_tx, policy)? {
+            let Some(to) = resolve_file_conflict(src, &dst, false, conflict_tx, policy)? else {
                 return Ok(None);
+            };
+            if to == dst {
+                remove_overwrite_target(&dst)?;
             }
-            remove_overwrite_target(&dst)?;
+            dst = to;
         }
         copy_symlink(src, &dst)?;
+        if ctx.preserve {
+            preserve_attributes(src, &dst);
+        }
         ctx.report();
     } else if meta.is_dir() {
         if dst.exists() {
@@ -489,13 +579,17 @@ fn copy_path_progress(
                     format!("Cannot copy file over directory: {}", dst.display()),
                 ));
             }
-            if !resolve_file_conflict(src, &dst, false, conflict_tx, policy)? {
+            let Some(to) = resolve_file_conflict(src, &dst, false, conflict_tx, policy)? else {
                 return Ok(None);
-            }
+            };
+            dst = to;
         }
         let size = meta.len();
         fs::copy(src, &dst)?;
         copy_timestamps(src, &dst);
+        if ctx.preserve {
+            preserve_attributes(src, &dst);
+        }
         ctx.bytes_done += size;
         ctx.report();
     }
@@ -513,7 +607,7 @@ fn move_path_progress(
     policy: &mut ConflictPolicy,
 ) -> std::io::Result<Option<OpRecord>> {
     let name = filename(src)?;
-    let dst = dst_dir.join(&name);
+    let mut dst = dst_dir.join(&name);
     let meta = fs::symlink_metadata(src)?;
     let src_size = if meta.is_symlink() {
         meta.len()
@@ -551,11 +645,14 @@ fn move_path_progress(
             ));
         }
         // File -> File conflict
-        if !resolve_file_conflict(src, &dst, false, conflict_tx, policy)? {
+        let Some(to) = resolve_file_conflict(src, &dst, false, conflict_tx, policy)? else {
             return Ok(None);
+        };
+        if to == dst {
+            // Remove existing before rename/copy
+            remove_overwrite_target(&dst)?;
         }
-        // Remove existing before rename/copy
-        remove_overwrite_target(&dst)?;
+        dst = to;
     }
 
     match fs::rename(src, &dst) {
@@ -566,12 +663,19 @@ fn move_path_progress(
         Err(ref e) if is_cross_device(e) => {
             if meta.is_symlink() {
                 copy_symlink(src, &dst)?;
+                if ctx.preserve {
+                    preserve_attributes(src, &dst);
+                }
                 fs::remove_file(src)?;
             } else if meta.is_dir() {
                 ctx.report();
                 copy_dir_progress(src, &dst, ctx, conflict_tx, policy, true)?;
             } else {
                 fs::copy(src, &dst)?;
+                copy_timestamps(src, &dst);
+                if ctx.preserve {
+                    preserve_attributes(src, &dst);
+                }
                 ctx.bytes_done += src_size;
                 ctx.report();
                 fs::remove_file(src)?;
@@ -585,6 +689,15 @@ fn move_path_progress(
     }))
 }
 
+/// Settings a paste takes from the config.
+#[derive(Clone, Debug, Default)]
+pub struct PasteOptions {
+    /// Mirror ownership, mode bits and timestamps (`preserve_attributes`).
+    pub preserve: bool,
+    /// Name for a copy kept next to an existing file (`copy_name`).
+    pub copy_name: NameTemplate,
+}
+
 pub fn paste_in_background(
     paths: Vec<PathBuf>,
     dst_dir: PathBuf,
@@ -592,6 +705,7 @@ pub fn paste_in_background(
     tx: tokio::sync::mpsc::Sender<ProgressMsg>,
     conflict_tx: tokio::sync::mpsc::Sender<ConflictInfo>,
     cancel: Arc<AtomicBool>,
+    options: PasteOptions,
 ) {
     tokio::task::spawn_blocking(move || {
         let bytes_total: u64 = paths.iter().map(|p| path_size(p)).sum();
@@ -604,11 +718,26 @@ pub fn paste_in_background(
             item_index: 0,
             item_total,
             last_report: None,
+            preserve: options.preserve,
         };
-        let mut policy = ConflictPolicy::default();
+        let mut policy = ConflictPolicy::with_copy_name(options.copy_name);
 
         ctx.report();
 
+        if let Some(src) = source_containing(&paths, &dst_dir) {
+            let verb = match op {
+                RegisterOp::Yank => "copy",
+                RegisterOp::Cut => "move",
+            };
+            let _ = tx.blocking_send(ProgressMsg::Finished {
+                records: Vec::new(),
+                error: Some(format!("Cannot {verb} {} into itself", src.display())),
+                bytes_total,
+                cancelled: false,
+            });
+            return;
+        }
+
         let mut records = Vec::new();
         for (i, src) in paths.iter().enumerate() {
             // Cancellation is checked between items: an in-flight single file is not
@@ -749,6 +878,49 @@ pub fn touch(dir: &Path, name: &str) -> std::io::Result<OpRecord> {
     Ok(OpRecord::Created { path })
 }
 
+/// Set the access and modification times of existing `paths` to `time`,
+/// keeping the old ones for undo. Unlike [`touch`], nothing is created.
+pub fn retouch(paths: &[PathBuf], time: filetime::FileTime) -> BatchOutcome {
+    let mut out = BatchOutcome::default();
+    for path in paths {
+        let result = fs::metadata(path).and_then(|meta| {
+            filetime::set_file_times(path, time, time)?;
+            Ok(OpRecord::Touched {
+                path: path.clone(),
+                old_atime: filetime::FileTime::from_last_access_time(&meta),
+                old_mtime: filetime::FileTime::from_last_modification_time(&meta),
+            })
+        });
+        match result {
+            Ok(rec) => out.records.push(rec),
+            Err(e) => out.failures.push((path.clone(), e.to_string())),
+        }
+    }
+    out
+}
+
+/// The time `:touch! <when>` asks for, in local time: `YYYY-MM-DD`,
+/// `YYYY-MM-DD HH:MM[:SS]`, or `@<unix seconds>`.
+pub fn parse_touch_time(when: &str) -> Option<filetime::FileTime> {
+    use chrono::{Local, NaiveDate, NaiveDateTime, TimeZone};
+    let when = when.trim();
+    if let Some(secs) = when.strip_prefix('@') {
+        return secs
+            .parse()
+            .ok()
+            .map(|s| filetime::FileTime::from_unix_time(s, 0));
+    }
+    let naive = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(when, f).ok())
+        .or_else(|| {
+            let date = NaiveDate::parse_from_str(when, "%Y-%m-%d").ok()?;
+            date.and_hms_opt(0, 0, 0)
+        })?;
+    let local = Local.from_local_datetime(&naive).earliest()?;
+    Some(filetime::FileTime::from_unix_time(local.timestamp(), 0))
+}
+
 pub fn rename_path(path: &Path, new_name: &str) -> std::io::Result<OpRecord> {
     validate_name(new_name)?;
     let parent = path
@@ -768,6 +940,147 @@ pub fn rename_path(path: &Path, new_name: &str) -> std::io::Result<OpRecord> {
     })
 }
 
+// --- Dry run ---
+
+/// What `paste_in_background` would do with one item, as decided up front by
+/// [`paste_steps`].
+pub enum PasteStep {
+    /// The source has no file name (e.g. `/`).
+    NoName(PathBuf),
+    /// The destination is the source itself or inside it.
+    IntoItself(PathBuf),
+    /// A plain copy or move to a free name.
+    Fresh { src: PathBuf, dst: PathBuf },
+    /// A directory onto an existing directory.
+    Merge { src: PathBuf, dst: PathBuf },
+    /// A file onto a directory or the other way round.
+    TypeMismatch(PathBuf),
+    /// A file onto an existing file (would prompt at run time).
+    Conflict { src: PathBuf, dst: PathBuf },
+}
+
+impl PasteStep {
+    /// Whether the destination name already exists.
+    pub fn name_taken(&self) -> bool {
+        matches!(
+            self,
+            PasteStep::Merge { .. } | PasteStep::TypeMismatch(_) | PasteStep::Conflict { .. }
+        )
+    }
+
+    /// The step as a plan line, e.g. `copy /a/x → /b/x`.
+    pub fn describe(&self, op: RegisterOp) -> String {
+        let verb = match op {
+            RegisterOp::Yank => "copy",
+            RegisterOp::Cut => "move",
+        };
+        match self {
+            PasteStep::NoName(src) => format!("skip {}: no filename", src.display()),
+            PasteStep::IntoItself(src) => {
+                format!("error: cannot {verb} {} into itself", src.display())
+            }
+            PasteStep::Fresh { src, dst } => {
+                format!("{verb} {} \u{2192} {}", src.display(), dst.display())
+            }
+            PasteStep::Merge { src, dst } => {
+                format!("merge {} \u{2192} {}", src.display(), dst.display())
+            }
+            PasteStep::TypeMismatch(dst) => format!("error: type mismatch at {}", dst.display()),
+            PasteStep::Conflict { src, dst } => format!(
+                "conflict: {verb} {} \u{2192} {} (would ask: overwrite/skip)",
+                src.display(),
+                dst.display()
+            ),
+        }
+    }
+}
+
+/// Plan a paste of `paths` into `dst_dir`, one step per item, without touching
+/// the filesystem. Mirrors `paste_in_background`'s top-level decisions:
+/// self-copy rejection, directory merges, type mismatches and file conflicts.
+pub fn paste_steps(paths: &[PathBuf], dst_dir: &Path) -> Vec<PasteStep> {
+    paths
+        .iter()
+        .map(|src| {
+            let Ok(name) = filename(src) else {
+                return PasteStep::NoName(src.clone());
+            };
+            let dst = dst_dir.join(&name);
+            if is_self_or_descendant(src, &dst) {
+                return PasteStep::IntoItself(src.clone());
+            }
+            let src_dir = fs::symlink_metadata(src).is_ok_and(|m| m.is_dir());
+            let src = src.clone();
+            match fs::symlink_metadata(&dst) {
+                Err(_) => PasteStep::Fresh { src, dst },
+                Ok(m) if src_dir && m.is_dir() => PasteStep::Merge { src, dst },
+                Ok(m) if src_dir != m.is_dir() => PasteStep::TypeMismatch(dst),
+                Ok(_) => PasteStep::Conflict { src, dst },
+            }
+        })
+        .collect()
+}
+
+/// Describe a delete (trash or permanent) of `paths` without removing anything.
+pub fn plan_delete(paths: &[PathBuf], permanent: bool) -> Vec<String> {
+    let verb = if permanent { "delete" } else { "trash" };
+    paths
+        .iter()
+        .map(|p| match fs::symlink_metadata(p) {
+            Ok(m) if m.is_dir() => {
+                let n = fs::read_dir(p).map(|rd| rd.count()).unwrap_or(0);
+                format!("{verb} {}/ ({n} entries)", p.display())
+            }
+            Ok(_) => format!("{verb} {}", p.display()),
+            Err(e) => format!("error: {}: {e}", p.display()),
+        })
+        .collect()
+}
+
+/// Describe `rename_path(path, new_name)`, including the errors it would hit.
+pub fn plan_rename(path: &Path, new_name: &str) -> String {
+    if let Err(e) = validate_name(new_name) {
+        return format!("error: {e}");
+    }
+    let new = path.parent().unwrap_or(Path::new("")).join(new_name);
+    if new.exists() {
+        return format!("error: {} already exists", new.display());
+    }
+    format!("rename {} \u{2192} {}", path.display(), new.display())
+}
+
+/// Describe a bulk rename. A target that another entry of the batch is renamed
+/// away from is free by then (swaps go through temporary names).
+pub fn plan_bulk_rename(renames: &[(PathBuf, String)]) -> Vec<String> {
+    let sources: Vec<&std::ffi::OsStr> =
+        renames.iter().filter_map(|(p, _)| p.file_name()).collect();
+    renames
+        .iter()
+        .map(|(path, new_name)| {
+            if sources.contains(&std::ffi::OsStr::new(new_name)) {
+                let new = path.with_file_name(new_name);
+                format!("rename {} \u{2192} {}", path.display(), new.display())
+            } else {
+                plan_rename(path, new_name)
+            }
+        })
+        .collect()
+}
+
+/// Describe `mkdir` (`is_dir`) or `touch` of `name` in `dir`.
+pub fn plan_create(dir: &Path, name: &str, is_dir: bool) -> String {
+    if let Err(e) = validate_name(name) {
+        return format!("error: {e}");
+    }
+    let path = dir.join(name);
+    match (is_dir, path.exists()) {
+        (true, true) if path.is_dir() => format!("mkdir {} (already exists)", path.display()),
+        (_, true) => format!("error: {} already exists", path.display()),
+        (true, false) => format!("mkdir {}", path.display()),
+        (false, false) => format!("touch {}", path.display()),
+    }
+}
+
 // --- Undo ---
 
 pub fn undo(records: &[OpRecord]) -> std::io::Result<String> {
@@ -792,6 +1105,7 @@ pub fn undo(records: &[OpRecord]) -> std::io::Result<String> {
                                 item_index: 0,
                                 item_total: 1,
                                 last_report: None,
+                                preserve: false,
                             };
                             copy_dir_progress_simple(dst, src, &mut ctx)?;
                             fs::remove_dir_all(dst)?;
@@ -808,6 +1122,15 @@ pub fn undo(records: &[OpRecord]) -> std::io::Result<String> {
             OpRecord::Renamed { from, to } => {
                 fs::rename(to, from)?;
             }
+            OpRecord::Chmod { path, mode } => crate::fs::perms::chmod(path, *mode)?,
+            OpRecord::Touched {
+                path,
+                old_atime,
+                old_mtime,
+            } => filetime::set_file_times(path, *old_atime, *old_mtime)?,
+            OpRecord::Chown { path, uid, gid } => {
+                crate::fs::perms::chown(path, Some(*uid), Some(*gid))?;
+            }
             OpRecord::Trashed(item) => crate::fs::trash::restore(item)?,
         }
     }
@@ -824,6 +1147,29 @@ fn copy_timestamps(src: &Path, dst: &Path) {
     }
 }
 
+/// Best-effort `cp -a` style metadata copy for the `preserve_attributes`
+/// option: owner/group (only succeeds as root or for our own files), the full
+/// mode including setuid/setgid/sticky — re-applied after `chown`, which clears
+/// those bits — and, for symlinks, the link's own timestamps. Regular file and
+/// directory timestamps are already handled by `copy_timestamps`.
+fn preserve_attributes(src: &Path, dst: &Path) {
+    let Ok(meta) = fs::symlink_metadata(src) else {
+        return;
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        let _ = std::os::unix::fs::lchown(dst, Some(meta.uid()), Some(meta.gid()));
+    }
+    if meta.is_symlink() {
+        let mtime = filetime::FileTime::from_last_modification_time(&meta);
+        let atime = filetime::FileTime::from_last_access_time(&meta);
+        let _ = filetime::set_symlink_file_times(dst, atime, mtime);
+    } else {
+        let _ = fs::set_permissions(dst, meta.permissions());
+    }
+}
+
 fn copy_symlink(src: &Path, dst: &Path) -> std::io::Result<()> {
     let link_target = fs::read_link(src)?;
     #[cfg(unix)]
@@ -849,41 +1195,6 @@ fn filename(p: &Path) -> std::io::Result<String> {
         .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "no filename"))
 }
 
-#[cfg(test)]
-fn auto_rename(dir: &Path, name: &str) -> PathBuf {
-    let candidate = dir.join(name);
-    if !candidate.exists() {
-        return candidate;
-    }
-    let stem = Path::new(name)
-        .file_stem()
-        .map(|s| s.to_string_lossy().into_owned())
-        .unwrap_or_else(|| name.into());
-    let ext = Path::new(name)
-        .extension()
-        .map(|e| format!(".{}", e.to_string_lossy()));
-    for i in 1u32..=99999 {
-        let new_name = match &ext {
-            Some(e) => format!("{stem}_{i}{e}"),
-            None => format!("{stem}_{i}"),
-        };
-        let p = dir.join(&new_name);
-        if !p.exists() {
-            return p;
-        }
-    }
-    // Fallback with timestamp
-    let ts = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_millis())
-        .unwrap_or(0);
-    let fallback = match &ext {
-        Some(e) => format!("{stem}_{ts}{e}"),
-        None => format!("{stem}_{ts}"),
-    };
-    dir.join(fallback)
-}
-
 #[cfg(test)]
 fn copy_dir(src: &Path, dst: &Path) -> std::io::Result<()> {
     fs::create_dir_all(dst)?;
@@ -921,6 +1232,22 @@ fn remove_overwrite_target(path: &Path) -> std::io::Result<()> {
     }
 }
 
+/// Whether moving `src` into `dst_dir` leaves its filesystem, turning the
+/// rename into a copy plus delete. Paths that can't be read count as not.
+#[cfg(unix)]
+pub fn crosses_device(src: &Path, dst_dir: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    match (fs::symlink_metadata(src), fs::metadata(dst_dir)) {
+        (Ok(s), Ok(d)) => s.dev() != d.dev(),
+        _ => false,
+    }
+}
+
+#[cfg(not(unix))]
+pub fn crosses_device(_src: &Path, _dst_dir: &Path) -> bool {
+    false
+}
+
 fn is_cross_device(e: &std::io::Error) -> bool {
     e.kind() == std::io::ErrorKind::CrossesDevices
 }
@@ -1018,8 +1345,65 @@ mod tests {
         assert_eq!(stack.entries.len(), MAX_UNDO);
     }
 
+    // --- retouch ---
+
+    #[test]
+    fn retouch_sets_times_and_undo_restores_them() {
+        let dir = tmp_dir();
+        let f = dir.join("a.txt");
+        fs::write(&f, "x").unwrap();
+        let old = filetime::FileTime::from_unix_time(1_000_000_000, 0);
+        filetime::set_file_times(&f, old, old).unwrap();
+
+        let when = parse_touch_time("@1700000000").unwrap();
+        let out = retouch(&[f.clone(), dir.join("missing")], when);
+        assert_eq!(out.records.len(), 1);
+        assert_eq!(out.failures.len(), 1);
+        // Unlike `touch`, nothing is created.
+        assert!(!dir.join("missing").exists());
+        let mtime =
+            |p: &Path| filetime::FileTime::from_last_modification_time(&fs::metadata(p).unwrap());
+        assert_eq!(mtime(&f), when);
+
+        undo(&out.records).unwrap();
+        assert_eq!(mtime(&f), old);
+        let atime = filetime::FileTime::from_last_access_time(&fs::metadata(&f).unwrap());
+        assert_eq!(atime, old);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn parse_touch_time_formats() {
+        use chrono::{Local, TimeZone};
+        let local = |y, mo, d, h, mi| {
+            let t = Local
+                .with_ymd_and_hms(y, mo, d, h, mi, 0)
+                .earliest()
+                .unwrap();
+            filetime::FileTime::from_unix_time(t.timestamp(), 0)
+        };
+        assert_eq!(
+            parse_touch_time("2024-05-01"),
+            Some(local(2024, 5, 1, 0, 0))
+        );
+        assert_eq!(
+            parse_touch_time("2024-05-01 14:30"),
+            Some(local(2024, 5, 1, 14, 30))
+        );
+        assert_eq!(
+            parse_touch_time("@1714572600"),
+            Some(filetime::FileTime::from_unix_time(1_714_572_600, 0))
+        );
+        assert_eq!(parse_touch_time("yesterday"), None);
+        assert_eq!(parse_touch_time("2024-13-01"), None);
+    }
+
     // --- auto_rename ---
 
+    fn auto_rename(dir: &Path, name: &str) -> PathBuf {
+        NameTemplate::default().free_path(dir, name)
+    }
+
     #[test]
     fn auto_rename_no_conflict() {
         let dir = tmp_dir();
@@ -1426,6 +1810,59 @@ mod tests {
         assert!(validate_name("...hidden").is_ok());
     }
 
+    // --- dry run ---
+
+    #[test]
+    fn paste_steps_report_conflicts_and_touch_nothing() {
+        let src_dir = tmp_dir();
+        let dst_dir = tmp_dir();
+        fs::write(src_dir.join("a.txt"), "a").unwrap();
+        fs::write(src_dir.join("b.txt"), "b").unwrap();
+        fs::write(dst_dir.join("b.txt"), "old").unwrap();
+        let paths = vec![src_dir.join("a.txt"), src_dir.join("b.txt")];
+
+        let steps = paste_steps(&paths, &dst_dir);
+        assert_eq!(steps.len(), 2);
+        assert!(!steps[0].name_taken());
+        assert!(steps[1].name_taken());
+        let plan: Vec<String> = steps.iter().map(|s| s.describe(RegisterOp::Cut)).collect();
+        assert!(plan[0].starts_with("move "));
+        assert!(plan[1].starts_with("conflict: move "));
+        // Nothing moved, nothing overwritten.
+        assert!(src_dir.join("a.txt").exists());
+        assert!(!dst_dir.join("a.txt").exists());
+        assert_eq!(fs::read_to_string(dst_dir.join("b.txt")).unwrap(), "old");
+        let _ = fs::remove_dir_all(&src_dir);
+        let _ = fs::remove_dir_all(&dst_dir);
+    }
+
+    #[test]
+    fn plan_rename_and_create_report_errors() {
+        let dir = tmp_dir();
+        fs::write(dir.join("a.txt"), "").unwrap();
+        fs::write(dir.join("b.txt"), "").unwrap();
+        assert!(plan_rename(&dir.join("a.txt"), "b.txt").starts_with("error:"));
+        assert!(plan_rename(&dir.join("a.txt"), "c.txt").starts_with("rename "));
+        assert!(plan_create(&dir, "../x", true).starts_with("error:"));
+        assert!(plan_create(&dir, "a.txt", false).starts_with("error:"));
+        assert!(plan_create(&dir, "new", true).starts_with("mkdir "));
+        assert!(!dir.join("new").exists());
+
+        // A swap within the batch is fine; clobbering a bystander is not.
+        let swap = [
+            (dir.join("a.txt"), "b.txt".to_string()),
+            (dir.join("b.txt"), "a.txt".to_string()),
+        ];
+        assert!(
+            plan_bulk_rename(&swap)
+                .iter()
+                .all(|l| l.starts_with("rename "))
+        );
+        let clobber = [(dir.join("a.txt"), "b.txt".to_string())];
+        assert!(plan_bulk_rename(&clobber)[0].starts_with("error:"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     // --- symlink tests ---
 
     #[cfg(unix)]
@@ -1479,6 +1916,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         copy_dir_progress_simple(&src, &target, &mut ctx).unwrap();
 
@@ -1512,6 +1950,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         let (ctxt, _crx) = make_conflict_channel();
         let mut policy = ConflictPolicy::default();
@@ -1530,6 +1969,49 @@ mod tests {
         let _ = fs::remove_dir_all(&dst_dir);
     }
 
+    #[test]
+    fn rename_on_conflict_keeps_both_files() {
+        let dir = tmp_dir();
+        let src = dir.join("a.txt");
+        fs::write(&src, "new").unwrap();
+        let dst_dir = tmp_dir();
+        fs::write(dst_dir.join("a.txt"), "old").unwrap();
+        let (tx, _rx) = tokio::sync::mpsc::channel(64);
+        let mut ctx = ProgressCtx {
+            tx,
+            bytes_done: 0,
+            bytes_total: 3,
+            item_index: 0,
+            item_total: 1,
+            last_report: None,
+            preserve: false,
+        };
+        let (ctxt, mut crx) = make_conflict_channel();
+        let answer = std::thread::spawn(move || {
+            let info = crx.blocking_recv().unwrap();
+            assert!(info.can_rename);
+            info.response_tx.send(ConflictChoice::Rename).unwrap();
+        });
+        let copy_name = NameTemplate::parse("{stem} ({n}){ext}").unwrap();
+        let mut policy = ConflictPolicy::with_copy_name(copy_name);
+        let record = move_path_progress(&src, &dst_dir, &mut ctx, &ctxt, &mut policy)
+            .unwrap()
+            .unwrap();
+        answer.join().unwrap();
+        match record {
+            OpRecord::Moved { dst, .. } => assert_eq!(dst, dst_dir.join("a (1).txt")),
+            _ => panic!("expected Moved record"),
+        }
+        assert_eq!(fs::read_to_string(dst_dir.join("a.txt")).unwrap(), "old");
+        assert_eq!(
+            fs::read_to_string(dst_dir.join("a (1).txt")).unwrap(),
+            "new"
+        );
+        assert!(!src.exists());
+        let _ = fs::remove_dir_all(&dir);
+        let _ = fs::remove_dir_all(&dst_dir);
+    }
+
     #[test]
     fn copy_path_progress_directory() {
         let dir = tmp_dir();
@@ -1545,6 +2027,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         let (ctxt, _crx) = make_conflict_channel();
         let mut policy = ConflictPolicy::default();
@@ -1561,6 +2044,86 @@ mod tests {
         let _ = fs::remove_dir_all(&dst_dir);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn copy_path_progress_preserve_keeps_mode_and_symlink_times() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tmp_dir();
+        let src = dir.join("tool");
+        fs::write(&src, "#!/bin/sh").unwrap();
+        fs::set_permissions(&src, fs::Permissions::from_mode(0o1755)).unwrap();
+        let link = dir.join("tool.lnk");
+        std::os::unix::fs::symlink(&src, &link).unwrap();
+        let old = filetime::FileTime::from_unix_time(1_000_000_000, 0);
+        filetime::set_symlink_file_times(&link, old, old).unwrap();
+
+        let dst_dir = tmp_dir();
+        let (tx, _rx) = tokio::sync::mpsc::channel(64);
+        let mut ctx = ProgressCtx {
+            tx,
+            bytes_done: 0,
+            bytes_total: 100,
+            item_index: 0,
+            item_total: 2,
+            last_report: None,
+            preserve: true,
+        };
+        let (ctxt, _crx) = make_conflict_channel();
+        let mut policy = ConflictPolicy::default();
+        copy_path_progress(&src, &dst_dir, &mut ctx, &ctxt, &mut policy).unwrap();
+        copy_path_progress(&link, &dst_dir, &mut ctx, &ctxt, &mut policy).unwrap();
+
+        let mode = fs::metadata(dst_dir.join("tool"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o7777, 0o1755);
+        let link_meta = fs::symlink_metadata(dst_dir.join("tool.lnk")).unwrap();
+        assert_eq!(
+            filetime::FileTime::from_last_modification_time(&link_meta),
+            old
+        );
+        let _ = fs::remove_dir_all(&dir);
+        let _ = fs::remove_dir_all(&dst_dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_path_progress_preserve_keeps_nested_symlink_times() {
+        let dir = tmp_dir();
+        let tree = dir.join("tree");
+        fs::create_dir(&tree).unwrap();
+        fs::write(tree.join("f.txt"), "x").unwrap();
+        let link = tree.join("f.lnk");
+        std::os::unix::fs::symlink("f.txt", &link).unwrap();
+        let old = filetime::FileTime::from_unix_time(1_000_000_000, 0);
+        filetime::set_symlink_file_times(&link, old, old).unwrap();
+
+        let dst_dir = tmp_dir();
+        let (tx, _rx) = tokio::sync::mpsc::channel(64);
+        let mut ctx = ProgressCtx {
+            tx,
+            bytes_done: 0,
+            bytes_total: 1,
+            item_index: 0,
+            item_total: 1,
+            last_report: None,
+            preserve: true,
+        };
+        let (ctxt, _crx) = make_conflict_channel();
+        let mut policy = ConflictPolicy::default();
+        copy_path_progress(&tree, &dst_dir, &mut ctx, &ctxt, &mut policy).unwrap();
+
+        let link_meta = fs::symlink_metadata(dst_dir.join("tree/f.lnk")).unwrap();
+        assert!(link_meta.is_symlink());
+        assert_eq!(
+            filetime::FileTime::from_last_modification_time(&link_meta),
+            old
+        );
+        let _ = fs::remove_dir_all(&dir);
+        let _ = fs::remove_dir_all(&dst_dir);
+    }
+
     #[cfg(unix)]
     #[test]
     fn copy_path_progress_symlink() {
@@ -1579,6 +2142,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         let (ctxt, _crx) = make_conflict_channel();
         let mut policy = ConflictPolicy::default();
@@ -1609,6 +2173,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         let (ctxt, _crx) = make_conflict_channel();
         let mut policy = ConflictPolicy::default();
@@ -1642,6 +2207,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         let (ctxt, _crx) = make_conflict_channel();
         let mut policy = ConflictPolicy::default();
@@ -1683,11 +2249,13 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         let (ctxt, _crx) = make_conflict_channel();
         let mut policy = ConflictPolicy {
             overwrite_all: false,
             skip_all: true,
+            ..Default::default()
         };
         move_path_progress(&src, &dst_dir, &mut ctx, &ctxt, &mut policy).unwrap();
 
@@ -1770,6 +2338,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         copy_dir_progress_simple(&src, &target, &mut ctx).unwrap();
 
@@ -1858,6 +2427,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         let (ctxt, _crx) = make_conflict_channel();
         let mut policy = ConflictPolicy::default();
@@ -1872,6 +2442,44 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
     }
 
+    #[tokio::test]
+    async fn paste_into_own_subtree_copies_nothing() {
+        let dir = tmp_dir();
+        let a = dir.join("a");
+        fs::create_dir_all(a.join("b")).unwrap();
+        fs::write(dir.join("first.txt"), "x").unwrap();
+        let paths = vec![dir.join("first.txt"), a.clone()];
+        assert_eq!(source_containing(&paths, &a.join("b")), Some(a.as_path()));
+        assert_eq!(source_containing(&paths, &dir), None);
+
+        let (tx, mut rx) = tokio::sync::mpsc::channel(64);
+        let (conflict_tx, _crx) = make_conflict_channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        paste_in_background(
+            paths,
+            a.join("b"),
+            RegisterOp::Yank,
+            tx,
+            conflict_tx,
+            cancel,
+            PasteOptions::default(),
+        );
+        let error = loop {
+            match rx.recv().await.unwrap() {
+                ProgressMsg::Finished { error, records, .. } => {
+                    assert!(records.is_empty());
+                    break error;
+                }
+                _ => continue,
+            }
+        };
+        let expected = format!("Cannot copy {} into itself", a.display());
+        assert_eq!(error, Some(expected));
+        // The file ahead of the bad source was not copied either.
+        assert!(!a.join("b/first.txt").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn copy_file_onto_itself_is_rejected() {
         let dir = tmp_dir();
@@ -1886,6 +2494,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         let (ctxt, _crx) = make_conflict_channel();
         let mut policy = ConflictPolicy::default();
@@ -1916,6 +2525,7 @@ mod tests {
             item_index: 0,
             item_total: 1,
             last_report: None,
+            preserve: false,
         };
         let (ctxt, _crx) = make_conflict_channel();
         let mut policy = ConflictPolicy::default();
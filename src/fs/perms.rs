@@ -1,7 +1,99 @@
-//! Permission and ownership changes: `chmod` / `chown`.
+//! Permission and ownership changes (`chmod` / `chown`) and uid/gid → name
+//! lookups.
 
+use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+use crate::fs::ops::{BatchOutcome, OpRecord};
+
+/// Set `mode` on each path, and with `recursive` on everything below the
+/// directories among them. Symlinks inside a tree are left alone, so the walk
+/// never changes files outside it.
+pub fn chmod_all(paths: &[PathBuf], mode: u32, recursive: bool) -> BatchOutcome {
+    apply_all(paths, recursive, &mut |path| {
+        let (old, _, _) = current_perms(path)?;
+        chmod(path, mode)?;
+        Ok(OpRecord::Chmod {
+            path: path.to_path_buf(),
+            mode: old,
+        })
+    })
+}
+
+/// Change the owner and/or group of each path, recursing like [`chmod_all`].
+pub fn chown_all(
+    paths: &[PathBuf],
+    uid: Option<u32>,
+    gid: Option<u32>,
+    recursive: bool,
+) -> BatchOutcome {
+    apply_all(paths, recursive, &mut |path| {
+        let (_, old_uid, old_gid) = current_perms(path)?;
+        chown(path, uid, gid)?;
+        Ok(OpRecord::Chown {
+            path: path.to_path_buf(),
+            uid: old_uid,
+            gid: old_gid,
+        })
+    })
+}
+
+fn apply_all(
+    paths: &[PathBuf],
+    recursive: bool,
+    apply: &mut dyn FnMut(&Path) -> std::io::Result<OpRecord>,
+) -> BatchOutcome {
+    let mut out = BatchOutcome::default();
+    for path in paths {
+        apply_tree(path, recursive, apply, &mut out);
+    }
+    out
+}
+
+fn apply_tree(
+    path: &Path,
+    recursive: bool,
+    apply: &mut dyn FnMut(&Path) -> std::io::Result<OpRecord>,
+    out: &mut BatchOutcome,
+) {
+    let is_dir = fs::symlink_metadata(path).is_ok_and(|m| m.is_dir());
+    if recursive && is_dir {
+        // Children first: a mode without `x` on the directory would otherwise
+        // lock the walk out of it.
+        match fs::read_dir(path) {
+            Ok(entries) => {
+                for entry in entries.flatten() {
+                    if entry.file_type().is_ok_and(|t| !t.is_symlink()) {
+                        apply_tree(&entry.path(), true, apply, out);
+                    }
+                }
+            }
+            Err(e) => out.failures.push((path.to_path_buf(), e.to_string())),
+        }
+    }
+    match apply(path) {
+        Ok(rec) => out.records.push(rec),
+        Err(e) => out.failures.push((path.to_path_buf(), e.to_string())),
+    }
+}
+
+/// Mode bits, uid and gid of `path` (following a symlink, as chmod does).
+#[cfg(unix)]
+fn current_perms(path: &Path) -> std::io::Result<(u32, u32, u32)> {
+    use std::os::unix::fs::MetadataExt;
+    let meta = fs::metadata(path)?;
+    Ok((meta.mode() & 0o7777, meta.uid(), meta.gid()))
+}
+
+#[cfg(not(unix))]
+fn current_perms(_path: &Path) -> std::io::Result<(u32, u32, u32)> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "permissions are not supported on this platform",
+    ))
+}
 
 #[cfg(unix)]
 pub fn chmod(path: &Path, mode: u32) -> std::io::Result<()> {
@@ -41,6 +133,82 @@ pub fn chown(_path: &Path, _uid: Option<u32>, _gid: Option<u32>) -> std::io::Res
     ))
 }
 
+/// Global mutex to serialize access to non-thread-safe POSIX passwd/group
+/// functions: the iterators (setpwent/getpwent/endpwent, setgrent/getgrent/
+/// endgrent) AND the single-lookup variants getpwuid/getgrgid, which all
+/// return pointers into shared static libc storage. Anything calling these
+/// from a blocking thread must hold this lock (see also app/chmod.rs).
+#[cfg(unix)]
+pub(crate) static PWGRP_LOCK: Mutex<()> = Mutex::new(());
+
+#[cfg(unix)]
+fn lookup_user(uid: u32) -> Option<String> {
+    // getpwuid shares static libc storage with getpwent (and other lookups),
+    // so hold the same lock to avoid a data race across blocking threads.
+    let _guard = PWGRP_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    unsafe {
+        let pw = libc::getpwuid(uid);
+        if pw.is_null() {
+            return None;
+        }
+        Some(
+            std::ffi::CStr::from_ptr((*pw).pw_name)
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+#[cfg(not(unix))]
+fn lookup_user(_uid: u32) -> Option<String> {
+    None
+}
+
+#[cfg(unix)]
+fn lookup_group(gid: u32) -> Option<String> {
+    // See lookup_user: getgrgid shares static libc storage; hold the lock.
+    let _guard = PWGRP_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    unsafe {
+        let gr = libc::getgrgid(gid);
+        if gr.is_null() {
+            return None;
+        }
+        Some(
+            std::ffi::CStr::from_ptr((*gr).gr_name)
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+#[cfg(not(unix))]
+fn lookup_group(_gid: u32) -> Option<String> {
+    None
+}
+
+/// User name for `uid`, or the number when it has no passwd entry. Cached:
+/// the panel asks for every visible row on every frame.
+pub fn user_name(uid: u32) -> String {
+    cached_name(&USER_NAMES, uid, lookup_user)
+}
+
+/// Group name for `gid`, or the number when it has no group entry. Cached.
+pub fn group_name(gid: u32) -> String {
+    cached_name(&GROUP_NAMES, gid, lookup_group)
+}
+
+type NameCache = LazyLock<Mutex<HashMap<u32, String>>>;
+
+static USER_NAMES: NameCache = LazyLock::new(Default::default);
+static GROUP_NAMES: NameCache = LazyLock::new(Default::default);
+
+fn cached_name(cache: &NameCache, id: u32, lookup: fn(u32) -> Option<String>) -> String {
+    let mut map = cache.lock().unwrap_or_else(|e| e.into_inner());
+    map.entry(id)
+        .or_insert_with(|| lookup(id).unwrap_or_else(|| id.to_string()))
+        .clone()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,9 +237,52 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn chmod_all_recurses_and_undo_restores_each_mode() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tmp_dir();
+        let tree = dir.join("tree");
+        fs::create_dir_all(tree.join("sub")).unwrap();
+        fs::write(tree.join("a.sh"), "").unwrap();
+        fs::write(tree.join("sub/b.txt"), "").unwrap();
+        chmod(&tree.join("a.sh"), 0o755).unwrap();
+        chmod(&tree.join("sub/b.txt"), 0o600).unwrap();
+        let mode = |p: &Path| fs::metadata(p).unwrap().permissions().mode() & 0o7777;
+
+        let out = chmod_all(std::slice::from_ref(&tree), 0o700, true);
+        assert!(out.failures.is_empty());
+        assert_eq!(out.records.len(), 4);
+        for p in ["", "sub", "a.sh", "sub/b.txt"].map(|rel| tree.join(rel)) {
+            assert_eq!(mode(&p), 0o700, "{}", p.display());
+        }
+
+        crate::fs::ops::undo(&out.records).unwrap();
+        assert_eq!(mode(&tree.join("a.sh")), 0o755);
+        assert_eq!(mode(&tree.join("sub/b.txt")), 0o600);
+
+        // Without `recursive` only the named paths change; a missing one fails.
+        let gone = dir.join("gone");
+        let out = chmod_all(&[tree.clone(), gone.clone()], 0o750, false);
+        assert_eq!(out.records.len(), 1);
+        assert_eq!(out.failures.len(), 1);
+        assert_eq!(out.failures[0].0, gone);
+        assert_eq!(mode(&tree.join("a.sh")), 0o755);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[cfg(unix)]
     #[test]
     fn chmod_nonexistent_fails() {
         assert!(chmod(Path::new("/nonexistent/file"), 0o755).is_err());
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn owner_names_resolve_root_and_fall_back_to_ids() {
+        assert_eq!(user_name(0), "root");
+        // An id with no passwd/group entry shows as the number.
+        assert_eq!(user_name(4_000_000_123), "4000000123");
+        assert_eq!(group_name(4_000_000_123), "4000000123");
+    }
 }
@@ -0,0 +1,235 @@
+//! Copies to and from `[user@]host:path` targets by shelling out to `rsync`,
+//! reporting through the same `ProgressMsg` channel as a local paste.
+
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::fs::ops::{PROGRESS_INTERVAL, ProgressMsg};
+
+/// Whether `target` is rsync/scp remote syntax: `host:path` or
+/// `user@host:path`, where the part before the first `:` has no `/`. A single
+/// letter before the colon is a Windows drive, not a host.
+pub fn is_remote(target: &str) -> bool {
+    let Some((host, _)) = target.split_once(':') else {
+        return false;
+    };
+    let bare = host.rsplit('@').next().unwrap_or(host);
+    !bare.is_empty()
+        && !host.contains('/')
+        && !host.starts_with(['.', '~'])
+        && (bare.len() != 1 || host.contains('@'))
+}
+
+/// Bytes done and percent from one `--info=progress2` update, e.g.
+/// `  1,234,567  45%  1.23MB/s  0:00:10 (xfr#1, to-chk=0/3)`.
+pub fn parse_progress2(line: &str) -> Option<(u64, u8)> {
+    let mut fields = line.split_whitespace();
+    let bytes = fields.next()?.replace(',', "").parse().ok()?;
+    let pct = fields.next()?.strip_suffix('%')?.parse().ok()?;
+    Some((bytes, pct))
+}
+
+/// ssh as rsync's transport, never prompting: a password or host-key question
+/// would write to the terminal under the TUI, so it fails with a message instead.
+const RSH: &str = "ssh -o BatchMode=yes";
+
+/// How often the wait loop checks for a cancel while rsync is quiet.
+const CANCEL_POLL: Duration = Duration::from_millis(50);
+
+/// `(major, minor)` from `rsync --version`'s first line, e.g.
+/// `rsync  version 3.2.7  protocol version 31`.
+pub fn parse_version(text: &str) -> Option<(u32, u32)> {
+    let mut words = text.lines().next()?.split_whitespace();
+    words.find(|w| *w == "version")?;
+    let mut parts = words.next()?.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor: String = parts
+        .next()?
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    Some((major, minor.parse().ok()?))
+}
+
+/// Whether the installed rsync knows `--info=progress2` (3.1 and later).
+/// `Err` when rsync can't be run at all.
+fn has_progress2() -> Result<bool, String> {
+    match Command::new("rsync")
+        .arg("--version")
+        .stdin(Stdio::null())
+        .output()
+    {
+        Ok(out) => Ok(parse_version(&String::from_utf8_lossy(&out.stdout)) >= Some((3, 1))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err("rsync is not installed".into()),
+        Err(e) => Err(format!("rsync: {e}")),
+    }
+}
+
+/// Run `rsync -a --info=progress2 <sources...> <dest>` off the UI thread; an
+/// rsync older than 3.1 runs without progress. There are no undo records:
+/// rsync may have touched the remote side.
+pub fn rsync_in_background(
+    sources: Vec<String>,
+    dest: String,
+    tx: tokio::sync::mpsc::Sender<ProgressMsg>,
+    cancel: Arc<AtomicBool>,
+) {
+    tokio::task::spawn_blocking(move || {
+        let item_total = sources.len();
+        let finish = |error: Option<String>, bytes_total: u64, cancelled: bool| {
+            let _ = tx.blocking_send(ProgressMsg::Finished {
+                records: Vec::new(),
+                error,
+                bytes_total,
+                cancelled,
+            });
+        };
+        let progress = match has_progress2() {
+            Ok(p) => p,
+            Err(e) => return finish(Some(e), 0, false),
+        };
+        let child = Command::new("rsync")
+            .args(["-a", "-e", RSH])
+            .args(progress.then_some("--info=progress2"))
+            .args(&sources)
+            .arg(&dest)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+        let mut child = match child {
+            Ok(c) => c,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return finish(Some("rsync is not installed".into()), 0, false);
+            }
+            Err(e) => return finish(Some(format!("rsync: {e}")), 0, false),
+        };
+        // Drain stderr on its own thread so a chatty rsync can't fill the pipe
+        // while we block reading progress from stdout.
+        let mut stderr = child.stderr.take();
+        let stderr_reader = std::thread::spawn(move || {
+            let mut text = String::new();
+            if let Some(err) = stderr.as_mut() {
+                let _ = err.read_to_string(&mut text);
+            }
+            text
+        });
+
+        // Progress is parsed on its own thread too, so this one only waits for
+        // the exit or a cancel and a stalled transfer can still be killed.
+        let bytes_done = Arc::new(AtomicU64::new(0));
+        let bytes_total = Arc::new(AtomicU64::new(0));
+        let mut stdout = child.stdout.take();
+        let (done, total) = (Arc::clone(&bytes_done), Arc::clone(&bytes_total));
+        std::thread::spawn(move || {
+            let Some(out) = stdout.as_mut() else {
+                return;
+            };
+            let mut line = Vec::new();
+            let mut buf = [0u8; 4096];
+            // progress2 redraws its line with `\r`, so treat both `\r` and `\n` as ends.
+            while let Ok(n @ 1..) = out.read(&mut buf) {
+                for &b in &buf[..n] {
+                    if b != b'\r' && b != b'\n' {
+                        line.push(b);
+                        continue;
+                    }
+                    if let Some((d, pct)) = parse_progress2(&String::from_utf8_lossy(&line)) {
+                        done.store(d, Ordering::Relaxed);
+                        if pct > 0 {
+                            total.fetch_max(d * 100 / pct as u64, Ordering::Relaxed);
+                        }
+                    }
+                    line.clear();
+                }
+            }
+        });
+
+        let mut last_report: Option<Instant> = None;
+        let mut cancelled = false;
+        let status = loop {
+            if cancel.load(Ordering::Relaxed) {
+                let _ = child.kill();
+                cancelled = true;
+                break child.wait();
+            }
+            match child.try_wait() {
+                Ok(Some(s)) => break Ok(s),
+                Ok(None) => {}
+                Err(e) => break Err(e),
+            }
+            let now = Instant::now();
+            if last_report.is_none_or(|t| now.duration_since(t) >= PROGRESS_INTERVAL) {
+                last_report = Some(now);
+                let done = bytes_done.load(Ordering::Relaxed);
+                let _ = tx.try_send(ProgressMsg::Progress {
+                    bytes_done: done,
+                    bytes_total: bytes_total.load(Ordering::Relaxed).max(done),
+                    item_index: 0,
+                    item_total,
+                });
+            }
+            std::thread::sleep(CANCEL_POLL);
+        };
+
+        // ssh may outlive a killed rsync and hold stderr open; don't wait on it.
+        let stderr = if cancelled {
+            String::new()
+        } else {
+            stderr_reader.join().unwrap_or_default()
+        };
+        let bytes_done = bytes_done.load(Ordering::Relaxed);
+        let error = match status {
+            _ if cancelled => None,
+            Ok(s) if s.success() => None,
+            Ok(s) => Some(
+                stderr
+                    .lines()
+                    .rev()
+                    .find(|l| !l.trim().is_empty())
+                    .map(|l| l.trim().to_string())
+                    .unwrap_or_else(|| format!("rsync exited with {s}")),
+            ),
+            Err(e) => Some(format!("rsync: {e}")),
+        };
+        finish(error, bytes_done, cancelled);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remote_syntax_is_recognised() {
+        assert!(is_remote("user@host:/srv/www"));
+        assert!(is_remote("host:backups"));
+        assert!(is_remote("nas.local:"));
+        assert!(!is_remote("/home/u/a:b"));
+        assert!(!is_remote("./x:y"));
+        assert!(!is_remote("C:\\Users"));
+        assert!(!is_remote("plain"));
+    }
+
+    #[test]
+    fn progress2_lines_parse() {
+        let line = "      1,234,567  45%    1.23MB/s    0:00:10 (xfr#1, to-chk=0/3)";
+        assert_eq!(parse_progress2(line), Some((1_234_567, 45)));
+        assert_eq!(parse_progress2("sending incremental file list"), None);
+    }
+
+    #[test]
+    fn version_line_parses() {
+        let text = "rsync  version 3.2.7  protocol version 31\nCopyright (C) 1996-2022";
+        assert_eq!(parse_version(text), Some((3, 2)));
+        assert_eq!(
+            parse_version("rsync  version 3.4pre1  protocol version 32"),
+            Some((3, 4))
+        );
+        assert_eq!(parse_version("openrsync: protocol version 29"), None);
+        assert!(parse_version("rsync  version 2.6.9  protocol version 29") < Some((3, 1)));
+    }
+}
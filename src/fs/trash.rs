@@ -87,6 +87,49 @@ pub fn restore(item: &TrashedItem) -> std::io::Result<()> {
     restore_impl(item)
 }
 
+/// One top-level entry currently in the system trash, whoever put it there.
+/// Listed by `:emptytrash` to report the total before purging.
+pub struct TrashContent {
+    pub name: String,
+    /// Bytes on disk (recursive for directories).
+    pub size: u64,
+    #[cfg(target_os = "macos")]
+    location: PathBuf,
+    #[cfg(trash_os_limited)]
+    item: trash::TrashItem,
+}
+
+#[cfg(test)]
+impl TrashContent {
+    /// Build a fake entry for confirm-dialog tests; purging it fails.
+    pub fn new_for_test(name: &str, size: u64) -> Self {
+        TrashContent {
+            name: name.to_string(),
+            size,
+            #[cfg(target_os = "macos")]
+            location: PathBuf::from("/nonexistent/fcmd-test-trash"),
+            #[cfg(trash_os_limited)]
+            item: trash::TrashItem {
+                id: std::ffi::OsString::new(),
+                name: std::ffi::OsString::new(),
+                original_parent: PathBuf::new(),
+                time_deleted: 0,
+            },
+        }
+    }
+}
+
+/// Everything in the system trash. Slow for large trashes (sizes are summed
+/// recursively); call it off the UI thread.
+pub fn list_contents() -> std::io::Result<Vec<TrashContent>> {
+    list_contents_impl()
+}
+
+/// Permanently remove one entry from the system trash.
+pub fn purge(content: &TrashContent) -> std::io::Result<()> {
+    purge_impl(content)
+}
+
 // --- macOS: snapshot ~/.Trash, move the file back ourselves ---
 
 #[cfg(target_os = "macos")]
@@ -213,6 +256,35 @@ fn copy_recursive(from: &Path, to: &Path) -> std::io::Result<()> {
     }
 }
 
+#[cfg(target_os = "macos")]
+fn list_contents_impl() -> std::io::Result<Vec<TrashContent>> {
+    let dir = dirs::home_dir()
+        .map(|h| h.join(".Trash"))
+        .ok_or_else(|| std::io::Error::other("no home directory"))?;
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(rd) => rd,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    Ok(entries
+        .flatten()
+        .filter(|e| e.file_name() != ".DS_Store")
+        .map(|e| {
+            let location = e.path();
+            TrashContent {
+                name: e.file_name().to_string_lossy().into_owned(),
+                size: crate::fs::ops::path_size(&location),
+                location,
+            }
+        })
+        .collect())
+}
+
+#[cfg(target_os = "macos")]
+fn purge_impl(content: &TrashContent) -> std::io::Result<()> {
+    crate::fs::ops::remove_path(&content.location)
+}
+
 // --- Freedesktop / Windows: handles via the `trash` crate's os_limited API ---
 
 #[cfg(trash_os_limited)]
@@ -237,6 +309,42 @@ fn restore_impl(item: &TrashedItem) -> std::io::Result<()> {
     trash::os_limited::restore_all([item.item.clone()]).map_err(std::io::Error::other)
 }
 
+#[cfg(trash_os_limited)]
+fn list_contents_impl() -> std::io::Result<Vec<TrashContent>> {
+    let items = trash::os_limited::list().map_err(std::io::Error::other)?;
+    Ok(items
+        .into_iter()
+        .map(|item| TrashContent {
+            name: item.name.to_string_lossy().into_owned(),
+            size: item_size(&item),
+            item,
+        })
+        .collect())
+}
+
+/// On Freedesktop `id` is the `.trashinfo` path and the payload sits under the
+/// same stem in the sibling `files/` directory; measure that directly so
+/// directories get their full size. Elsewhere ask the crate (files only).
+#[cfg(trash_os_limited)]
+fn item_size(item: &trash::TrashItem) -> u64 {
+    let info = Path::new(&item.id);
+    if let (Some(root), Some(stem)) = (info.parent().and_then(Path::parent), info.file_stem()) {
+        let payload = root.join("files").join(stem);
+        if payload.symlink_metadata().is_ok() {
+            return crate::fs::ops::path_size(&payload);
+        }
+    }
+    trash::os_limited::metadata(item)
+        .ok()
+        .and_then(|m| m.size.size())
+        .unwrap_or(0)
+}
+
+#[cfg(trash_os_limited)]
+fn purge_impl(content: &TrashContent) -> std::io::Result<()> {
+    trash::os_limited::purge_all([content.item.clone()]).map_err(std::io::Error::other)
+}
+
 // --- Other platforms (e.g. iOS/Android): trash works, restore does not ---
 
 #[cfg(not(any(target_os = "macos", trash_os_limited)))]
@@ -253,6 +361,22 @@ fn restore_impl(_item: &TrashedItem) -> std::io::Result<()> {
     ))
 }
 
+#[cfg(not(any(target_os = "macos", trash_os_limited)))]
+fn list_contents_impl() -> std::io::Result<Vec<TrashContent>> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "listing the trash is not supported on this platform",
+    ))
+}
+
+#[cfg(not(any(target_os = "macos", trash_os_limited)))]
+fn purge_impl(_content: &TrashContent) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "emptying the trash is not supported on this platform",
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
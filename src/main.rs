@@ -1,4 +1,5 @@
 use std::io;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use crossterm::{
@@ -13,6 +14,9 @@ use ratatui::{Terminal, backend::CrosstermBackend};
 
 mod app;
 mod archive;
+mod batch;
+mod cli;
+mod config;
 mod exe;
 mod fs;
 mod model;
@@ -24,9 +28,62 @@ mod ui;
 mod util;
 mod viewer;
 
+/// The session as of the last frame drawn, with the DB to write it to; the
+/// panic hook saves it since a panic never reaches `save_session` in `run`.
+static CRASH_SESSION: Mutex<Option<(Arc<Mutex<storage::Db>>, app::SessionSnapshot)>> =
+    Mutex::new(None);
+
+/// Best effort from inside the panic hook: `try_lock` everywhere, since the
+/// panicking thread may be the one holding either lock.
+fn save_crash_session() {
+    let Ok(guard) = CRASH_SESSION.try_lock() else {
+        return;
+    };
+    let Some((db, snapshot)) = guard.as_ref() else {
+        return;
+    };
+    if let Ok(db) = db.try_lock() {
+        snapshot.write(&db);
+    }
+}
+
 #[tokio::main]
 async fn main() -> io::Result<()> {
-    // Restore terminal on panic
+    let args = match cli::parse(std::env::args_os().skip(1)) {
+        Ok(cli::Command::Run(args)) => args,
+        Ok(cli::Command::Batch(batch)) => {
+            let cwd = std::env::current_dir()?;
+            std::process::exit(batch::run(batch, &cwd).await);
+        }
+        Ok(cli::Command::Help) => {
+            print!("{}", cli::USAGE);
+            return Ok(());
+        }
+        Ok(cli::Command::Version) => {
+            println!("fcmd {}", env!("CARGO_PKG_VERSION"));
+            return Ok(());
+        }
+        Err(e) => {
+            eprintln!("fcmd: {e}\n\n{}", cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    let cwd = std::env::current_dir()?;
+    if let Some(dir) = &args.config_dir {
+        util::set_config_dir(cwd.join(dir));
+    }
+    // Check the path before taking over the terminal, so an error reads normally.
+    let start = match args.path.as_deref().map(|p| cli::start_location(p, &cwd)) {
+        Some(Ok(start)) => Some(start),
+        Some(Err(e)) => {
+            eprintln!("fcmd: {e}");
+            std::process::exit(1);
+        }
+        None => None,
+    };
+
+    // Restore terminal on panic, save the session, then let the default hook
+    // print the message onto the normal screen.
     let default_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
         let _ = disable_raw_mode();
@@ -36,6 +93,7 @@ async fn main() -> io::Result<()> {
             LeaveAlternateScreen,
             crossterm::cursor::Show
         );
+        save_crash_session();
         default_hook(info);
     }));
 
@@ -47,10 +105,14 @@ async fn main() -> io::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Run app
-    let mut app = app::App::new()?;
+    let mut app = app::App::new(start, args.no_session)?;
+    if args.choose_files.is_some() {
+        app.start_chooser();
+    }
     let result = run(&mut terminal, &mut app).await;
 
     // Restore terminal
+    clear_preview_image(&mut terminal, &mut app)?;
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -59,18 +121,42 @@ async fn main() -> io::Result<()> {
     )?;
     terminal.show_cursor()?;
 
-    if let Err(e) = result {
-        eprintln!("Error: {e}");
+    match result {
+        Err(e) => eprintln!("Error: {e}"),
+        // For a shell function that cds to wherever fcmd was left.
+        Ok(()) => {
+            if let Some(file) = &args.cd_file {
+                let dir = app.active_panel().path.as_os_str().as_encoded_bytes();
+                if let Err(e) = std::fs::write(cwd.join(file), dir) {
+                    eprintln!("fcmd: {}: {e}", file.display());
+                }
+            }
+            // Chooser: one path per line; no choice (quit) is exit status 1.
+            if let Some(file) = &args.choose_files {
+                let Some(chosen) = app.chosen.take() else {
+                    std::process::exit(1);
+                };
+                let mut out = Vec::new();
+                for path in chosen {
+                    out.extend_from_slice(path.as_os_str().as_encoded_bytes());
+                    out.push(b'\n');
+                }
+                if let Err(e) = std::fs::write(cwd.join(file), out) {
+                    eprintln!("fcmd: {}: {e}", file.display());
+                    std::process::exit(1);
+                }
+            }
+        }
     }
     Ok(())
 }
 
-fn open_in_editor(
+/// Hand the terminal to a child process: switch to the main screen, clear it.
+fn suspend_tui(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut app::App,
-    path: &std::path::Path,
 ) -> io::Result<()> {
-    // Suspend TUI: switch to main screen, then clear it
+    clear_preview_image(terminal, app)?;
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -79,16 +165,11 @@ fn open_in_editor(
         terminal::Clear(terminal::ClearType::All),
         crossterm::cursor::MoveTo(0, 0),
         crossterm::cursor::Show,
-    )?;
-
-    // Determine editor: $VISUAL -> $EDITOR -> vi
-    let editor = std::env::var("VISUAL")
-        .or_else(|_| std::env::var("EDITOR"))
-        .unwrap_or_else(|_| "vi".into());
-
-    let result = std::process::Command::new(&editor).arg(path).status();
+    )
+}
 
-    // Restore TUI: enter alternate screen, force full repaint
+/// Take the terminal back after `suspend_tui`: alternate screen, full repaint.
+fn resume_tui(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
     execute!(
         terminal.backend_mut(),
         EnterAlternateScreen,
@@ -98,49 +179,177 @@ fn open_in_editor(
     )?;
     enable_raw_mode()?;
     // Invalidate ratatui's internal buffer so next draw() repaints every cell
-    terminal.clear()?;
+    terminal.clear()
+}
+
+/// Bring the image the terminal draws in line with the preview: the old one
+/// is hidden and the new one placed when the image or its area changed. A
+/// new image is uploaded first (freeing the previous one); one already
+/// uploaded is only placed again.
+fn sync_preview_image(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut app::App,
+) -> io::Result<()> {
+    use preview::image;
+    use std::io::Write;
+    let wanted = app.preview_image_area.and_then(|area| {
+        let upload = app.preview.as_ref()?.image.as_ref()?.upload.as_ref()?;
+        Some((upload.id, area))
+    });
+    if wanted == app.image_on_screen {
+        return Ok(());
+    }
+    let out = terminal.backend_mut();
+    if let Some((shown, _)) = app.image_on_screen.take() {
+        out.write_all(&image::hide_sequence(shown))?;
+    }
+    let image = app.preview.as_ref().and_then(|p| p.image.as_ref());
+    let (Some((id, area)), Some(image)) = (wanted, image) else {
+        return out.flush();
+    };
+    if app.image_uploaded != Some(id) {
+        if let Some(old) = app.image_uploaded {
+            out.write_all(&image::free_sequence(old))?;
+        }
+        if let Some(upload) = &image.upload {
+            out.write_all(&upload.sequence)?;
+        }
+    }
+    let (cols, rows) = image::fit(
+        image.width,
+        image.height,
+        area.width,
+        area.height,
+        image::cell_size(),
+    );
+    let x = area.x + area.width.saturating_sub(cols) / 2;
+    out.write_all(&image::place_sequence(id, x, area.y, cols, rows))?;
+    out.flush()?;
+    app.image_uploaded = Some(id);
+    app.image_on_screen = Some((id, area));
+    Ok(())
+}
+
+/// Take the preview's image off the screen and free what the terminal holds,
+/// before it goes to another program or fcmd exits.
+fn clear_preview_image(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut app::App,
+) -> io::Result<()> {
+    use std::io::Write;
+    let shown = app.image_on_screen.take().is_some();
+    if app.image_uploaded.take().is_some() || shown {
+        let out = terminal.backend_mut();
+        out.write_all(preview::image::CLEAR_SEQUENCE)?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+/// Suspend the TUI and edit `paths` in `$VISUAL`/`$EDITOR`. Returns whether
+/// the editor exited successfully; if not, the status line says why.
+fn open_in_editor(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut app::App,
+    paths: &[std::path::PathBuf],
+    line: Option<usize>,
+) -> io::Result<bool> {
+    suspend_tui(terminal, app)?;
+
+    // Determine editor: $VISUAL -> $EDITOR -> vi
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".into());
+
+    // Several paths open as buffers/tabs in one session for most editors; a
+    // single file from the viewer opens at the line it was showing.
+    let args = match (paths, line) {
+        ([path], Some(line)) => util::editor_line_args(&editor, path, line),
+        _ => paths.iter().map(|p| p.into()).collect(),
+    };
+    let result = std::process::Command::new(&editor).args(args).status();
+
+    resume_tui(terminal)?;
 
     match result {
         Ok(status) if status.success() => {
             // Refresh panel in case the file was modified
             app.reload_active_panel();
+            return Ok(true);
         }
         Ok(status) => {
-            app.status_message = format!("{editor} exited with {status}");
+            app.set_status(format!("{editor} exited with {status}"));
         }
         Err(e) => {
-            app.status_message = format!("Failed to open {editor}: {e}");
+            app.set_status(format!("Failed to open {editor}: {e}"));
         }
     }
 
-    Ok(())
+    Ok(false)
 }
 
-/// Lightweight snapshot of state that poll functions may change.
-/// Used to detect whether a tick actually modified anything worth redrawing.
-fn snapshot(
-    app: &app::App,
-) -> (
-    String,
-    Option<String>,
-    Option<String>,
-    bool,
-    usize,
-    usize,
-    usize,
-    usize,
-) {
-    let find_count = app.find_state.as_ref().map_or(0, |fs| fs.total_count());
-    (
-        app.status_message.clone(),
-        app.task_notification.clone(),
-        app.background_progress.clone(),
-        app.conflict_info.is_some(),
-        app.dir_sizes.len(),
-        app.git_statuses.len(),
-        app.info_lines.len(),
-        find_count,
-    )
+/// `:pipe`: run the command through `sh -c` in the panel's directory with the
+/// paths on stdin, leave its output on screen until Enter, then report the exit
+/// status and refresh.
+fn run_pipe(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut app::App,
+    req: app::PipeRequest,
+) -> io::Result<()> {
+    use crossterm::event::{self, KeyCode};
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    suspend_tui(terminal, app)?;
+    println!("$ {}  ({} path(s) on stdin)", req.command, req.paths.len());
+
+    let cwd = app.active_panel().path.clone();
+    let child = Command::new("sh")
+        .arg("-c")
+        .arg(&req.command)
+        .current_dir(&cwd)
+        .stdin(Stdio::piped())
+        .spawn();
+    let result = child.and_then(|mut child| {
+        if let Some(mut stdin) = child.stdin.take() {
+            let mut input = String::new();
+            for p in &req.paths {
+                input.push_str(&p.to_string_lossy());
+                input.push('\n');
+            }
+            // A command that exits without reading all of stdin is not an error.
+            let _ = stdin.write_all(input.as_bytes());
+        }
+        child.wait()
+    });
+
+    match &result {
+        Ok(status) => print!("\n[{status}] Press Enter to return"),
+        Err(e) => print!("\n[failed to run: {e}] Press Enter to return"),
+    }
+    let _ = io::stdout().flush();
+    // The app's event stream is dropped while this runs (see `run`), so this is
+    // the terminal's only reader.
+    enable_raw_mode()?;
+    loop {
+        if let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+            && matches!(key.code, KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q'))
+        {
+            break;
+        }
+    }
+
+    resume_tui(terminal)?;
+
+    match result {
+        Ok(status) => {
+            app.show_toast(format!("pipe: {status}"), !status.success());
+        }
+        Err(e) => app.show_toast(format!("pipe: failed to run sh: {e}"), true),
+    }
+    app.reload_active_panel();
+    Ok(())
 }
 
 /// Helper: await a value from an Option<oneshot::Receiver>, or pend forever if None.
@@ -156,31 +365,44 @@ async fn recv_or_pend<T>(rx: &mut Option<tokio::sync::oneshot::Receiver<T>>) ->
     }
 }
 
+/// Helper: sleep until `deadline`, or pend forever if None.
+async fn sleep_until_or_pend(deadline: Option<Instant>) {
+    match deadline {
+        Some(at) => tokio::time::sleep_until(tokio::time::Instant::from_std(at)).await,
+        None => std::future::pending().await,
+    }
+}
+
 async fn run(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut app::App,
 ) -> io::Result<()> {
     let mut reader = EventStream::new();
-    const TICK_MS: u64 = 250;
-    let mut tick = tokio::time::interval(Duration::from_millis(TICK_MS));
+    // The tick period follows what the app is doing (see `App::tick_interval`):
+    // short while something animates, long when idle. It is re-checked after
+    // every event and the interval rebuilt when it changes.
+    let mut tick_period = app.tick_interval();
+    let mut tick = tokio::time::interval(tick_period);
     // How often to refresh the progress animation of a running task. Progress is
     // purely cosmetic, so we redraw it about once per second instead of on every
     // tick to avoid spending CPU on terminal redraws during long copies/moves.
-    // Task completion, conflicts and status messages are tracked by `snapshot`
+    // Task completion, conflicts and status messages are tracked by `poll_snapshot`
     // and still redraw immediately.
-    const TASK_REDRAW_EVERY_N_TICKS: u32 = if 1000 / TICK_MS > 0 {
-        (1000 / TICK_MS) as u32
-    } else {
-        1
-    };
+    const TASK_REDRAW_INTERVAL: Duration = Duration::from_secs(1);
+    let mut last_task_redraw = Instant::now();
     // Minimum wall-clock gap between two consecutive redraws driven by *background*
     // work (task progress, streaming dir/find loads, etc.). This keeps the terminal
     // from being redrawn dozens of times per second when background channels fire
     // rapidly. Direct keyboard / resize input bypasses this cap (`draw_immediately`)
     // so navigation always feels instant. A pending background redraw that is held
-    // back is flushed by the next 250ms `tick`.
-    const MIN_REDRAW_INTERVAL: Duration = Duration::from_millis(TICK_MS);
+    // back keeps the tick at this period until it is flushed.
+    const MIN_REDRAW_INTERVAL: Duration = app::ANIMATION_TICK;
     let mut last_draw: Option<Instant> = None;
+    // Dragging a window edge sends a resize per step. Each one would redraw the
+    // whole screen, so a burst is folded into one frame per RESIZE_SETTLE; the
+    // size the burst ends on is always drawn, at most that long afterwards.
+    const RESIZE_SETTLE: Duration = Duration::from_millis(30);
+    let mut resize_redraw_at: Option<Instant> = None;
     // First frame must render unconditionally.
     let mut draw_immediately = true;
 
@@ -190,13 +412,30 @@ async fn run(
                 draw_immediately || last_draw.is_none_or(|t| t.elapsed() >= MIN_REDRAW_INTERVAL);
             if due {
                 terminal.draw(|f| ui::render(f, app))?;
+                sync_preview_image(terminal, app)?;
                 app.needs_redraw = false;
                 last_draw = Some(Instant::now());
+                if let Some(db) = &app.db
+                    && !app.no_session
+                    && let Ok(mut crash) = CRASH_SESSION.lock()
+                {
+                    *crash = Some((Arc::clone(db), app.session_snapshot()));
+                }
             }
-            // Otherwise keep `needs_redraw` set; the next tick flushes it within 250ms.
+            // Otherwise keep `needs_redraw` set; the next tick flushes it.
         }
         draw_immediately = false;
 
+        let period = if app.needs_redraw {
+            MIN_REDRAW_INTERVAL
+        } else {
+            app.tick_interval()
+        };
+        if period != tick_period {
+            tick_period = period;
+            tick = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        }
+
         tokio::select! {
             maybe_event = reader.next() => {
                 match maybe_event {
@@ -207,7 +446,7 @@ async fn run(
                     }
                     Some(Ok(Event::Resize(_, _))) => {
                         app.needs_redraw = true;
-                        draw_immediately = true;
+                        resize_redraw_at.get_or_insert_with(|| Instant::now() + RESIZE_SETTLE);
                     }
                     Some(Ok(Event::Mouse(mouse))) => {
                         app.handle_mouse(mouse);
@@ -219,27 +458,36 @@ async fn run(
                     None => break,
                 }
             }
+            _ = sleep_until_or_pend(resize_redraw_at) => {
+                resize_redraw_at = None;
+                draw_immediately = true;
+            }
             _ = tick.tick() => {
                 app.tick_count = app.tick_count.wrapping_add(1);
-                let before = snapshot(app);
+                let before = app.poll_snapshot();
+                let had_pending = app.pending_key.is_some();
+                app.poll_pending_key();
                 app.poll_tasks();
                 app.poll_conflicts();
                 app.poll_du();
                 app.poll_find();
                 app.poll_info_du();
                 app.poll_git();
-                if before != snapshot(app) {
+                app.poll_links();
+                if before != app.poll_snapshot() {
                     app.needs_redraw = true;
                 }
-                // Pending key may need a redraw for which-key popup after delay
-                if app.pending_key.is_some() {
+                // Pending key may need a redraw for which-key popup after delay,
+                // and one more once it times out so the popup goes away.
+                if had_pending {
                     app.needs_redraw = true;
                 }
                 // Active tasks (copy/move/delete) animate their progress, but only
-                // about once per second — see TASK_REDRAW_EVERY_N_TICKS.
+                // about once per second — see TASK_REDRAW_INTERVAL.
                 if app.task_manager.active_count() > 0
-                    && app.tick_count.is_multiple_of(TASK_REDRAW_EVERY_N_TICKS)
+                    && last_task_redraw.elapsed() >= TASK_REDRAW_INTERVAL
                 {
+                    last_task_redraw = Instant::now();
                     app.needs_redraw = true;
                 }
                 // Animate the background-work spinner (e.g. dir-size calculation).
@@ -280,6 +528,12 @@ async fn run(
             result = recv_or_pend(&mut app.chown_load_rx) => {
                 if let Some(r) = result { app.apply_chown_load(r); app.needs_redraw = true; draw_immediately = true; }
             }
+            result = recv_or_pend(&mut app.empty_trash_rx) => {
+                if let Some(r) = result { app.apply_empty_trash_scan(r); app.needs_redraw = true; draw_immediately = true; }
+            }
+            result = recv_or_pend(&mut app.paste_picker_rx) => {
+                if let Some(r) = result { app.apply_paste_picker_load(r); app.needs_redraw = true; draw_immediately = true; }
+            }
             result = recv_or_pend(&mut app.nav_check_rx) => {
                 if let Some(r) = result { app.apply_nav_check(r); app.needs_redraw = true; draw_immediately = true; }
             }
@@ -294,8 +548,24 @@ async fn run(
             }
         }
 
-        if let Some(path) = app.open_editor.take() {
-            open_in_editor(terminal, app, &path)?;
+        if let Some(paths) = app.open_editor.take() {
+            let line = app.open_editor_line.take();
+            open_in_editor(terminal, app, &paths, line)?;
+            app.needs_redraw = true;
+        }
+
+        if let Some(file) = app.bulk_rename_edit.take() {
+            let edited = open_in_editor(terminal, app, std::slice::from_ref(&file), None)?;
+            app.finish_bulk_rename_edit(&file, edited);
+            app.needs_redraw = true;
+        }
+
+        if let Some(req) = app.pipe_request.take() {
+            // `run_pipe` reads its closing key itself; the stream's poll thread
+            // would race it for that input, so the stream goes for the duration.
+            drop(reader);
+            run_pipe(terminal, app, req)?;
+            reader = EventStream::new();
             app.needs_redraw = true;
         }
 
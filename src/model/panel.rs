@@ -4,7 +4,8 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::SystemTime;
 
-use crate::util::natsort::natsort;
+use crate::config::ParentEntry;
+use crate::util::natsort::name_order;
 
 #[derive(Clone)]
 pub struct FileEntry {
@@ -15,6 +16,16 @@ pub struct FileEntry {
     pub modified: Option<SystemTime>,
     pub created: Option<SystemTime>,
     pub is_symlink: bool,
+    /// `(uid, gid)` of the entry itself (not a symlink's target); `None` for
+    /// `..` and on platforms without Unix ownership.
+    pub owner: Option<(u32, u32)>,
+}
+
+/// Restricts a panel's listing to a fixed set of paths (e.g. `:marked 2`).
+pub struct PathFilter {
+    /// Short indicator shown in the panel title.
+    pub label: String,
+    pub paths: HashSet<PathBuf>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -56,6 +67,17 @@ impl SortMode {
         }
     }
 
+    /// The mode after this one in the `S` / header-click cycle.
+    pub fn next(self) -> SortMode {
+        match self {
+            SortMode::Name => SortMode::Size,
+            SortMode::Size => SortMode::Modified,
+            SortMode::Modified => SortMode::Created,
+            SortMode::Created => SortMode::Extension,
+            SortMode::Extension => SortMode::Name,
+        }
+    }
+
     pub fn from_label(s: &str) -> Option<SortMode> {
         match s {
             "name" => Some(SortMode::Name),
@@ -79,16 +101,27 @@ pub struct Panel {
     pub offset: usize,
     pub visual_anchor: Option<usize>,
     pub marked: HashSet<PathBuf>,
+    /// The entry last marked with `toggle_mark`, where `gv` starts its range.
+    /// A path rather than an index so a reload or another directory can't
+    /// point it at the wrong entry.
+    pub mark_anchor: Option<PathBuf>,
     pub sort_mode: SortMode,
     pub sort_reverse: bool,
     pub show_hidden: bool,
+    /// `Space T` override of the `relative_dates` setting for this panel.
+    pub relative_dates: Option<bool>,
     /// When true, hide cursor highlight (waiting for async load to position cursor).
     pub loading: bool,
     /// Active live-filter query ("" = inactive). While non-empty, `entries` holds
     /// only matching items and `full_entries` backs the complete listing.
     pub filter: String,
-    /// Complete unfiltered listing; only populated while `filter` is active.
+    /// Path whitelist applied on top of `filter` ("None" = inactive).
+    pub path_filter: Option<PathFilter>,
+    /// Complete unfiltered listing; only populated while a filter is active.
     pub full_entries: Arc<Vec<FileEntry>>,
+    /// Why the directory couldn't be listed ("Permission denied", …); `None`
+    /// after a successful load. The panel body shows it instead of looking empty.
+    pub load_error: Option<String>,
 }
 
 impl Panel {
@@ -100,12 +133,16 @@ impl Panel {
             offset: 0,
             visual_anchor: None,
             marked: HashSet::new(),
+            mark_anchor: None,
             sort_mode: SortMode::Name,
             sort_reverse: false,
             show_hidden: false,
+            relative_dates: None,
             loading: true,
             filter: String::new(),
+            path_filter: None,
             full_entries: Arc::new(Vec::new()),
+            load_error: None,
         }
     }
 
@@ -130,6 +167,7 @@ impl Panel {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             });
         }
 
@@ -157,6 +195,7 @@ impl Panel {
             &mut files,
             self.sort_mode,
             self.sort_reverse,
+            false,
             dir_sizes,
         );
 
@@ -256,6 +295,21 @@ impl Panel {
         }
     }
 
+    /// `count` consecutive entries from the cursor down, never `..` (`3dd`, `5yy`).
+    /// Marks and the visual range are ignored: the count says what to take.
+    pub fn register_entries_from_cursor(&self, count: usize) -> Vec<crate::fs::ops::RegisterEntry> {
+        self.entries
+            .iter()
+            .skip(self.selected)
+            .filter(|e| e.name != "..")
+            .take(count)
+            .map(|e| crate::fs::ops::RegisterEntry {
+                path: e.path.clone(),
+                is_dir: e.is_dir,
+            })
+            .collect()
+    }
+
     /// Number of targeted entries (for status display).
     pub fn targeted_count(&self) -> usize {
         if !self.marked.is_empty() {
@@ -277,27 +331,44 @@ impl Panel {
 
     /// Toggle mark on current entry and move cursor down.
     pub fn toggle_mark(&mut self) {
-        if let Some(entry) = self.entries.get(self.selected)
-            && entry.name != ".."
-        {
-            let path = entry.path.clone();
-            if !self.marked.remove(&path) {
-                self.marked.insert(path);
-            }
-        }
+        self.toggle_selected_mark();
         self.move_down();
     }
 
     pub fn toggle_mark_up(&mut self) {
+        self.toggle_selected_mark();
+        self.move_up();
+    }
+
+    /// Marking an entry also makes it the `gv` anchor.
+    fn toggle_selected_mark(&mut self) {
         if let Some(entry) = self.entries.get(self.selected)
             && entry.name != ".."
         {
             let path = entry.path.clone();
             if !self.marked.remove(&path) {
-                self.marked.insert(path);
+                self.marked.insert(path.clone());
+                self.mark_anchor = Some(path);
             }
         }
-        self.move_up();
+    }
+
+    /// Mark everything from the anchor to the cursor, both ends included,
+    /// and move the anchor to the cursor so the next `gv` carries on from
+    /// there. Returns how many entries were newly marked, or `None` when the
+    /// anchor is not in this listing.
+    pub fn mark_to_anchor(&mut self) -> Option<usize> {
+        let anchor = self.mark_anchor.as_ref()?;
+        let at = self.entries.iter().position(|e| &e.path == anchor)?;
+        let (lo, hi) = (at.min(self.selected), at.max(self.selected));
+        let mut added = 0;
+        for entry in &self.entries[lo..=hi] {
+            if entry.name != ".." && self.marked.insert(entry.path.clone()) {
+                added += 1;
+            }
+        }
+        self.mark_anchor = self.selected_entry().map(|e| e.path.clone());
+        Some(added)
     }
 
     pub fn adjust_scroll(&mut self, visible_height: usize) {
@@ -347,35 +418,68 @@ impl Panel {
         scratch.contains(query_lower)
     }
 
-    /// Rebuild `entries` from `full_entries` using the active `filter`.
+    /// Is the live filter or a path filter narrowing the listing?
+    pub fn filter_active(&self) -> bool {
+        !self.filter.is_empty() || self.path_filter.is_some()
+    }
+
+    /// Does `entry` pass both the path filter and the live filter?
+    fn entry_visible(
+        entry: &FileEntry,
+        paths: Option<&PathFilter>,
+        query_lower: &str,
+        scratch: &mut String,
+    ) -> bool {
+        if entry.name != ".."
+            && let Some(pf) = paths
+            && !pf.paths.contains(&entry.path)
+        {
+            return false;
+        }
+        Self::filter_matches(&entry.name, query_lower, scratch)
+    }
+
+    /// Rebuild `entries` from `full_entries` using the active filters.
     /// No-op when no filter is set (`entries` already holds the full list).
     fn apply_filter_view(&mut self) {
-        if self.filter.is_empty() {
+        if !self.filter_active() {
             return;
         }
         let q = self.filter.to_lowercase();
         let mut scratch = String::new();
+        let paths = self.path_filter.as_ref();
         self.entries = Arc::new(
             self.full_entries
                 .iter()
-                .filter(|e| Self::filter_matches(&e.name, &q, &mut scratch))
+                .filter(|e| Self::entry_visible(e, paths, &q, &mut scratch))
                 .cloned()
                 .collect(),
         );
     }
 
     /// Set or clear the live filter, rebuilding the visible `entries`. An empty
-    /// `query` clears the filter and restores the full listing. The cursor is
-    /// reset to the top of the (re)built list.
+    /// `query` clears the filter and, unless a path filter remains, restores the
+    /// full listing. The cursor is reset to the top of the (re)built list.
     pub fn set_filter(&mut self, query: String) {
+        let was_active = self.filter_active();
+        self.filter = query;
+        self.refilter(was_active);
+    }
+
+    /// Set or clear the path filter; it combines with the live filter.
+    pub fn set_path_filter(&mut self, path_filter: Option<PathFilter>) {
+        let was_active = self.filter_active();
+        self.path_filter = path_filter;
+        self.refilter(was_active);
+    }
+
+    fn refilter(&mut self, was_active: bool) {
         self.selected = 0;
         self.offset = 0;
-        let was_active = !self.filter.is_empty();
-        if query.is_empty() {
+        if !self.filter_active() {
             if was_active {
                 self.entries = std::mem::take(&mut self.full_entries);
             }
-            self.filter.clear();
             self.clamp_selected();
             return;
         }
@@ -383,32 +487,34 @@ impl Panel {
             // Becoming active: snapshot the full list before narrowing.
             self.full_entries = self.entries.clone();
         }
-        self.filter = query;
         self.apply_filter_view();
         self.clamp_selected();
     }
 
-    /// Clear the filter if active, restoring the full listing. Returns whether a
-    /// filter was actually cleared.
+    /// Clear the live and path filters if active, restoring the full listing.
+    /// Returns whether a filter was actually cleared.
     pub fn clear_filter(&mut self) -> bool {
-        if self.filter.is_empty() {
+        if !self.filter_active() {
             return false;
         }
-        self.set_filter(String::new());
+        self.filter.clear();
+        self.path_filter = None;
+        self.refilter(true);
         true
     }
 
     /// Append a batch of unsorted entries during streaming load.
     pub fn append_entries(&mut self, new_entries: Vec<FileEntry>) {
-        if self.filter.is_empty() {
+        if !self.filter_active() {
             Arc::make_mut(&mut self.entries).extend(new_entries);
         } else {
             let q = self.filter.to_lowercase();
             let mut scratch = String::new();
+            let paths = self.path_filter.as_ref();
             Arc::make_mut(&mut self.entries).extend(
                 new_entries
                     .iter()
-                    .filter(|e| Self::filter_matches(&e.name, &q, &mut scratch))
+                    .filter(|e| Self::entry_visible(e, paths, &q, &mut scratch))
                     .cloned(),
             );
             Arc::make_mut(&mut self.full_entries).extend(new_entries);
@@ -416,11 +522,30 @@ impl Panel {
         self.clamp_selected();
     }
 
+    /// Re-sort the listing in place, e.g. once directory sizes come in for a
+    /// size-sorted panel; the cursor stays on its entry.
+    pub fn resort(&mut self, case_sensitive: bool, dir_sizes: &HashMap<PathBuf, u64>) {
+        let name = self.selected_entry().map(|e| e.name.clone());
+        let mut entries = if self.filter_active() {
+            (*self.full_entries).clone()
+        } else {
+            (*self.entries).clone()
+        };
+        resort_entries(
+            &mut entries,
+            self.sort_mode,
+            self.sort_reverse,
+            case_sensitive,
+            dir_sizes,
+        );
+        self.apply_entries(Arc::new(entries), name.as_deref());
+    }
+
     /// Swap in entries loaded asynchronously, optionally re-selecting a named entry.
     /// Takes a shared `Arc` so a cache hit (or the loader sharing with `DirCache`)
     /// installs the listing with a refcount bump instead of a deep clone.
     pub fn apply_entries(&mut self, entries: Arc<Vec<FileEntry>>, select_name: Option<&str>) {
-        if self.filter.is_empty() {
+        if !self.filter_active() {
             self.entries = entries;
         } else {
             self.full_entries = entries;
@@ -480,14 +605,30 @@ fn read_file_entry(entry: &fs::DirEntry) -> FileEntry {
         modified,
         created,
         is_symlink,
+        owner: owner_of(symlink_meta.as_ref()),
     }
 }
 
+#[cfg(unix)]
+fn owner_of(meta: Option<&fs::Metadata>) -> Option<(u32, u32)> {
+    use std::os::unix::fs::MetadataExt;
+    meta.map(|m| (m.uid(), m.gid()))
+}
+
+#[cfg(not(unix))]
+fn owner_of(_meta: Option<&fs::Metadata>) -> Option<(u32, u32)> {
+    None
+}
+
 pub struct DirLoadRequest {
     pub path: PathBuf,
     pub show_hidden: bool,
     pub sort_mode: SortMode,
     pub sort_reverse: bool,
+    /// `case_sensitive_sort` from the config.
+    pub case_sensitive: bool,
+    /// `parent_entry` from the config.
+    pub parent_entry: ParentEntry,
     pub dir_sizes: std::sync::Arc<HashMap<PathBuf, u64>>,
     pub panel_idx: usize,
     pub tab_index: usize,
@@ -505,14 +646,30 @@ pub fn stream_dir_entries(
         show_hidden,
         sort_mode,
         sort_reverse,
+        case_sensitive,
+        parent_entry: placement,
         ref dir_sizes,
         panel_idx,
         tab_index,
         select_name,
     } = req;
 
-    let entries =
-        load_dir_entries(path, show_hidden, sort_mode, sort_reverse, dir_sizes).unwrap_or_default();
+    let (mut entries, error) = match load_dir_entries(
+        path,
+        show_hidden,
+        sort_mode,
+        sort_reverse,
+        case_sensitive,
+        dir_sizes,
+    ) {
+        Ok(entries) => (entries, None),
+        // Keep ".." so the user can step back out of the unreadable directory.
+        Err(e) => (
+            parent_entry(path).into_iter().collect(),
+            Some(load_error_text(&e)),
+        ),
+    };
+    place_parent_entry(&mut entries, placement);
 
     // For large directories, send intermediate batches from the already-loaded
     // entries so the user sees content appearing progressively. These are purely
@@ -542,30 +699,58 @@ pub fn stream_dir_entries(
         path: path.clone(),
         entries,
         select_name,
+        error,
     });
 }
 
+/// Short reason a directory listing failed, shown in the panel body.
+pub fn load_error_text(e: &std::io::Error) -> String {
+    match e.kind() {
+        std::io::ErrorKind::PermissionDenied => "Permission denied".into(),
+        std::io::ErrorKind::NotFound => "Directory not found".into(),
+        _ => e.to_string(),
+    }
+}
+
+/// The ".." row leading to `path`'s parent (`None` at the root).
+fn parent_entry(path: &Path) -> Option<FileEntry> {
+    path.parent().map(|parent| FileEntry {
+        name: "..".into(),
+        path: parent.to_path_buf(),
+        is_dir: true,
+        size: 0,
+        modified: None,
+        created: None,
+        is_symlink: false,
+        owner: None,
+    })
+}
+
+/// Move the ".." row that [`load_dir_entries`] lists first to the bottom, or
+/// drop it, as the `parent_entry` setting asks.
+pub fn place_parent_entry(entries: &mut Vec<FileEntry>, placement: ParentEntry) {
+    if entries.first().is_none_or(|e| e.name != "..") {
+        return;
+    }
+    match placement {
+        ParentEntry::Top => {}
+        ParentEntry::Bottom => entries.rotate_left(1),
+        ParentEntry::Hidden => {
+            entries.remove(0);
+        }
+    }
+}
+
 /// Load directory entries as a pure function (can run on any thread).
 pub fn load_dir_entries(
     path: &Path,
     show_hidden: bool,
     sort_mode: SortMode,
     sort_reverse: bool,
+    case_sensitive: bool,
     dir_sizes: &HashMap<PathBuf, u64>,
 ) -> std::io::Result<Vec<FileEntry>> {
-    let mut entries = Vec::new();
-
-    if let Some(parent) = path.parent() {
-        entries.push(FileEntry {
-            name: "..".into(),
-            path: parent.to_path_buf(),
-            is_dir: true,
-            size: 0,
-            modified: None,
-            created: None,
-            is_symlink: false,
-        });
-    }
+    let mut entries: Vec<FileEntry> = parent_entry(path).into_iter().collect();
 
     let mut dirs = Vec::new();
     let mut files = Vec::new();
@@ -586,7 +771,14 @@ pub fn load_dir_entries(
         }
     }
 
-    sort_file_entries(&mut dirs, &mut files, sort_mode, sort_reverse, dir_sizes);
+    sort_file_entries(
+        &mut dirs,
+        &mut files,
+        sort_mode,
+        sort_reverse,
+        case_sensitive,
+        dir_sizes,
+    );
 
     entries.extend(dirs);
     entries.extend(files);
@@ -594,13 +786,16 @@ pub fn load_dir_entries(
 }
 
 /// Sort dirs and files vectors in place according to the given sort mode.
+/// Names compare naturally, and ignore case unless `case_sensitive`.
 pub fn sort_file_entries(
     dirs: &mut Vec<FileEntry>,
     files: &mut Vec<FileEntry>,
     sort_mode: SortMode,
     sort_reverse: bool,
+    case_sensitive: bool,
     dir_sizes: &HashMap<PathBuf, u64>,
 ) {
+    let natsort = name_order(case_sensitive);
     let sort_name = |v: &mut Vec<FileEntry>| {
         v.sort_by(|a, b| natsort(a.name.as_bytes(), b.name.as_bytes()));
     };
@@ -610,16 +805,22 @@ pub fn sort_file_entries(
             sort_name(files);
         }
         SortMode::Size => {
-            dirs.sort_by(|a, b| {
-                let sa = dir_sizes.get(&a.path).copied();
-                let sb = dir_sizes.get(&b.path).copied();
-                match (sa, sb) {
-                    (Some(sa), Some(sb)) => sa.cmp(&sb),
-                    (Some(_), None) => std::cmp::Ordering::Greater,
-                    (None, Some(_)) => std::cmp::Ordering::Less,
-                    (None, None) => std::cmp::Ordering::Equal,
-                }
+            // Directories sort by their measured size; unmeasured ones have
+            // nothing to compare, so they follow by name in either direction.
+            let (mut measured, mut unmeasured): (Vec<_>, Vec<_>) = std::mem::take(dirs)
+                .into_iter()
+                .partition(|d| dir_sizes.contains_key(&d.path));
+            measured.sort_by(|a, b| {
+                dir_sizes[&a.path]
+                    .cmp(&dir_sizes[&b.path])
+                    .then_with(|| natsort(a.name.as_bytes(), b.name.as_bytes()))
             });
+            if sort_reverse {
+                measured.reverse();
+            }
+            sort_name(&mut unmeasured);
+            measured.extend(unmeasured);
+            *dirs = measured;
             files.sort_by_key(|a| a.size);
         }
         SortMode::Modified => {
@@ -653,21 +854,28 @@ pub fn sort_file_entries(
     }
 
     if sort_reverse {
-        dirs.reverse();
+        // Size order already placed its directories.
+        if sort_mode != SortMode::Size {
+            dirs.reverse();
+        }
         files.reverse();
     }
 }
 
-/// Re-sort a flat entries list (which may start with "..") using the given sort mode.
+/// Re-sort a flat entries list (which may start or end with "..") using the
+/// given sort mode. The ".." row stays at whichever end it was.
 pub fn resort_entries(
     entries: &mut Vec<FileEntry>,
     sort_mode: SortMode,
     sort_reverse: bool,
+    case_sensitive: bool,
     dir_sizes: &HashMap<PathBuf, u64>,
 ) {
     // Separate ".." from the rest, then split into dirs/files
-    let has_dotdot = entries.first().is_some_and(|e| e.name == "..");
-    let dotdot = if has_dotdot {
+    let dotdot_last = entries.len() > 1 && entries.last().is_some_and(|e| e.name == "..");
+    let dotdot = if dotdot_last {
+        entries.pop()
+    } else if entries.first().is_some_and(|e| e.name == "..") {
         Some(entries.remove(0))
     } else {
         None
@@ -683,13 +891,23 @@ pub fn resort_entries(
         }
     }
 
-    sort_file_entries(&mut dirs, &mut files, sort_mode, sort_reverse, dir_sizes);
+    sort_file_entries(
+        &mut dirs,
+        &mut files,
+        sort_mode,
+        sort_reverse,
+        case_sensitive,
+        dir_sizes,
+    );
 
-    if let Some(dd) = dotdot {
-        entries.push(dd);
+    if let Some(dd) = dotdot.as_ref().filter(|_| !dotdot_last) {
+        entries.push(dd.clone());
     }
     entries.extend(dirs);
     entries.extend(files);
+    if let Some(dd) = dotdot.filter(|_| dotdot_last) {
+        entries.push(dd);
+    }
 }
 
 pub struct DirCacheEntry {
@@ -787,6 +1005,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             })
             .collect();
         Panel {
@@ -796,12 +1015,16 @@ mod tests {
             offset: 0,
             visual_anchor: None,
             marked: HashSet::new(),
+            mark_anchor: None,
             sort_mode: SortMode::Name,
             sort_reverse: false,
             show_hidden: false,
+            relative_dates: None,
             loading: false,
             filter: String::new(),
+            path_filter: None,
             full_entries: Arc::new(Vec::new()),
+            load_error: None,
         }
     }
 
@@ -894,6 +1117,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             })
             .collect();
         p.apply_entries(shorter.into(), None);
@@ -973,6 +1197,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
         );
         p.selected = 0; // selecting ".."
@@ -994,6 +1219,30 @@ mod tests {
         assert!(!p.marked.contains(&PathBuf::from("/tmp/file_0")));
     }
 
+    #[test]
+    fn mark_to_anchor_fills_the_gap() {
+        let mut p = make_panel_with_entries(10);
+        assert_eq!(p.mark_to_anchor(), None);
+
+        p.selected = 2;
+        p.toggle_mark();
+        p.selected = 5;
+        assert_eq!(p.mark_to_anchor(), Some(3));
+        assert_eq!(p.marked.len(), 4);
+
+        // Upwards, from the new anchor, and skipping what is already marked.
+        p.selected = 4;
+        p.toggle_mark(); // unmarks file_4; the anchor stays on file_5
+        p.selected = 0;
+        assert_eq!(p.mark_to_anchor(), Some(3));
+        assert_eq!(p.marked.len(), 6);
+        assert_eq!(p.mark_anchor, Some(PathBuf::from("/tmp/file_0")));
+
+        // An anchor that isn't in the listing does nothing.
+        p.mark_anchor = Some(PathBuf::from("/elsewhere/x"));
+        assert_eq!(p.mark_to_anchor(), None);
+    }
+
     #[test]
     fn adjust_scroll_follows_cursor() {
         let mut p = make_panel_with_entries(20);
@@ -1019,6 +1268,7 @@ mod tests {
             modified: None,
             created: None,
             is_symlink: false,
+            owner: None,
         }
     }
 
@@ -1037,6 +1287,7 @@ mod tests {
             &mut files,
             SortMode::Name,
             false,
+            false,
             &HashMap::new(),
         );
         assert_eq!(dirs[0].name, "alpha");
@@ -1058,6 +1309,7 @@ mod tests {
             &mut files,
             SortMode::Size,
             false,
+            false,
             &HashMap::new(),
         );
         assert_eq!(files[0].name, "small.txt");
@@ -1065,6 +1317,24 @@ mod tests {
         assert_eq!(files[2].name, "big.txt");
     }
 
+    #[test]
+    fn sort_dirs_by_measured_size() {
+        let names = ["small", "unsized_b", "big", "unsized_a"];
+        let dirs: Vec<FileEntry> = names.iter().map(|n| make_file_entry(n, true, 0)).collect();
+        let sizes: HashMap<PathBuf, u64> = [("/tmp/small", 10), ("/tmp/big", 5000)]
+            .into_iter()
+            .map(|(p, s)| (PathBuf::from(p), s))
+            .collect();
+        let order = |reverse: bool| {
+            let mut d = dirs.clone();
+            sort_file_entries(&mut d, &mut vec![], SortMode::Size, reverse, false, &sizes);
+            d.into_iter().map(|e| e.name).collect::<Vec<_>>()
+        };
+        // Unmeasured directories trail the measured ones either way.
+        assert_eq!(order(false), ["small", "big", "unsized_a", "unsized_b"]);
+        assert_eq!(order(true), ["big", "small", "unsized_a", "unsized_b"]);
+    }
+
     #[test]
     fn sort_by_extension() {
         let mut dirs = vec![];
@@ -1078,6 +1348,7 @@ mod tests {
             &mut files,
             SortMode::Extension,
             false,
+            false,
             &HashMap::new(),
         );
         assert_eq!(files[0].name, "c.go");
@@ -1093,7 +1364,14 @@ mod tests {
             make_file_entry("c.txt", false, 3),
             make_file_entry("b.txt", false, 2),
         ];
-        sort_file_entries(&mut dirs, &mut files, SortMode::Name, true, &HashMap::new());
+        sort_file_entries(
+            &mut dirs,
+            &mut files,
+            SortMode::Name,
+            true,
+            false,
+            &HashMap::new(),
+        );
         assert_eq!(files[0].name, "c.txt");
         assert_eq!(files[1].name, "b.txt");
         assert_eq!(files[2].name, "a.txt");
@@ -1114,6 +1392,7 @@ mod tests {
                 modified: Some(t2),
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
             FileEntry {
                 name: "older.txt".into(),
@@ -1123,6 +1402,7 @@ mod tests {
                 modified: Some(t1),
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
         ];
         sort_file_entries(
@@ -1130,6 +1410,7 @@ mod tests {
             &mut files,
             SortMode::Modified,
             false,
+            false,
             &HashMap::new(),
         );
         assert_eq!(files[0].name, "older.txt");
@@ -1149,25 +1430,53 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
             make_file_entry("z_dir", true, 0),
             make_file_entry("a_dir", true, 0),
             make_file_entry("b.txt", false, 0),
         ];
-        resort_entries(&mut entries, SortMode::Name, false, &HashMap::new());
+        resort_entries(&mut entries, SortMode::Name, false, false, &HashMap::new());
         assert_eq!(entries[0].name, "..");
         assert_eq!(entries[1].name, "a_dir");
         assert_eq!(entries[2].name, "z_dir");
         assert_eq!(entries[3].name, "b.txt");
     }
 
+    #[test]
+    fn parent_entry_moves_to_the_bottom_or_goes() {
+        let listing = || {
+            vec![
+                parent_entry(Path::new("/test")).unwrap(),
+                make_file_entry("b.txt", false, 0),
+                make_file_entry("a_dir", true, 0),
+            ]
+        };
+        let names = |entries: &[FileEntry]| -> Vec<String> {
+            entries.iter().map(|e| e.name.clone()).collect()
+        };
+
+        let mut entries = listing();
+        place_parent_entry(&mut entries, ParentEntry::Bottom);
+        assert_eq!(names(&entries), ["b.txt", "a_dir", ".."]);
+        // A re-sort keeps ".." at the bottom.
+        resort_entries(&mut entries, SortMode::Name, false, false, &HashMap::new());
+        assert_eq!(names(&entries), ["a_dir", "b.txt", ".."]);
+
+        let mut entries = listing();
+        place_parent_entry(&mut entries, ParentEntry::Hidden);
+        assert_eq!(names(&entries), ["b.txt", "a_dir"]);
+        resort_entries(&mut entries, SortMode::Name, false, false, &HashMap::new());
+        assert_eq!(names(&entries), ["a_dir", "b.txt"]);
+    }
+
     #[test]
     fn resort_entries_reorders() {
         let mut entries = vec![
             make_file_entry("b.txt", false, 200),
             make_file_entry("a.txt", false, 100),
         ];
-        resort_entries(&mut entries, SortMode::Size, false, &HashMap::new());
+        resort_entries(&mut entries, SortMode::Size, false, false, &HashMap::new());
         assert_eq!(entries[0].name, "a.txt");
         assert_eq!(entries[1].name, "b.txt");
     }
@@ -1175,7 +1484,7 @@ mod tests {
     #[test]
     fn resort_entries_empty() {
         let mut entries: Vec<FileEntry> = vec![];
-        resort_entries(&mut entries, SortMode::Name, false, &HashMap::new());
+        resort_entries(&mut entries, SortMode::Name, false, false, &HashMap::new());
         assert!(entries.is_empty());
     }
 
@@ -1292,6 +1601,7 @@ mod tests {
                 modified: None,
                 created: Some(t2),
                 is_symlink: false,
+                owner: None,
             },
             FileEntry {
                 name: "older.txt".into(),
@@ -1301,6 +1611,7 @@ mod tests {
                 modified: None,
                 created: Some(t1),
                 is_symlink: false,
+                owner: None,
             },
         ];
         sort_file_entries(
@@ -1308,6 +1619,7 @@ mod tests {
             &mut files,
             SortMode::Created,
             false,
+            false,
             &HashMap::new(),
         );
         assert_eq!(files[0].name, "older.txt");
@@ -1326,6 +1638,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
             FileEntry {
                 name: "a.rs".into(),
@@ -1335,6 +1648,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
             FileEntry {
                 name: "c.go".into(),
@@ -1344,6 +1658,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
         ];
         sort_file_entries(
@@ -1351,6 +1666,7 @@ mod tests {
             &mut files,
             SortMode::Extension,
             false,
+            false,
             &HashMap::new(),
         );
         // go < py < rs
@@ -1371,6 +1687,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
             FileEntry {
                 name: "has_time.txt".into(),
@@ -1380,6 +1697,7 @@ mod tests {
                 modified: Some(std::time::SystemTime::now()),
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
         ];
         // Should not panic with mixed None/Some timestamps
@@ -1388,6 +1706,7 @@ mod tests {
             &mut files,
             SortMode::Modified,
             false,
+            false,
             &HashMap::new(),
         );
         assert_eq!(files.len(), 2);
@@ -1407,6 +1726,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
             FileEntry {
                 name: "a".into(),
@@ -1416,6 +1736,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
         ]);
         panel.selected = 1;
@@ -1434,6 +1755,7 @@ mod tests {
             modified: None,
             created: None,
             is_symlink: false,
+            owner: None,
         }]);
         panel.selected = 0;
         panel.move_up();
@@ -1464,6 +1786,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
             FileEntry {
                 name: "a.txt".into(),
@@ -1473,6 +1796,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
         ]);
         panel.selected = 0; // ".."
@@ -1493,6 +1817,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
             FileEntry {
                 name: "a".into(),
@@ -1502,6 +1827,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
             FileEntry {
                 name: "b".into(),
@@ -1511,6 +1837,7 @@ mod tests {
                 modified: None,
                 created: None,
                 is_symlink: false,
+                owner: None,
             },
         ]);
         panel.marked.insert(PathBuf::from("/a"));
@@ -1561,6 +1888,7 @@ mod tests {
             modified: None,
             created: None,
             is_symlink: false,
+            owner: None,
         }]);
         panel.selected = 0;
         assert_eq!(panel.selected_entry().unwrap().name, "a");
@@ -1584,6 +1912,7 @@ mod tests {
             modified: None,
             created: None,
             is_symlink: false,
+            owner: None,
         };
         let mut panel = Panel::new(PathBuf::from("/tmp"));
         panel.entries = Arc::new(vec![
@@ -1609,6 +1938,37 @@ mod tests {
         assert_eq!(panel.entries.len(), 4);
     }
 
+    #[test]
+    fn path_filter_combines_with_live_filter() {
+        let mk = |name: &str| FileEntry {
+            name: name.into(),
+            path: PathBuf::from(format!("/tmp/{name}")),
+            is_dir: false,
+            size: 0,
+            modified: None,
+            created: None,
+            is_symlink: false,
+            owner: None,
+        };
+        let mut panel = Panel::new(PathBuf::from("/tmp"));
+        panel.entries = Arc::new(vec![mk("a.rs"), mk("b.rs"), mk("c.txt")]);
+        let paths = ["/tmp/a.rs", "/tmp/c.txt"].map(PathBuf::from).into();
+        panel.set_path_filter(Some(PathFilter {
+            label: "m".into(),
+            paths,
+        }));
+        panel.set_filter("rs".into());
+        let names: Vec<&str> = panel.entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["a.rs"]);
+
+        // Dropping the text query keeps the path filter in force.
+        panel.set_filter(String::new());
+        assert_eq!(panel.entries.len(), 2);
+        assert!(panel.clear_filter());
+        assert_eq!(panel.entries.len(), 3);
+        assert!(!panel.clear_filter());
+    }
+
     #[test]
     fn load_dir_real_filesystem() {
         let dir = std::env::temp_dir().join("fcmd_panel_test");
@@ -1634,4 +1994,34 @@ mod tests {
         // Cleanup
         let _ = fs::remove_dir_all(&dir);
     }
+
+    #[test]
+    fn stream_reports_unreadable_directory() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+        let req = DirLoadRequest {
+            path: tmp.path().join("gone"),
+            show_hidden: false,
+            sort_mode: SortMode::Name,
+            sort_reverse: false,
+            case_sensitive: false,
+            parent_entry: ParentEntry::Top,
+            dir_sizes: Arc::new(HashMap::new()),
+            panel_idx: 0,
+            tab_index: 0,
+            select_name: None,
+        };
+        stream_dir_entries(req, &tx);
+        let Some(crate::app::DirLoadMsg::Finished { entries, error, .. }) = rx.blocking_recv()
+        else {
+            panic!("expected Finished");
+        };
+        // Only ".." remains, so the user can step back out.
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "..");
+        assert_eq!(error.as_deref(), Some("Directory not found"));
+
+        let denied = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        assert_eq!(load_error_text(&denied), "Permission denied");
+    }
 }
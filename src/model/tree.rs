@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::fs;
 use std::path::{Component, Path, PathBuf};
 
+#[derive(Clone)]
 pub struct TreeLine {
     pub prefix: String,
     pub name: String,
@@ -11,6 +12,12 @@ pub struct TreeLine {
     pub is_on_path: bool,
     pub is_expanded: bool,
     pub depth: usize,
+    /// Directory that couldn't be listed (permission denied, …) when it was
+    /// expanded; drawn with a lock. A collapsed directory isn't read, so it
+    /// only shows once opened.
+    pub unreadable: bool,
+    /// Symlinks only: whether the target lies outside the tree's root.
+    pub link_escapes: Option<bool>,
 }
 
 /// Build a flat list of tree lines from `root` down to `current`,
@@ -40,6 +47,8 @@ pub fn build_tree(
         is_on_path: true,
         is_expanded: true,
         depth: 0,
+        unreadable: false,
+        link_escapes: None,
     });
 
     let rel_components: Vec<String> = match current.strip_prefix(root) {
@@ -56,7 +65,7 @@ pub fn build_tree(
         Err(_) => return lines,
     };
 
-    expand_dir(
+    lines[0].unreadable = !expand_dir(
         root,
         &rel_components,
         &mut lines,
@@ -66,9 +75,18 @@ pub fn build_tree(
         expanded,
     );
 
+    // Only symlinks need resolving, against the root resolved once.
+    let real_root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+    for line in &mut lines {
+        if let Some(escapes) = line.link_escapes.as_mut() {
+            *escapes = crate::fs::link_escapes(&line.path, &real_root);
+        }
+    }
+
     lines
 }
 
+/// Push the lines under `dir`. Returns false if it can't be listed.
 fn expand_dir(
     dir: &Path,
     path_ahead: &[String],
@@ -77,27 +95,28 @@ fn expand_dir(
     show_hidden: bool,
     collapsed: &HashSet<PathBuf>,
     expanded: &HashSet<PathBuf>,
-) {
+) -> bool {
     let mut subdirs: Vec<(String, PathBuf, bool)> = Vec::new(); // (name, path, is_symlink)
-    let mut files: Vec<(String, PathBuf)> = Vec::new();
-
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            let is_dir = entry.metadata().map(|m| m.is_dir()).unwrap_or(false);
-            let is_symlink = entry
-                .path()
-                .symlink_metadata()
-                .map(|m| m.is_symlink())
-                .unwrap_or(false);
-            let name = entry.file_name().to_string_lossy().into_owned();
-            if !show_hidden && name.starts_with('.') {
-                continue;
-            }
-            if is_dir {
-                subdirs.push((name, entry.path(), is_symlink));
-            } else {
-                files.push((name, entry.path()));
-            }
+    let mut files: Vec<(String, PathBuf, bool)> = Vec::new();
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return false;
+    };
+    for entry in entries.flatten() {
+        let is_dir = entry.metadata().map(|m| m.is_dir()).unwrap_or(false);
+        let is_symlink = entry
+            .path()
+            .symlink_metadata()
+            .map(|m| m.is_symlink())
+            .unwrap_or(false);
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !show_hidden && name.starts_with('.') {
+            continue;
+        }
+        if is_dir {
+            subdirs.push((name, entry.path(), is_symlink));
+        } else {
+            files.push((name, entry.path(), is_symlink));
         }
     }
     subdirs.sort_by_key(|a| a.0.to_lowercase());
@@ -106,7 +125,7 @@ fn expand_dir(
     // Combined: dirs first, then files — needed for correct "is_last" connectors
     let total = subdirs.len() + files.len();
     if total == 0 {
-        return;
+        return true;
     }
 
     let target = path_ahead.first();
@@ -132,37 +151,33 @@ fn expand_dir(
             is_on_path: on_path,
             is_expanded: should_expand,
             depth: connector_state.len() + 1,
+            unreadable: false,
+            link_escapes: is_symlink.then_some(false),
         });
+        let line = lines.len() - 1;
 
         // Expand dirs that should be expanded, but skip symlinks to prevent cycles
         if should_expand && !is_symlink {
             let mut next_connectors = connector_state.to_vec();
             next_connectors.push(!is_last);
-            if on_path && path_ahead.len() > 1 {
-                expand_dir(
-                    path,
-                    &path_ahead[1..],
-                    lines,
-                    &next_connectors,
-                    show_hidden,
-                    collapsed,
-                    expanded,
-                );
+            let ahead = if on_path && path_ahead.len() > 1 {
+                &path_ahead[1..]
             } else {
-                expand_dir(
-                    path,
-                    &[],
-                    lines,
-                    &next_connectors,
-                    show_hidden,
-                    collapsed,
-                    expanded,
-                );
-            }
+                &[]
+            };
+            lines[line].unreadable = !expand_dir(
+                path,
+                ahead,
+                lines,
+                &next_connectors,
+                show_hidden,
+                collapsed,
+                expanded,
+            );
         }
     }
 
-    for (name, path) in &files {
+    for (name, path, is_symlink) in &files {
         idx += 1;
         let is_last = idx == total;
         let prefix = make_prefix(connector_state, is_last);
@@ -176,8 +191,61 @@ fn expand_dir(
             is_on_path: false,
             is_expanded: false,
             depth: connector_state.len() + 1,
+            unreadable: false,
+            link_escapes: is_symlink.then_some(false),
         });
     }
+    true
+}
+
+/// Whether a tree name matches a filter query: a case-insensitive fuzzy match
+/// against the lowercased `query` chars, so a plain substring matches too.
+pub fn name_matches(name: &str, query: &[char]) -> bool {
+    let lower = name.to_lowercase();
+    crate::search::fuzzy_score_pre(query, &lower, lower.chars().count()).is_some()
+}
+
+/// The lines whose name matches `query`, with the directories leading to
+/// them kept for context and the connectors redrawn for what remains. The
+/// root always stays.
+pub fn filter_tree(lines: &[TreeLine], query: &str) -> Vec<TreeLine> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let mut keep = vec![false; lines.len()];
+    // ancestors[d]: the latest line at depth d, on the way to the current one.
+    let mut ancestors: Vec<usize> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        ancestors.truncate(line.depth);
+        if line.depth == 0 || name_matches(&line.name, &query) {
+            keep[i] = true;
+            for &a in &ancestors {
+                keep[a] = true;
+            }
+        }
+        ancestors.push(i);
+    }
+
+    let kept: Vec<&TreeLine> = lines
+        .iter()
+        .zip(keep)
+        .filter_map(|(l, k)| k.then_some(l))
+        .collect();
+    // open[d]: a line at depth d still has a sibling below it.
+    let mut open: Vec<bool> = Vec::new();
+    let mut out = Vec::with_capacity(kept.len());
+    for (k, line) in kept.iter().enumerate() {
+        let mut line = (*line).clone();
+        if line.depth > 0 {
+            let is_last = kept[k + 1..]
+                .iter()
+                .find(|l| l.depth <= line.depth)
+                .is_none_or(|l| l.depth < line.depth);
+            open.resize(line.depth + 1, false);
+            open[line.depth] = !is_last;
+            line.prefix = make_prefix(&open[1..line.depth], is_last);
+        }
+        out.push(line);
+    }
+    out
 }
 
 fn make_prefix(connector_state: &[bool], is_last: bool) -> String {
@@ -226,6 +294,17 @@ mod tests {
         assert!(lines[0].is_current);
         assert!(lines[0].is_dir);
         assert_eq!(lines[0].depth, 0);
+        assert!(!lines[0].unreadable);
+        assert!(lines.iter().all(|l| !l.unreadable));
+    }
+
+    #[test]
+    fn build_tree_marks_unreadable_root() {
+        let tmp = tempfile::tempdir().unwrap();
+        let gone = tmp.path().join("gone");
+        let lines = build_tree(&gone, &gone, false, &HashSet::new(), &HashSet::new());
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].unreadable);
     }
 
     #[test]
@@ -325,4 +404,39 @@ mod tests {
             }
         }
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn build_tree_flags_symlinks_leaving_the_root() {
+        use std::os::unix::fs::symlink;
+        let tmp = setup_tree_dir();
+        let root = tmp.path();
+        symlink("alpha/nested", root.join("near")).unwrap();
+        symlink("/", root.join("far")).unwrap();
+        let lines = build_tree(root, root, false, &HashSet::new(), &HashSet::new());
+        let escapes = |name: &str| lines.iter().find(|l| l.name == name).unwrap().link_escapes;
+        assert_eq!(escapes("near"), Some(false));
+        assert_eq!(escapes("far"), Some(true));
+        assert_eq!(escapes("file.txt"), None);
+    }
+
+    #[test]
+    fn filter_tree_keeps_matches_and_their_directories() {
+        let tmp = setup_tree_dir();
+        let root = tmp.path();
+        let expanded: HashSet<PathBuf> = [root.join("alpha"), root.join("beta")].into();
+        let lines = build_tree(root, root, false, &HashSet::new(), &expanded);
+
+        let filtered = filter_tree(&lines, "NEST");
+        let names: Vec<&str> = filtered.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(names[1..], ["alpha", "nested"]);
+        assert_eq!(filtered[1].prefix, "└─ ");
+        assert_eq!(filtered[2].prefix, "   └─ ");
+
+        // Fuzzy: "btx" finds b.txt under beta; "zzz" leaves only the root.
+        let filtered = filter_tree(&lines, "btx");
+        let names: Vec<&str> = filtered.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(names[1..], ["beta", "b.txt"]);
+        assert_eq!(filter_tree(&lines, "zzz").len(), 1);
+    }
 }
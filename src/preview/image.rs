@@ -0,0 +1,375 @@
+//! Image previews: the pixel size of a PNG, JPEG, GIF or WebP, read from its
+//! header, and the kitty graphics protocol sequences that draw it in the
+//! preview pane. The picture is decoded to RGBA and uploaded to the terminal
+//! once per preview under an image id; showing it again after a popup only
+//! places that id, so nothing is re-encoded on the UI thread.
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use base64::Engine;
+
+/// Largest image file decoded for drawing; a bigger one previews as text.
+const MAX_DRAWN_BYTES: u64 = 32 * 1_048_576; // 32 MB
+
+/// Largest picture decoded for drawing, in pixels, so a small file that
+/// unpacks to gigabytes of RGBA previews as text.
+const MAX_DRAWN_PIXELS: u64 = 64 * 1024 * 1024;
+
+/// Longest side, in pixels, of the picture sent to the terminal; a bigger one
+/// is scaled down first. The pane is never wider than this on any display.
+const MAX_SENT_SIDE: u32 = 1280;
+
+/// Header bytes read to find the dimensions. A JPEG's frame header comes
+/// after its EXIF block, which can hold a thumbnail of up to 64 KB.
+const HEAD_BYTES: u64 = 256 * 1024;
+
+/// Base64 bytes per graphics escape; the protocol's limit.
+const CHUNK: usize = 4096;
+
+/// Deletes every image on screen and frees its data.
+pub const CLEAR_SEQUENCE: &[u8] = b"\x1b_Ga=d,d=A,q=2\x1b\\";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+    WebP,
+}
+
+impl ImageFormat {
+    /// Detect by extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "png" => Some(ImageFormat::Png),
+            "jpg" | "jpeg" => Some(ImageFormat::Jpeg),
+            "gif" => Some(ImageFormat::Gif),
+            "webp" => Some(ImageFormat::WebP),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "PNG",
+            ImageFormat::Jpeg => "JPEG",
+            ImageFormat::Gif => "GIF",
+            ImageFormat::WebP => "WebP",
+        }
+    }
+}
+
+pub struct ImageInfo {
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+    /// The picture ready to send, when the terminal can draw it.
+    pub upload: Option<Upload>,
+}
+
+/// A decoded picture as the escapes that upload it, built while the preview
+/// loads in the background. The terminal keeps it under `id` until freed.
+pub struct Upload {
+    pub id: u32,
+    pub sequence: Vec<u8>,
+}
+
+/// Whether the terminal speaks the kitty graphics protocol: kitty sets
+/// `$KITTY_WINDOW_ID`, and `$TERM` to `xterm-kitty`.
+pub fn graphics_supported() -> bool {
+    static SUPPORTED: OnceLock<bool> = OnceLock::new();
+    *SUPPORTED.get_or_init(|| {
+        std::env::var_os("KITTY_WINDOW_ID").is_some()
+            || std::env::var("TERM").is_ok_and(|t| t.contains("kitty"))
+    })
+}
+
+/// Read the image at `path` (`size` bytes long): `None` if its extension is
+/// not an image's or its header doesn't give the dimensions.
+pub fn probe(path: &Path, size: u64) -> Option<ImageInfo> {
+    let format = ImageFormat::from_path(path)?;
+    let mut file = File::open(path).ok()?;
+    let mut bytes = Vec::new();
+    (&mut file).take(HEAD_BYTES).read_to_end(&mut bytes).ok()?;
+    let (width, height) = dimensions(format, &bytes)?;
+    let drawn = size <= MAX_DRAWN_BYTES
+        && u64::from(width) * u64::from(height) <= MAX_DRAWN_PIXELS
+        && graphics_supported();
+    let upload = drawn
+        .then(|| {
+            file.read_to_end(&mut bytes)
+                .ok()
+                .and_then(|_| decode(format, &bytes))
+        })
+        .flatten();
+    Some(ImageInfo {
+        format,
+        width,
+        height,
+        upload,
+    })
+}
+
+/// Decode the whole file, scale it down to [`MAX_SENT_SIDE`] and build its
+/// upload under a new id.
+fn decode(format: ImageFormat, bytes: &[u8]) -> Option<Upload> {
+    let format = match format {
+        ImageFormat::Png => image::ImageFormat::Png,
+        ImageFormat::Jpeg => image::ImageFormat::Jpeg,
+        ImageFormat::Gif => image::ImageFormat::Gif,
+        ImageFormat::WebP => image::ImageFormat::WebP,
+    };
+    let mut picture = image::load_from_memory_with_format(bytes, format).ok()?;
+    if picture.width() > MAX_SENT_SIDE || picture.height() > MAX_SENT_SIDE {
+        picture = picture.thumbnail(MAX_SENT_SIDE, MAX_SENT_SIDE);
+    }
+    let rgba = picture.into_rgba8();
+    static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    Some(Upload {
+        id,
+        sequence: upload_sequence(id, rgba.width(), rgba.height(), rgba.as_raw()),
+    })
+}
+
+/// Width and height in pixels from the start of the file.
+fn dimensions(format: ImageFormat, b: &[u8]) -> Option<(u32, u32)> {
+    let be16 = |i: usize| Some(u16::from_be_bytes(b.get(i..i + 2)?.try_into().ok()?) as u32);
+    let le16 = |i: usize| Some(u16::from_le_bytes(b.get(i..i + 2)?.try_into().ok()?) as u32);
+    let le24 = |i: usize| {
+        let s = b.get(i..i + 3)?;
+        Some(u32::from_le_bytes([s[0], s[1], s[2], 0]))
+    };
+    let (w, h) = match format {
+        ImageFormat::Png => {
+            if !b.starts_with(b"\x89PNG\r\n\x1a\n") || b.get(12..16)? != b"IHDR" {
+                return None;
+            }
+            let be32 = |i: usize| Some(u32::from_be_bytes(b.get(i..i + 4)?.try_into().ok()?));
+            (be32(16)?, be32(20)?)
+        }
+        ImageFormat::Gif => {
+            if !b.starts_with(b"GIF87a") && !b.starts_with(b"GIF89a") {
+                return None;
+            }
+            (le16(6)?, le16(8)?)
+        }
+        ImageFormat::WebP => {
+            if !b.starts_with(b"RIFF") || b.get(8..12)? != b"WEBP" {
+                return None;
+            }
+            match b.get(12..16)? {
+                b"VP8 " if b.get(23..26)? == [0x9d, 0x01, 0x2a] => {
+                    (le16(26)? & 0x3fff, le16(28)? & 0x3fff)
+                }
+                b"VP8L" if *b.get(20)? == 0x2f => {
+                    let bits = u32::from_le_bytes(b.get(21..25)?.try_into().ok()?);
+                    ((bits & 0x3fff) + 1, ((bits >> 14) & 0x3fff) + 1)
+                }
+                b"VP8X" => (le24(24)? + 1, le24(27)? + 1),
+                _ => return None,
+            }
+        }
+        ImageFormat::Jpeg => {
+            if !b.starts_with(&[0xff, 0xd8]) {
+                return None;
+            }
+            let mut i = 2;
+            loop {
+                while *b.get(i)? == 0xff && *b.get(i + 1)? == 0xff {
+                    i += 1; // fill bytes
+                }
+                if *b.get(i)? != 0xff {
+                    return None;
+                }
+                match *b.get(i + 1)? {
+                    // Start of frame; C4, C8 and CC share the range but aren't.
+                    0xc0..=0xcf if !matches!(b[i + 1], 0xc4 | 0xc8 | 0xcc) => {
+                        break (be16(i + 7)?, be16(i + 5)?);
+                    }
+                    // Markers without a length.
+                    0x01 | 0xd0..=0xd8 => i += 2,
+                    _ => i += 2 + be16(i + 2)? as usize,
+                }
+            }
+        }
+    };
+    (w > 0 && h > 0).then_some((w, h))
+}
+
+/// A cell's size in pixels, from the terminal; 8×16 if it doesn't say.
+pub fn cell_size() -> (u16, u16) {
+    match crossterm::terminal::window_size() {
+        Ok(s) if s.width > 0 && s.height > 0 && s.columns > 0 && s.rows > 0 => {
+            (s.width / s.columns, s.height / s.rows)
+        }
+        _ => (8, 16),
+    }
+}
+
+/// The columns and rows a `width`×`height` pixel image takes in a
+/// `cols`×`rows` area: shrunk to fit with its aspect ratio kept, never
+/// enlarged past its own size.
+pub fn fit(width: u32, height: u32, cols: u16, rows: u16, cell: (u16, u16)) -> (u16, u16) {
+    let (cw, ch) = (f64::from(cell.0.max(1)), f64::from(cell.1.max(1)));
+    let (w, h) = (f64::from(width.max(1)), f64::from(height.max(1)));
+    let scale = (f64::from(cols) * cw / w)
+        .min(f64::from(rows) * ch / h)
+        .min(1.0);
+    let fit_cols = ((w * scale / cw).ceil() as u16).clamp(1, cols.max(1));
+    let fit_rows = ((h * scale / ch).ceil() as u16).clamp(1, rows.max(1));
+    (fit_cols, fit_rows)
+}
+
+/// The escapes that upload `width`×`height` RGBA pixels as image `id`,
+/// zlib-compressed, without showing it. `q=2` keeps the terminal's replies
+/// out of the key input.
+fn upload_sequence(id: u32, width: u32, height: u32, rgba: &[u8]) -> Vec<u8> {
+    let mut zlib = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::fast());
+    let compressed = zlib.write_all(rgba).and_then(|()| zlib.finish());
+    let data = base64::engine::general_purpose::STANDARD.encode(compressed.unwrap_or_default());
+    let mut out = Vec::with_capacity(data.len() + data.len() / CHUNK * 16 + 64);
+    let count = data.len().div_ceil(CHUNK);
+    for (i, chunk) in data.as_bytes().chunks(CHUNK).enumerate() {
+        let more = u8::from(i + 1 < count);
+        let keys = if i == 0 {
+            format!("a=t,f=32,s={width},v={height},o=z,t=d,i={id},q=2,m={more}")
+        } else {
+            format!("m={more}")
+        };
+        out.extend_from_slice(format!("\x1b_G{keys};").as_bytes());
+        out.extend_from_slice(chunk);
+        out.extend_from_slice(b"\x1b\\");
+    }
+    out
+}
+
+/// The escape that shows uploaded image `id` with its top-left cell at
+/// column `x`, row `y` (0-based), scaled to `cols`×`rows` cells. `C=1` leaves
+/// the cursor where it is.
+pub fn place_sequence(id: u32, x: u16, y: u16, cols: u16, rows: u16) -> Vec<u8> {
+    format!(
+        "\x1b[{};{}H\x1b_Ga=p,i={id},c={cols},r={rows},C=1,q=2\x1b\\",
+        y + 1,
+        x + 1
+    )
+    .into_bytes()
+}
+
+/// Takes image `id` off the screen; the terminal keeps its data for the next
+/// [`place_sequence`].
+pub fn hide_sequence(id: u32) -> Vec<u8> {
+    format!("\x1b_Ga=d,d=i,i={id},q=2\x1b\\").into_bytes()
+}
+
+/// Takes image `id` off the screen and frees its data.
+pub fn free_sequence(id: u32) -> Vec<u8> {
+    format!("\x1b_Ga=d,d=I,i={id},q=2\x1b\\").into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png_header(w: u32, h: u32) -> Vec<u8> {
+        let mut b = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        b.extend(w.to_be_bytes());
+        b.extend(h.to_be_bytes());
+        b
+    }
+
+    #[test]
+    fn reads_dimensions_from_each_header() {
+        assert_eq!(
+            dimensions(ImageFormat::Png, &png_header(640, 480)),
+            Some((640, 480))
+        );
+        assert_eq!(
+            dimensions(ImageFormat::Png, b"not a png at all, really"),
+            None
+        );
+
+        let gif = b"GIF89a\x20\x03\x58\x02".to_vec();
+        assert_eq!(dimensions(ImageFormat::Gif, &gif), Some((800, 600)));
+
+        // SOI, an APP0 segment to skip, then a baseline frame header.
+        let mut jpeg = vec![0xff, 0xd8, 0xff, 0xe0, 0x00, 0x04, 0, 0];
+        jpeg.extend([0xff, 0xc0, 0x00, 0x11, 0x08, 0x01, 0xe0, 0x02, 0x80]);
+        assert_eq!(dimensions(ImageFormat::Jpeg, &jpeg), Some((640, 480)));
+
+        let mut webp = b"RIFF\0\0\0\0WEBPVP8X\x0a\0\0\0\0\0\0\0".to_vec();
+        webp.extend([0x7f, 0x02, 0x00, 0xdf, 0x01, 0x00]);
+        assert_eq!(dimensions(ImageFormat::WebP, &webp), Some((640, 480)));
+    }
+
+    #[test]
+    fn probe_goes_by_extension_and_header() {
+        let dir = tempfile::tempdir().unwrap();
+        let png = dir.path().join("shot.PNG");
+        std::fs::write(&png, png_header(2, 3)).unwrap();
+        let info = probe(&png, 24).unwrap();
+        assert_eq!(
+            (info.format, info.width, info.height),
+            (ImageFormat::Png, 2, 3)
+        );
+
+        let txt = dir.path().join("shot.txt");
+        std::fs::write(&txt, png_header(2, 3)).unwrap();
+        assert!(probe(&txt, 24).is_none());
+    }
+
+    #[test]
+    fn fit_keeps_the_aspect_ratio_and_never_enlarges() {
+        // 1600×800 px in 40×40 cells of 10×20 px: width-bound, half the rows.
+        assert_eq!(fit(1600, 800, 40, 40, (10, 20)), (40, 10));
+        // Tall image: height-bound.
+        assert_eq!(fit(400, 1600, 40, 20, (10, 20)), (10, 20));
+        // Small image stays its own size.
+        assert_eq!(fit(50, 40, 40, 20, (10, 20)), (5, 2));
+    }
+
+    #[test]
+    fn upload_sequence_chunks_the_data() {
+        // Noise (xorshift), so zlib can't shrink it below one chunk.
+        let mut x = 0x2545_f491_u32;
+        let rgba: Vec<u8> = (0..CHUNK * 2)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 17;
+                x ^= x << 5;
+                x as u8
+            })
+            .collect();
+        let seq = String::from_utf8(upload_sequence(7, 64, 32, &rgba)).unwrap();
+        assert!(seq.starts_with("\x1b_Ga=t,f=32,s=64,v=32,o=z,t=d,i=7,q=2,m=1;"));
+        assert!(seq.matches("\x1b_G").count() >= 2);
+        assert!(seq.contains("\x1b_Gm=0;"));
+        assert!(seq.ends_with("\x1b\\"));
+
+        let place = String::from_utf8(place_sequence(7, 4, 1, 10, 5)).unwrap();
+        assert_eq!(place, "\x1b[2;5H\x1b_Ga=p,i=7,c=10,r=5,C=1,q=2\x1b\\");
+    }
+
+    #[test]
+    fn decode_scales_every_format_down_to_rgba() {
+        let picture = image::RgbImage::from_pixel(MAX_SENT_SIDE * 2, 4, image::Rgb([200, 0, 0]));
+        for (format, out) in [
+            (ImageFormat::Png, image::ImageFormat::Png),
+            (ImageFormat::Jpeg, image::ImageFormat::Jpeg),
+            (ImageFormat::Gif, image::ImageFormat::Gif),
+        ] {
+            let mut bytes = std::io::Cursor::new(Vec::new());
+            picture.write_to(&mut bytes, out).unwrap();
+            let upload = decode(format, bytes.get_ref()).expect("decodes");
+            let seq = String::from_utf8(upload.sequence).unwrap();
+            let keys = format!("s={MAX_SENT_SIDE},v=2,o=z,t=d,i={},", upload.id);
+            assert!(seq.contains(&keys), "{format:?}: {}", &seq[..60]);
+        }
+        assert!(decode(ImageFormat::WebP, b"RIFF\0\0\0\0WEBPVP8 junk").is_none());
+    }
+}
@@ -1,6 +1,9 @@
 use std::fs;
 use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
 use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+pub mod image;
 
 /// Display title for `path`: its file name, or the full path when it has none.
 fn title_of(path: &Path) -> String {
@@ -32,6 +35,19 @@ fn sanitize_line(line: &str) -> String {
     out
 }
 
+/// Split `line` into rows of at most [`MAX_LINE_CHARS`] chars. A short line is
+/// returned whole (an empty line stays one empty row).
+fn segments(line: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut rest = line;
+    while let Some((cut, _)) = rest.char_indices().nth(MAX_LINE_CHARS) {
+        out.push(&rest[..cut]);
+        rest = &rest[cut..];
+    }
+    out.push(rest);
+    out
+}
+
 /// Extract printable ASCII and UTF-16LE strings of at least `min_len` chars from
 /// `bytes`, returning at most `max` lines formatted as `<offset:08x>  <text>` in
 /// ascending offset order.
@@ -90,7 +106,12 @@ fn extract_strings(bytes: &[u8], min_len: usize, max: usize) -> Vec<String> {
 }
 
 pub const MAX_LINES: usize = 50_000;
+/// Bytes the full preview reads; a longer file is cut off with a notice.
 const MAX_FILE_SIZE: u64 = 50 * 1_048_576; // 50 MB
+
+/// Longest row a preview keeps in one piece. Longer lines (minified JS, one-line
+/// dumps) are split into segments of this many chars, so no row is costly to draw.
+pub const MAX_LINE_CHARS: usize = 4096;
 pub const HEX_DUMP_MAX: usize = 262_144; // 256 KB
 
 /// Maximum bytes scanned when extracting printable strings (`:strings`).
@@ -99,6 +120,19 @@ pub const STRINGS_MAX: usize = 16 * 1_048_576; // 16 MB
 /// Minimum run length for an extracted string (matches `strings(1)` default).
 const STRINGS_MIN_LEN: usize = 4;
 
+/// Lines of a preview that get syntax colors; the rest stay plain, so a huge
+/// source file costs no more to open than a short one.
+const HL_LINES: usize = 2000;
+
+/// Most entries an archive preview lists; the rest are summarized.
+pub const ARCHIVE_MAX_ENTRIES: usize = 1000;
+
+/// Most entries an archive preview reads to count files and unpacked size.
+const ARCHIVE_SCAN_ENTRIES: usize = 20_000;
+
+/// Most of a tar stream an archive preview unpacks before it stops counting.
+const ARCHIVE_SCAN_BYTES: u64 = 256 * 1_048_576; // 256 MB
+
 /// Bytes per hex row (offset granularity for binary scroll positions).
 pub const HEX_COLS: usize = 16;
 
@@ -125,6 +159,13 @@ pub struct Preview {
     /// Raw bytes backing the hex dump (binary mode only); empty otherwise. Rows
     /// are colored per-byte from this window rather than from `lines`.
     pub hex_bytes: Vec<u8>,
+    /// Set for an image: `lines` then describe it (format, pixel size, file
+    /// size) and show without line numbers, or give way to the picture itself
+    /// when the terminal can draw it.
+    pub image: Option<image::ImageInfo>,
+    /// Syntax colors for the first [`HL_LINES`] lines of a source file, set
+    /// by [`Preview::highlight_syntax`]; `None` renders in the plain color.
+    pub highlight: Option<crate::viewer::HlCache>,
 }
 
 impl Preview {
@@ -140,6 +181,8 @@ impl Preview {
             is_binary: false,
             binary_size: 0,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         }
     }
 
@@ -161,6 +204,12 @@ impl Preview {
     }
 
     pub fn load(path: &Path, max_lines: usize) -> Self {
+        Self::load_cancellable(path, max_lines, &AtomicBool::new(false))
+    }
+
+    /// [`Preview::load`], giving up on an archive listing once `cancel` is
+    /// set (the cursor has moved on).
+    pub fn load_cancellable(path: &Path, max_lines: usize, cancel: &AtomicBool) -> Self {
         let title = title_of(path);
 
         if path.is_dir() {
@@ -172,9 +221,14 @@ impl Preview {
             Err(_) => return Self::read_error(title),
         };
 
-        if meta.len() > MAX_FILE_SIZE {
-            let info = format!("{} bytes", meta.len());
-            return Self::message(title, &format!("[Too large: {} bytes]", meta.len()), &info);
+        if let Some(preview) = Self::load_image(path, &title, meta.len()) {
+            return preview;
+        }
+
+        // Listing streams the archive rather than reading it in, so this goes
+        // before the size cap. Unreadable "archives" fall through to text/hex.
+        if let Some(preview) = Self::load_archive(path, &title, max_lines, cancel) {
+            return preview;
         }
 
         let file_size = meta.len() as usize;
@@ -286,7 +340,10 @@ impl Preview {
     }
 
     fn load_full(path: &Path, title: String, file_size: usize) -> Self {
-        match fs::read(path) {
+        let mut bytes = Vec::new();
+        let read = fs::File::open(path)
+            .and_then(|f| f.take(MAX_FILE_SIZE).read_to_end(&mut bytes).map(|_| bytes));
+        match read {
             Ok(bytes) => {
                 // Check sample for binary content: NUL bytes or high ratio of control chars
                 let sample = &bytes[..bytes.len().min(8192)];
@@ -299,17 +356,40 @@ impl Preview {
                     return Preview::load_binary(&bytes, title, file_size);
                 }
 
-                Self::text_preview(&bytes, title)
+                Self::text_preview(&bytes, title, file_size)
             }
             Err(_) => Self::read_error(title),
         }
     }
 
-    /// Decode `bytes` as UTF-8 (lossy) text, sanitize, and cap at `MAX_LINES`.
-    fn text_preview(bytes: &[u8], title: String) -> Self {
+    /// Decode `bytes` (the first part of a `file_size`-byte file) as UTF-8 (lossy)
+    /// text, sanitize, split over-long lines and cap at `MAX_LINES` rows. A file
+    /// cut short by [`MAX_FILE_SIZE`] ends with a notice row.
+    fn text_preview(bytes: &[u8], title: String, file_size: usize) -> Self {
         let text = String::from_utf8_lossy(bytes);
-        let lines: Vec<String> = text.lines().take(MAX_LINES).map(sanitize_line).collect();
-        let info = format!("{} lines", lines.len());
+        let mut lines = Vec::new();
+        let mut split = false;
+        for line in text.lines() {
+            if lines.len() >= MAX_LINES {
+                break;
+            }
+            let parts = segments(line);
+            split |= parts.len() > 1;
+            lines.extend(parts.into_iter().map(sanitize_line));
+        }
+        lines.truncate(MAX_LINES);
+        // Only a capped preview pays for a second pass to count the rest.
+        let total = (lines.len() == MAX_LINES)
+            .then(|| text.lines().map(|l| segments(l).len()).sum::<usize>());
+        let mut info = Self::text_info(lines.len(), total, false, file_size);
+        if split {
+            info.push_str(" \u{b7} long lines split");
+        }
+        if file_size > bytes.len() {
+            let shown = crate::util::format_bytes(bytes.len() as u64);
+            let size = crate::util::format_bytes(file_size as u64);
+            lines.push(format!("[preview truncated: first {shown} of {size}]"));
+        }
         Self::text(title, info, lines)
     }
 
@@ -436,6 +516,19 @@ impl Preview {
         }
     }
 
+    /// Info for a text file: line count and size, e.g. `1423 lines · 58.0K`.
+    /// `total` is the file's full line count when the preview stopped short of
+    /// it; `more` marks a partial read whose total isn't known.
+    fn text_info(count: usize, total: Option<usize>, more: bool, size: usize) -> String {
+        let size = crate::util::format_bytes(size as u64);
+        match total {
+            Some(total) if total > count => {
+                format!("first {count} lines of {total} \u{b7} {size}")
+            }
+            _ => format!("{} \u{b7} {size}", Self::lines_info(count, more)),
+        }
+    }
+
     fn load_partial(path: &Path, title: String, file_size: usize, max_lines: usize) -> Self {
         let mut file = match fs::File::open(path) {
             Ok(f) => f,
@@ -464,18 +557,29 @@ impl Preview {
             return Self::load_binary(&bytes, title, file_size);
         }
 
-        // Text: seek back, read only max_lines via BufReader
-        let _ = file.seek(SeekFrom::Start(0));
-        let reader = BufReader::new(file);
+        // Text: seek back and read only max_lines, each line's read capped, so a
+        // newline-free multi-gigabyte file costs no more than a short one.
+        if file.seek(SeekFrom::Start(0)).is_err() {
+            return Self::read_error(title);
+        }
+        let (read, next_byte) = match Self::read_lines_from(file, 0, max_lines) {
+            Ok(r) => r,
+            Err(_) => return Self::read_error(title),
+        };
+        let mut split = false;
         let mut lines = Vec::with_capacity(max_lines);
-        for line_result in reader.lines().take(max_lines) {
-            match line_result {
-                Ok(line) => lines.push(sanitize_line(&line)),
-                Err(_) => break,
-            }
+        for line in &read {
+            let parts = segments(line);
+            split |= parts.len() > 1;
+            lines.extend(parts.into_iter().map(str::to_string));
         }
+        let more = next_byte.is_some() || lines.len() > max_lines;
+        lines.truncate(max_lines);
 
-        let info = Self::lines_info(lines.len(), lines.len() >= max_lines);
+        let mut info = Self::text_info(lines.len(), None, more, file_size);
+        if split {
+            info.push_str(" \u{b7} long lines split");
+        }
         Self::text(title, info, lines)
     }
 
@@ -501,6 +605,91 @@ impl Preview {
         }
     }
 
+    /// List an archive's entries (size, then path) instead of its bytes, at
+    /// most `max_lines` / [`ARCHIVE_MAX_ENTRIES`] of them. Counting stops at
+    /// [`ARCHIVE_SCAN_ENTRIES`] entries or [`ARCHIVE_SCAN_BYTES`] unpacked, or
+    /// when `cancel` is set, and the totals then say they are partial. `None`
+    /// when `path` isn't an archive or can't be listed.
+    fn load_archive(
+        path: &Path,
+        title: &str,
+        max_lines: usize,
+        cancel: &AtomicBool,
+    ) -> Option<Self> {
+        let format = crate::archive::ArchiveFormat::detect(path)?;
+        let limit = crate::archive::ListLimit {
+            max_entries: ARCHIVE_SCAN_ENTRIES,
+            max_bytes: ARCHIVE_SCAN_BYTES,
+            cancel,
+        };
+        let (_, entries, complete) = crate::archive::peek_archive(path, &limit).ok()?;
+        let files = entries.iter().filter(|e| !e.is_dir).count();
+        let unpacked: u64 = entries.iter().map(|e| e.size).sum();
+        let cap = max_lines.min(ARCHIVE_MAX_ENTRIES);
+
+        let mut lines: Vec<String> = entries
+            .iter()
+            .take(cap)
+            .map(|e| {
+                let size = if e.is_dir {
+                    String::new()
+                } else {
+                    crate::util::format_bytes(e.size)
+                };
+                format!("{size:>7}  {}", sanitize_line(&e.path))
+            })
+            .collect();
+        if max_lines >= MAX_LINES {
+            let more = entries.len().saturating_sub(cap);
+            if !complete {
+                lines.push(format!(
+                    "[\u{2026} {more} more entries, and the rest not read]"
+                ));
+            } else if more > 0 {
+                lines.push(format!("[\u{2026} {more} more entries]"));
+            }
+        }
+        if entries.is_empty() && complete {
+            lines.push("[empty archive]".into());
+        }
+        let plus = if complete { "" } else { "+" };
+        let info = format!(
+            "{}, {files}{plus} files, {}{plus} unpacked",
+            format.label(),
+            crate::util::format_bytes(unpacked)
+        );
+        Some(Self::text(title.to_string(), info, lines))
+    }
+
+    /// Color the start of a source file by its syntax (`dark` picks the
+    /// color scheme). Called once, where the preview is loaded, not per
+    /// frame; a file syntect doesn't recognize keeps the plain color.
+    pub fn highlight_syntax(&mut self, path: &Path, dark: bool) {
+        if self.is_binary || self.image.is_some() || path.is_dir() {
+            return;
+        }
+        let lines = &self.lines[..self.lines.len().min(HL_LINES)];
+        self.highlight = crate::viewer::highlight(lines, path, dark);
+    }
+
+    /// An image by extension whose header gives its pixel size; anything else
+    /// (including a broken image) falls through to the text/hex preview.
+    fn load_image(path: &Path, title: &str, size: u64) -> Option<Self> {
+        let image = image::probe(path, size)?;
+        let size = crate::util::format_bytes(size);
+        let (w, h) = (image.width, image.height);
+        let lines = vec![
+            format!("{} image", image.format.label()),
+            format!("{w} \u{d7} {h} pixels"),
+            size.clone(),
+        ];
+        let info = format!("{w}\u{d7}{h} \u{b7} {size}");
+        Some(Preview {
+            image: Some(image),
+            ..Self::text(title.to_string(), info, lines)
+        })
+    }
+
     fn load_binary(bytes: &[u8], title: String, total_size: usize) -> Self {
         let dump_bytes = &bytes[..bytes.len().min(HEX_DUMP_MAX)];
         // Hex rows render directly from `hex_bytes`; `lines` is unused in binary
@@ -515,6 +704,8 @@ impl Preview {
             is_binary: true,
             binary_size: total_size,
             hex_bytes: dump_bytes.to_vec(),
+            image: None,
+            highlight: None,
         }
     }
 
@@ -528,16 +719,17 @@ impl Preview {
         }
     }
 
-    /// For text previews: returns (first_visible_line, total_lines, percentage).
-    pub fn text_position(&self, visible: usize) -> (usize, usize, u8) {
+    /// For text previews: returns (first_visible_line, last_visible_line,
+    /// total_lines, percentage).
+    pub fn text_position(&self, visible: usize) -> (usize, usize, usize, u8) {
         if self.is_binary || self.lines.is_empty() {
-            return (0, 0, 0);
+            return (0, 0, 0, 0);
         }
         let first = self.scroll + 1;
         let total = self.lines.len();
         let last = (self.scroll + visible).min(total);
         let pct = ((last as u64 * 100) / total as u64) as u8;
-        (first, total, pct)
+        (first, last, total, pct)
     }
 
     /// For binary previews: returns (first_byte_offset, last_byte_offset,
@@ -649,8 +841,10 @@ mod tests {
             is_binary: false,
             binary_size: 0,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         };
-        assert_eq!(p.text_position(10), (0, 0, 0));
+        assert_eq!(p.text_position(10), (0, 0, 0, 0));
     }
 
     #[test]
@@ -663,8 +857,10 @@ mod tests {
             is_binary: true,
             binary_size: 100,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         };
-        assert_eq!(p.text_position(10), (0, 0, 0));
+        assert_eq!(p.text_position(10), (0, 0, 0, 0));
     }
 
     #[test]
@@ -678,9 +874,12 @@ mod tests {
             is_binary: false,
             binary_size: 0,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         };
-        let (first, total, pct) = p.text_position(20);
+        let (first, last, total, pct) = p.text_position(20);
         assert_eq!(first, 11); // scroll + 1
+        assert_eq!(last, 30);
         assert_eq!(total, 100);
         assert_eq!(pct, 30); // (10+20)*100/100 = 30
     }
@@ -697,6 +896,8 @@ mod tests {
             is_binary: false,
             binary_size: 0,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         };
         assert_eq!(p.hex_position(10), (0, 0, 0, 0));
     }
@@ -711,6 +912,8 @@ mod tests {
             is_binary: true,
             binary_size: 256,
             hex_bytes: (0..=255u8).collect(), // 256 bytes loaded
+            image: None,
+            highlight: None,
         };
         let (first_byte, last_byte, total, pct) = p.hex_position(10);
         assert_eq!(first_byte, 32); // 2 * 16
@@ -731,6 +934,8 @@ mod tests {
             is_binary: false,
             binary_size: 0,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         };
         p.scroll_up(5);
         assert_eq!(p.scroll, 0);
@@ -776,7 +981,7 @@ mod tests {
         assert_eq!(p.lines.len(), 3);
         assert_eq!(p.lines[0], "line1");
         assert_eq!(p.title, "test.txt");
-        assert!(p.info.contains("3 lines"));
+        assert_eq!(p.info, "3 lines \u{b7} 18B");
         let _ = std::fs::remove_dir_all(&dir);
     }
 
@@ -803,6 +1008,34 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn load_archive_lists_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(dir.path().join("src/main.rs"), "fn main() {}\n").unwrap();
+        std::fs::write(dir.path().join("README"), vec![b'x'; 2048]).unwrap();
+        let out = dir.path().join("pkg.tar.gz");
+        let inputs = [dir.path().join("src"), dir.path().join("README")];
+        crate::archive::create_archive(&inputs, dir.path(), &out).unwrap();
+
+        let p = Preview::load(&out, MAX_LINES);
+        assert!(!p.is_binary);
+        assert_eq!(p.info, "tar.gz, 2 files, 2.0K unpacked");
+        assert!(p.lines.iter().any(|l| l.ends_with("  src/main.rs")));
+        assert!(p.lines.iter().any(|l| l == "   2.0K  README"));
+
+        // A side panel only lists what fits.
+        assert_eq!(Preview::load(&out, 1).lines.len(), 1);
+
+        // A load the cursor has left stops reading, and says it was cut short.
+        let p = Preview::load_cancellable(&out, MAX_LINES, &AtomicBool::new(true));
+        assert_eq!(p.info, "tar.gz, 0+ files, 0B+ unpacked");
+        assert_eq!(
+            p.lines,
+            ["[\u{2026} 0 more entries, and the rest not read]"]
+        );
+    }
+
     #[test]
     fn load_directory_preview() {
         let dir = std::env::temp_dir().join("fcmd_preview_test_dir");
@@ -858,6 +1091,18 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn text_info_flags_truncation() {
+        assert_eq!(
+            Preview::text_info(5000, Some(8000), false, 60 * 1024),
+            "first 5000 lines of 8000 \u{b7} 60.0K"
+        );
+        assert_eq!(
+            Preview::text_info(1423, Some(1423), false, 100),
+            "1423 lines \u{b7} 100B"
+        );
+    }
+
     #[test]
     fn loading_placeholder_sets_title() {
         let p = Preview::loading_placeholder(std::path::Path::new("/tmp/foo.rs"));
@@ -919,6 +1164,8 @@ mod tests {
             is_binary: false,
             binary_size: 0,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         };
         p.scroll_down(10, 5);
         assert_eq!(p.scroll, 0);
@@ -934,6 +1181,8 @@ mod tests {
             is_binary: false,
             binary_size: 0,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         };
         p.scroll_up(10);
         assert_eq!(p.scroll, 0);
@@ -950,6 +1199,8 @@ mod tests {
             is_binary: false,
             binary_size: 0,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         };
         // visible=10, max scroll = 20 - 10 = 10
         p.scroll_down(100, 10);
@@ -960,13 +1211,37 @@ mod tests {
     fn load_too_large_file() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("huge.txt");
-        // Create a file and fake its "size" by checking the branch condition
-        // We can't create 50MB in a test, but we can check the branch message
+        // We can't create 50MB in a test; cut a small text as if it were the
+        // first part of a bigger file instead.
         std::fs::write(&path, "small").unwrap();
         let p = Preview::load(&path, MAX_LINES);
         // Small file loads normally
-        assert!(!p.lines.is_empty());
-        assert!(!p.info.contains("Too large"));
+        assert_eq!(p.lines, vec!["small"]);
+        let p = Preview::text_preview(b"one\ntwo\n", "huge.txt".into(), 3 * 1_048_576);
+        assert_eq!(p.lines.len(), 3);
+        assert_eq!(p.lines[2], "[preview truncated: first 8B of 3.0M]");
+        assert!(p.info.ends_with("3.0M"));
+    }
+
+    #[test]
+    fn long_single_line_is_split_into_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("min.js");
+        // One newline-free line of 2.5 segments, then a short one.
+        let long = "x".repeat(MAX_LINE_CHARS * 5 / 2);
+        std::fs::write(&path, format!("{long}\nend\n")).unwrap();
+
+        let p = Preview::load(&path, MAX_LINES);
+        assert_eq!(p.lines.len(), 4);
+        assert!(p.lines[..3].iter().all(|l| l.len() <= MAX_LINE_CHARS));
+        assert_eq!(p.lines[..3].concat(), long);
+        assert_eq!(p.lines[3], "end");
+        assert!(p.info.ends_with("long lines split"));
+
+        // The side panel reads only what it shows.
+        let p = Preview::load(&path, 2);
+        assert_eq!(p.lines.len(), 2);
+        assert!(p.info.starts_with("2+ lines"));
     }
 
     #[test]
@@ -1063,6 +1338,8 @@ mod tests {
             is_binary: false,
             binary_size: 0,
             hex_bytes: Vec::new(),
+            image: None,
+            highlight: None,
         };
         p.scroll_down(5, 10); // scroll 5 down
         assert_eq!(p.scroll, 5);
@@ -1071,4 +1348,43 @@ mod tests {
         p.scroll_up(100); // scroll way up - clamps at 0
         assert_eq!(p.scroll, 0);
     }
+
+    #[test]
+    fn load_image_describes_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pic.gif");
+        std::fs::write(&path, b"GIF89a\x20\x03\x58\x02\0\0\0").unwrap();
+        let p = Preview::load(&path, MAX_LINES);
+        assert!(p.image.is_some());
+        assert!(!p.is_binary);
+        assert_eq!(p.lines, ["GIF image", "800 \u{d7} 600 pixels", "13B"]);
+        assert_eq!(p.info, "800\u{d7}600 \u{b7} 13B");
+
+        // A broken header is not an image.
+        std::fs::write(&path, b"GIF89").unwrap();
+        assert!(Preview::load(&path, MAX_LINES).image.is_none());
+    }
+
+    #[test]
+    fn highlight_syntax_colors_the_start_of_source_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.rs");
+        let source = "fn main() {}\n".repeat(HL_LINES + 10);
+        std::fs::write(&path, &source).unwrap();
+        let mut p = Preview::load(&path, MAX_LINES);
+        p.highlight_syntax(&path, true);
+        let hl = p.highlight.as_ref().expect("rust is highlighted");
+        assert_eq!(hl.lines.len(), HL_LINES);
+        assert!(hl.lines[0].len() > 1);
+
+        let notes = dir.path().join("notes.txt");
+        std::fs::write(&notes, "plain words\n").unwrap();
+        let mut p = Preview::load(&notes, MAX_LINES);
+        p.highlight_syntax(&notes, true);
+        assert!(p.highlight.is_none());
+
+        let mut p = Preview::load(dir.path(), MAX_LINES);
+        p.highlight_syntax(dir.path(), true);
+        assert!(p.highlight.is_none());
+    }
 }
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
@@ -20,6 +21,10 @@ const SKIP_DIRS: &[&str] = &[
 
 const MDFIND_LIMIT: usize = 5000;
 
+/// Quiet period after the last keystroke before an external (global/grep)
+/// search is spawned, so fast typing doesn't start a process per character.
+const SEARCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(250);
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum FindScope {
     Local,
@@ -28,6 +33,52 @@ pub enum FindScope {
     Content,
 }
 
+/// Which kinds of result Find shows; cycled with Ctrl-t in the overlay.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FindKind {
+    All,
+    Dirs,
+    Files,
+}
+
+impl FindKind {
+    pub fn next(self) -> Self {
+        match self {
+            FindKind::All => FindKind::Dirs,
+            FindKind::Dirs => FindKind::Files,
+            FindKind::Files => FindKind::All,
+        }
+    }
+
+    fn admits(self, is_dir: bool) -> bool {
+        match self {
+            FindKind::All => true,
+            FindKind::Dirs => is_dir,
+            FindKind::Files => !is_dir,
+        }
+    }
+}
+
+impl FindScope {
+    /// Stable name used to persist the last Find.
+    pub fn label(self) -> &'static str {
+        match self {
+            FindScope::Local => "local",
+            FindScope::Global => "global",
+            FindScope::Content => "grep",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "local" => Some(FindScope::Local),
+            "global" => Some(FindScope::Global),
+            "grep" => Some(FindScope::Content),
+            _ => None,
+        }
+    }
+}
+
 struct Entry {
     rel_path: String,
     rel_path_lower: String,
@@ -47,16 +98,27 @@ pub struct FindState {
     // so the queue is bounded in practice without throttling discovery to tick rate.
     rx: Option<tokio::sync::mpsc::UnboundedReceiver<Entry>>,
     pub filtered: Vec<usize>,
+    /// Results picked for a bulk yank/delete, as indices into `entries` so they
+    /// survive re-filtering. Cleared when a new external search replaces them.
+    picked: HashSet<usize>,
     pub selected: usize,
     pub scroll: usize,
     pub loading: bool,
     pub scope: FindScope,
+    pub kind: FindKind,
     base_dir: PathBuf,
+    /// Where global name search starts: `$HOME` unless set with `:findin` or
+    /// toggled to the panel directory with Ctrl-r.
+    global_root: PathBuf,
     search_task: Option<tokio::task::JoinHandle<()>>,
+    /// When a debounced external search should fire (see `schedule_search`).
+    search_due: Option<Instant>,
     pub find_preview: Option<crate::preview::Preview>,
     // Cache key for the loaded preview: file path plus, for content matches, the
     // target line — so moving between two matches in the same file re-scrolls.
     find_preview_path: Option<(PathBuf, Option<usize>)>,
+    /// The previewed result is a directory, so `find_preview` is its listing.
+    find_preview_dir: bool,
     find_preview_rx: Option<tokio::sync::oneshot::Receiver<(PathBuf, crate::preview::Preview)>>,
     pub search_started: Option<Instant>,
     pub tick: usize,
@@ -82,14 +144,19 @@ impl FindState {
             entries: Vec::new(),
             rx: Some(rx),
             filtered: Vec::new(),
+            picked: HashSet::new(),
             selected: 0,
             scroll: 0,
             loading: true,
             scope: FindScope::Local,
+            kind: FindKind::All,
             base_dir: base_dir.to_path_buf(),
+            global_root: PathBuf::from(crate::util::home_dir_string()),
             search_task: None,
+            search_due: None,
             find_preview: None,
             find_preview_path: None,
+            find_preview_dir: false,
             find_preview_rx: None,
             search_started: Some(Instant::now()),
             tick: 0,
@@ -102,20 +169,32 @@ impl FindState {
             entries: Vec::new(),
             rx: None,
             filtered: Vec::new(),
+            picked: HashSet::new(),
             selected: 0,
             scroll: 0,
             loading: false,
             scope: FindScope::Global,
+            kind: FindKind::All,
             base_dir: base_dir.to_path_buf(),
+            global_root: PathBuf::from(crate::util::home_dir_string()),
             search_task: None,
+            search_due: None,
             find_preview: None,
             find_preview_path: None,
+            find_preview_dir: false,
             find_preview_rx: None,
             search_started: None,
             tick: 0,
         }
     }
 
+    /// Global search confined to `root` instead of `$HOME`.
+    pub fn new_global_in(base_dir: &Path, root: &Path) -> Self {
+        let mut state = Self::new_global(base_dir);
+        state.global_root = root.to_path_buf();
+        state
+    }
+
     /// Content (grep) search rooted at `base_dir` for `pattern`. Spawns the search
     /// immediately so results stream in like a global search.
     pub fn new_content(base_dir: &Path, pattern: &str) -> Self {
@@ -128,6 +207,27 @@ impl FindState {
         state
     }
 
+    /// A local Find run to completion on the calling thread: `base_dir` is
+    /// walked as [`FindState::new_local`] walks it and the names are ranked
+    /// for `query` the same way, best match first (`fcmd find`).
+    pub fn local_matches(base_dir: &Path, query: &str) -> Vec<PathBuf> {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        walk_send(base_dir, base_dir, &tx, 0, &mut 0);
+        drop(tx);
+        let mut state = Self::new_global(base_dir);
+        state.scope = FindScope::Local;
+        state.query = query.to_string();
+        while let Ok(entry) = rx.try_recv() {
+            state.entries.push(entry);
+        }
+        state.refilter();
+        state
+            .filtered
+            .iter()
+            .map(|&i| state.entries[i].full_path.clone())
+            .collect()
+    }
+
     pub fn switch_scope(&self) -> Self {
         let mut new_state = match self.scope {
             FindScope::Local => Self::new_global(&self.base_dir),
@@ -135,6 +235,8 @@ impl FindState {
             FindScope::Global | FindScope::Content => Self::new_local(&self.base_dir),
         };
         new_state.query = self.query.clone();
+        new_state.kind = self.kind;
+        new_state.global_root = self.global_root.clone();
         // For global, trigger search if query is non-empty
         if new_state.scope == FindScope::Global && !new_state.query.is_empty() {
             new_state.trigger_search();
@@ -142,6 +244,38 @@ impl FindState {
         new_state
     }
 
+    pub fn global_root(&self) -> &Path {
+        &self.global_root
+    }
+
+    /// Flip the global search root between `$HOME` and the directory Find was
+    /// opened from, re-running a global search.
+    pub fn toggle_global_root(&mut self) {
+        let home = PathBuf::from(crate::util::home_dir_string());
+        self.global_root = if self.global_root == home {
+            self.base_dir.clone()
+        } else {
+            home
+        };
+        if self.scope == FindScope::Global {
+            self.trigger_search();
+        }
+    }
+
+    /// Re-run the external search once typing pauses. Any search already
+    /// running keeps streaming until the new one actually starts.
+    pub fn schedule_search(&mut self) {
+        self.search_due = Some(Instant::now() + SEARCH_DEBOUNCE);
+    }
+
+    /// Fire a scheduled search whose quiet period has passed. Called every tick.
+    pub fn poll_scheduled_search(&mut self) {
+        if self.search_due.is_some_and(|due| Instant::now() >= due) {
+            self.search_due = None;
+            self.trigger_search();
+        }
+    }
+
     /// Re-run the active search for the current query. Global searches names
     /// (mdfind/fd/find); content searches file contents (rg/grep). No-op for
     /// the in-memory local scope.
@@ -157,12 +291,14 @@ impl FindState {
     /// fresh channel sender if the query is non-empty, or `None` when it's empty
     /// (in which case the caller should just show an empty/placeholder state).
     fn reset_for_search(&mut self) -> Option<tokio::sync::mpsc::UnboundedSender<Entry>> {
+        self.search_due = None;
         // Abort old search task (kills child processes via kill_on_drop)
         if let Some(handle) = self.search_task.take() {
             handle.abort();
         }
         self.entries.clear();
         self.filtered.clear();
+        self.picked.clear();
         self.selected = 0;
         self.scroll = 0;
 
@@ -185,7 +321,7 @@ impl FindState {
     }
 
     /// Global name search. Tries mdfind (macOS), then `fd`/`fdfind`, then
-    /// Unix `find`, searching from the user's home directory.
+    /// Unix `find`, searching from `global_root`.
     fn trigger_global(&mut self) {
         let Some(tx) = self.reset_for_search() else {
             return;
@@ -193,10 +329,10 @@ impl FindState {
 
         // Sanitize: strip characters that could be interpreted by find/mdfind/fd
         let sanitized_query: String = self.query.chars().filter(|c| *c != '\0').collect();
-        let home = crate::util::home_dir_string();
+        let root = self.global_root.to_string_lossy().into_owned();
 
         // Spawn the best available search tool for this platform.
-        let Some((mut child, is_mdfind)) = spawn_global_search(&sanitized_query, &home) else {
+        let Some((mut child, is_mdfind)) = spawn_global_search(&sanitized_query, &root) else {
             // No usable search tool (e.g. Windows without `fd` on PATH).
             self.clear_loading();
             return;
@@ -213,7 +349,7 @@ impl FindState {
             // mdfind returns 0 results when Spotlight is disabled — retry with fd/find.
             if is_mdfind
                 && count == 0
-                && let Some(mut fallback) = spawn_fallback_search(&sanitized_query, &home)
+                && let Some(mut fallback) = spawn_fallback_search(&sanitized_query, &root)
             {
                 if let Some(stdout) = fallback.stdout.take() {
                     global_search_read(stdout, &tx).await;
@@ -304,16 +440,20 @@ impl FindState {
     }
 
     fn refilter(&mut self) {
+        // The kind restriction applies first so the counts reflect it.
+        let kind = self.kind;
+        let admitted = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| kind.admits(e.is_dir));
         // Content matches are already filtered by grep — never fuzzy-filter them
         // by the query (the query is the grep pattern, not a path subsequence).
         if self.query.is_empty() || self.scope == FindScope::Content {
-            self.filtered = (0..self.entries.len()).collect();
+            self.filtered = admitted.map(|(i, _)| i).collect();
         } else {
             let query_lower: Vec<char> = self.query.to_lowercase().chars().collect();
-            let mut scored: Vec<(usize, i32)> = self
-                .entries
-                .iter()
-                .enumerate()
+            let mut scored: Vec<(usize, i32)> = admitted
                 .filter_map(|(i, e)| {
                     fuzzy_score_pre(&query_lower, &e.rel_path_lower, e.rel_path.len())
                         .map(|s| (i, s))
@@ -330,13 +470,19 @@ impl FindState {
         }
     }
 
+    /// Step to the next result kind (all → dirs → files) and re-filter.
+    pub fn cycle_kind(&mut self) {
+        self.kind = self.kind.next();
+        self.update_filter();
+    }
+
     pub fn update_filter(&mut self) {
         self.refilter();
         self.selected = 0;
         self.scroll = 0;
     }
 
-    pub fn update_find_preview(&mut self, visible_height: usize) {
+    pub fn update_find_preview(&mut self, visible_height: usize, dark: bool) {
         let target_line = self.selected_line();
         let current = self
             .selected_path()
@@ -345,6 +491,7 @@ impl FindState {
             return;
         }
         self.find_preview_path = current.clone();
+        self.find_preview_dir = self.selected_is_dir();
         if let Some((p, line)) = current {
             self.find_preview = Some(crate::preview::Preview::loading_placeholder(&p));
             let (tx, rx) = tokio::sync::oneshot::channel();
@@ -353,6 +500,7 @@ impl FindState {
             let vis = visible_height;
             tokio::task::spawn_blocking(move || {
                 let mut prev = crate::preview::Preview::load(&path, vis);
+                prev.highlight_syntax(&path, dark);
                 // For a content match, scroll so the matched line sits near the top
                 // with a little context above it.
                 if let Some(line) = line
@@ -370,6 +518,11 @@ impl FindState {
         }
     }
 
+    /// Whether `find_preview` lists a directory's contents rather than a file.
+    pub fn preview_is_dir(&self) -> bool {
+        self.find_preview_dir
+    }
+
     pub fn poll_find_preview(&mut self) {
         let rx = match self.find_preview_rx.as_mut() {
             Some(rx) => rx,
@@ -429,10 +582,34 @@ impl FindState {
         self.entries.len()
     }
 
+    /// A preview of the selected result is still loading.
+    pub fn preview_pending(&self) -> bool {
+        self.find_preview_rx.is_some()
+    }
+
     pub fn filtered_count(&self) -> usize {
         self.filtered.len()
     }
 
+    /// Char indices of `rel_path` matched by the query, for highlighting a
+    /// result row. Empty for content matches (the query is a grep pattern).
+    pub fn match_positions(&self, filtered_idx: usize) -> Vec<usize> {
+        if self.query.is_empty() || self.scope == FindScope::Content {
+            return Vec::new();
+        }
+        let Some(e) = self
+            .filtered
+            .get(filtered_idx)
+            .and_then(|&i| self.entries.get(i))
+        else {
+            return Vec::new();
+        };
+        let query_lower: Vec<char> = self.query.to_lowercase().chars().collect();
+        fuzzy_match(&query_lower, &e.rel_path_lower, e.rel_path.len())
+            .map(|(_, hits)| hits)
+            .unwrap_or_default()
+    }
+
     /// Result row at `filtered_idx`: the relative path, whether it's a directory,
     /// and (for content/grep results) the line number and matched text.
     pub fn get_item_full(
@@ -452,6 +629,59 @@ impl FindState {
             })
     }
 
+    /// Toggle the result under the cursor in the picked set and step down, so
+    /// repeated presses pick consecutive rows.
+    pub fn toggle_picked(&mut self) {
+        let Some(&i) = self.filtered.get(self.selected) else {
+            return;
+        };
+        if !self.picked.remove(&i) {
+            self.picked.insert(i);
+        }
+        self.move_down();
+    }
+
+    /// Pick every result the query currently shows; if they are all picked
+    /// already, unpick them instead.
+    pub fn toggle_pick_all(&mut self) {
+        if self.filtered.iter().all(|i| self.picked.contains(i)) {
+            for i in &self.filtered {
+                self.picked.remove(i);
+            }
+        } else {
+            self.picked.extend(self.filtered.iter().copied());
+        }
+    }
+
+    pub fn picked_count(&self) -> usize {
+        self.picked.len()
+    }
+
+    /// Whether the result row at `filtered_idx` is picked.
+    pub fn is_picked(&self, filtered_idx: usize) -> bool {
+        self.filtered
+            .get(filtered_idx)
+            .is_some_and(|i| self.picked.contains(i))
+    }
+
+    /// Targets for a bulk action: the picked results (one per path, since grep
+    /// can match a file several times) in result order, or the result under the
+    /// cursor when nothing is picked.
+    pub fn action_targets(&self) -> Vec<(PathBuf, bool)> {
+        let mut seen = HashSet::new();
+        let mut indices: Vec<usize> = self.picked.iter().copied().collect();
+        indices.sort_unstable();
+        if indices.is_empty() {
+            indices.extend(self.filtered.get(self.selected).copied());
+        }
+        indices
+            .into_iter()
+            .filter_map(|i| self.entries.get(i))
+            .filter(|e| seen.insert(e.full_path.clone()))
+            .map(|e| (e.full_path.clone(), e.is_dir))
+            .collect()
+    }
+
     /// Line number of the selected content match, if any.
     pub fn selected_line(&self) -> Option<usize> {
         self.filtered
@@ -482,14 +712,19 @@ impl FindState {
             entries,
             rx: None,
             filtered,
+            picked: HashSet::new(),
             selected: 0,
             scroll: 0,
             loading: false,
             scope: FindScope::Local,
+            kind: FindKind::All,
             base_dir: base_dir.to_path_buf(),
+            global_root: PathBuf::from(crate::util::home_dir_string()),
             search_task: None,
+            search_due: None,
             find_preview: None,
             find_preview_path: None,
+            find_preview_dir: false,
             find_preview_rx: None,
             search_started: None,
             tick: 0,
@@ -497,15 +732,16 @@ impl FindState {
     }
 }
 
-/// Spawn the best available global-search tool for this platform.
+/// Spawn the best available global-search tool for this platform, confined
+/// to `root`.
 ///
 /// Returns the child plus a flag marking whether it is `mdfind` (which needs a
 /// fallback when Spotlight is disabled). Order: macOS Spotlight → `fd`/`fdfind`
 /// (cross-platform, incl. Windows) → Unix `find`.
-fn spawn_global_search(query: &str, home: &str) -> Option<(tokio::process::Child, bool)> {
+fn spawn_global_search(query: &str, root: &str) -> Option<(tokio::process::Child, bool)> {
     #[cfg(target_os = "macos")]
     if let Ok(child) = tokio::process::Command::new("mdfind")
-        .args(["-name", query])
+        .args(["-onlyin", root, "-name", query])
         .stdout(Stdio::piped())
         .stderr(Stdio::null())
         .kill_on_drop(true)
@@ -514,20 +750,20 @@ fn spawn_global_search(query: &str, home: &str) -> Option<(tokio::process::Child
         return Some((child, true));
     }
 
-    spawn_fallback_search(query, home).map(|child| (child, false))
+    spawn_fallback_search(query, root).map(|child| (child, false))
 }
 
 /// Spawn a non-Spotlight search tool: `fd`/`fdfind` if on PATH, else Unix `find`.
 ///
 /// Never falls back to the Windows `find.exe` (an unrelated text-search tool),
 /// so on Windows this yields a result only when `fd` is installed.
-fn spawn_fallback_search(query: &str, home: &str) -> Option<tokio::process::Child> {
+fn spawn_fallback_search(query: &str, root: &str) -> Option<tokio::process::Child> {
     for bin in ["fd", "fdfind"] {
         if let Ok(child) = tokio::process::Command::new(bin)
             .args(["--hidden", "--no-ignore", "--fixed-strings", "--absolute-path"])
             .arg("--")
             .arg(query)
-            .arg(home)
+            .arg(root)
             .stdout(Stdio::piped())
             .stderr(Stdio::null())
             .kill_on_drop(true)
@@ -542,7 +778,7 @@ fn spawn_fallback_search(query: &str, home: &str) -> Option<tokio::process::Chil
         let pattern = format!("*{query}*");
         if let Ok(child) = tokio::process::Command::new("find")
             .arg("--")
-            .args([home, "-maxdepth", "6", "-iname", &pattern])
+            .args([root, "-maxdepth", "6", "-iname", &pattern])
             .stdout(Stdio::piped())
             .stderr(Stdio::null())
             .kill_on_drop(true)
@@ -655,7 +891,7 @@ fn parse_grep_line(line: &str) -> Option<(&str, usize, &str)> {
     None
 }
 
-fn abbreviate_home(path: &str) -> String {
+pub(crate) fn abbreviate_home(path: &str) -> String {
     if let Some(home) = dirs::home_dir()
         && let Some(rest) = path.strip_prefix(&*home.to_string_lossy())
     {
@@ -763,7 +999,55 @@ fn walk_send(
 }
 
 /// Fuzzy score using pre-lowercased query chars and cached lowercase text.
-fn fuzzy_score_pre(query_chars: &[char], text_lower: &str, text_len: usize) -> Option<i32> {
+pub(crate) fn fuzzy_score_pre(
+    query_chars: &[char],
+    text_lower: &str,
+    text_len: usize,
+) -> Option<i32> {
+    fuzzy_walk(query_chars, text_lower, text_len, |_| {})
+}
+
+/// Indices of the `texts` that match `query`, best score first. Equal scores
+/// (and every entry, for an empty query) keep the order they were given in.
+pub(crate) fn fuzzy_rank<S: AsRef<str>>(
+    query: &str,
+    texts: impl IntoIterator<Item = S>,
+) -> Vec<usize> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let mut scored: Vec<(i32, usize)> = texts
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, text)| {
+            let lower = text.as_ref().to_lowercase();
+            fuzzy_score_pre(&query, &lower, lower.chars().count()).map(|s| (s, i))
+        })
+        .collect();
+    if !query.is_empty() {
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+    }
+    scored.into_iter().map(|(_, i)| i).collect()
+}
+
+/// Like `fuzzy_score_pre`, but also returns the char indices in `text_lower`
+/// that the query matched, for highlighting. Only called for rendered rows.
+pub(crate) fn fuzzy_match(
+    query_chars: &[char],
+    text_lower: &str,
+    text_len: usize,
+) -> Option<(i32, Vec<usize>)> {
+    let mut hits = Vec::with_capacity(query_chars.len());
+    let score = fuzzy_walk(query_chars, text_lower, text_len, |ti| hits.push(ti))?;
+    Some((score, hits))
+}
+
+/// Greedy left-to-right subsequence match shared by the scorers above;
+/// `on_hit` sees the char index of every matched character.
+fn fuzzy_walk(
+    query_chars: &[char],
+    text_lower: &str,
+    text_len: usize,
+    mut on_hit: impl FnMut(usize),
+) -> Option<i32> {
     if query_chars.is_empty() {
         return Some(0);
     }
@@ -776,6 +1060,7 @@ fn fuzzy_score_pre(query_chars: &[char], text_lower: &str, text_len: usize) -> O
 
     for (ti, &tc) in t.iter().enumerate() {
         if qi < query_chars.len() && tc == query_chars[qi] {
+            on_hit(ti);
             qi += 1;
             consecutive += 1;
             score += consecutive;
@@ -827,6 +1112,13 @@ mod tests {
         assert_eq!(score, Some(0));
     }
 
+    #[test]
+    fn fuzzy_rank_orders_best_first_and_keeps_ties() {
+        let texts = ["Zzz", "my_main.rs", "main.rs", "Main.rs"];
+        assert_eq!(fuzzy_rank("main", texts), vec![2, 3, 1]);
+        assert_eq!(fuzzy_rank("", texts), vec![0, 1, 2, 3]);
+    }
+
     #[test]
     fn fuzzy_path_separator_bonus() {
         let q: Vec<char> = "ar".chars().collect();
@@ -836,6 +1128,15 @@ mod tests {
         assert!(score_with_sep.unwrap_or(0) > score_without.unwrap_or(0));
     }
 
+    #[test]
+    fn fuzzy_match_reports_positions() {
+        let q: Vec<char> = "mr".chars().collect();
+        let (score, hits) = fuzzy_match(&q, "main.rs", 7).unwrap();
+        assert_eq!(Some(score), fuzzy_score_pre(&q, "main.rs", 7));
+        assert_eq!(hits, [0, 5]);
+        assert!(fuzzy_match(&q, "lib.c", 5).is_none());
+    }
+
     #[test]
     fn fuzzy_case_insensitive() {
         let q: Vec<char> = "main".chars().collect();
@@ -1203,6 +1504,73 @@ mod tests {
         assert_eq!(fs.filtered_count(), 2);
     }
 
+    #[test]
+    fn cycle_kind_restricts_before_scoring() {
+        let mut fs = FindState::new_test(
+            Path::new("/tmp"),
+            &[
+                ("src", true),
+                ("src/main.rs", false),
+                ("docs/src.md", false),
+            ],
+        );
+        fs.query = "src".into();
+        fs.update_filter();
+        assert_eq!(fs.filtered_count(), 3);
+        fs.cycle_kind();
+        assert_eq!(fs.kind, FindKind::Dirs);
+        assert_eq!(fs.filtered_count(), 1);
+        assert_eq!(fs.selected_path(), Some(Path::new("/tmp/src")));
+        fs.cycle_kind();
+        assert_eq!(fs.filtered_count(), 2);
+        assert!(!fs.selected_is_dir());
+        fs.cycle_kind();
+        assert_eq!(fs.kind, FindKind::All);
+        assert_eq!(fs.filtered_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn global_root_carries_across_scopes_and_toggles() {
+        let fs = FindState::new_global_in(Path::new("/work/proj"), Path::new("/var/log"));
+        assert_eq!(fs.global_root(), Path::new("/var/log"));
+        let local = fs.switch_scope();
+        let mut global = local.switch_scope();
+        assert_eq!(global.global_root(), Path::new("/var/log"));
+
+        let home = PathBuf::from(crate::util::home_dir_string());
+        global.toggle_global_root();
+        assert_eq!(global.global_root(), home);
+        global.toggle_global_root();
+        assert_eq!(global.global_root(), Path::new("/work/proj"));
+    }
+
+    #[tokio::test]
+    async fn directory_result_previews_as_listing() {
+        let mut fs = FindState::new_test(Path::new("/tmp"), &[("sub", true), ("a.rs", false)]);
+        fs.update_find_preview(10, true);
+        assert!(fs.preview_is_dir());
+        fs.move_down();
+        fs.update_find_preview(10, true);
+        assert!(!fs.preview_is_dir());
+    }
+
+    #[tokio::test]
+    async fn scheduled_search_waits_for_quiet_period() {
+        let mut fs = FindState::new_test(Path::new("/tmp"), &[("a.rs", false)]);
+        fs.scope = FindScope::Global;
+        fs.schedule_search();
+        fs.poll_scheduled_search();
+        // Still within the debounce window: old results stay put.
+        assert!(fs.search_due.is_some());
+        assert_eq!(fs.total_count(), 1);
+
+        fs.search_due = Some(Instant::now() - SEARCH_DEBOUNCE);
+        fs.poll_scheduled_search();
+        assert!(fs.search_due.is_none());
+        // Empty query: the fired search just clears the list.
+        assert_eq!(fs.total_count(), 0);
+    }
+
     #[test]
     fn selected_path_at_index() {
         let fs = FindState::new_test(
@@ -1220,4 +1588,20 @@ mod tests {
         // Spinner should return something
         assert!(!s1.is_empty());
     }
+
+    #[test]
+    fn local_matches_walks_and_ranks() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src/config")).unwrap();
+        std::fs::create_dir_all(root.join("target")).unwrap();
+        std::fs::write(root.join("src/config/loader.rs"), "").unwrap();
+        std::fs::write(root.join("target/config_loader.rs"), "").unwrap();
+        std::fs::write(root.join("notes.txt"), "").unwrap();
+
+        let hits = FindState::local_matches(root, "cfgld");
+        // `target` is skipped, as it is in the Find overlay.
+        assert_eq!(hits, vec![root.join("src/config/loader.rs")]);
+        assert!(FindState::local_matches(root, "zzz").is_empty());
+    }
 }
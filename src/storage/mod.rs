@@ -9,6 +9,18 @@ pub struct SavedTab {
     pub active_panel: usize,       // 0, 1, or 2
 }
 
+/// Panes that were open when the session was saved. The panel layout has its
+/// own key (`save_layout`).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SavedView {
+    pub preview: bool,
+    pub tree: bool,
+    pub tree_focused: bool,
+    /// `Space P` put the preview on the other side from the config's
+    /// `preview_side`. Kept relative, so an edit to the config still applies.
+    pub preview_flipped: bool,
+}
+
 pub struct Db {
     conn: Connection,
 }
@@ -46,6 +58,11 @@ impl Db {
              CREATE TABLE IF NOT EXISTS git_statuses (
                  path TEXT PRIMARY KEY,
                  status TEXT NOT NULL
+             );
+             CREATE TABLE IF NOT EXISTS input_history (
+                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                 kind TEXT NOT NULL,
+                 entry TEXT NOT NULL
              );",
         )?;
         // Migrate: add level column if missing
@@ -120,7 +137,9 @@ impl Db {
 
         tx.execute("DELETE FROM session_tabs", [])?;
         tx.execute(
-            "DELETE FROM session_meta WHERE key NOT IN ('theme', 'layout', 'transparent')",
+            "DELETE FROM session_meta WHERE key NOT IN \
+             ('theme', 'layout', 'view', 'transparent', 'last_dir', 'last_find_scope', \
+             'last_find_query', 'last_find_root')",
             [],
         )?;
 
@@ -177,6 +196,93 @@ impl Db {
             .ok()
     }
 
+    /// Which panes were open, kept as one comma-separated value (`preview,tree`).
+    pub fn save_view(&self, view: SavedView) -> rusqlite::Result<()> {
+        let flags: Vec<&str> = [
+            (view.preview, "preview"),
+            (view.tree, "tree"),
+            (view.tree_focused, "tree_focused"),
+            (view.preview_flipped, "preview_flipped"),
+        ]
+        .into_iter()
+        .filter_map(|(on, name)| on.then_some(name))
+        .collect();
+        self.conn.execute(
+            "INSERT OR REPLACE INTO session_meta (key, value) VALUES ('view', ?1)",
+            params![flags.join(",")],
+        )?;
+        Ok(())
+    }
+
+    pub fn load_view(&self) -> SavedView {
+        let value = self
+            .conn
+            .query_row(
+                "SELECT value FROM session_meta WHERE key = 'view'",
+                [],
+                |row| row.get::<_, String>(0),
+            )
+            .unwrap_or_default();
+        let has = |name: &str| value.split(',').any(|f| f == name);
+        SavedView {
+            preview: has("preview"),
+            tree: has("tree"),
+            tree_focused: has("tree_focused"),
+            preview_flipped: has("preview_flipped"),
+        }
+    }
+
+    /// The active panel's directory, written on every navigation so it survives
+    /// a crash that skips `save_session`.
+    pub fn save_last_dir(&self, path: &Path) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO session_meta (key, value) VALUES ('last_dir', ?1)",
+            params![path.to_string_lossy().as_ref()],
+        )?;
+        Ok(())
+    }
+
+    pub fn load_last_dir(&self) -> Option<PathBuf> {
+        self.conn
+            .query_row(
+                "SELECT value FROM session_meta WHERE key = 'last_dir'",
+                [],
+                |row| row.get::<_, String>(0),
+            )
+            .ok()
+            .map(PathBuf::from)
+    }
+
+    /// The most recent Find, reopened pre-filled by `Space ;`. `root` is where
+    /// a global search ran (`$HOME`, or a `:findin` directory).
+    pub fn save_last_find(&self, scope: &str, query: &str, root: &Path) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO session_meta (key, value) VALUES \
+             ('last_find_scope', ?1), ('last_find_query', ?2), ('last_find_root', ?3)",
+            params![scope, query, root.to_string_lossy()],
+        )?;
+        Ok(())
+    }
+
+    /// Scope, query and root; the root is absent in databases written before
+    /// it was saved.
+    pub fn load_last_find(&self) -> Option<(String, String, Option<PathBuf>)> {
+        let get = |key: &str| {
+            self.conn
+                .query_row(
+                    "SELECT value FROM session_meta WHERE key = ?1",
+                    params![key],
+                    |row| row.get::<_, String>(0),
+                )
+                .ok()
+        };
+        Some((
+            get("last_find_scope")?,
+            get("last_find_query")?,
+            get("last_find_root").map(PathBuf::from),
+        ))
+    }
+
     pub fn save_theme(&self, name: &str) -> rusqlite::Result<()> {
         self.conn.execute(
             "INSERT OR REPLACE INTO session_meta (key, value) VALUES ('theme', ?1)",
@@ -309,6 +415,11 @@ impl Db {
              CREATE TABLE IF NOT EXISTS git_statuses (
                  path TEXT PRIMARY KEY,
                  status TEXT NOT NULL
+             );
+             CREATE TABLE IF NOT EXISTS input_history (
+                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                 kind TEXT NOT NULL,
+                 entry TEXT NOT NULL
              );",
         )?;
         Ok(Db { conn })
@@ -358,6 +469,35 @@ impl Db {
         Ok(())
     }
 
+    // --- Input history ---
+
+    /// Newest `limit` entries of one history kind ("command", "search"), oldest first.
+    pub fn load_history(&self, kind: &str, limit: usize) -> rusqlite::Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT entry FROM input_history WHERE kind = ?1 ORDER BY id DESC LIMIT ?2")?;
+        let rows = stmt.query_map(params![kind, limit as i64], |row| row.get(0))?;
+        let mut entries: Vec<String> = rows.flatten().collect();
+        entries.reverse();
+        Ok(entries)
+    }
+
+    /// Append an entry and trim the kind back down to `cap` rows.
+    pub fn push_history(&self, kind: &str, entry: &str, cap: usize) -> rusqlite::Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "INSERT INTO input_history (kind, entry) VALUES (?1, ?2)",
+            params![kind, entry],
+        )?;
+        tx.execute(
+            "DELETE FROM input_history WHERE kind = ?1 AND id NOT IN
+                 (SELECT id FROM input_history WHERE kind = ?1 ORDER BY id DESC LIMIT ?2)",
+            params![kind, cap as i64],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
     // --- Git statuses cache ---
 
     pub fn save_git_statuses(&self, statuses: &HashMap<PathBuf, char>) -> rusqlite::Result<()> {
@@ -554,6 +694,25 @@ mod tests {
         assert_eq!(db.load_layout(), Some("single".into()));
     }
 
+    #[test]
+    fn view_save_load() {
+        let db = Db::init_in_memory().unwrap();
+        assert_eq!(db.load_view(), SavedView::default());
+
+        let view = SavedView {
+            preview: true,
+            tree: true,
+            tree_focused: false,
+            preview_flipped: true,
+        };
+        db.save_view(view).unwrap();
+        db.save_session(&[], 0).unwrap();
+        assert_eq!(db.load_view(), view);
+
+        db.save_view(SavedView::default()).unwrap();
+        assert_eq!(db.load_view(), SavedView::default());
+    }
+
     #[test]
     fn dir_sizes_save_load() {
         let db = Db::init_in_memory().unwrap();
@@ -633,6 +792,19 @@ mod tests {
         assert_eq!(bm[0].0, "projects");
     }
 
+    #[test]
+    fn history_push_load_and_cap() {
+        let db = Db::init_in_memory().unwrap();
+        db.push_history("command", "cd /tmp", 3).unwrap();
+        db.push_history("search", "foo", 3).unwrap();
+        for e in ["a", "b", "c"] {
+            db.push_history("command", e, 3).unwrap();
+        }
+        assert_eq!(db.load_history("command", 10).unwrap(), vec!["a", "b", "c"]);
+        assert_eq!(db.load_history("command", 2).unwrap(), vec!["b", "c"]);
+        assert_eq!(db.load_history("search", 10).unwrap(), vec!["foo"]);
+    }
+
     #[test]
     fn bookmarks_rename() {
         let db = Db::init_in_memory().unwrap();
@@ -665,6 +837,36 @@ mod tests {
         assert_eq!(db.load_theme(), Some("dracula".into()));
     }
 
+    #[test]
+    fn last_dir_survives_session_save() {
+        let db = Db::init_in_memory().unwrap();
+        assert_eq!(db.load_last_dir(), None);
+
+        db.save_last_dir(Path::new("/srv/data")).unwrap();
+        db.save_session(&[], 0).unwrap();
+        assert_eq!(db.load_last_dir(), Some(PathBuf::from("/srv/data")));
+    }
+
+    #[test]
+    fn last_find_survives_session_save() {
+        let db = Db::init_in_memory().unwrap();
+        assert_eq!(db.load_last_find(), None);
+
+        db.save_last_find("local", "config", Path::new("/home/u"))
+            .unwrap();
+        db.save_last_find("global", "foo", Path::new("/var/log"))
+            .unwrap();
+        db.save_session(&[], 0).unwrap();
+        assert_eq!(
+            db.load_last_find(),
+            Some((
+                "global".into(),
+                "foo".into(),
+                Some(PathBuf::from("/var/log"))
+            ))
+        );
+    }
+
     #[test]
     fn transparent_save_load() {
         let db = Db::init_in_memory().unwrap();
@@ -1,17 +1,20 @@
 use ratatui::{
     Frame,
     layout::Rect,
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
 };
-use unicode_width::UnicodeWidthStr;
 
-use crate::search::{FindScope, FindState};
+use crate::preview::Preview;
+use crate::search::{FindKind, FindScope, FindState, abbreviate_home};
 use crate::theme::Theme;
-use crate::util::icons::file_icon;
+use crate::util::icons::{file_icon, glyph, label};
 
-use super::util::{centered_rect, display_width, truncate_to_width, truncate_to_width_left};
+use super::util::{
+    centered_rect, display_width, highlight_spans, hits_in_truncated, truncate_to_width,
+    truncate_to_width_left,
+};
 
 /// Number of result rows shown in the find popup for a given screen area.
 /// Shared with the scroll-clamp logic in `ui::render` so the clamp height and the
@@ -27,9 +30,9 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
     f.render_widget(Clear, popup);
 
     let scope_label = match fs.scope {
-        FindScope::Local => "󰉋 Local",
-        FindScope::Global => "󰖟 Global",
-        FindScope::Content => "\u{f002} Grep",
+        FindScope::Local => label("󰉋 Local"),
+        FindScope::Global => label("󰖟 Global"),
+        FindScope::Content => label("\u{f002} Grep"),
     };
     let scope_color = match fs.scope {
         FindScope::Local => t.cyan,
@@ -45,8 +48,22 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
     } else {
         String::new()
     };
+    let kind_part = match fs.kind {
+        FindKind::All => String::new(),
+        FindKind::Dirs => label(" \u{f07b} dirs"),
+        FindKind::Files => label(" \u{f15b} files"),
+    };
+    let picked_part = match fs.picked_count() {
+        0 => String::new(),
+        n => format!("\u{2713}{n} "),
+    };
+    let root_part = if fs.scope == FindScope::Global {
+        format!(" {}", abbreviate_home(&fs.global_root().to_string_lossy()))
+    } else {
+        String::new()
+    };
     let title = format!(
-        "  Find [{scope_label}] ({}/{}) {status_part} ",
+        "  Find [{scope_label}{root_part}{kind_part}] ({}/{}) {picked_part}{status_part} ",
         fs.filtered_count(),
         fs.total_count()
     );
@@ -97,9 +114,9 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
     // Placeholders for external (global / content) searches with no query yet.
     if fs.scope != FindScope::Local && fs.query.is_empty() {
         let prompt = if fs.scope == FindScope::Content {
-            "  󰍉 Type to grep..."
+            label("  󰍉 Type to grep...")
         } else {
-            "  󰍉 Type to search..."
+            label("  󰍉 Type to search...")
         };
         let placeholder = Paragraph::new(Line::from(Span::styled(
             prompt,
@@ -124,13 +141,20 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
             .filter_map(|idx| {
                 let (rel_path, is_dir, line_no, match_text) = fs.get_item_full(idx)?;
                 let is_selected = idx == fs.selected;
+                let is_picked = fs.is_picked(idx);
 
                 let icon = file_icon(rel_path.rsplit('/').next().unwrap_or(rel_path), is_dir);
 
-                let prefix = if is_selected { "> " } else { "  " };
+                let prefix = match (is_selected, is_picked) {
+                    (true, false) => "> ",
+                    (_, true) => "\u{2713} ",
+                    (false, false) => "  ",
+                };
 
                 let style = if is_selected {
                     Style::default().fg(t.bg_text).bg(sel_bg)
+                } else if is_picked {
+                    Style::default().fg(t.green)
                 } else if is_dir {
                     Style::default().fg(t.dir_color)
                 } else {
@@ -173,11 +197,17 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
                         rel_path.to_string()
                     };
                     let truncated = truncate_to_width_left(&display, avail);
-                    Line::from(vec![
-                        Span::styled(prefix, style),
-                        Span::styled(icon, icon_style),
-                        Span::styled(truncated, style),
-                    ])
+                    let hits = fs.match_positions(idx);
+                    let hits = hits_in_truncated(&display, &truncated, &hits, true);
+                    let hl_style = if is_selected {
+                        style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                    } else {
+                        style.fg(t.yellow).add_modifier(Modifier::BOLD)
+                    };
+                    let mut spans =
+                        vec![Span::styled(prefix, style), Span::styled(icon, icon_style)];
+                    spans.extend(highlight_spans(&truncated, &hits, style, hl_style));
+                    Line::from(spans)
                 };
 
                 Some(ListItem::new(line))
@@ -195,19 +225,23 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
         Span::styled(" open  ", Style::default().fg(t.fg_dim)),
         Span::styled("tab", Style::default().fg(scope_color)),
         Span::styled(" scope  ", Style::default().fg(t.fg_dim)),
+        Span::styled("^t", Style::default().fg(scope_color)),
+        Span::styled(" type  ", Style::default().fg(t.fg_dim)),
+        Span::styled("^sp", Style::default().fg(scope_color)),
+        Span::styled(" pick  ", Style::default().fg(t.fg_dim)),
         Span::styled("esc", Style::default().fg(scope_color)),
         Span::styled(" close", Style::default().fg(t.fg_dim)),
     ];
     // Show search progress on the right side of the hint bar
     if fs.scope != FindScope::Local && !fs.query.is_empty() {
-        let used: usize = hint_spans.iter().map(|s| s.content.chars().count()).sum();
+        let used: usize = hint_spans.iter().map(|s| display_width(&s.content)).sum();
         let elapsed = fs.elapsed_str();
         let status_text = if fs.loading {
             format!(" {} {} found", fs.spinner(), fs.total_count())
         } else {
             format!(" \u{2714} {} found ({})", fs.total_count(), elapsed)
         };
-        let pad = (left_w as usize).saturating_sub(used + status_text.chars().count());
+        let pad = (left_w as usize).saturating_sub(used + display_width(&status_text));
         if pad > 0 {
             hint_spans.push(Span::styled(" ".repeat(pad), Style::default()));
         }
@@ -232,7 +266,7 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
     match &fs.find_preview {
         Some(p) => {
             // Preview title
-            let preview_title = format!(" 󰈈 {} [{}] ", p.title, p.info);
+            let preview_title = format!(" {}{} [{}] ", glyph("󰈈 ", ""), p.title, p.info);
             let title_display = truncate_to_width(&preview_title, right_w as usize);
             let title_area = Rect::new(right_x, inner.y, right_w, 1);
             f.render_widget(
@@ -248,7 +282,9 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
             let content_area = Rect::new(right_x, inner.y + 1, right_w, content_height as u16);
             let rwidth = right_w as usize;
 
-            let items: Vec<ListItem> = if p.is_binary {
+            let items: Vec<ListItem> = if fs.preview_is_dir() {
+                dir_listing_rows(p, content_height, rwidth, t)
+            } else if p.is_binary {
                 super::hex::render_rows(
                     p,
                     p.scroll,
@@ -272,13 +308,13 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
                             format!("{line_num:>num_width$}\u{2502}", num_width = num_width),
                             Style::default().fg(t.fg_dim),
                         )];
-                        let line = &p.lines[line_idx];
-                        let content = if line.width() > max_content {
-                            super::preview::truncate_to_width(line, max_content)
-                        } else {
-                            line.clone()
-                        };
-                        spans.push(Span::styled(content, Style::default().fg(t.fg)));
+                        let hl = p.highlight.as_ref().and_then(|h| h.lines.get(line_idx));
+                        spans.extend(super::util::syntax_spans(
+                            &p.lines[line_idx],
+                            max_content,
+                            hl.map(Vec::as_slice),
+                            Style::default().fg(t.fg),
+                        ));
                         Some(ListItem::new(Line::from(spans)))
                     })
                     .collect()
@@ -290,8 +326,8 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
             // No preview placeholder
             let center_y = inner.y + inner.height / 2;
             let placeholder_area = Rect::new(right_x, center_y, right_w, 1);
-            let text = "󰈈 No preview";
-            let pad = (right_w as usize).saturating_sub(text.chars().count()) / 2;
+            let text = label("󰈈 No preview");
+            let pad = (right_w as usize).saturating_sub(display_width(&text)) / 2;
             f.render_widget(
                 Paragraph::new(Line::from(Span::styled(
                     format!("{}{text}", " ".repeat(pad)),
@@ -302,3 +338,31 @@ pub(super) fn render_find(f: &mut Frame, fs: &FindState, t: &Theme, area: Rect)
         }
     }
 }
+
+/// A directory result's contents as icon + name rows, directories in the
+/// directory colour, like the panel shows them.
+fn dir_listing_rows<'a>(p: &Preview, height: usize, width: usize, t: &Theme) -> Vec<ListItem<'a>> {
+    if p.lines.is_empty() {
+        return vec![ListItem::new(Line::from(Span::styled(
+            " (empty directory)",
+            Style::default().fg(t.fg_dim),
+        )))];
+    }
+    p.lines
+        .iter()
+        .skip(p.scroll)
+        .take(height)
+        .map(|line| {
+            let is_dir = line.ends_with('/');
+            let name = line.trim_end_matches('/');
+            let icon = file_icon(name, is_dir);
+            let fg = if is_dir { t.dir_color } else { t.fg };
+            let avail = width.saturating_sub(1 + display_width(icon));
+            ListItem::new(Line::from(vec![
+                Span::raw(" "),
+                Span::styled(icon, Style::default().fg(t.fg_dim)),
+                Span::styled(truncate_to_width(line, avail), Style::default().fg(fg)),
+            ]))
+        })
+        .collect()
+}
@@ -10,8 +10,10 @@ use ratatui::{
 };
 
 use crate::app::{App, Mode, PanelLayout};
+use crate::config::PreviewSide;
 use crate::fs::ops::Register;
 use crate::theme::Theme;
+use crate::util::icons::{label, sep_left, sep_right};
 
 mod find_overlay;
 pub(crate) mod hex;
@@ -22,10 +24,6 @@ mod status;
 mod tree;
 pub(crate) mod util;
 
-// Powerline separators
-pub(super) const SEP_RIGHT: &str = "\u{e0b0}"; //
-pub(super) const SEP_LEFT: &str = "\u{e0b2}"; //
-
 // Spinner frames for ongoing background work, shared by the tab bar, the panel
 // loading row, and overlays so they animate identically.
 pub(super) const SPINNER: [&str; 4] = ["\u{25d0}", "\u{25d3}", "\u{25d1}", "\u{25d2}"];
@@ -47,9 +45,23 @@ pub struct RenderContext<'a> {
     pub register: Option<&'a Register>,
     pub register_paths: HashSet<PathBuf>,
     pub git_statuses: &'a HashMap<PathBuf, char>,
+    /// Symlinks known to point outside the tree's root, flagged in the sign
+    /// column.
+    pub link_escapes: &'a HashMap<PathBuf, bool>,
     pub theme: &'a Theme,
     pub is_select_mode: bool,
     pub tick_count: u32,
+    pub show_owner: bool,
+    /// Dates as ages, for panels without their own `Space T` choice.
+    pub relative_dates: bool,
+    /// Phantom rows of a pending paste slot in by case-sensitive name order.
+    pub case_sensitive_sort: bool,
+    /// The `/` search pattern, highlighted in the active panel's names.
+    pub search_query: &'a str,
+    /// Highlighted title segment while picking an ancestor with `gp`.
+    pub crumb_selected: Option<usize>,
+    /// Name being typed and its cursor while renaming in place on the row.
+    pub inline_edit: Option<(&'a str, usize)>,
 }
 
 // ── Main render ─────────────────────────────────────────────────────
@@ -60,6 +72,7 @@ pub fn render(f: &mut Frame, app: &mut App) {
     // Reset mouse hit-testing geometry; it is rebuilt below as widgets are laid out.
     app.mouse_regions.tabs.clear();
     app.mouse_regions.panels.clear();
+    app.preview_image_area = None;
 
     if is_too_small(full_area) {
         render_too_small(f, full_area, &app.theme);
@@ -78,7 +91,9 @@ pub fn render(f: &mut Frame, app: &mut App) {
     let (tab_bar_area, panel_chunk, status_area) = (chunks[0], chunks[1], chunks[2]);
 
     app.mouse_regions.tab_bar_row = tab_bar_area.y;
-    app.mouse_regions.tabs = render_tab_bar(f, app, tab_bar_area);
+    let (tabs, tasks_badge) = render_tab_bar(f, app, tab_bar_area);
+    app.mouse_regions.tabs = tabs;
+    app.mouse_regions.tasks_badge = Some(tasks_badge);
 
     // Build horizontal layout based on panel layout + tree
     let layout = app.layout;
@@ -110,8 +125,14 @@ pub fn render(f: &mut Frame, app: &mut App) {
         if needs_rebuild && app.tree_load_rx.is_none() {
             app.spawn_rebuild_tree();
         }
-        // If not focused, auto-position cursor on current dir
+        // If not focused, auto-position cursor on current dir, unless it is on
+        // a file in that dir (a find result just revealed there).
+        let on_current_file = app
+            .tree_data
+            .get(app.tree_selected)
+            .is_some_and(|l| !l.is_dir && l.path.parent() == Some(current_path.as_path()));
         if !app.tree_focused
+            && !on_current_file
             && let Some(idx) = app.tree_data.iter().position(|l| l.is_current)
         {
             app.tree_selected = idx;
@@ -143,50 +164,68 @@ pub fn render(f: &mut Frame, app: &mut App) {
         register: app.register.as_ref(),
         register_paths,
         git_statuses: &app.git_statuses,
+        link_escapes: &app.link_escapes,
         theme: &app.theme,
         is_select_mode: app.mode == Mode::Select,
         tick_count: app.tick_count,
+        show_owner: app.config.show_owner,
+        relative_dates: app.config.relative_dates,
+        case_sensitive_sort: app.config.case_sensitive_sort,
+        search_query: &app.search_query,
+        crumb_selected: (app.mode == Mode::Breadcrumb).then_some(app.crumb_selected),
+        inline_edit: inline_rename(app).then(|| {
+            (
+                app.rename_input.as_str(),
+                app.line_editor.cursor(&app.rename_input),
+            )
+        }),
     };
 
     let panels_active = !app.tree_focused;
     let tab = app.tab();
     let active_idx = tab.active;
 
-    // Determine how many panels to render as file panels vs preview
-    let preview_replaces_last = app.preview_mode && visible_count >= 2;
-    let file_panel_count = if preview_replaces_last {
-        visible_count - 1
+    // With the preview on, it takes one column on the configured side (or
+    // across from the active panel) and the remaining columns show the other
+    // panels, the active one always among them.
+    let (shown, file_areas, preview_area) = if app.preview_mode && visible_count >= 2 {
+        let side = app.config.preview_side_for(visible_count, active_idx);
+        let shown = preview_shown_panels(visible_count, active_idx, side);
+        let (file_areas, preview_area) =
+            split_for_preview(&panel_areas, side, app.config.preview_percent());
+        (shown, file_areas, Some(preview_area))
     } else {
-        visible_count
+        ((0..visible_count).collect(), panel_areas, None)
     };
 
     // Render file panels
-    for (i, &area) in panel_areas.iter().enumerate().take(file_panel_count) {
+    let mut titles = Vec::with_capacity(shown.len());
+    for (&i, &area) in shown.iter().zip(&file_areas) {
         let phantoms = app.phantoms_for(&tab.panels[i].path);
-        panel::render_panel(
+        titles.push(panel::render_panel(
             f,
             &tab.panels[i],
             area,
             panels_active && i == active_idx,
             &phantoms,
             &ctx,
-        );
+        ));
     }
 
-    // Render preview in the last slot if preview mode is on
-    if preview_replaces_last {
-        let last = visible_count - 1;
-        preview::render_preview(f, &app.preview, panel_areas[last], ctx.theme);
-    }
+    let image_area =
+        preview_area.and_then(|area| preview::render_preview(f, &app.preview, area, ctx.theme));
 
     // Record file-panel content areas for mouse hit-testing. The inner area mirrors
     // the `Borders::ALL` block used in `render_panel` (1-cell border on each side).
-    let panel_regions: Vec<crate::app::PanelRegion> = panel_areas
+    let panel_regions: Vec<crate::app::PanelRegion> = file_areas
         .iter()
-        .take(file_panel_count)
-        .enumerate()
-        .map(|(i, a)| crate::app::PanelRegion {
+        .zip(shown)
+        .zip(titles)
+        .map(|((a, i), title)| crate::app::PanelRegion {
             index: i,
+            title_row: a.y,
+            crumbs: title.crumbs,
+            sort_toggle: title.sort,
             inner: ratatui::layout::Rect {
                 x: a.x.saturating_add(1),
                 y: a.y.saturating_add(1),
@@ -219,10 +258,13 @@ pub fn render(f: &mut Frame, app: &mut App) {
         overlays::render_bookmarks(f, app, full_area);
     }
 
+    if app.mode == Mode::Rename && !inline_rename(app) {
+        overlays::render_input_popup(f, app, full_area);
+    }
+
     if matches!(
         app.mode,
-        Mode::Rename
-            | Mode::Create
+        Mode::Create
             | Mode::BookmarkAdd
             | Mode::BookmarkRename
             | Mode::SelectPattern
@@ -263,6 +305,10 @@ pub fn render(f: &mut Frame, app: &mut App) {
         overlays::render_quit_confirm(f, app, full_area);
     }
 
+    if app.mode == Mode::ConfirmEmptyTrash {
+        overlays::render_empty_trash_confirm(f, app, full_area);
+    }
+
     if app.mode == Mode::Conflict {
         overlays::render_conflict_popup(f, app, full_area);
     }
@@ -283,6 +329,26 @@ pub fn render(f: &mut Frame, app: &mut App) {
         overlays::render_trash(f, app, full_area);
     }
 
+    if app.mode == Mode::Plan {
+        overlays::render_plan_popup(f, app, full_area);
+    }
+
+    if app.mode == Mode::Palette {
+        overlays::render_palette(f, app, full_area);
+    }
+
+    if app.mode == Mode::Messages {
+        overlays::render_messages(f, app, full_area);
+    }
+
+    if app.mode == Mode::Mounts {
+        overlays::render_mounts(f, app, full_area);
+    }
+
+    if app.mode == Mode::PastePicker {
+        overlays::render_paste_picker(f, app, full_area);
+    }
+
     if let Some(ref fs) = app.find_state {
         find_overlay::render_find(f, fs, &app.theme, full_area);
     }
@@ -296,6 +362,12 @@ pub fn render(f: &mut Frame, app: &mut App) {
             full_area,
         );
     }
+
+    // The terminal draws an image over the text, so it would cover an overlay.
+    let overlay = !matches!(app.mode, Mode::Normal | Mode::Visual | Mode::Select)
+        || app.find_state.is_some()
+        || app.which_key_hints().is_some();
+    app.preview_image_area = image_area.filter(|_| !overlay);
 }
 
 /// Build panel layout areas based on layout mode and tree visibility.
@@ -344,6 +416,55 @@ fn build_panel_layout(
     }
 }
 
+/// Whether a rename in progress is edited on the panel row, not in a popup.
+fn inline_rename(app: &App) -> bool {
+    app.mode == Mode::Rename && app.config.inline_rename
+}
+
+/// Panels left visible when the preview covers one of `count` columns, in
+/// column order. The preview takes the panel at the `side` edge, or its
+/// neighbour when that one is active, so the active panel never disappears.
+fn preview_shown_panels(count: usize, active: usize, side: PreviewSide) -> Vec<usize> {
+    let (edge, inner) = match side {
+        PreviewSide::Right => (count - 1, count - 2),
+        PreviewSide::Left => (0, 1),
+    };
+    let covered = if active == edge { inner } else { edge };
+    (0..count).filter(|&i| i != covered).collect()
+}
+
+/// Re-split the panel columns so the preview gets `percent` of their combined
+/// width on `side` and the file panels share the rest equally.
+fn split_for_preview(panel_areas: &[Rect], side: PreviewSide, percent: u16) -> (Vec<Rect>, Rect) {
+    let first = panel_areas[0];
+    let last = panel_areas[panel_areas.len() - 1];
+    let whole = Rect {
+        width: last.x + last.width - first.x,
+        ..first
+    };
+    let preview = Constraint::Percentage(percent);
+    let files = Constraint::Percentage(100 - percent);
+    let halves = match side {
+        PreviewSide::Right => [files, preview],
+        PreviewSide::Left => [preview, files],
+    };
+    let cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(halves)
+        .split(whole);
+    let (files_area, preview_area) = match side {
+        PreviewSide::Right => (cols[0], cols[1]),
+        PreviewSide::Left => (cols[1], cols[0]),
+    };
+    let n = panel_areas.len() as u32 - 1;
+    let file_areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints((0..n).map(|_| Constraint::Ratio(1, n)))
+        .split(files_area)
+        .to_vec();
+    (file_areas, preview_area)
+}
+
 /// Render a centered "terminal too small" notice. Each line is width-clamped and the
 /// block is vertically centered, so it degrades cleanly down to a 1-cell area.
 fn render_too_small(f: &mut Frame, area: Rect, t: &Theme) {
@@ -381,9 +502,11 @@ fn render_too_small(f: &mut Frame, area: Rect, t: &Theme) {
 
 // ── Tab bar ─────────────────────────────────────────────────────────
 
-/// Renders the tab bar and returns the clickable span of each tab as
-/// `(x_start, x_end_exclusive, tab_index)` for mouse hit-testing.
-fn render_tab_bar(f: &mut Frame, app: &App, area: Rect) -> Vec<(u16, u16, usize)> {
+/// Clickable tab spans: (x_start, x_end_exclusive, tab_index).
+type TabSpans = Vec<(u16, u16, usize)>;
+
+/// Draw the tab bar; returns the clickable tab spans and the TASKS badge span.
+fn render_tab_bar(f: &mut Frame, app: &App, area: Rect) -> (TabSpans, (u16, u16)) {
     let t = &app.theme;
     let mut spans = Vec::new();
     let mut regions: Vec<(u16, u16, usize)> = Vec::with_capacity(app.tabs.len());
@@ -410,7 +533,7 @@ fn render_tab_bar(f: &mut Frame, app: &App, area: Rect) -> Vec<(u16, u16, usize)
                 Style::default().fg(t.bg_text).bg(t.blue),
             ));
             spans.push(Span::styled(
-                SEP_RIGHT,
+                sep_right(),
                 Style::default().fg(t.blue).bg(t.status_bg),
             ));
             col += 1; // SEP_RIGHT
@@ -433,21 +556,27 @@ fn render_tab_bar(f: &mut Frame, app: &App, area: Rect) -> Vec<(u16, u16, usize)
         }
     }
 
-    // Right side: info segment (optional) + TASKS section
+    // Right side: info segment (optional) + TASKS section. The badge counts
+    // running tasks plus a dir-size scan, with the tasks' mean progress.
     let active_tasks = app.task_manager.active_count();
-    let tasks_label = if active_tasks > 0 {
-        format!(" \u{f0ae} TASKS {active_tasks} ")
-    } else {
-        " \u{f0ae} TASKS ".into()
+    let jobs = active_tasks + usize::from(app.background_progress.is_some());
+    let tasks_label = match app.task_manager.aggregate_pct() {
+        Some(pct) => label(&format!(" \u{f0ae} TASKS {jobs} \u{b7} {pct}% ")),
+        None if jobs > 0 => label(&format!(" \u{f0ae} TASKS {jobs} ")),
+        None => label(" \u{f0ae} TASKS "),
     };
     let tasks_sep_w = 1; // SEP_LEFT char
     let tasks_w = util::display_width(&tasks_label) + tasks_sep_w;
-    let tasks_bg = if active_tasks > 0 { t.green } else { t.magenta };
+    let tasks_bg = if jobs > 0 { t.green } else { t.magenta };
 
-    // Build info segment: running task status or finished notification
+    // Build info segment: the latest operation result (toast) first, so a finished
+    // task isn't hidden behind one still running; otherwise running task status.
     use crate::app::task_manager::{TaskKind, TaskState};
 
-    let info_segment: Option<(String, ratatui::style::Color)> = if active_tasks > 0 {
+    let info_segment: Option<(String, ratatui::style::Color)> = if let Some(toast) = &app.toast {
+        let fg = if toast.error { t.red } else { t.fg };
+        Some((toast.text.clone(), fg))
+    } else if active_tasks > 0 {
         // Show latest running task status
         app.task_manager.tasks().iter().rev().find_map(|task| {
             if let TaskState::Running {
@@ -460,6 +589,8 @@ fn render_tab_bar(f: &mut Frame, app: &App, area: Rect) -> Vec<(u16, u16, usize)
                     TaskKind::Move { .. } => t.yellow,
                     TaskKind::Delete { .. } => t.red,
                     TaskKind::Archive { .. } => t.magenta,
+                    TaskKind::Checksum { .. } => t.green,
+                    TaskKind::Rsync { .. } => t.cyan,
                 };
                 Some((format!("{status_text} {progress_pct}%"), fg))
             } else {
@@ -471,9 +602,7 @@ fn render_tab_bar(f: &mut Frame, app: &App, area: Rect) -> Vec<(u16, u16, usize)
         let spinner = SPINNER[(app.tick_count % 4) as usize];
         Some((format!("{spinner} {progress}"), t.cyan))
     } else {
-        app.task_notification
-            .as_ref()
-            .map(|notif| (notif.clone(), t.fg))
+        None
     };
 
     // Compute info segment width
@@ -507,17 +636,17 @@ fn render_tab_bar(f: &mut Frame, app: &App, area: Rect) -> Vec<(u16, u16, usize)
     // Info segment (powerline style)
     if let Some(info_span) = info_spans {
         spans.push(Span::styled(
-            SEP_LEFT,
+            sep_left(),
             Style::default().fg(t.bg_light).bg(t.status_bg),
         ));
         spans.push(info_span);
         spans.push(Span::styled(
-            SEP_LEFT,
+            sep_left(),
             Style::default().fg(tasks_bg).bg(t.bg_light),
         ));
     } else {
         spans.push(Span::styled(
-            SEP_LEFT,
+            sep_left(),
             Style::default().fg(tasks_bg).bg(t.status_bg),
         ));
     }
@@ -530,13 +659,38 @@ fn render_tab_bar(f: &mut Frame, app: &App, area: Rect) -> Vec<(u16, u16, usize)
     let line = Line::from(spans);
     f.render_widget(Paragraph::new(line), area);
 
-    regions
+    let badge_end = area.x + area.width;
+    let badge = (badge_end.saturating_sub(tasks_w as u16), badge_end);
+    (regions, badge)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn preview_never_covers_the_active_panel() {
+        use PreviewSide::{Left, Right};
+        assert_eq!(preview_shown_panels(2, 0, Right), [0]);
+        assert_eq!(preview_shown_panels(2, 1, Right), [1]);
+        assert_eq!(preview_shown_panels(2, 1, Left), [1]);
+        assert_eq!(preview_shown_panels(2, 0, Left), [0]);
+        assert_eq!(preview_shown_panels(3, 1, Right), [0, 1]);
+        assert_eq!(preview_shown_panels(3, 2, Right), [0, 2]);
+        assert_eq!(preview_shown_panels(3, 0, Left), [0, 2]);
+    }
+
+    #[test]
+    fn preview_split_honours_side_and_ratio() {
+        let areas = [Rect::new(10, 1, 45, 20), Rect::new(55, 1, 45, 20)];
+        let (files, preview) = split_for_preview(&areas, PreviewSide::Left, 60);
+        assert_eq!(preview, Rect::new(10, 1, 54, 20));
+        assert_eq!(files, [Rect::new(64, 1, 36, 20)]);
+        let (files, preview) = split_for_preview(&areas, PreviewSide::Right, 50);
+        assert_eq!(files, [Rect::new(10, 1, 45, 20)]);
+        assert_eq!(preview.x, 55);
+    }
+
     #[test]
     fn too_small_below_minimums() {
         assert!(is_too_small(Rect::new(0, 0, MIN_TERM_WIDTH - 1, 40)));
@@ -554,4 +708,221 @@ mod tests {
         )));
         assert!(!is_too_small(Rect::new(0, 0, 80, 40)));
     }
+
+    #[tokio::test]
+    async fn render_survives_tiny_terminals() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let modes = [
+            Mode::Normal,
+            Mode::Command,
+            Mode::Confirm,
+            Mode::Find,
+            Mode::Help,
+            Mode::Rename,
+            Mode::Create,
+            Mode::Viewer,
+            Mode::ThemePicker,
+            Mode::Bookmarks,
+            Mode::Chmod,
+            Mode::Info,
+            Mode::Tasks,
+            Mode::Trash,
+            Mode::Palette,
+            Mode::Messages,
+            Mode::Mounts,
+        ];
+        // Around the minimum, below it, and lopsided: a pane resized to a
+        // sliver must neither panic nor index past a layout.
+        let sizes = [(0, 0), (1, 1), (24, 6), (30, 7), (200, 6), (24, 60)];
+        for mode in modes {
+            for (w, h) in sizes {
+                let mut app = App::new_for_test(crate::app::make_test_entries(&["a.txt", "b/"]));
+                app.mode = mode;
+                app.show_tree = true;
+                app.preview_mode = true;
+                app.viewer = Some(crate::viewer::Viewer::loading(PathBuf::from("/test/a.txt")));
+                app.find_state = Some(crate::search::FindState::new_test(
+                    std::path::Path::new("/test"),
+                    &[("a.txt", false), ("b", true)],
+                ));
+                app.show_toast(String::from("a toast wider than the terminal"), true);
+                let mut term = Terminal::new(TestBackend::new(w, h)).unwrap();
+                term.draw(|f| render(f, &mut app)).unwrap();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn tree_shows_git_status_and_visual_marks() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App::new_for_test(crate::app::make_test_entries(&["a.txt"]));
+        let line = |name: &str, depth| crate::model::tree::TreeLine {
+            prefix: " ".repeat(depth),
+            name: name.into(),
+            path: PathBuf::from("/").join(name),
+            is_dir: true,
+            is_current: false,
+            is_on_path: false,
+            is_expanded: false,
+            depth,
+            unreadable: false,
+            link_escapes: None,
+        };
+        app.tree_data = vec![line("root", 0), line("changed", 1), line("clean", 1)];
+        app.tree_selected = 0;
+        app.git_statuses.insert(PathBuf::from("/changed"), 'M');
+
+        let mut term = Terminal::new(TestBackend::new(30, 6)).unwrap();
+        term.draw(|f| tree::render_tree(f, &app, f.area())).unwrap();
+        let buf = term.backend().buffer();
+        let fg_of = |row: u16, text: &str| {
+            let line: String = (0..30).map(|x| buf[(x, row)].symbol()).collect();
+            let col = line.find(text).expect(text);
+            let x = line[..col].chars().count() as u16;
+            buf[(x, row)].fg
+        };
+        assert_eq!(fg_of(2, "changed"), app.theme.yellow);
+        assert_eq!(fg_of(3, "clean"), app.theme.dir_color);
+        assert!(!(0..30).any(|x| buf[(x, 3)].symbol() == "\u{f024}"));
+
+        // A visual mark adds the panel's flag after the name, in its level color.
+        app.visual_marks.insert(PathBuf::from("/clean"), 3);
+        term.draw(|f| tree::render_tree(f, &app, f.area())).unwrap();
+        let buf = term.backend().buffer();
+        let flag = (0..30).find(|&x| buf[(x, 3)].symbol() == "\u{f024}");
+        assert_eq!(flag.map(|x| buf[(x, 3)].fg), Some(app.theme.red));
+    }
+
+    #[tokio::test]
+    async fn symlinks_leaving_the_tree_get_an_arrow() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut entries = crate::app::make_test_entries(&["inner", "outer"]);
+        for e in &mut entries[1..] {
+            e.is_symlink = true;
+        }
+        let mut app = App::new_for_test(entries);
+        app.link_escapes.insert(PathBuf::from("/test/inner"), false);
+        app.link_escapes.insert(PathBuf::from("/test/outer"), true);
+        let mut term = Terminal::new(TestBackend::new(60, 8)).unwrap();
+        term.draw(|f| render(f, &mut app)).unwrap();
+        let buf = term.backend().buffer();
+        let row_of = |name: &str| {
+            (0..8)
+                .map(|y| (0..60).map(|x| buf[(x, y)].symbol()).collect::<String>())
+                .find(|row| row.contains(name))
+                .unwrap()
+        };
+        assert!(!row_of("inner").contains(panel::LINK_ESCAPES));
+        assert!(row_of("outer").contains(panel::LINK_ESCAPES));
+
+        app.tree_data = vec![crate::model::tree::TreeLine {
+            prefix: String::new(),
+            name: "outer".into(),
+            path: PathBuf::from("/test/outer"),
+            is_dir: false,
+            is_current: false,
+            is_on_path: false,
+            is_expanded: false,
+            depth: 1,
+            unreadable: false,
+            link_escapes: Some(true),
+        }];
+        term.draw(|f| tree::render_tree(f, &app, f.area())).unwrap();
+        let buf = term.backend().buffer();
+        let row: String = (0..60).map(|x| buf[(x, 1)].symbol()).collect();
+        assert!(row.contains("outer"), "{row}");
+        assert!(row.contains(panel::LINK_ESCAPES), "{row}");
+    }
+
+    #[tokio::test]
+    async fn status_bar_right_segment_reaches_the_edge() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App::new_for_test(crate::app::make_test_entries(&["a.txt"]));
+        // Double-width text in the info segment must be cut by columns, not chars.
+        let wide = "日本語のファイル名".repeat(10);
+        for (message, width) in [("", 40), ("short", 81), (wide.as_str(), 40), (&wide, 81)] {
+            app.status_message = message.to_string();
+            let mut term = Terminal::new(TestBackend::new(width, 1)).unwrap();
+            term.draw(|f| status::render_status(f, &app, f.area()))
+                .unwrap();
+            let buf = term.backend().buffer();
+            let row: String = (0..width).map(|x| buf[(x, 0)].symbol()).collect();
+            assert!(row.ends_with("/2 "), "{row:?}");
+            assert_eq!(buf[(width - 1, 0)].bg, app.theme.blue, "{row:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn empty_directory_shows_a_placeholder() {
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App::new_for_test(crate::app::make_test_entries(&[]));
+        let mut term = Terminal::new(TestBackend::new(60, 10)).unwrap();
+        let screen = |term: &Terminal<TestBackend>| {
+            let buf = term.backend().buffer();
+            (0..10)
+                .map(|y| (0..60).map(|x| buf[(x, y)].symbol()).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        term.draw(|f| render(f, &mut app)).unwrap();
+        assert!(screen(&term).contains("\u{2014} empty \u{2014}"));
+
+        // An unreadable directory says why instead.
+        app.active_panel_mut().load_error = Some("Permission denied".into());
+        term.draw(|f| render(f, &mut app)).unwrap();
+        let text = screen(&term);
+        assert!(text.contains("Permission denied"));
+        assert!(!text.contains("empty"));
+    }
+
+    #[tokio::test]
+    async fn image_preview_leaves_room_for_the_picture() {
+        use crate::preview::{Preview, image};
+        use ratatui::{Terminal, backend::TestBackend};
+
+        let mut app = App::new_for_test(crate::app::make_test_entries(&["pic.png"]));
+        app.preview_mode = true;
+        let mut preview = Preview::from_text("pic.png".into(), "PNG image\n2 \u{d7} 3 pixels");
+        preview.image = Some(image::ImageInfo {
+            format: image::ImageFormat::Png,
+            width: 2,
+            height: 3,
+            upload: Some(image::Upload {
+                id: 1,
+                sequence: Vec::new(),
+            }),
+        });
+        app.preview = Some(preview);
+        let mut term = Terminal::new(TestBackend::new(80, 12)).unwrap();
+        term.draw(|f| render(f, &mut app)).unwrap();
+        let area = app.preview_image_area.expect("room for the image");
+        assert!(area.x >= 40 && area.width > 0);
+        let buf = term.backend().buffer();
+        let text: String = (area.y..area.y + area.height)
+            .flat_map(|y| (area.x..area.x + area.width).map(move |x| (x, y)))
+            .map(|pos| buf[pos].symbol())
+            .collect();
+        assert!(text.trim().is_empty(), "{text:?}");
+
+        // An overlay would be covered by the picture, so it goes.
+        app.mode = Mode::Help;
+        term.draw(|f| render(f, &mut app)).unwrap();
+        assert!(app.preview_image_area.is_none());
+
+        // Without a picture to draw, the description shows, unnumbered.
+        app.mode = Mode::Normal;
+        app.preview.as_mut().unwrap().image.as_mut().unwrap().upload = None;
+        term.draw(|f| render(f, &mut app)).unwrap();
+        assert!(app.preview_image_area.is_none());
+        let buf = term.backend().buffer();
+        let row: String = (area.x..area.x + area.width)
+            .map(|x| buf[(x, area.y)].symbol())
+            .collect();
+        assert_eq!(row.trim_end(), " PNG image");
+    }
 }
@@ -8,6 +8,7 @@ use ratatui::{
 
 use crate::app::App;
 use crate::ui::util::{display_width, fit_truncated};
+use crate::util::icons::glyph;
 
 pub(in crate::ui) fn render_archive(f: &mut Frame, app: &App, area: Rect) {
     let state = match app.archive_state {
@@ -35,7 +36,8 @@ pub(in crate::ui) fn render_archive(f: &mut Frame, app: &App, area: Rect) {
         .unwrap_or_default();
 
     let title = format!(
-        " \u{f410} {} ({} files, {}) ",
+        " {}{} ({} files, {}) ",
+        glyph("\u{f410} ", ""),
         archive_name,
         state.file_count,
         format_size(state.total_size),
@@ -78,11 +80,11 @@ pub(in crate::ui) fn render_archive(f: &mut Frame, app: &App, area: Rect) {
         // Icon + expand indicator
         let (icon, icon_fg) = if node.is_dir {
             let arrow = if node.expanded {
-                "\u{f0078} "
+                glyph("\u{f0078} ", "v ")
             } else {
-                "\u{f0054} "
+                glyph("\u{f0054} ", "> ")
             };
-            (format!("{arrow}\u{f07b} "), t.yellow)
+            (format!("{arrow}{}", glyph("\u{f07b} ", "/ ")), t.yellow)
         } else {
             let ext_icon = crate::util::icons::file_icon(&node.name, false);
             (ext_icon.to_string(), t.fg)
@@ -8,7 +8,8 @@ use ratatui::{
 
 use crate::app::App;
 use crate::app::bulk_rename::BulkRenameSubMode;
-use crate::ui::util::{display_width, visible_input_tail};
+use crate::ui::util::{display_width, truncate_to_width, visible_input_tail};
+use crate::util::icons::{glyph, label};
 
 pub(in crate::ui) fn render_bulk_rename(f: &mut Frame, app: &App, area: Rect) {
     let state = match app.bulk_rename {
@@ -32,7 +33,9 @@ pub(in crate::ui) fn render_bulk_rename(f: &mut Frame, app: &App, area: Rect) {
 
     let changed = state.changed_count();
     let total = state.entries.len();
-    let title = format!(" \u{f044} Bulk Rename ({changed}/{total} changed) ");
+    let title = label(&format!(
+        " \u{f044} Bulk Rename ({changed}/{total} changed) "
+    ));
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -73,15 +76,14 @@ pub(in crate::ui) fn render_bulk_rename(f: &mut Frame, app: &App, area: Rect) {
         let is_conflict = conflicts.contains(&i);
 
         let icon = if entry.is_dir {
-            "\u{f07b} "
+            glyph("\u{f07b} ", "/ ")
         } else {
-            "\u{f15b} "
+            glyph("\u{f15b} ", "  ")
         };
 
         // Original name (left column)
-        let orig_display =
-            truncate_with_ellipsis(&format!("{icon}{}", entry.original_name), name_col_w);
-        let orig_pad = name_col_w.saturating_sub(char_width(&orig_display));
+        let orig_display = truncate_to_width(&format!("{icon}{}", entry.original_name), name_col_w);
+        let orig_pad = name_col_w.saturating_sub(display_width(&orig_display));
 
         let orig_fg = if is_active { t.fg } else { t.fg_dim };
         let orig_bg = if is_active { t.bg_light } else { t.bg };
@@ -90,11 +92,11 @@ pub(in crate::ui) fn render_bulk_rename(f: &mut Frame, app: &App, area: Rect) {
         let new_display = if is_active && state.sub_mode == BulkRenameSubMode::Edit {
             // Show edit input with cursor
             let input = &state.edit_input;
-            truncate_with_ellipsis(input, new_col_w.saturating_sub(1))
+            truncate_to_width(input, new_col_w.saturating_sub(1))
         } else {
-            truncate_with_ellipsis(&entry.new_name, new_col_w)
+            truncate_to_width(&entry.new_name, new_col_w)
         };
-        let new_pad = new_col_w.saturating_sub(char_width(&new_display));
+        let new_pad = new_col_w.saturating_sub(display_width(&new_display));
 
         let new_fg = if is_conflict {
             t.red
@@ -153,7 +155,7 @@ pub(in crate::ui) fn render_bulk_rename(f: &mut Frame, app: &App, area: Rect) {
     if state.sub_mode == BulkRenameSubMode::FindReplace {
         let prefix = ":%s";
         let input = &state.find_replace_input;
-        let pad = iw.saturating_sub(char_width(prefix) + char_width(input) + 1);
+        let pad = iw.saturating_sub(display_width(prefix) + display_width(input) + 1);
         let fr_line = Line::from(vec![
             Span::styled(prefix, Style::default().fg(t.yellow)),
             Span::styled(input.as_str(), Style::default().fg(t.fg)),
@@ -164,8 +166,8 @@ pub(in crate::ui) fn render_bulk_rename(f: &mut Frame, app: &App, area: Rect) {
         f.render_widget(Paragraph::new(fr_line), fr_area);
         bottom_row += 1;
     } else if let Some(ref err) = state.error {
-        let err_display = truncate_with_ellipsis(err, iw);
-        let pad = iw.saturating_sub(char_width(&err_display));
+        let err_display = truncate_to_width(err, iw);
+        let pad = iw.saturating_sub(display_width(&err_display));
         let err_line = Line::from(vec![
             Span::styled(err_display, Style::default().fg(t.red)),
             Span::styled(" ".repeat(pad), Style::default()),
@@ -196,6 +198,8 @@ pub(in crate::ui) fn render_bulk_rename(f: &mut Frame, app: &App, area: Rect) {
             Span::styled(" edit  ", Style::default().fg(t.fg_dim)),
             Span::styled(":", Style::default().fg(t.yellow)),
             Span::styled("%s find/replace  ", Style::default().fg(t.fg_dim)),
+            Span::styled("E", Style::default().fg(t.yellow)),
+            Span::styled(" $EDITOR  ", Style::default().fg(t.fg_dim)),
             Span::styled("d", Style::default().fg(t.yellow)),
             Span::styled(" remove  ", Style::default().fg(t.fg_dim)),
             Span::styled("u", Style::default().fg(t.yellow)),
@@ -225,11 +229,3 @@ pub(in crate::ui) fn render_bulk_rename(f: &mut Frame, app: &App, area: Rect) {
     let hint_area = Rect::new(inner.x, bottom_row, inner.width, 1);
     f.render_widget(Paragraph::new(Line::from(hints)), hint_area);
 }
-
-fn truncate_with_ellipsis(s: &str, max: usize) -> String {
-    crate::ui::util::truncate_to_width(s, max)
-}
-
-fn char_width(s: &str) -> usize {
-    crate::ui::util::display_width(s)
-}
@@ -8,6 +8,7 @@ use ratatui::{
 
 use crate::app::App;
 use crate::ui::util::{display_width, truncate_to_width_left};
+use crate::util::icons::{glyph, label};
 
 pub(in crate::ui) fn render_chmod_popup(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -46,7 +47,7 @@ pub(in crate::ui) fn render_chmod_popup(f: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(accent))
-        .title(" 󰌑 Permissions ")
+        .title(label(" 󰌑 Permissions "))
         .title_style(Style::default().fg(accent))
         .style(Style::default().bg(t.bg));
 
@@ -58,7 +59,7 @@ pub(in crate::ui) fn render_chmod_popup(f: &mut Frame, app: &App, area: Rect) {
 
     // -- File context line --
     {
-        let icon = " 󰈔 ";
+        let icon = glyph(" 󰈔 ", " ");
         let icon_w = display_width(icon);
         let max_name = iw.saturating_sub(icon_w);
         let name_display = truncate_to_width_left(&file_ctx, max_name);
@@ -144,11 +145,11 @@ pub(in crate::ui) fn render_chmod_popup(f: &mut Frame, app: &App, area: Rect) {
             [None, None, None]
         };
 
-        let labels = [" 󰀄 owner ", " 󰡉 group ", " 󰀈 other "];
-        let label_w = 9; // all labels are 9 display chars
+        let labels = [" 󰀄 owner ", " 󰡉 group ", " 󰀈 other "].map(label);
+        let label_w = display_width(&labels[0]); // all labels are one width
 
         for i in 0..3 {
-            let label = labels[i];
+            let label = labels[i].clone();
             let digit = digits[i];
             let active = digit.is_some();
 
@@ -256,13 +257,20 @@ pub(in crate::ui) fn render_chmod_popup(f: &mut Frame, app: &App, area: Rect) {
         } else {
             Style::default().fg(t.fg_dim)
         };
+        let recursive_style = if app.chmod_recursive {
+            Style::default().fg(t.bg).bg(accent)
+        } else {
+            Style::default().fg(t.fg_dim)
+        };
         let hint_line = Line::from(vec![
             Span::styled(" \u{23ce}", enter_style),
             Span::styled(" apply  ", Style::default().fg(t.fg_dim)),
             Span::styled("esc", Style::default().fg(accent)),
             Span::styled(" cancel  ", Style::default().fg(t.fg_dim)),
             Span::styled("0-7", Style::default().fg(accent)),
-            Span::styled(" octal", Style::default().fg(t.fg_dim)),
+            Span::styled(" octal  ", Style::default().fg(t.fg_dim)),
+            Span::styled("r", Style::default().fg(accent)),
+            Span::styled(" recursive", recursive_style),
         ]);
         f.render_widget(
             Paragraph::new(hint_line),
@@ -230,12 +230,19 @@ pub(in crate::ui) fn render_chown_picker(f: &mut Frame, app: &App, area: Rect) {
     );
 
     // Hint line
+    let recursive_style = if picker.recursive {
+        Style::default().fg(t.bg).bg(accent)
+    } else {
+        Style::default().fg(t.fg_dim)
+    };
     let hint_line = Line::from(vec![
         Span::styled(" \u{23ce}", Style::default().fg(accent)),
         Span::styled(" apply  ", Style::default().fg(t.fg_dim)),
         Span::styled("tab", Style::default().fg(accent)),
         Span::styled(" switch  ", Style::default().fg(t.fg_dim)),
-        Span::styled("esc", Style::default().fg(accent)),
+        Span::styled("r", Style::default().fg(accent)),
+        Span::styled(" recursive", recursive_style),
+        Span::styled("  esc", Style::default().fg(accent)),
         Span::styled(" cancel", Style::default().fg(t.fg_dim)),
     ]);
     let hint_y = inner.y + inner.height.saturating_sub(1);
@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::util::icons::label;
 
 pub(in crate::ui) fn render_command_popup(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -21,7 +22,7 @@ pub(in crate::ui) fn render_command_popup(f: &mut Frame, app: &App, area: Rect)
 
     f.render_widget(Clear, popup);
 
-    let title = " \u{f018d} Command ".to_string();
+    let title = label(" \u{f018d} Command ");
 
     let block = Block::default()
         .borders(Borders::ALL)
@@ -36,8 +37,9 @@ pub(in crate::ui) fn render_command_popup(f: &mut Frame, app: &App, area: Rect)
     let iw = inner.width as usize;
     let mut row = 0u16;
 
-    // Input field (cursor stays at the end; text scrolls to show the tail)
-    let input_line = super::input_field_line(&app.command_input, " : ", iw, accent, t);
+    // Input field (text scrolls to keep the cursor in view)
+    let cursor = app.line_editor.cursor(&app.command_input);
+    let input_line = super::input_field_line_at(&app.command_input, cursor, " : ", iw, accent, t);
     let input_area = Rect::new(inner.x, inner.y + row, inner.width, 1);
     f.render_widget(Paragraph::new(input_line), input_area);
     row += 1;
@@ -8,6 +8,7 @@ use ratatui::{
 
 use crate::app::App;
 use crate::ui::util::{display_width, fit_truncated};
+use crate::util::icons::{glyph, label};
 
 pub(in crate::ui) fn render_confirm_popup(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -28,9 +29,9 @@ pub(in crate::ui) fn render_confirm_popup(f: &mut Frame, app: &App, area: Rect)
     f.render_widget(Clear, popup);
 
     let title = if permanent {
-        format!(" 󰗨 Permanently Delete ({n}) ")
+        label(&format!(" 󰗨 PERMANENTLY delete ({n}) "))
     } else {
-        format!("  Move to Trash ({n}) ")
+        label(&format!("  Move to Trash ({n}) "))
     };
     let block = Block::default()
         .borders(Borders::ALL)
@@ -56,7 +57,11 @@ pub(in crate::ui) fn render_confirm_popup(f: &mut Frame, app: &App, area: Rect)
             .map(|n| n.to_string_lossy().into_owned())
             .unwrap_or_default();
         let is_dir = *is_dir;
-        let icon = if is_dir { " " } else { " 󰈔 " };
+        let icon = if is_dir {
+            glyph(" ", " / ")
+        } else {
+            glyph(" 󰈔 ", "   ")
+        };
         let icon_color = if is_dir { t.blue } else { t.fg_dim };
 
         let (name_display, pad) = fit_truncated(&name, iw, display_width(icon));
@@ -88,12 +93,16 @@ pub(in crate::ui) fn render_confirm_popup(f: &mut Frame, app: &App, area: Rect)
     );
 
     // Hint line
-    let hint_line = Line::from(vec![
+    let mut hint = vec![
         Span::styled(" y", Style::default().fg(accent)),
         Span::styled(" confirm  ", Style::default().fg(t.fg_dim)),
         Span::styled("esc", Style::default().fg(accent)),
         Span::styled(" cancel", Style::default().fg(t.fg_dim)),
-    ]);
+    ];
+    if permanent {
+        hint.push(Span::styled("  no undo", Style::default().fg(t.red)));
+    }
+    let hint_line = Line::from(hint);
     let hint_y = inner.y + inner.height.saturating_sub(1);
     let hint_area = Rect::new(inner.x, hint_y, inner.width, 1);
     f.render_widget(Paragraph::new(hint_line), hint_area);
@@ -116,7 +125,7 @@ pub(in crate::ui) fn render_quit_confirm(f: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(accent))
-        .title(" \u{f0a48} Quit ")
+        .title(label(" \u{f0a48} Quit "))
         .title_style(Style::default().fg(accent))
         .style(Style::default().bg(t.bg));
     let inner = block.inner(popup);
@@ -148,3 +157,55 @@ pub(in crate::ui) fn render_quit_confirm(f: &mut Frame, app: &App, area: Rect) {
         ),
     );
 }
+
+/// `:emptytrash` confirmation: what the system trash holds and that purging
+/// it is final.
+pub(in crate::ui) fn render_empty_trash_confirm(f: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let accent = t.red;
+    let n = app.empty_trash_items.len();
+    let size = crate::util::format_bytes(app.empty_trash_size());
+
+    let w = 50u16.min(area.width.saturating_sub(4)).max(30);
+    let h = 6u16.min(area.height);
+    let x = (area.width.saturating_sub(w)) / 2;
+    let y = (area.height.saturating_sub(h)) / 2;
+    let popup = Rect::new(area.x + x, area.y + y, w, h);
+
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(accent))
+        .title(label(" \u{f05e8} Empty trash "))
+        .title_style(Style::default().fg(accent))
+        .style(Style::default().bg(t.bg));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let msg = Line::from(Span::styled(
+        format!("The trash holds {n} item(s), {size}."),
+        Style::default().fg(t.fg),
+    ));
+    let sub = Line::from(Span::styled(
+        "They will be deleted permanently.",
+        Style::default().fg(t.fg_dim),
+    ));
+    let hint = Line::from(vec![
+        Span::styled("y", Style::default().fg(accent)),
+        Span::styled(" empty  ", Style::default().fg(t.fg_dim)),
+        Span::styled("esc", Style::default().fg(accent)),
+        Span::styled(" cancel  ", Style::default().fg(t.fg_dim)),
+        Span::styled("no undo", Style::default().fg(accent)),
+    ]);
+
+    f.render_widget(
+        Paragraph::new(vec![msg, sub, Line::from(""), hint]),
+        Rect::new(
+            inner.x + 1,
+            inner.y,
+            inner.width.saturating_sub(2),
+            inner.height,
+        ),
+    );
+}
@@ -7,8 +7,10 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::app::dialogs::{CONFLICT_BUTTONS, CONFLICT_COLS, conflict_button_enabled};
 use crate::ui::util::{display_width, truncate_to_width_left};
 use crate::util::format_bytes;
+use crate::util::icons::glyph;
 
 fn format_datetime(time: std::time::SystemTime) -> String {
     use chrono::{DateTime, Local, Utc};
@@ -78,9 +80,9 @@ pub(in crate::ui) fn render_conflict_popup(f: &mut Frame, app: &App, area: Rect)
     )));
 
     let icon = if info.is_dir {
-        " \u{f115} "
+        glyph(" \u{f115} ", " / ")
     } else {
-        " \u{f016} "
+        glyph(" \u{f016} ", "   ")
     };
     let max_name = iw.saturating_sub(display_width(icon) + 1);
     let name_disp = truncate_to_width_left(&src_name, max_name);
@@ -131,27 +133,20 @@ pub(in crate::ui) fn render_conflict_popup(f: &mut Frame, app: &App, area: Rect)
     // The action buttons and their separator are pinned to the bottom of the popup so
     // they stay visible even on a short terminal (the file info above is what gets
     // truncated instead of the controls the user must press).
-    // Button rows: 2 rows x 3 cols
-    // Row 1: [O]verwrite [S]kip  [A]ll
+    // Button rows: 4 buttons, then 3
+    // Row 1: [O]verwrite [S]kip [R]ename [A]ll
     // Row 2: skip al[N]  ne[W]er [Esc]
-    let buttons = [
-        ("[O]verwrite", 0),
-        ("[S]kip", 1),
-        ("overwrite [A]ll", 2),
-        ("skip al[N]", 3),
-        ("ne[W]er", 4),
-        ("[Esc] abort", 5),
-    ];
-
     let mut button_lines: Vec<Line> = Vec::new();
-    for row in 0..2 {
+    for (row, buttons) in CONFLICT_BUTTONS.chunks(CONFLICT_COLS).enumerate() {
         let mut spans = vec![Span::raw(" ")];
-        for col in 0..3 {
-            let idx = row * 3 + col;
-            let (label, btn_idx) = buttons[idx];
+        for (col, (_, label)) in buttons.iter().enumerate() {
+            let btn_idx = row * CONFLICT_COLS + col;
             let is_selected = app.conflict_selected == btn_idx;
+            let enabled = conflict_button_enabled(btn_idx, info.can_rename);
 
-            let style = if is_selected {
+            let style = if !enabled {
+                Style::default().fg(t.fg_dim)
+            } else if is_selected {
                 Style::default()
                     .fg(t.bg)
                     .bg(t.yellow)
@@ -159,8 +154,8 @@ pub(in crate::ui) fn render_conflict_popup(f: &mut Frame, app: &App, area: Rect)
             } else {
                 Style::default().fg(t.yellow)
             };
-            spans.push(Span::styled(label, style));
-            if col < 2 {
+            spans.push(Span::styled(*label, style));
+            if col + 1 < buttons.len() {
                 spans.push(Span::styled("  ", Style::default()));
             }
         }
@@ -7,8 +7,10 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::config::Keymap;
 use crate::theme::Theme;
 use crate::ui::util::{centered_rect, pad_to_width, truncate_to_width};
+use crate::util::icons::{glyph, label};
 
 #[derive(Clone, Copy)]
 enum Cell {
@@ -16,6 +18,8 @@ enum Cell {
     Separator,
     Header(&'static str),
     Binding(&'static str, &'static str),
+    /// Legend row: the visual-mark flag in its level color, then the meaning.
+    MarkLevel(u8, &'static str),
 }
 
 fn push_section(col: &mut Vec<Cell>, header: &'static str, keys: &[(&'static str, &'static str)]) {
@@ -31,7 +35,7 @@ fn align(left: &mut Vec<Cell>, right: &mut Vec<Cell>) {
     right.resize(target, Cell::Blank);
 }
 
-fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
+fn build_help_rows(keymap: Keymap) -> (Vec<Cell>, Vec<Cell>) {
     let mut l: Vec<Cell> = Vec::new();
     let mut r: Vec<Cell> = Vec::new();
 
@@ -42,13 +46,17 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
         &[
             ("j k / \u{2191}\u{2193}", "Move down / up"),
             ("h l / \u{2190}\u{2192}", "Parent / Enter dir"),
-            ("Enter", "Open dir or view file"),
+            ("gp", "Jump to an ancestor dir"),
+            ("Enter", "Open dir or file (file_action)"),
             ("gg G", "Top / Bottom"),
             ("Ctrl-d/u", "Half page \u{2195}"),
             ("Ctrl-l/h", "Focus panel \u{2192}/\u{2190}"),
             ("Tab", "Cycle panels"),
             ("=", "Equalize panels"),
+            ("\\", "Swap panels"),
             ("~", "Home"),
+            ("g/ g.", "Root / Launch dir"),
+            ("]] / [[", "Next / Prev sibling dir"),
         ],
     );
     push_section(
@@ -61,6 +69,7 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
             ("y d D p", "Yank/Del/Paste"),
             ("cw", "Bulk rename"),
             ("cp co", "Chmod / Chown"),
+            (":g <glob>", "Keep matches \u{2192} Select"),
             ("v Esc", "Exit \u{2192} Normal"),
         ],
     );
@@ -71,6 +80,7 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
         &[
             ("Shift-\u{2191}/\u{2193}", "Toggle mark & move"),
             ("j k", "Move (keep marks)"),
+            ("gv", "Mark from last mark to here"),
             ("y d D p", "Yank/Del/Paste"),
             ("cw", "Bulk rename"),
             ("cp co", "Chmod / Chown"),
@@ -88,18 +98,23 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
         &[
             ("yy", "Yank (copy to register)"),
             ("dd", "Move to trash"),
+            ("3dd / 3yy", "Delete / yank 3 from cursor"),
             ("dD", "Permanent delete"),
             ("p / P", "Paste here / other"),
             ("yp / yn", "Copy path / name"),
+            ("yr", "Copy path from repo root"),
+            ("yc", "Copy file contents"),
             ("r F2", "Rename"),
             ("a F7", "Create (/ = dir)"),
             ("cw", "Bulk rename"),
+            ("cW", "Bulk rename in $EDITOR"),
             ("cp / co", "Chmod / Chown"),
             ("i", "File info"),
-            ("o F4", "Open in editor"),
+            ("o F4", "Edit file / marked files"),
             ("u", "Undo (restores last trash)"),
             ("F3", "View file"),
             ("F5 / F6", "Copy / Move to other"),
+            ("S-F5 / S-F6", "Copy / Move other's marked here"),
             ("gf", "Follow symlink target"),
             ("gs gu gd", "Git stage/unstage/diff"),
         ],
@@ -131,6 +146,7 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
             ("type", "Filter incrementally"),
             ("Enter", "Accept match"),
             ("Esc", "Cancel, restore cursor"),
+            ("\u{2191} / \u{2193}", "Recall history"),
             ("n / N", "Next / Prev (Normal)"),
         ],
     );
@@ -144,11 +160,16 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
         "\u{f1720} Space Leader",
         &[
             ("Sp+p", "Toggle preview panel"),
+            ("Sp+P", "Flip preview side"),
             ("Sp+t", "Toggle tree sidebar"),
             ("Sp+h", "Toggle hidden files"),
+            ("Sp+o", "Toggle owner column"),
+            ("Sp+T", "Relative dates (panel)"),
             ("Sp+d", "Calculate dir sizes"),
+            ("Sp+D", "Auto dir sizes on/off"),
             ("Sp+f", "Filter listing"),
             ("Sp+, / .", "Find local / global"),
+            ("Sp+;", "Reopen last find"),
             ("Sp+a / n", "Select all / Unselect"),
             ("Sp+b", "Bookmarks list"),
             ("Sp+j", "Task manager"),
@@ -158,6 +179,7 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
             ("Sp+ut", "Toggle transparent"),
             ("Sp+w1/2/3", "Layout 1/2/3 panels"),
             ("Sp+ws / we", "Swap / Equalize"),
+            ("Sp+wo", "Go to other panel's dir"),
         ],
     );
     push_section(
@@ -167,7 +189,13 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
             ("type", "Fuzzy filter files"),
             ("\u{2191}/\u{2193}", "Navigate results"),
             ("Tab", "Local \u{2194} Global"),
+            ("Ctrl-t", "All / dirs / files"),
+            ("Ctrl-r", "Global root: ~ \u{2194} here"),
             ("Enter", "Open selected"),
+            ("Ctrl-Space", "Pick result"),
+            ("Ctrl-a", "Pick all shown"),
+            ("Ctrl-y/d", "Yank / delete picked"),
+            ("Ctrl-u", "Clear query"),
             ("Esc", "Cancel"),
         ],
     );
@@ -178,6 +206,8 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
         &[
             ("j k", "Move cursor"),
             ("l / h", "Expand / Collapse"),
+            ("zR / zM", "Expand / Collapse all"),
+            ("/", "Filter (Esc clears)"),
             ("Enter", "Navigate to entry"),
             ("G / gg", "Bottom / Top"),
             ("Tab", "Return to panel"),
@@ -196,6 +226,7 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
             ("sn ss", "Name / Size"),
             ("sm sc", "Modified / Created"),
             ("se sr", "Extension / Reverse"),
+            ("S", "Cycle sort mode"),
             ("gt gT", "Next / Prev tab"),
             ("Ctrl-t/w", "New / Close tab"),
             ("J K", "Scroll preview \u{2193}/\u{2191}"),
@@ -222,45 +253,69 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
         &mut l,
         "\u{f02b} Marks & Selection",
         &[
-            ("m", "Toggle visual mark"),
+            ("m", "Cycle mark 1\u{2192}2\u{2192}3\u{2192}off"),
+            ("Sp+m", "Clear visual mark"),
             ("M", "Jump to next marked"),
+            ("]m [m", "Next / Prev marked"),
+            (":marked [1-3]", "Show marked only"),
             ("'{a-z}", "Go to named mark"),
             ("v V", "Enter visual mode"),
             ("A", "Select all \u{2192} Select"),
             ("+ / -", "Sel / Unsel by pattern"),
             ("*", "Invert selection"),
             ("Shift-\u{2191}/\u{2193}", "Mark entry & move"),
+            ("gv", "Mark from last mark to here"),
             ("b / B", "Add / List bookmarks"),
             ("T", "Theme picker"),
+            ("Ctrl-p", "Command palette"),
+            ("?", "Everyday keys"),
             ("Ctrl-r", "Refresh panel"),
         ],
     );
+    l.push(Cell::MarkLevel(1, "Level 1 \u{00b7} low"));
+    l.push(Cell::MarkLevel(2, "Level 2 \u{00b7} medium"));
+    l.push(Cell::MarkLevel(3, "Level 3 \u{00b7} high"));
     push_section(
         &mut r,
         "\u{f120} Command  (:)",
         &[
+            ("\u{2191} / \u{2193}", "Recall history"),
             (":q :quit", "Quit application"),
             (":cd <path>", "Change directory"),
             (":sort ..", "Sort name/size/mod.."),
             (":find ..", "Open fuzzy finder"),
+            (":findin ..", "Global find under path"),
             (":grep ..", "Search file contents"),
             (":strings", "View printable strings"),
             (":struct", "Parse PE/ELF/Mach-O structure"),
             (":ln <t> [n]", "Create symlink"),
+            (":pipe <cmd>", "Selection \u{2192} cmd stdin"),
+            (":rm :rm!", "Trash / Delete permanently"),
             (":sel ..", "Select by glob"),
             (":unsel ..", "Unselect by glob"),
             (":theme ..", "Load / list themes"),
             (":mark a-z", "Set named mark"),
             (":marks", "List named marks"),
+            (":messages", "Message history"),
             (":du", "Directory sizes"),
             (":tasks :jobs", "Task manager"),
             (":trash :restore", "Restore from trash"),
+            (":emptytrash", "Empty the system trash"),
+            (":mount :unmount", "Removable drives"),
             (":hidden", "Toggle hidden files"),
             (":bulkrename", "Bulk rename selected"),
+            (":bulkrename!", "Bulk rename in $EDITOR"),
             (":mkdir <n>", "Create directory"),
             (":touch <n>", "Create file"),
+            (":touch! [date]", "Set times to now/date"),
             (":rename <n>", "Rename selected"),
+            (":dryrun", "Toggle dry run (plan only)"),
+            (":icons on|off", "Nerd Font glyphs / ASCII"),
             (":archive <n>", "Create archive (.zip..)"),
+            (":compress <n>", "Same as :archive"),
+            (":extract", "Unpack into new directory"),
+            (":genchecksums", "Write SHA256SUMS"),
+            (":put / :get", "Copy to / from host:path"),
             (":bookmark <n>", "Add bookmark"),
             (":tabnew", "New tab"),
             (":tabclose", "Close tab"),
@@ -299,15 +354,67 @@ fn build_help_rows() -> (Vec<Cell>, Vec<Cell>) {
             ("u", "Undo line (reset name)"),
             ("d", "Remove from list"),
             (":", "Find/Replace (%s/old/new)"),
+            ("E", "Edit all names in $EDITOR"),
             ("Enter", "Apply all renames"),
             ("q Esc", "Exit"),
         ],
     );
     align(&mut l, &mut r);
 
+    // ── Block 7: the `keymap = "mc"` extras, only when they are live ────
+    if keymap == Keymap::Mc {
+        l.push(Cell::Separator);
+        r.push(Cell::Separator);
+        push_section(
+            &mut l,
+            "\u{f11c} MC keymap",
+            &[
+                ("F3", "View file / Enter dir"),
+                ("F7", "Make directory"),
+                ("Insert", "Mark & move down"),
+            ],
+        );
+        align(&mut l, &mut r);
+    }
+
     (l, r)
 }
 
+/// The `/` filter: bindings whose keys or description contain `query`
+/// (ignoring case), each under its section header. A section whose header
+/// matches is kept whole.
+fn filter_cells(cells: &[Cell], query: &str) -> Vec<Cell> {
+    let query = query.to_lowercase();
+    let hit = |s: &str| s.to_lowercase().contains(&query);
+    let mut out = Vec::new();
+    let mut flush = |header: Option<&'static str>, found: &mut Vec<Cell>| {
+        if let Some(header) = header.filter(|_| !found.is_empty()) {
+            if !out.is_empty() {
+                out.push(Cell::Blank);
+            }
+            out.push(Cell::Header(header));
+            out.append(found);
+        }
+    };
+    let mut header = None;
+    let mut header_hit = false;
+    let mut found = Vec::new();
+    for &cell in cells {
+        match cell {
+            Cell::Header(h) => {
+                flush(header, &mut found);
+                header = Some(h);
+                header_hit = hit(h);
+            }
+            Cell::Binding(k, d) if header_hit || hit(k) || hit(d) => found.push(cell),
+            Cell::MarkLevel(_, d) if header_hit || hit(d) => found.push(cell),
+            _ => {}
+        }
+    }
+    flush(header, &mut found);
+    out
+}
+
 fn render_cell(
     spans: &mut Vec<Span<'static>>,
     cell: Cell,
@@ -320,7 +427,7 @@ fn render_cell(
             spans.push(Span::raw(" ".repeat(col_w)));
         }
         Cell::Header(header) => {
-            let text = format!("  {header}");
+            let text = format!("  {}", label(header));
             let used = text.chars().count();
             let pad = col_w.saturating_sub(used);
             spans.push(Span::styled(text, Style::default().fg(t.cyan)));
@@ -338,6 +445,15 @@ fn render_cell(
             spans.push(Span::styled(key_text, Style::default().fg(t.yellow)));
             spans.push(Span::styled(desc_text, Style::default().fg(t.fg)));
         }
+        Cell::MarkLevel(level, desc) => {
+            let key_text = format!("  {:<width$}", glyph("\u{f024}", "*"), width = key_width);
+            let key_used = key_text.chars().count();
+            let desc_space = col_w.saturating_sub(key_used);
+            let desc_text = pad_to_width(&truncate_to_width(desc, desc_space), desc_space);
+            let color = crate::ui::panel::visual_mark_color(t, level);
+            spans.push(Span::styled(key_text, Style::default().fg(color)));
+            spans.push(Span::styled(desc_text, Style::default().fg(t.fg)));
+        }
     }
 }
 
@@ -349,41 +465,58 @@ pub(in crate::ui) fn render_help(f: &mut Frame, app: &mut App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(t.cyan))
-        .title(" \u{f02d6} Help ")
+        .title(label(" \u{f02d6} Help "))
         .title_style(Style::default().fg(t.cyan))
         .style(Style::default().bg(t.bg));
 
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
-    let (left_cells, right_cells) = build_help_rows();
-    let total_rows = left_cells.len();
+    let (left_cells, right_cells) = build_help_rows(app.config.keymap);
+    let filtering = app.help_filter_editing || !app.help_filter.is_empty();
 
     let inner_w = inner.width as usize;
     let col_w = inner_w / 2;
     let right_w = inner_w.saturating_sub(col_w);
     let key_width = 14;
 
-    let mut rows: Vec<Line> = Vec::with_capacity(total_rows);
-    for (lc, rc) in left_cells.into_iter().zip(right_cells) {
-        let line = match (lc, rc) {
-            (Cell::Separator, Cell::Separator) => {
-                // Full-width dim separator between blocks
-                let dashes = "\u{2500}".repeat(inner_w.saturating_sub(2));
-                Line::from(Span::styled(
-                    format!(" {dashes} "),
-                    Style::default().fg(t.border_inactive),
-                ))
-            }
-            _ => {
-                let mut spans = Vec::new();
-                render_cell(&mut spans, lc, col_w, key_width, t);
-                render_cell(&mut spans, rc, right_w, key_width, t);
-                Line::from(spans)
-            }
-        };
-        rows.push(line);
+    let mut rows: Vec<Line> = Vec::with_capacity(left_cells.len());
+    if filtering {
+        // One column: matches from both sides, in reading order.
+        let cells: Vec<Cell> = left_cells.into_iter().chain(right_cells).collect();
+        for cell in filter_cells(&cells, &app.help_filter) {
+            let mut spans = Vec::new();
+            render_cell(&mut spans, cell, inner_w, key_width, t);
+            rows.push(Line::from(spans));
+        }
+        if rows.is_empty() {
+            rows.push(Line::from(Span::styled(
+                "  No bindings match",
+                Style::default().fg(t.fg_dim),
+            )));
+        }
+    } else {
+        for (lc, rc) in left_cells.into_iter().zip(right_cells) {
+            let line = match (lc, rc) {
+                (Cell::Separator, Cell::Separator) => {
+                    // Full-width dim separator between blocks
+                    let dashes = "\u{2500}".repeat(inner_w.saturating_sub(2));
+                    Line::from(Span::styled(
+                        format!(" {dashes} "),
+                        Style::default().fg(t.border_inactive),
+                    ))
+                }
+                _ => {
+                    let mut spans = Vec::new();
+                    render_cell(&mut spans, lc, col_w, key_width, t);
+                    render_cell(&mut spans, rc, right_w, key_width, t);
+                    Line::from(spans)
+                }
+            };
+            rows.push(line);
+        }
     }
+    let total_rows = rows.len();
 
     // Reserve 2 lines at bottom: separator + hint
     let list_height = inner.height.saturating_sub(2) as usize;
@@ -415,16 +548,78 @@ pub(in crate::ui) fn render_help(f: &mut Frame, app: &mut App, area: Rect) {
         sep_area,
     );
 
-    // Hint line
-    let hint_line = Line::from(vec![
-        Span::styled(" j/k", Style::default().fg(t.yellow)),
-        Span::styled(" scroll  ", Style::default().fg(t.fg_dim)),
-        Span::styled("G/g", Style::default().fg(t.yellow)),
-        Span::styled(" bottom/top  ", Style::default().fg(t.fg_dim)),
-        Span::styled("q", Style::default().fg(t.yellow)),
-        Span::styled(" close", Style::default().fg(t.fg_dim)),
-    ]);
+    // Hint line, or the filter being typed
+    let hint_line = if app.help_filter_editing {
+        super::input_field_line(&app.help_filter, " / ", inner_w, t.cyan, t)
+    } else if filtering {
+        Line::from(vec![
+            Span::styled(
+                format!(" /{}", app.help_filter),
+                Style::default().fg(t.cyan),
+            ),
+            Span::styled("  ", Style::default().fg(t.fg_dim)),
+            Span::styled("esc", Style::default().fg(t.yellow)),
+            Span::styled(" clear  ", Style::default().fg(t.fg_dim)),
+            Span::styled("q", Style::default().fg(t.yellow)),
+            Span::styled(" close", Style::default().fg(t.fg_dim)),
+        ])
+    } else {
+        Line::from(vec![
+            Span::styled(" j/k", Style::default().fg(t.yellow)),
+            Span::styled(" scroll  ", Style::default().fg(t.fg_dim)),
+            Span::styled("G/g", Style::default().fg(t.yellow)),
+            Span::styled(" bottom/top  ", Style::default().fg(t.fg_dim)),
+            Span::styled("/", Style::default().fg(t.yellow)),
+            Span::styled(" filter  ", Style::default().fg(t.fg_dim)),
+            Span::styled("q", Style::default().fg(t.yellow)),
+            Span::styled(" close", Style::default().fg(t.fg_dim)),
+        ])
+    };
     let hint_y = inner.y + inner.height.saturating_sub(1);
     let hint_area = Rect::new(inner.x, hint_y, inner.width, 1);
     f.render_widget(Paragraph::new(hint_line), hint_area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(cells: &[Cell]) -> Vec<&'static str> {
+        cells
+            .iter()
+            .filter_map(|c| match c {
+                Cell::Header(h) => Some(*h),
+                Cell::Binding(k, _) => Some(*k),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn filter_keeps_matching_bindings_under_their_headers() {
+        let cells = [
+            Cell::Header("Files"),
+            Cell::Binding("yy", "Yank"),
+            Cell::Binding("dd", "Move to trash"),
+            Cell::Blank,
+            Cell::Header("Tabs"),
+            Cell::Binding("gt", "Next tab"),
+        ];
+        assert_eq!(keys(&filter_cells(&cells, "TRASH")), ["Files", "dd"]);
+        // A matching header keeps its whole section.
+        assert_eq!(keys(&filter_cells(&cells, "tabs")), ["Tabs", "gt"]);
+        assert_eq!(keys(&filter_cells(&cells, "y")), ["Files", "yy"]);
+        assert!(filter_cells(&cells, "zzz").is_empty());
+    }
+
+    #[test]
+    fn mc_section_only_with_mc_keymap() {
+        let has_mc = |keymap| {
+            let (l, _) = build_help_rows(keymap);
+            l.iter()
+                .any(|c| matches!(c, Cell::Header(h) if h.contains("MC keymap")))
+        };
+        assert!(!has_mc(Keymap::Vim));
+        assert!(has_mc(Keymap::Mc));
+    }
+}
@@ -8,6 +8,7 @@ use ratatui::{
 
 use crate::app::App;
 use crate::ui::util::{display_width, fit_truncated};
+use crate::util::icons::glyph;
 
 pub(in crate::ui) fn render_info_popup(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -23,7 +24,11 @@ pub(in crate::ui) fn render_info_popup(f: &mut Frame, app: &App, area: Rect) {
         .first()
         .map(|(_, v)| {
             let is_dir = lines.iter().any(|(k, v)| k == "Type" && v == "Directory");
-            let icon = if is_dir { " " } else { " 󰈔 " };
+            let icon = if is_dir {
+                glyph(" ", "")
+            } else {
+                glyph(" 󰈔 ", "")
+            };
             format!(" {icon}{v} ")
         })
         .unwrap_or_else(|| " Info ".into());
@@ -8,8 +8,9 @@ use ratatui::{
 
 use crate::app::{App, Mode};
 use crate::ui::util::{display_width, truncate_to_width_left};
+use crate::util::icons::{glyph, label};
 
-use super::input_field_line;
+use super::input_field_line_at;
 
 pub(in crate::ui) fn render_input_popup(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -37,6 +38,7 @@ pub(in crate::ui) fn render_input_popup(f: &mut Frame, app: &App, area: Rect) {
         }
         Mode::SelectPattern => (" 󰒅 Select ", t.green, None),
         Mode::UnselectPattern => (" 󰒅 Unselect ", t.red, None),
+        Mode::Create if app.create_dir_only => (" \u{f0256} New directory ", t.cyan, None),
         _ => (" 󰝒 New ", t.cyan, None),
     };
 
@@ -53,7 +55,7 @@ pub(in crate::ui) fn render_input_popup(f: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(accent))
-        .title(title)
+        .title(label(title))
         .title_style(Style::default().fg(accent))
         .style(Style::default().bg(t.bg));
 
@@ -65,7 +67,7 @@ pub(in crate::ui) fn render_input_popup(f: &mut Frame, app: &App, area: Rect) {
 
     // Context line (original name for rename)
     if let Some(ref orig) = context {
-        let label = " 󰈔 ";
+        let label = glyph(" 󰈔 ", " ");
         let max_name = iw.saturating_sub(display_width(label));
         let name_display = truncate_to_width_left(orig, max_name);
         let pad = iw.saturating_sub(display_width(label) + display_width(&name_display));
@@ -79,8 +81,9 @@ pub(in crate::ui) fn render_input_popup(f: &mut Frame, app: &App, area: Rect) {
         row += 1;
     }
 
-    // Input field (cursor stays at the end; text scrolls to show the tail)
-    let input_line = input_field_line(&app.rename_input, " \u{276f} ", iw, accent, t);
+    // Input field (text scrolls to keep the cursor in view)
+    let cursor = app.line_editor.cursor(&app.rename_input);
+    let input_line = input_field_line_at(&app.rename_input, cursor, " \u{276f} ", iw, accent, t);
     let input_area = Rect::new(inner.x, inner.y + row, inner.width, 1);
     f.render_widget(Paragraph::new(input_line), input_area);
     row += 1;
@@ -96,6 +99,12 @@ pub(in crate::ui) fn render_input_popup(f: &mut Frame, app: &App, area: Rect) {
 
     // Hint line
     let hint_line = match app.mode {
+        Mode::Create if app.create_dir_only => Line::from(vec![
+            Span::styled(" \u{23ce}", Style::default().fg(accent)),
+            Span::styled(" create  ", Style::default().fg(t.fg_dim)),
+            Span::styled("esc", Style::default().fg(accent)),
+            Span::styled(" cancel", Style::default().fg(t.fg_dim)),
+        ]),
         Mode::Create => Line::from(vec![
             Span::styled(" \u{23ce}", Style::default().fg(accent)),
             Span::styled(" confirm  ", Style::default().fg(t.fg_dim)),
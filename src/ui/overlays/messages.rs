@@ -0,0 +1,86 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+};
+
+use crate::app::App;
+use crate::app::message_log::MESSAGES_ROWS;
+use crate::ui::util::fit_truncated;
+use crate::util::icons::label;
+
+/// `:messages` — recent status and error messages, oldest first.
+pub(in crate::ui) fn render_messages(f: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let accent = t.cyan;
+    let log = &app.message_log;
+    let n = log.len();
+
+    // Height: border(2) + list (capped) + separator(1) + hint(1)
+    let list_h = n.clamp(1, MESSAGES_ROWS);
+    let h = (list_h as u16 + 4).min(area.height);
+    let w = 80u16.min(area.width.saturating_sub(4)).max(30);
+    let x = (area.width.saturating_sub(w)) / 2;
+    let y = (area.height.saturating_sub(h)) / 2;
+    let popup = Rect::new(area.x + x, area.y + y, w, h);
+
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(accent))
+        .title(label(&format!(" \u{f0369} Messages ({n}) ")))
+        .title_style(Style::default().fg(accent))
+        .style(Style::default().bg(t.bg));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let iw = inner.width as usize;
+    let list_height = inner.height.saturating_sub(2) as usize;
+    let max_scroll = n.saturating_sub(list_height.max(1));
+    let scroll = app.messages_scroll.min(max_scroll);
+
+    let items: Vec<ListItem> = log
+        .iter()
+        .skip(scroll)
+        .take(list_height)
+        .map(|m| {
+            let time = format!(" {} ", m.at.format("%H:%M:%S"));
+            let fg = if m.error { t.red } else { t.fg };
+            let (text, pad) = fit_truncated(&m.text, iw, time.len());
+            ListItem::new(Line::from(vec![
+                Span::styled(time, Style::default().fg(t.fg_dim).bg(t.bg_light)),
+                Span::styled(text, Style::default().fg(fg).bg(t.bg_light)),
+                Span::styled(" ".repeat(pad), Style::default().bg(t.bg_light)),
+            ]))
+        })
+        .collect();
+    let list_area = Rect::new(inner.x, inner.y, inner.width, list_height as u16);
+    f.render_widget(List::new(items), list_area);
+
+    let sep_y = inner.y + list_height as u16;
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            super::scroll_separator(iw, scroll, max_scroll),
+            Style::default().fg(t.border_inactive),
+        ))),
+        Rect::new(inner.x, sep_y, inner.width, 1),
+    );
+
+    let hint_line = Line::from(vec![
+        Span::styled(" j/k", Style::default().fg(accent)),
+        Span::styled(" scroll  ", Style::default().fg(t.fg_dim)),
+        Span::styled("g/G", Style::default().fg(accent)),
+        Span::styled(" top/bottom  ", Style::default().fg(t.fg_dim)),
+        Span::styled("esc", Style::default().fg(accent)),
+        Span::styled(" close", Style::default().fg(t.fg_dim)),
+    ]);
+    let hint_y = inner.y + inner.height.saturating_sub(1);
+    f.render_widget(
+        Paragraph::new(hint_line),
+        Rect::new(inner.x, hint_y, inner.width, 1),
+    );
+}
@@ -9,6 +9,11 @@ mod conflict;
 mod help;
 mod info;
 mod input;
+mod messages;
+mod mounts;
+mod palette;
+mod paste_picker;
+mod plan;
 mod search;
 mod tasks;
 mod theme_picker;
@@ -23,11 +28,16 @@ pub(super) use chmod::render_chmod_popup;
 pub(super) use chown::render_chown_picker;
 pub(super) use command::render_command_popup;
 pub(super) use confirm::render_confirm_popup;
-pub(super) use confirm::render_quit_confirm;
+pub(super) use confirm::{render_empty_trash_confirm, render_quit_confirm};
 pub(super) use conflict::render_conflict_popup;
 pub(super) use help::render_help;
 pub(super) use info::render_info_popup;
 pub(super) use input::render_input_popup;
+pub(super) use messages::render_messages;
+pub(super) use mounts::render_mounts;
+pub(super) use palette::render_palette;
+pub(super) use paste_picker::render_paste_picker;
+pub(super) use plan::render_plan_popup;
 pub(super) use search::render_filter_popup;
 pub(super) use search::render_search_popup;
 pub(super) use tasks::render_tasks_overlay;
@@ -45,24 +55,62 @@ pub(in crate::ui) fn input_field_line<'a>(
     total_cols: usize,
     accent: ratatui::style::Color,
     t: &crate::theme::Theme,
+) -> ratatui::text::Line<'a> {
+    input_field_line_at(input, input.chars().count(), prefix, total_cols, accent, t)
+}
+
+/// Like [`input_field_line`], but with the cursor at char index `cursor`: the char
+/// under it is drawn in reverse video and the text scrolls to keep it in view.
+pub(in crate::ui) fn input_field_line_at<'a>(
+    input: &str,
+    cursor: usize,
+    prefix: &'a str,
+    total_cols: usize,
+    accent: ratatui::style::Color,
+    t: &crate::theme::Theme,
 ) -> ratatui::text::Line<'a> {
     use crate::ui::util::{display_width, visible_input_tail};
     use ratatui::{
         style::Style,
         text::{Line, Span},
     };
+    use unicode_width::UnicodeWidthChar;
+
+    let split = input
+        .char_indices()
+        .nth(cursor)
+        .map_or(input.len(), |(b, _)| b);
+    let (before, rest) = input.split_at(split);
+    let mut rest_chars = rest.chars();
+    let cursor_span = match rest_chars.next() {
+        Some(c) => Span::styled(c.to_string(), Style::default().fg(t.bg_light).bg(accent)),
+        None => Span::styled("\u{2588}", Style::default().fg(accent).bg(t.bg_light)),
+    };
+    let cursor_w = display_width(&cursor_span.content).max(1);
 
     let prefix_w = display_width(prefix);
-    // Reserve 1 column for the cursor block at the end.
-    let field_w = total_cols.saturating_sub(prefix_w).max(1).saturating_sub(1);
-    let visible = visible_input_tail(input, field_w);
-    let used = prefix_w + display_width(&visible) + 1;
+    let field_w = total_cols.saturating_sub(prefix_w).max(1);
+    let visible = visible_input_tail(before, field_w.saturating_sub(cursor_w));
+
+    // Whatever follows the cursor gets the leftover columns.
+    let mut room = field_w.saturating_sub(display_width(&visible) + cursor_w);
+    let after: String = rest_chars
+        .take_while(|&c| {
+            let w = UnicodeWidthChar::width(c).unwrap_or(0);
+            let fits = w <= room;
+            room = room.saturating_sub(w);
+            fits
+        })
+        .collect();
+
+    let used = prefix_w + display_width(&visible) + cursor_w + display_width(&after);
     let pad = total_cols.saturating_sub(used);
 
     Line::from(vec![
         Span::styled(prefix, Style::default().fg(accent)),
         Span::styled(visible, Style::default().fg(t.fg).bg(t.bg_light)),
-        Span::styled("\u{2588}", Style::default().fg(accent).bg(t.bg_light)),
+        cursor_span,
+        Span::styled(after, Style::default().fg(t.fg).bg(t.bg_light)),
         Span::styled(" ".repeat(pad), Style::default().bg(t.bg_light)),
     ])
 }
@@ -0,0 +1,118 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+};
+
+use crate::app::App;
+use crate::app::mounts::MOUNTS_ROWS;
+use crate::search::abbreviate_home;
+use crate::ui::util::{display_width, fit_truncated};
+use crate::util::icons::{glyph, label};
+
+/// `:mount` — devices with a filesystem, removable ones first.
+pub(in crate::ui) fn render_mounts(f: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let accent = t.orange;
+    let devices = &app.mount_devices;
+    let len = devices.len();
+
+    // Height: border(2) + list (capped) + separator(1) + hint(1)
+    let list_h = len.clamp(1, MOUNTS_ROWS);
+    let h = (list_h as u16 + 4).min(area.height);
+    let w = 76u16.min(area.width.saturating_sub(4)).max(34);
+    let x = (area.width.saturating_sub(w)) / 2;
+    let y = (area.height.saturating_sub(h)) / 2;
+    let popup = Rect::new(area.x + x, area.y + y, w, h);
+
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(accent))
+        .title(label(&format!(" \u{f0a0} Devices ({len}) ")))
+        .title_style(Style::default().fg(accent))
+        .style(Style::default().bg(t.bg));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let iw = inner.width as usize;
+    let list_height = inner.height.saturating_sub(2) as usize;
+    // Keep the cursor in view.
+    let scroll = app
+        .mount_cursor
+        .saturating_sub(list_height.saturating_sub(1));
+    let max_scroll = len.saturating_sub(list_height.max(1));
+
+    let mut items: Vec<ListItem> = Vec::new();
+    for (i, dev) in devices.iter().enumerate().skip(scroll).take(list_height) {
+        let is_cursor = i == app.mount_cursor;
+        let icon = if dev.removable {
+            glyph(" \u{f287} ", " ")
+        } else {
+            glyph(" \u{f0a0} ", " ")
+        };
+        let name = if dev.label.is_empty() {
+            dev.device.clone()
+        } else {
+            format!("{} ({})", dev.device, dev.label)
+        };
+        let size = if dev.size > 0 {
+            crate::util::format_bytes(dev.size)
+        } else {
+            String::new()
+        };
+        let place = match &dev.mount_point {
+            Some(p) => abbreviate_home(&p.to_string_lossy()),
+            None => "not mounted".to_string(),
+        };
+        let right = format!(" {:>8}  {place} ", size);
+        let reserved = display_width(icon) + display_width(&right);
+        let (name_display, pad) = fit_truncated(&name, iw, reserved);
+
+        let (style, right_style) = if is_cursor {
+            let s = Style::default().fg(t.bg_text).bg(t.blue);
+            (s, s)
+        } else if dev.mount_point.is_some() {
+            (Style::default().fg(t.fg), Style::default().fg(t.green))
+        } else {
+            (Style::default().fg(t.fg), Style::default().fg(t.fg_dim))
+        };
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(icon, style),
+            Span::styled(name_display, style),
+            Span::styled(" ".repeat(pad), style),
+            Span::styled(right, right_style),
+        ])));
+    }
+    let list_area = Rect::new(inner.x, inner.y, inner.width, list_height as u16);
+    f.render_widget(List::new(items), list_area);
+
+    let sep_y = inner.y + list_height as u16;
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            super::scroll_separator(iw, scroll, max_scroll),
+            Style::default().fg(t.border_inactive),
+        ))),
+        Rect::new(inner.x, sep_y, inner.width, 1),
+    );
+
+    let hint_line = Line::from(vec![
+        Span::styled(" \u{23ce}", Style::default().fg(accent)),
+        Span::styled(" mount/open  ", Style::default().fg(t.fg_dim)),
+        Span::styled("u", Style::default().fg(accent)),
+        Span::styled(" unmount  ", Style::default().fg(t.fg_dim)),
+        Span::styled("r", Style::default().fg(accent)),
+        Span::styled(" refresh  ", Style::default().fg(t.fg_dim)),
+        Span::styled("esc", Style::default().fg(accent)),
+        Span::styled(" close", Style::default().fg(t.fg_dim)),
+    ]);
+    let hint_y = inner.y + inner.height.saturating_sub(1);
+    f.render_widget(
+        Paragraph::new(hint_line),
+        Rect::new(inner.x, hint_y, inner.width, 1),
+    );
+}
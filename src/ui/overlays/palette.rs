@@ -0,0 +1,194 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+};
+
+use crate::app::App;
+use crate::app::palette::PALETTE_ACTIONS;
+use crate::theme::Theme;
+use crate::ui::util::{display_width, fit_truncated};
+use crate::util::icons::label;
+
+/// One row of a [`ListPrompt`]: a lead column and the text after it, cut to
+/// the popup's width. The cursor row is drawn in the selection colours instead.
+pub(super) struct ListRow {
+    pub lead: String,
+    pub lead_color: Color,
+    pub text: String,
+    pub text_color: Color,
+}
+
+/// The variant-specific content of a fuzzy list popup; the scaffold is shared
+/// between the command palette and the directory picker.
+pub(super) struct ListPrompt<'a> {
+    pub accent: Color,
+    pub title: String,
+    pub width: u16,
+    pub max_rows: usize,
+    pub query: &'a str,
+    /// Number of rows (matches) and the cursor among them.
+    pub len: usize,
+    pub selected: usize,
+    /// Label after the Enter key and after Esc in the hint line.
+    pub enter_hint: &'a str,
+    pub esc_hint: &'a str,
+}
+
+/// A centered fuzzy list: title bar, query field, separator, the rows around
+/// the cursor, a scroll separator and a hint line. `row(i)` draws match `i`.
+pub(super) fn render_list_prompt(
+    f: &mut Frame,
+    area: Rect,
+    t: &Theme,
+    p: ListPrompt,
+    row: impl Fn(usize) -> ListRow,
+) {
+    let ListPrompt {
+        accent,
+        title,
+        width,
+        max_rows,
+        query,
+        len,
+        selected,
+        enter_hint,
+        esc_hint,
+    } = p;
+
+    // Height: border(2) + input(1) + separator(1) + list + separator(1) + hint(1)
+    let list_h = len.clamp(1, max_rows);
+    let h = (list_h as u16 + 6).min(area.height);
+    let w = width.min(area.width.saturating_sub(4)).max(30);
+    let x = (area.width.saturating_sub(w)) / 2;
+    let y = (area.height.saturating_sub(h)) / 3;
+    let popup = Rect::new(area.x + x, area.y + y, w, h);
+
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(accent))
+        .title(title)
+        .title_style(Style::default().fg(accent))
+        .style(Style::default().bg(t.bg));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+    if inner.height < 4 {
+        return;
+    }
+
+    let iw = inner.width as usize;
+    let input_line = super::input_field_line(query, " \u{276f} ", iw, accent, t);
+    f.render_widget(
+        Paragraph::new(input_line),
+        Rect::new(inner.x, inner.y, inner.width, 1),
+    );
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "\u{2500}".repeat(iw),
+            Style::default().fg(t.border_inactive),
+        ))),
+        Rect::new(inner.x, inner.y + 1, inner.width, 1),
+    );
+
+    let list_height = inner.height.saturating_sub(4) as usize;
+    // Keep the cursor in view.
+    let scroll = selected.saturating_sub(list_height.saturating_sub(1));
+    let max_scroll = len.saturating_sub(list_height.max(1));
+
+    let mut items: Vec<ListItem> = Vec::new();
+    for i in (0..len).skip(scroll).take(list_height) {
+        let ListRow {
+            lead,
+            lead_color,
+            text,
+            text_color,
+        } = row(i);
+        let (text_display, pad) = fit_truncated(&text, iw, display_width(&lead));
+        let (lead_style, text_style) = if i == selected {
+            let style = Style::default().fg(t.bg_text).bg(t.blue);
+            (style, style)
+        } else {
+            (
+                Style::default().fg(lead_color),
+                Style::default().fg(text_color),
+            )
+        };
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(lead, lead_style),
+            Span::styled(text_display, text_style),
+            Span::styled(" ".repeat(pad), text_style),
+        ])));
+    }
+    f.render_widget(
+        List::new(items),
+        Rect::new(inner.x, inner.y + 2, inner.width, list_height as u16),
+    );
+
+    let sep_y = inner.y + 2 + list_height as u16;
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            super::scroll_separator(iw, scroll, max_scroll),
+            Style::default().fg(t.border_inactive),
+        ))),
+        Rect::new(inner.x, sep_y, inner.width, 1),
+    );
+
+    let hint_line = Line::from(vec![
+        Span::styled(" \u{23ce}", Style::default().fg(accent)),
+        Span::styled(enter_hint, Style::default().fg(t.fg_dim)),
+        Span::styled("\u{2191}\u{2193}", Style::default().fg(accent)),
+        Span::styled(" move  ", Style::default().fg(t.fg_dim)),
+        Span::styled("esc", Style::default().fg(accent)),
+        Span::styled(esc_hint, Style::default().fg(t.fg_dim)),
+    ]);
+    let hint_y = inner.y + inner.height.saturating_sub(1);
+    f.render_widget(
+        Paragraph::new(hint_line),
+        Rect::new(inner.x, hint_y, inner.width, 1),
+    );
+}
+
+pub(in crate::ui) fn render_palette(f: &mut Frame, app: &App, area: Rect) {
+    let t = &app.theme;
+    let matches = &app.palette_matches;
+    let len = matches.len();
+
+    // Trigger column: Space shown as ␣ so leader sequences are readable.
+    let key_label = |keys: &str| keys.trim_end().replace(' ', "\u{2423}");
+    let key_w = PALETTE_ACTIONS
+        .iter()
+        .map(|(k, _)| display_width(&key_label(k)))
+        .max()
+        .unwrap_or(0);
+
+    let prompt = ListPrompt {
+        accent: t.cyan,
+        title: label(&format!(
+            " \u{f120} Command Palette ({len}/{}) ",
+            PALETTE_ACTIONS.len()
+        )),
+        width: 60,
+        max_rows: 16,
+        query: &app.palette_query,
+        len,
+        selected: app.palette_selected,
+        enter_hint: " run  ",
+        esc_hint: " close",
+    };
+    render_list_prompt(f, area, t, prompt, |row| {
+        let (keys, desc) = PALETTE_ACTIONS[matches[row]];
+        let keys = key_label(keys);
+        let pad = " ".repeat(key_w.saturating_sub(display_width(&keys)));
+        ListRow {
+            lead: format!(" {keys}{pad} "),
+            lead_color: t.yellow,
+            text: desc.to_string(),
+            text_color: t.fg,
+        }
+    });
+}
@@ -0,0 +1,57 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::palette::{ListPrompt, ListRow, render_list_prompt};
+use crate::app::{App, PickAction};
+use crate::search::abbreviate_home;
+use crate::util::icons::{glyph, label};
+
+pub(in crate::ui) fn render_paste_picker(f: &mut Frame, app: &App, area: Rect) {
+    let Some(picker) = app.paste_picker.as_ref() else {
+        return;
+    };
+    let t = &app.theme;
+    let len = picker.matches.len();
+    let total = picker.dirs.len();
+    let (title, enter_hint) = match picker.action {
+        PickAction::Paste => {
+            let count = app.register.as_ref().map_or(0, |r| r.entries.len());
+            (
+                label(&format!(
+                    " \u{f0192} Paste {count} item(s) into ({len}/{total}) "
+                )),
+                " paste here  ",
+            )
+        }
+        PickAction::Cd => (
+            label(&format!(" \u{f07b} Go to ({len}/{total}) ")),
+            " open  ",
+        ),
+    };
+    let current = &app.tab().active_panel().path;
+
+    let prompt = ListPrompt {
+        accent: t.green,
+        title,
+        width: 64,
+        max_rows: 14,
+        query: &picker.query,
+        len,
+        selected: picker.selected,
+        enter_hint,
+        esc_hint: " cancel",
+    };
+    render_list_prompt(f, area, t, prompt, |row| {
+        let dir = &picker.dirs[picker.matches[row]];
+        let (icon, color) = if dir == current {
+            (glyph(" \u{f07c} ", " > "), t.green)
+        } else {
+            (glyph(" \u{f07b} ", "   "), t.fg)
+        };
+        ListRow {
+            lead: icon.to_string(),
+            lead_color: color,
+            text: abbreviate_home(&dir.to_string_lossy()),
+            text_color: color,
+        }
+    });
+}
@@ -8,6 +8,7 @@ use ratatui::{
 
 use crate::app::App;
 use crate::theme::Theme;
+use crate::util::icons::{glyph, label};
 
 /// The variant-specific content of an input prompt; the surrounding scaffold is
 /// shared between the search and filter popups.
@@ -15,6 +16,8 @@ struct InputPrompt<'a> {
     accent: Color,
     title: String,
     input: &'a str,
+    /// Cursor position in `input` (char index).
+    cursor: usize,
     prefix: &'a str,
     /// Hint line entries as (accented key, dimmed label) pairs.
     hints: &'a [(&'a str, &'a str)],
@@ -27,6 +30,7 @@ fn render_input_prompt(f: &mut Frame, area: Rect, t: &Theme, p: InputPrompt) {
         accent,
         title,
         input,
+        cursor,
         prefix,
         hints,
     } = p;
@@ -51,8 +55,8 @@ fn render_input_prompt(f: &mut Frame, area: Rect, t: &Theme, p: InputPrompt) {
 
     let iw = inner.width as usize;
 
-    // Input field (cursor stays at the end; text scrolls to show the tail).
-    let input_line = super::input_field_line(input, prefix, iw, accent, t);
+    // Input field (text scrolls to keep the cursor in view).
+    let input_line = super::input_field_line_at(input, cursor, prefix, iw, accent, t);
     f.render_widget(
         Paragraph::new(input_line),
         Rect::new(inner.x, inner.y, inner.width, 1),
@@ -97,9 +101,9 @@ pub(in crate::ui) fn render_search_popup(f: &mut Frame, app: &App, area: Rect) {
     };
 
     let title = if app.search_query.is_empty() {
-        " \u{f0349} Search ".to_string()
+        label(" \u{f0349} Search ")
     } else {
-        format!(" \u{f0349} Search ({match_count}) ")
+        label(&format!(" \u{f0349} Search ({match_count}) "))
     };
 
     render_input_prompt(
@@ -110,6 +114,7 @@ pub(in crate::ui) fn render_search_popup(f: &mut Frame, app: &App, area: Rect) {
             accent,
             title,
             input: &app.search_query,
+            cursor: app.line_editor.cursor(&app.search_query),
             prefix: " / ",
             hints: &[
                 (" \u{23ce}", " confirm  "),
@@ -124,19 +129,31 @@ pub(in crate::ui) fn render_filter_popup(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
     let accent = t.green;
 
-    // Visible matches (the panel is already narrowed live; exclude "..").
-    let match_count = app
-        .tab()
-        .active_panel()
-        .entries
-        .iter()
-        .filter(|e| e.name != "..")
-        .count();
+    // Visible matches (the panel is already narrowed live; exclude ".."). In
+    // the tree, the lines matching themselves rather than kept as ancestors.
+    let (what, match_count) = if app.tree_focused {
+        let query: Vec<char> = app.filter_input.to_lowercase().chars().collect();
+        let count = app
+            .tree_data
+            .iter()
+            .filter(|l| l.depth > 0 && crate::model::tree::name_matches(&l.name, &query))
+            .count();
+        ("Tree filter", count)
+    } else {
+        let count = app
+            .tab()
+            .active_panel()
+            .entries
+            .iter()
+            .filter(|e| e.name != "..")
+            .count();
+        ("Filter", count)
+    };
 
     let title = if app.filter_input.is_empty() {
-        " \u{f0233} Filter ".to_string()
+        label(&format!(" \u{f0233} {what} "))
     } else {
-        format!(" \u{f0233} Filter ({match_count}) ")
+        label(&format!(" \u{f0233} {what} ({match_count}) "))
     };
 
     render_input_prompt(
@@ -147,7 +164,8 @@ pub(in crate::ui) fn render_filter_popup(f: &mut Frame, app: &App, area: Rect) {
             accent,
             title,
             input: &app.filter_input,
-            prefix: " \u{f0233} ",
+            cursor: app.filter_input.chars().count(),
+            prefix: glyph(" \u{f0233} ", " / "),
             hints: &[(" \u{23ce}", " keep  "), ("esc", " cancel")],
         },
     );
@@ -10,6 +10,7 @@ use crate::app::App;
 use crate::app::task_manager::{TaskManager, TaskState};
 use crate::ui::SPINNER;
 use crate::ui::util::{display_width, fit_truncated};
+use crate::util::icons::label;
 
 pub(in crate::ui) fn render_tasks_overlay(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -24,7 +25,9 @@ pub(in crate::ui) fn render_tasks_overlay(f: &mut Frame, app: &App, area: Rect)
     f.render_widget(Clear, popup);
 
     let active = app.task_manager.active_count();
-    let title = format!(" \u{f0ae} Tasks ({active} running / {len} total) ");
+    let title = label(&format!(
+        " \u{f0ae} Tasks ({active} running / {len} total) "
+    ));
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(accent))
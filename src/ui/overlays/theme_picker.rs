@@ -8,6 +8,8 @@ use ratatui::{
 
 use crate::app::App;
 use crate::theme::Theme;
+use crate::ui::util::{display_width, pad_to_width, truncate_to_width};
+use crate::util::icons::label;
 
 pub(in crate::ui) fn render_theme_picker(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -78,19 +80,10 @@ fn render_groups_column(f: &mut Frame, t: &Theme, area: Rect, app: &App) {
         let total = group.dark_themes.len() + group.light_themes.len();
         let count_str = format!(" ({total})");
         let name_max = liw.saturating_sub(2 + count_str.len());
-        let name_display: String = if group.name.chars().count() > name_max {
-            group
-                .name
-                .chars()
-                .take(name_max.saturating_sub(1))
-                .chain(std::iter::once('\u{2026}'))
-                .collect()
-        } else {
-            group.name.to_string()
-        };
+        let name_display = truncate_to_width(group.name, name_max);
         let marker = if is_cursor { "\u{25b8} " } else { "  " };
         let pad = liw.saturating_sub(
-            marker.chars().count() + name_display.chars().count() + count_str.chars().count(),
+            display_width(marker) + display_width(&name_display) + display_width(&count_str),
         );
 
         if is_cursor {
@@ -155,8 +148,8 @@ fn render_themes_column(f: &mut Frame, t: &Theme, area: Rect, app: &App) {
     let toggle_y = inner.y;
     let liw = inner.width as usize;
     if inner.height >= 3 {
-        let dark_label = "\u{f0594} Dark"; // moon icon
-        let light_label = "\u{f0595} Light"; // sun icon
+        let dark_label = label("\u{f0594} Dark"); // moon icon
+        let light_label = label("\u{f0595} Light"); // sun icon
         if app.theme_show_light {
             let spans = vec![
                 Span::styled(format!(" {dark_label}  "), Style::default().fg(t.fg_dim)),
@@ -224,16 +217,8 @@ fn render_themes_column(f: &mut Frame, t: &Theme, area: Rect, app: &App) {
         let is_cursor = is_focused && i == cursor;
         let is_active = active_name.is_some_and(|an| an == name);
         let marker = if is_cursor { "\u{25b8} " } else { "  " };
-        let name_max = liw.saturating_sub(marker.chars().count());
-        let name_display: String = if name.chars().count() > name_max {
-            name.chars()
-                .take(name_max.saturating_sub(1))
-                .chain(std::iter::once('\u{2026}'))
-                .collect()
-        } else {
-            let pad = name_max - name.chars().count();
-            format!("{name}{}", " ".repeat(pad))
-        };
+        let name_max = liw.saturating_sub(display_width(marker));
+        let name_display = pad_to_width(&truncate_to_width(name, name_max), name_max);
 
         if is_cursor {
             let cs = Style::default().fg(t.bg_text).bg(t.blue);
@@ -333,14 +318,14 @@ fn render_column_footer(
 }
 
 fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
-    use crate::util::icons::file_icon;
+    use crate::util::icons::{file_icon, glyph, sep_left, sep_right};
 
     if area.height < 7 || area.width < 24 {
         return;
     }
 
-    let sep_r = "\u{e0b0}";
-    let sep_l = "\u{e0b2}";
+    let sep_r = sep_right();
+    let sep_l = sep_left();
 
     // Layout: tab bar (1) | panels (h-3) | modes (1) | status bar (1)
     let tab_area = Rect::new(area.x, area.y, area.width, 1);
@@ -359,8 +344,8 @@ fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
         let tab1 = "  1: Projects ";
         let tab2 = " 2: Documents ";
 
-        let task_info = " \u{f0c5} Copying 3 items 67% ";
-        let task_badge = " \u{f0ae} TASKS 1 ";
+        let task_info = label(" \u{f0c5} Copying 3 items 67% ");
+        let task_badge = label(" \u{f0ae} TASKS 1 ");
 
         let task_info_w = task_info.chars().count() + 1; // +sep
         let task_badge_w = task_badge.chars().count() + 1; // +sep
@@ -430,7 +415,7 @@ fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(pt.border_inactive))
-            .title(" \u{f0645} ")
+            .title(glyph(" \u{f0645} ", " Tree "))
             .title_style(Style::default().fg(pt.cyan))
             .style(Style::default().bg(pt.bg));
         let inner = block.inner(tree_area);
@@ -466,15 +451,7 @@ fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
                 pt.fg_dim
             };
             let bg = if is_cur { pt.blue } else { pt.bg };
-            let display: String = if name.chars().count() > tiw {
-                name.chars()
-                    .take(tiw.saturating_sub(1))
-                    .chain(std::iter::once('\u{2026}'))
-                    .collect()
-            } else {
-                let pad = tiw.saturating_sub(name.chars().count());
-                format!("{name}{}", " ".repeat(pad))
-            };
+            let display = pad_to_width(&truncate_to_width(&label(name), tiw), tiw);
             f.render_widget(
                 Paragraph::new(Line::from(Span::styled(
                     display,
@@ -598,7 +575,7 @@ fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
                 }
                 let is_cursor = i == cursor_row;
                 let icon = if entry.name == ".." {
-                    " \u{f07c} "
+                    glyph(" \u{f07c} ", "^ ")
                 } else {
                     file_icon(entry.name, entry.is_dir)
                 };
@@ -609,9 +586,9 @@ fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
                 };
 
                 let (git_icon, git_color) = match entry.git {
-                    'M' => ("\u{f03eb}", Some(pt.yellow)),
-                    'A' => ("\u{f0415}", Some(pt.green)),
-                    '?' => ("\u{f0613}", Some(pt.cyan)),
+                    'M' => (glyph("\u{f03eb}", "M"), Some(pt.yellow)),
+                    'A' => (glyph("\u{f0415}", "A"), Some(pt.green)),
+                    '?' => (glyph("\u{f0613}", "?"), Some(pt.cyan)),
                     _ => (" ", None),
                 };
                 let (vm_text, vm_color) = match entry.mark {
@@ -708,7 +685,7 @@ fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(pt.border_inactive))
-            .title(" \u{f0214} main.rs ")
+            .title(label(" \u{f0214} main.rs "))
             .title_style(Style::default().fg(pt.fg_dim))
             .style(Style::default().bg(pt.bg));
         let inner = block.inner(code_area);
@@ -823,7 +800,7 @@ fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
         let modal_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(pt.cyan))
-            .title(" \u{f018d} Command ")
+            .title(label(" \u{f018d} Command "))
             .title_style(Style::default().fg(pt.cyan))
             .style(Style::default().bg(pt.bg));
         let modal_inner = modal_block.inner(modal_area);
@@ -889,13 +866,14 @@ fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
         ];
         let mut spans: Vec<Span> = Vec::new();
         let mut used = 0usize;
-        for (i, &(label, color)) in modes.iter().enumerate() {
-            let lw = label.chars().count() + 1; // +1 for sep
+        for (i, &(text, color)) in modes.iter().enumerate() {
+            let text = label(text);
+            let lw = text.chars().count() + 1; // +1 for sep
             if used + lw > mw {
                 break;
             }
             spans.push(Span::styled(
-                label,
+                text,
                 Style::default().fg(pt.bg_text).bg(color),
             ));
             let next_bg = if i + 1 < modes.len() {
@@ -919,8 +897,11 @@ fn render_preview_panel(f: &mut Frame, pt: &Theme, area: Rect) {
     {
         let sw = status_area.width as usize;
 
-        let mode_text = " \u{f018d} NORMAL ";
-        let mode_span = Span::styled(mode_text, Style::default().fg(pt.bg_text).bg(pt.green));
+        let mode_text = label(" \u{f018d} NORMAL ");
+        let mode_span = Span::styled(
+            mode_text.clone(),
+            Style::default().fg(pt.bg_text).bg(pt.green),
+        );
         let mode_sep = Span::styled(sep_r, Style::default().fg(pt.green).bg(pt.bg_light));
 
         let info_text = " src/ \u{2502} 5 files, 3 dirs ";
@@ -8,6 +8,7 @@ use ratatui::{
 
 use crate::app::App;
 use crate::ui::util::{display_width, truncate_to_width_left};
+use crate::util::icons::label;
 
 pub(in crate::ui) fn render_trash(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -28,7 +29,7 @@ pub(in crate::ui) fn render_trash(f: &mut Frame, app: &App, area: Rect) {
 
     f.render_widget(Clear, popup);
 
-    let title = format!(" \u{f014} Trash \u{2014} restore ({len}) ");
+    let title = label(&format!(" \u{f014} Trash \u{2014} restore ({len}) "));
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(accent))
@@ -10,6 +10,7 @@ use ratatui::{
 use unicode_width::UnicodeWidthChar;
 
 use crate::app::{App, Mode};
+use crate::util::icons::glyph;
 use crate::viewer::HlSpan;
 
 /// Gutter width (line-number column) for text content, in cells: 4 digits + space.
@@ -99,17 +100,18 @@ pub(in crate::ui) fn render_viewer(f: &mut Frame, app: &mut App, area: Rect) {
 
     // Title: name + info, plus match counter when searching.
     let wrap_tag = if v.wrap && !is_binary {
-        " \u{f1290}"
+        glyph(" \u{f1290}", " wrap")
     } else {
         ""
     }; // 󱊐 wrap icon
+    let icon = glyph("\u{f0208} ", "");
     let title = if let Some((cur, total)) = match_count {
         format!(
-            " \u{f0208} {} [{}]{} ({cur}/{total}) ",
+            " {icon}{} [{}]{} ({cur}/{total}) ",
             p.title, p.info, wrap_tag
         )
     } else {
-        format!(" \u{f0208} {} [{}]{} ", p.title, p.info, wrap_tag)
+        format!(" {icon}{} [{}]{} ", p.title, p.info, wrap_tag)
     };
     let block = block.title(title).title_style(Style::default().fg(t.cyan));
     f.render_widget(block, area);
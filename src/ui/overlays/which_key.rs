@@ -7,6 +7,7 @@ use ratatui::{
 };
 
 use crate::theme::Theme;
+use crate::util::icons::label;
 
 pub(in crate::ui) fn render_which_key(
     f: &mut Frame,
@@ -25,6 +26,9 @@ pub(in crate::ui) fn render_which_key(
         '\'' => (" ", "Mark"),
         'w' => ("󰕰 ", "Layout"),
         'u' => ("󰔃 ", "UI"),
+        ']' => ("\u{f0142} ", "Next"),
+        '[' => ("\u{f0141} ", "Prev"),
+        '?' => ("\u{f030c} ", "Keys"),
         _ => return,
     };
 
@@ -73,7 +77,7 @@ pub(in crate::ui) fn render_which_key(
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(t.orange))
-        .title(format!(" {leader_icon}{leader_label} "))
+        .title(label(&format!(" {leader_icon}{leader_label} ")))
         .title_style(Style::default().fg(t.orange))
         .style(Style::default().bg(t.bg));
 
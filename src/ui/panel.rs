@@ -1,28 +1,153 @@
 use std::cmp::Ordering;
+use std::path::{MAIN_SEPARATOR, MAIN_SEPARATOR_STR, PathBuf};
 
 use ratatui::{
     Frame,
     layout::Rect,
-    style::Style,
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
 };
 
 use crate::app::PhantomEntry;
 use crate::fs::ops::RegisterOp;
 use crate::model::panel::{Panel, SortMode};
+use crate::theme::Theme;
 use crate::util::format_bytes;
-use crate::util::icons::file_icon;
-use crate::util::natsort::natsort;
+use crate::util::icons::{file_icon, glyph};
+use crate::util::natsort::name_order;
 
 use super::RenderContext;
-use super::util::{display_width, pad_to_width, truncate_to_width};
+use super::util::{
+    display_width, highlight_spans, hits_in_truncated, pad_to_width, truncate_to_width,
+    truncate_to_width_left,
+};
 
 enum DisplaySlot {
     Real(usize),
     Phantom(usize),
 }
 
+/// Flag color for a visual-mark level: 1 green, 2 yellow, 3 red.
+pub(super) fn visual_mark_color(t: &Theme, level: u8) -> Color {
+    match level {
+        1 => t.green,
+        2 => t.yellow,
+        _ => t.red,
+    }
+}
+
+/// Marker for a symlink whose target is outside the tree's root, in the panel's
+/// sign column and after the name in the tree.
+pub(super) const LINK_ESCAPES: &str = "\u{2197}";
+
+/// Color for a git status letter, shared by the panel column and the tree:
+/// M yellow, A green, ? cyan, D red, R magenta.
+pub(super) fn git_status_color(t: &Theme, status: char) -> Option<Color> {
+    match status {
+        'M' => Some(t.yellow),
+        'A' => Some(t.green),
+        '?' => Some(t.cyan),
+        'D' => Some(t.red),
+        'R' => Some(t.magenta),
+        _ => None,
+    }
+}
+
+/// One piece of a panel's breadcrumb title: a path segment (its index into
+/// `path_segments`) or a separator / elision marker.
+struct Crumb {
+    text: String,
+    segment: Option<usize>,
+}
+
+/// Lay out `segments` as a title of at most `max` columns. When the whole path
+/// does not fit, the middle is elided (`/…/src/app`), keeping the root and as
+/// many trailing segments as fit; a lone segment that is still too wide is
+/// tail-truncated.
+fn breadcrumb(segments: &[(String, PathBuf)], max: usize) -> Vec<Crumb> {
+    let Some(last) = segments.len().checked_sub(1) else {
+        return Vec::new();
+    };
+    // `elide_to`: segments 1..elide_to collapse into one `…`.
+    let build = |elide_to: usize| {
+        let mut out = vec![Crumb {
+            text: segments[0].0.clone(),
+            segment: Some(0),
+        }];
+        let mut prev = segments[0].0.as_str();
+        let mut push = |out: &mut Vec<Crumb>, text: String, segment: Option<usize>| {
+            if !prev.ends_with(MAIN_SEPARATOR) {
+                out.push(Crumb {
+                    text: MAIN_SEPARATOR_STR.to_string(),
+                    segment: None,
+                });
+            }
+            prev = "";
+            out.push(Crumb { text, segment });
+        };
+        if elide_to > 1 {
+            push(&mut out, "\u{2026}".to_string(), None);
+        }
+        for (i, (label, _)) in segments.iter().enumerate().skip(elide_to.max(1)) {
+            push(&mut out, label.clone(), Some(i));
+        }
+        out
+    };
+    let width = |crumbs: &[Crumb]| crumbs.iter().map(|c| display_width(&c.text)).sum::<usize>();
+
+    let full = build(1);
+    if width(&full) <= max {
+        return full;
+    }
+    for elide_to in 2..=last {
+        let crumbs = build(elide_to);
+        if width(&crumbs) <= max {
+            return crumbs;
+        }
+    }
+    vec![Crumb {
+        text: truncate_to_width_left(&segments[last].0, max),
+        segment: Some(last),
+    }]
+}
+
+/// Char indices of the first case-insensitive occurrence of `query_lower` in
+/// `name` — the same match the live filter and `/` search make.
+fn substring_hits(name: &str, query_lower: &str) -> Vec<usize> {
+    // Lowercasing can expand a char, so remember which original char each
+    // lowercased one came from.
+    let mut lower = String::with_capacity(name.len());
+    let mut origin = Vec::with_capacity(name.len());
+    for (i, c) in name.chars().enumerate() {
+        for l in c.to_lowercase() {
+            lower.push(l);
+            origin.push(i);
+        }
+    }
+    let Some(byte) = lower.find(query_lower) else {
+        return Vec::new();
+    };
+    let start = lower[..byte].chars().count();
+    let mut hits = origin[start..start + query_lower.chars().count()].to_vec();
+    hits.dedup();
+    hits
+}
+
+/// Width of the optional owner:group column, including its leading space.
+const OWNER_WIDTH: usize = 18;
+
+/// Narrowest panel that still gets the sort indicator in its top border.
+const SORT_INDICATOR_MIN_WIDTH: u16 = 24;
+
+/// Screen regions in a panel's top border that react to clicks.
+pub(super) struct TitleRegions {
+    /// Breadcrumb segments: (x_start, x_end_exclusive, directory).
+    pub crumbs: Vec<(u16, u16, PathBuf)>,
+    /// The sort indicator: (x_start, x_end_exclusive).
+    pub sort: Option<(u16, u16)>,
+}
+
 pub(super) fn render_panel(
     f: &mut Frame,
     panel: &Panel,
@@ -30,7 +155,7 @@ pub(super) fn render_panel(
     is_active: bool,
     phantoms: &[&PhantomEntry],
     ctx: &RenderContext,
-) {
+) -> TitleRegions {
     let t = ctx.theme;
     let border_color = if is_active {
         t.border_active
@@ -38,42 +163,70 @@ pub(super) fn render_panel(
         t.border_inactive
     };
 
-    let path_str = panel.path.to_string_lossy();
-    let max_title = area.width.saturating_sub(4) as usize;
-    let title_w = display_width(&path_str);
-    let title = if title_w > max_title {
-        // Trim from the left to show the most relevant (rightmost) part
-        let target = max_title.saturating_sub(1); // 1 for …
-        let mut start_byte = 0;
-        let mut col = 0;
-        // Walk from the end backwards to find the cutoff
-        let chars: Vec<(usize, char)> = path_str.char_indices().collect();
-        for &(byte_idx, c) in chars.iter().rev() {
-            let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-            if col + w > target {
-                start_byte = byte_idx + c.len_utf8();
-                break;
-            }
-            col += w;
+    // Sort indicator, right-aligned in the top border: "[size↓]". Dim, since
+    // every panel carries one; clicking it cycles the mode.
+    let sort_label = (area.width >= SORT_INDICATOR_MIN_WIDTH).then(|| {
+        let arrow = if panel.sort_reverse {
+            "\u{2191}"
+        } else {
+            "\u{2193}"
+        };
+        format!("[{}{arrow}]", panel.sort_mode.label())
+    });
+    let sort_width = sort_label.as_deref().map_or(0, display_width) as u16;
+    let sort_region = sort_label.as_ref().map(|_| {
+        let end = area.x + area.width.saturating_sub(1);
+        (end.saturating_sub(sort_width), end)
+    });
+
+    // Breadcrumb title: " /home/u/src " with each segment clickable, plus the
+    // filter indicators. The leading space sits after the border corner.
+    let segments = crate::util::path_segments(&panel.path);
+    let reserved = if sort_width > 0 { sort_width + 1 } else { 0 };
+    let max_title = area.width.saturating_sub(4 + reserved) as usize;
+    let crumbs = breadcrumb(&segments, max_title);
+    let title_fg = if is_active { t.fg } else { t.fg_dim };
+    let chosen = ctx.crumb_selected.filter(|_| is_active);
+
+    let mut title_spans = vec![Span::raw(" ")];
+    let mut crumb_regions = Vec::new();
+    let mut x = area.x + 2;
+    let right = area.x + area.width.saturating_sub(1 + reserved);
+    for crumb in &crumbs {
+        let w = display_width(&crumb.text) as u16;
+        let style = match crumb.segment {
+            Some(i) if chosen == Some(i) => Style::default().fg(t.bg_text).bg(t.blue),
+            Some(_) => Style::default().fg(title_fg),
+            None => Style::default().fg(t.fg_dim),
+        };
+        if let Some(i) = crumb.segment
+            && x < right
+        {
+            crumb_regions.push((x, (x + w).min(right), segments[i].1.clone()));
         }
-        format!("\u{2026}{}", &path_str[start_byte..])
-    } else {
-        path_str.into_owned()
-    };
+        title_spans.push(Span::styled(crumb.text.clone(), style));
+        x = x.saturating_add(w);
+    }
 
     // Append an indicator when a live filter is narrowing this panel.
-    let title = if panel.filter.is_empty() {
-        title
-    } else {
-        format!("{title}  \u{f0233} {}", panel.filter)
-    };
+    let mut suffix = String::new();
+    if !panel.filter.is_empty() {
+        suffix.push_str(&format!("  {}{}", glyph("\u{f0233} ", "/"), panel.filter));
+    }
+    if let Some(pf) = &panel.path_filter {
+        suffix.push_str(&format!("  {}", pf.label));
+    }
+    suffix.push(' ');
+    title_spans.push(Span::styled(suffix, Style::default().fg(title_fg)));
 
-    let block = Block::default()
+    let mut block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
-        .title(format!(" {title} "))
-        .title_style(Style::default().fg(if is_active { t.fg } else { t.fg_dim }))
+        .title(Line::from(title_spans))
         .style(Style::default().bg(t.bg));
+    if let Some(label) = sort_label {
+        block = block.title(Line::styled(label, Style::default().fg(t.fg_dim)).right_aligned());
+    }
 
     let inner = block.inner(area);
     f.render_widget(block, area);
@@ -88,10 +241,28 @@ pub(super) fn render_panel(
     let show_meta = inner_width >= 30;
     let meta_width = if show_meta { 16 } else { 0 };
     let vm_width = if show_meta { 1 } else { 0 }; // visual mark on the right
-    let name_width = inner_width.saturating_sub(meta_width + icon_width + sign_width + vm_width);
+    // The owner column only fits alongside a usable name column.
+    let owner_width = if ctx.show_owner && inner_width >= 50 {
+        OWNER_WIDTH
+    } else {
+        0
+    };
+    let name_width =
+        inner_width.saturating_sub(meta_width + icon_width + sign_width + vm_width + owner_width);
+    let relative_dates = panel.relative_dates.unwrap_or(ctx.relative_dates);
+    let now = std::time::SystemTime::now();
 
     let visual_range = panel.visual_range();
 
+    // The live filter wins; otherwise the active panel shows the `/` pattern.
+    let highlight = if !panel.filter.is_empty() {
+        panel.filter.to_lowercase()
+    } else if is_active {
+        ctx.search_query.to_lowercase()
+    } else {
+        String::new()
+    };
+
     let sort_mode = panel.sort_mode;
 
     // Build display slots: real entries interleaved with phantoms at sorted positions
@@ -102,6 +273,7 @@ pub(super) fn render_panel(
         visible_height,
         sort_mode,
         panel.sort_reverse,
+        ctx.case_sensitive_sort,
     );
 
     let mut items: Vec<ListItem> = Vec::with_capacity(slots.len());
@@ -112,7 +284,7 @@ pub(super) fn render_panel(
                 let ph = phantoms[*pi];
                 let ghost_style = Style::default().fg(t.fg_dim);
                 let icon = if ph.is_dir {
-                    "\u{f07b} "
+                    glyph("\u{f07b} ", "/ ")
                 } else {
                     file_icon(&ph.name, false)
                 };
@@ -135,7 +307,8 @@ pub(super) fn render_panel(
                     Span::styled(name_col, ghost_style),
                 ];
                 if show_meta {
-                    spans.push(Span::styled(" ".repeat(meta_width + vm_width), ghost_style));
+                    let pad = owner_width + meta_width + vm_width;
+                    spans.push(Span::styled(" ".repeat(pad), ghost_style));
                 }
                 items.push(ListItem::new(Line::from(spans)));
             }
@@ -171,9 +344,13 @@ pub(super) fn render_panel(
                 } else {
                     entry.modified
                 };
-                let date_str = date_source
-                    .map(crate::ui::util::format_time)
-                    .unwrap_or_else(|| "      ".into());
+                let date_str = match date_source {
+                    Some(time) if relative_dates => {
+                        crate::ui::util::format_time_relative(time, now)
+                    }
+                    Some(time) => crate::ui::util::format_time(time),
+                    None => "      ".into(),
+                };
 
                 let in_visual = visual_range
                     .map(|(lo, hi)| i >= lo && i <= hi)
@@ -244,21 +421,27 @@ pub(super) fn render_panel(
                 } else {
                     None
                 };
-                let sign_text = " ";
-                let mut sign_style = Style::default();
+                // A symlink leading out of the tree's root gets an arrow.
+                let escapes = entry.is_symlink && ctx.link_escapes.get(&entry.path) == Some(&true);
+                let (sign_text, mut sign_style) = if escapes {
+                    (LINK_ESCAPES, Style::default().fg(t.red))
+                } else {
+                    (" ", Style::default())
+                };
                 if let Some(bg) = row_bg {
                     sign_style = sign_style.bg(bg);
                 }
 
                 let git_raw = ctx.git_statuses.get(&entry.path).copied().unwrap_or(' ');
-                let (git_icon, git_color) = match git_raw {
-                    'M' => ("\u{f03eb}", Some(t.yellow)),  // 󰏫 md-pencil
-                    'A' => ("\u{f0415}", Some(t.green)),   // 󰐕 md-plus
-                    '?' => ("\u{f0613}", Some(t.cyan)),    // 󰘓 md-file_hidden
-                    'D' => ("\u{f0374}", Some(t.red)),     // 󰍴 md-minus
-                    'R' => ("\u{f0455}", Some(t.magenta)), // 󰑕 md-rename_box
-                    _ => (" ", None),
+                let git_icon = match git_raw {
+                    'M' => glyph("\u{f03eb}", "M"), // 󰏫 md-pencil
+                    'A' => glyph("\u{f0415}", "A"), // 󰐕 md-plus
+                    '?' => glyph("\u{f0613}", "?"), // 󰘓 md-file_hidden
+                    'D' => glyph("\u{f0374}", "D"), // 󰍴 md-minus
+                    'R' => glyph("\u{f0455}", "R"), // 󰑕 md-rename_box
+                    _ => " ",
                 };
+                let git_color = git_status_color(t, git_raw);
                 let git_style = match (git_color, row_bg) {
                     (Some(c), Some(_)) => Style::default().fg(t.bg_text).bg(c),
                     (Some(c), None) => Style::default().fg(c),
@@ -269,17 +452,13 @@ pub(super) fn render_panel(
                 let meta_text = format!(" {size_str} {date_str} ");
 
                 let (vm_text, vm_style) = if vm_level > 0 {
-                    let vm_color = match vm_level {
-                        1 => t.green,
-                        2 => t.yellow,
-                        _ => t.red,
-                    };
+                    let vm_color = visual_mark_color(t, vm_level);
                     let s = if row_bg.is_some() {
                         Style::default().fg(t.bg_text).bg(vm_color)
                     } else {
                         Style::default().fg(vm_color)
                     };
-                    ("\u{f024}", s)
+                    (glyph("\u{f024}", "*"), s)
                 } else {
                     let mut s = meta_style;
                     if let Some(bg) = row_bg {
@@ -288,12 +467,47 @@ pub(super) fn render_panel(
                     (" ", s)
                 };
 
+                let hits = if highlight.is_empty() || entry.name == ".." {
+                    Vec::new()
+                } else {
+                    let hits = substring_hits(&entry.name, &highlight);
+                    hits_in_truncated(&display_name, &name_col, &hits, false)
+                };
+                // Matched characters stand out by colour on plain rows and by
+                // weight on rows that already carry a background.
+                let hl_style = if row_bg.is_some() {
+                    name_style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                } else {
+                    name_style.fg(t.yellow).add_modifier(Modifier::BOLD)
+                };
+
                 let mut spans = vec![
                     Span::styled(git_icon, git_style),
                     Span::styled(sign_text, sign_style),
                     Span::styled(icon, icon_style),
-                    Span::styled(name_col, name_style),
                 ];
+                match ctx.inline_edit.filter(|_| is_active_cursor) {
+                    // Inline rename: the name column becomes the edit field.
+                    Some((input, cursor)) => {
+                        let field = super::overlays::input_field_line_at(
+                            input, cursor, "", name_width, t.yellow, t,
+                        );
+                        spans.extend(field.spans);
+                    }
+                    None => spans.extend(highlight_spans(&name_col, &hits, name_style, hl_style)),
+                }
+                if owner_width > 0 {
+                    let owner = entry.owner.map(|(uid, gid)| {
+                        let user = crate::fs::perms::user_name(uid);
+                        format!("{user}:{}", crate::fs::perms::group_name(gid))
+                    });
+                    let owner = owner.unwrap_or_default();
+                    let text = truncate_to_width(&owner, owner_width - 1);
+                    spans.push(Span::styled(
+                        format!(" {}", pad_to_width(&text, owner_width - 1)),
+                        meta_style,
+                    ));
+                }
                 if show_meta {
                     spans.push(Span::styled(meta_text, meta_style));
                     spans.push(Span::styled(vm_text, vm_style));
@@ -304,6 +518,39 @@ pub(super) fn render_panel(
     }
 
     f.render_widget(List::new(items), inner);
+
+    // An unreadable directory holds only "..": say why instead of looking empty.
+    if let Some(err) = panel.load_error.as_deref().filter(|_| !panel.loading)
+        && inner.height > 2
+    {
+        let y = inner.y + 1 + (inner.height - 1) / 2;
+        let msg = truncate_to_width(&format!("{}{err}", glyph("\u{f033e} ", "")), inner_width);
+        f.render_widget(
+            Paragraph::new(Line::styled(msg, Style::default().fg(t.red)).centered()),
+            Rect::new(inner.x, y, inner.width, 1),
+        );
+    } else if !panel.loading
+        && phantoms.is_empty()
+        && panel.entries.iter().all(|e| e.name == "..")
+        && inner.height > 2
+    {
+        // A readable directory with nothing in it (or nothing the filter lets
+        // through) would otherwise look like one that is still loading.
+        let msg = if panel.filter_active() {
+            "\u{2014} no matches \u{2014}"
+        } else {
+            "\u{2014} empty \u{2014}"
+        };
+        let y = inner.y + 1 + (inner.height - 1) / 2;
+        f.render_widget(
+            Paragraph::new(Line::styled(msg, Style::default().fg(t.fg_dim)).centered()),
+            Rect::new(inner.x, y, inner.width, 1),
+        );
+    }
+    TitleRegions {
+        crumbs: crumb_regions,
+        sort: sort_region,
+    }
 }
 
 /// Compute display slots merging real entries with phantoms at their sorted positions.
@@ -322,6 +569,7 @@ fn build_display_slots(
     visible_height: usize,
     sort_mode: SortMode,
     sort_reverse: bool,
+    case_sensitive: bool,
 ) -> Vec<DisplaySlot> {
     if phantoms.is_empty() {
         // Fast path: no phantoms
@@ -330,14 +578,20 @@ fn build_display_slots(
             .collect();
     }
 
-    // Find section boundaries: [.., dirs..., files...]
+    // Find section boundaries: [.., dirs..., files...] or, with `parent_entry`
+    // at the bottom, [dirs..., files..., ..]
     let has_dotdot = entries.first().is_some_and(|e| e.name == "..");
     let dir_start = if has_dotdot { 1 } else { 0 };
-    let file_start = entries[dir_start..]
+    let file_end = if !has_dotdot && entries.last().is_some_and(|e| e.name == "..") {
+        entries.len() - 1
+    } else {
+        entries.len()
+    };
+    let file_start = entries[dir_start..file_end]
         .iter()
         .position(|e| !e.is_dir)
         .map(|p| p + dir_start)
-        .unwrap_or(entries.len());
+        .unwrap_or(file_end);
 
     // Compute insertion position for each phantom
     let mut insertions: Vec<(usize, usize)> = Vec::with_capacity(phantoms.len());
@@ -345,9 +599,10 @@ fn build_display_slots(
         let (sec_start, sec_end) = if ph.is_dir {
             (dir_start, file_start)
         } else {
-            (file_start, entries.len())
+            (file_start, file_end)
         };
 
+        let natsort = name_order(case_sensitive);
         let insert_pos = if sort_mode == SortMode::Name {
             let section = &entries[sec_start..sec_end];
             let pos = if sort_reverse {
@@ -411,3 +666,26 @@ fn build_display_slots(
 
     slots
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(crumbs: &[Crumb]) -> String {
+        crumbs.iter().map(|c| c.text.as_str()).collect()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn breadcrumb_elides_the_middle() {
+        let segs = crate::util::path_segments(std::path::Path::new("/home/user/projects/fcmd"));
+        assert_eq!(texts(&breadcrumb(&segs, 40)), "/home/user/projects/fcmd");
+        let crumbs = breadcrumb(&segs, 18);
+        assert_eq!(texts(&crumbs), "/\u{2026}/projects/fcmd");
+        // Root and kept segments stay clickable; the elision does not.
+        let targets: Vec<Option<usize>> = crumbs.iter().map(|c| c.segment).collect();
+        assert_eq!(targets, [Some(0), None, None, Some(3), None, Some(4)]);
+        assert_eq!(texts(&breadcrumb(&segs, 8)), "/\u{2026}/fcmd");
+        assert_eq!(texts(&breadcrumb(&segs, 3)), "\u{2026}md");
+    }
+}
@@ -5,27 +5,17 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, Paragraph},
 };
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::preview::Preview;
 use crate::theme::Theme;
 
-/// Truncate a string to fit within `max_width` terminal cells.
-pub(super) fn truncate_to_width(s: &str, max_width: usize) -> String {
-    let mut out = String::new();
-    let mut w = 0;
-    for ch in s.chars() {
-        let cw = ch.width().unwrap_or(0);
-        if w + cw > max_width {
-            break;
-        }
-        out.push(ch);
-        w += cw;
-    }
-    out
-}
-
-pub(super) fn render_preview(f: &mut Frame, preview: &Option<Preview>, area: Rect, t: &Theme) {
+/// Returns the area left blank for the terminal to draw an image in.
+pub(super) fn render_preview(
+    f: &mut Frame,
+    preview: &Option<Preview>,
+    area: Rect,
+    t: &Theme,
+) -> Option<Rect> {
     let (title, info) = match preview {
         Some(p) => (p.title.as_str(), p.info.as_str()),
         None => ("Preview", ""),
@@ -41,7 +31,22 @@ pub(super) fn render_preview(f: &mut Frame, preview: &Option<Preview>, area: Rec
     let inner = block.inner(area);
     f.render_widget(block, area);
 
-    let Some(p) = preview else { return };
+    let p = preview.as_ref()?;
+
+    if let Some(image) = &p.image {
+        // The picture is drawn over the blank pane once the frame is out
+        // (`sync_preview_image` in main.rs); without one, say what it is.
+        if image.upload.is_some() {
+            return Some(inner);
+        }
+        let lines: Vec<Line> = p
+            .lines
+            .iter()
+            .map(|l| Line::from(Span::styled(format!(" {l}"), Style::default().fg(t.fg))))
+            .collect();
+        f.render_widget(Paragraph::new(lines), inner);
+        return None;
+    }
 
     // Right-aligned position in title area
     if p.is_binary {
@@ -63,8 +68,8 @@ pub(super) fn render_preview(f: &mut Frame, preview: &Option<Preview>, area: Rec
         }
     } else if !p.lines.is_empty() {
         let visible = inner.height as usize;
-        let (first, total, pct) = p.text_position(visible);
-        let pos_text = format!(" {first}/{total} {pct}% ");
+        let (first, last, total, pct) = p.text_position(visible);
+        let pos_text = format!(" {first}-{last}/{total} {pct}% ");
         let pos_w = pos_text.chars().count() as u16;
         if area.width > pos_w + 4 {
             let pos_x = area.x + area.width - pos_w - 1;
@@ -80,37 +85,67 @@ pub(super) fn render_preview(f: &mut Frame, preview: &Option<Preview>, area: Rec
     }
 
     let visible = inner.height as usize;
-    let width = inner.width as usize;
+    // Clamp here too: a resize can leave `scroll` past the last page.
+    let scroll = p.scroll.min(p.row_count().saturating_sub(visible));
+
+    // Scrollbar track in the rightmost column when the content overflows.
+    let thumb = super::util::scrollbar_thumb(p.row_count(), visible, scroll, visible);
+    let content_area = if thumb.is_some() {
+        Rect::new(
+            inner.x,
+            inner.y,
+            inner.width.saturating_sub(1),
+            inner.height,
+        )
+    } else {
+        inner
+    };
+    if let Some((start, len)) = thumb {
+        let bar: Vec<Line> = (0..visible)
+            .map(|row| {
+                if (start..start + len).contains(&row) {
+                    Line::from(Span::styled("\u{2503}", Style::default().fg(t.cyan)))
+                } else {
+                    Line::from(Span::styled(
+                        "\u{2502}",
+                        Style::default().fg(t.border_inactive),
+                    ))
+                }
+            })
+            .collect();
+        let bar_area = Rect::new(inner.x + inner.width - 1, inner.y, 1, inner.height);
+        f.render_widget(Paragraph::new(bar), bar_area);
+    }
+    let width = content_area.width as usize;
 
     let items: Vec<ListItem> = if p.is_binary {
-        super::hex::render_rows(p, p.scroll, visible, crate::preview::HEX_COLS, t, None, &[])
+        super::hex::render_rows(p, scroll, visible, crate::preview::HEX_COLS, t, None, &[])
     } else {
         (0..visible)
             .map(|i| {
-                let line_idx = i + p.scroll;
+                let line_idx = i + scroll;
                 if line_idx >= p.lines.len() {
                     return ListItem::new(Line::from(""));
                 }
                 let line_num = line_idx + 1;
                 let num_width = 4;
                 let max_content = width.saturating_sub(num_width + 2);
-                let line = &p.lines[line_idx];
-                let content = if line.width() > max_content {
-                    truncate_to_width(line, max_content)
-                } else {
-                    line.clone()
-                };
-                let spans = vec![
-                    Span::styled(
-                        format!("{line_num:>num_width$} ", num_width = num_width),
-                        Style::default().fg(t.fg_dim),
-                    ),
-                    Span::styled(content, Style::default().fg(t.fg)),
-                ];
+                let mut spans = vec![Span::styled(
+                    format!("{line_num:>num_width$} ", num_width = num_width),
+                    Style::default().fg(t.fg_dim),
+                )];
+                let hl = p.highlight.as_ref().and_then(|h| h.lines.get(line_idx));
+                spans.extend(super::util::syntax_spans(
+                    &p.lines[line_idx],
+                    max_content,
+                    hl.map(Vec::as_slice),
+                    Style::default().fg(t.fg),
+                ));
                 ListItem::new(Line::from(spans))
             })
             .collect()
     };
 
-    f.render_widget(List::new(items), inner);
+    f.render_widget(List::new(items), content_area);
+    None
 }
@@ -10,7 +10,7 @@ use crate::app::{App, Mode};
 use crate::model::panel::SortMode;
 
 use super::util::display_width;
-use super::{SEP_LEFT, SEP_RIGHT};
+use crate::util::icons::{glyph, label, sep_left, sep_right};
 
 pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
     let t = &app.theme;
@@ -22,13 +22,13 @@ pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
     );
 
     // Confirm mode — overlay handles the popup, status bar shows mode
-    if app.mode == Mode::Confirm {
+    if matches!(app.mode, Mode::Confirm | Mode::ConfirmEmptyTrash) {
         let mut spans = vec![
             Span::styled(
-                " \u{f05e8} CONFIRM ".to_string(),
+                label(" \u{f05e8} CONFIRM "),
                 Style::default().fg(t.bg_text).bg(t.red),
             ),
-            Span::styled(SEP_RIGHT, Style::default().fg(t.red).bg(t.status_bg)),
+            Span::styled(sep_right(), Style::default().fg(t.red).bg(t.status_bg)),
         ];
 
         let used: usize = spans.iter().map(|s| display_width(&s.content)).sum();
@@ -50,70 +50,102 @@ pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
     // ── Mode segment ────────────────
     let mode_str: String;
     let (mode_ref, mode_bg) = if app.tree_focused && app.mode == Mode::Normal {
-        mode_str = "\u{f0645} TREE".to_string(); // 󰙅
+        mode_str = label("\u{f0645} TREE"); // 󰙅
         (mode_str.as_str(), t.cyan)
     } else {
         match app.mode {
             Mode::Normal => {
-                mode_str = "\u{f018d} NORMAL".to_string(); // 󰆍
+                mode_str = label("\u{f018d} NORMAL"); // 󰆍
                 (mode_str.as_str(), t.green)
             }
             Mode::Visual => {
-                mode_str = "\u{f0489} VISUAL".to_string(); // 󰒉
+                mode_str = label("\u{f0489} VISUAL"); // 󰒉
                 (mode_str.as_str(), t.magenta)
             }
             Mode::Select => {
-                mode_str = "\u{f0135} SELECT".to_string(); // 󰄵
+                mode_str = label("\u{f0135} SELECT"); // 󰄵
                 (mode_str.as_str(), t.orange)
             }
             Mode::Find => {
-                mode_str = "\u{f002} FIND".to_string(); //
+                mode_str = label("\u{f002} FIND"); //
                 (mode_str.as_str(), t.cyan)
             }
             Mode::Viewer => {
-                mode_str = "\u{f0208} VIEW".to_string(); // 󰈈
+                mode_str = label("\u{f0208} VIEW"); // 󰈈
                 (mode_str.as_str(), t.cyan)
             }
             Mode::Help => {
-                mode_str = "\u{f02d6} HELP".to_string(); // 󰋖
+                mode_str = label("\u{f02d6} HELP"); // 󰋖
                 (mode_str.as_str(), t.cyan)
             }
             Mode::ThemePicker => {
-                mode_str = "\u{f03d8} THEME".to_string(); // 󰏘
+                mode_str = label("\u{f03d8} THEME"); // 󰏘
                 (mode_str.as_str(), t.cyan)
             }
             Mode::Bookmarks => {
-                mode_str = "\u{f02e6} BOOKMARKS".to_string(); // 󰋦
+                mode_str = label("\u{f02e6} BOOKMARKS"); // 󰋦
                 (mode_str.as_str(), t.cyan)
             }
             Mode::Rename => {
-                mode_str = "\u{f03eb} RENAME".to_string(); // 󰏫
+                mode_str = label("\u{f03eb} RENAME"); // 󰏫
                 (mode_str.as_str(), t.yellow)
             }
             Mode::Create => {
-                mode_str = "\u{f0415} CREATE".to_string(); // 󰐕
+                mode_str = label("\u{f0415} CREATE"); // 󰐕
                 (mode_str.as_str(), t.green)
             }
             Mode::Chmod => {
-                mode_str = "\u{f033e} CHMOD".to_string(); // 󰌾
+                mode_str = label("\u{f033e} CHMOD"); // 󰌾
                 (mode_str.as_str(), t.orange)
             }
             Mode::Chown => {
-                mode_str = "\u{f0004} CHOWN".to_string(); // 󰀄
+                mode_str = label("\u{f0004} CHOWN"); // 󰀄
                 (mode_str.as_str(), t.orange)
             }
             Mode::Info => {
-                mode_str = "\u{f02fd} INFO".to_string(); // 󰋽
+                mode_str = label("\u{f02fd} INFO"); // 󰋽
                 (mode_str.as_str(), t.cyan)
             }
             Mode::Search => {
-                mode_str = "\u{f0349} SEARCH".to_string(); // 󰍉
+                mode_str = label("\u{f0349} SEARCH"); // 󰍉
                 (mode_str.as_str(), t.cyan)
             }
             Mode::Command => {
-                mode_str = "\u{f018d} CMD".to_string(); // 󰆍
+                mode_str = label("\u{f018d} CMD"); // 󰆍
                 (mode_str.as_str(), t.cyan)
             }
+            Mode::Palette => {
+                mode_str = label("\u{f120} PALETTE"); // 
+                (mode_str.as_str(), t.cyan)
+            }
+            Mode::PastePicker => {
+                let cd = app
+                    .paste_picker
+                    .as_ref()
+                    .is_some_and(|p| p.action == crate::app::PickAction::Cd);
+                mode_str = if cd {
+                    label("\u{f07b} CD") // 
+                } else {
+                    label("\u{f0192} PASTE") // 󰆒
+                };
+                (mode_str.as_str(), t.green)
+            }
+            Mode::Messages => {
+                mode_str = label("\u{f0369} MESSAGES"); // 󰍩
+                (mode_str.as_str(), t.cyan)
+            }
+            Mode::Mounts => {
+                mode_str = label("\u{f0a0} MOUNT"); // 
+                (mode_str.as_str(), t.orange)
+            }
+            Mode::Plan => {
+                mode_str = label("\u{f0208} PLAN"); // 󰈈
+                (mode_str.as_str(), t.orange)
+            }
+            Mode::Breadcrumb => {
+                mode_str = label("\u{f07c} PATH"); //
+                (mode_str.as_str(), t.blue)
+            }
             _ => {
                 mode_str = String::new();
                 (mode_str.as_str(), t.fg_dim)
@@ -126,7 +158,7 @@ pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
         format!(" {mode_str} "),
         Style::default().fg(t.bg_text).bg(mode_bg),
     );
-    let mode_sep = Span::styled(SEP_RIGHT, Style::default().fg(mode_bg).bg(t.bg_light));
+    let mode_sep = Span::styled(sep_right(), Style::default().fg(mode_bg).bg(t.bg_light));
 
     // ── Right side segments (built first to compute width) ────────────────
     let mut right_parts: Vec<(String, Color, Color)> = Vec::new();
@@ -182,12 +214,19 @@ pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
 
     // Preview indicator
     if app.preview_mode {
-        right_parts.push((" 󰈈 ".to_string(), t.cyan, t.bg_light));
+        right_parts.push((glyph(" 󰈈 ", " P ").to_string(), t.cyan, t.bg_light));
+    }
+
+    // Pending key, after any count typed for it (`3d`)
+    if app.pending_key.is_some() || app.pending_count.is_some() {
+        let count = app.pending_count.map(|n| n.to_string()).unwrap_or_default();
+        let key = app.pending_key.map(String::from).unwrap_or_default();
+        right_parts.push((format!(" {count}{key} "), t.orange, t.bg_light));
     }
 
-    // Pending key
-    if let Some(c) = app.pending_key {
-        right_parts.push((format!(" {c} "), t.orange, t.bg_light));
+    // Dry-run badge: loud, so nobody mistakes a plan for a real operation.
+    if app.dry_run {
+        right_parts.push((" DRY RUN ".to_string(), t.bg_text, t.orange));
     }
 
     // Free disk space on the active panel's filesystem (leftmost right segment).
@@ -200,7 +239,10 @@ pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
         // Warn (red) when the volume is nearly full.
         let fg = if pct <= 10 { t.red } else { t.fg_dim };
         right_parts.push((
-            format!(" \u{f02ca} {} free ", crate::util::format_bytes(free)),
+            label(&format!(
+                " \u{f02ca} {} free ",
+                crate::util::format_bytes(free)
+            )),
             fg,
             t.bg_light,
         ));
@@ -215,7 +257,7 @@ pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
             right_parts[idx + 1].2
         };
         right_spans.push(Span::styled(
-            SEP_LEFT,
+            sep_left(),
             Style::default().fg(*seg_bg).bg(prev_bg),
         ));
         right_spans.push(Span::styled(
@@ -229,6 +271,8 @@ pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
     // ── Info segment (capped so right segments stay fixed) ────────────────
     let info_text = if !app.status_message.is_empty() {
         format!(" {} ", app.status_message)
+    } else if app.mode == Mode::Breadcrumb {
+        " h/l choose  \u{23ce} go  1-9 up N levels  esc cancel ".to_string()
     } else if app.mode == Mode::Visual {
         let count = panel.targeted_count();
         format!("  {count} selected ")
@@ -252,9 +296,11 @@ pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
         format!(" {selected_name} \u{2502} {file_count} files, {dir_count} dirs ")
     };
 
-    // Cap info width so right segments always stay at the right edge
-    let mode_width = display_width(mode_str) + 2 + 1; // " MODE " + SEP_RIGHT
-    let info_sep_width = 1;
+    // Cap info width so right segments always stay at the right edge. The
+    // separators are measured rather than assumed one column wide.
+    let info_sep = Span::styled(sep_right(), Style::default().fg(t.bg_light).bg(t.status_bg));
+    let mode_width = display_width(&mode_span.content) + display_width(&mode_sep.content);
+    let info_sep_width = display_width(&info_sep.content);
     let max_info = width.saturating_sub(mode_width + info_sep_width + right_used);
 
     let info_display = if display_width(&info_text) > max_info {
@@ -267,7 +313,6 @@ pub(super) fn render_status(f: &mut Frame, app: &App, area: Rect) {
         info_display.clone(),
         Style::default().fg(t.fg).bg(t.bg_light),
     );
-    let info_sep = Span::styled(SEP_RIGHT, Style::default().fg(t.bg_light).bg(t.status_bg));
 
     // Calculate fill to push right segments to the edge
     let left_used: usize = mode_width + display_width(&info_display) + info_sep_width;
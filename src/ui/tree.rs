@@ -7,8 +7,9 @@ use ratatui::{
 };
 
 use crate::app::App;
-use crate::util::icons::file_icon;
+use crate::util::icons::{file_icon, glyph, label};
 
+use super::panel::{LINK_ESCAPES, git_status_color, visual_mark_color};
 use super::util::{display_width, pad_to_width, truncate_to_width};
 
 pub(super) fn render_tree(f: &mut Frame, app: &App, area: Rect) {
@@ -23,7 +24,11 @@ pub(super) fn render_tree(f: &mut Frame, app: &App, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
-        .title(" 󰙅 Tree ")
+        .title(if app.tree_filter.is_empty() {
+            label(" 󰙅 Tree ")
+        } else {
+            format!(" {}Tree /{} ", glyph("󰙅 ", ""), app.tree_filter)
+        })
         .title_style(Style::default().fg(if is_focused { t.fg } else { t.cyan }))
         .style(Style::default().bg(t.bg));
 
@@ -46,33 +51,65 @@ pub(super) fn render_tree(f: &mut Frame, app: &App, area: Rect) {
         .map(|(i, line)| {
             let icon = if line.depth == 0 {
                 " "
+            } else if line.unreadable {
+                glyph("\u{f033e} ", "! ")
             } else if line.is_dir {
                 if line.is_expanded {
-                    "󰝰 "
+                    glyph("󰝰 ", "/ ")
                 } else {
-                    "\u{f07b} "
+                    glyph("\u{f07b} ", "/ ")
                 }
             } else {
                 file_icon(&line.name, false)
             };
 
             let is_cursor = i == app.tree_selected;
+            let on_cursor = |c| {
+                if is_cursor && is_focused {
+                    Style::default().fg(t.bg_text).bg(c)
+                } else {
+                    Style::default().fg(c)
+                }
+            };
+            // After the name: an arrow on a symlink leaving the root, then
+            // the visual mark's flag as the panel shows it.
+            let mut mark: Vec<Span> = Vec::new();
+            if line.link_escapes == Some(true) {
+                mark.push(Span::styled(format!(" {LINK_ESCAPES}"), on_cursor(t.red)));
+            }
+            if let Some(&level) = app.visual_marks.get(&line.path) {
+                let flag = on_cursor(visual_mark_color(t, level));
+                mark.push(Span::styled(format!(" {}", glyph("\u{f024}", "*")), flag));
+            }
+            let mark_w: usize = mark.iter().map(|m| display_width(&m.content)).sum();
+            let text_w = width.saturating_sub(mark_w);
 
             // Cursor row: uniform style for the whole line, padded so the
             // highlight bar spans the full panel width.
             if is_cursor && is_focused {
                 let full = format!("{}{}{}", line.prefix, icon, line.name);
-                let text = pad_to_width(&truncate_to_width(&full, width), width);
-                return ListItem::new(Line::from(Span::styled(
+                let text = pad_to_width(&truncate_to_width(&full, text_w), text_w);
+                let mut spans = vec![Span::styled(
                     text,
                     Style::default().fg(t.bg_text).bg(t.blue),
-                )));
+                )];
+                spans.extend(mark);
+                return ListItem::new(Line::from(spans));
             }
 
-            // Colors matching panels: dirs=dir_color, file icons=fg_dim, file names=file_color
+            // Colors matching panels: dirs=dir_color, file icons=fg_dim, file names=file_color.
+            // A git status (propagated up to the directories holding the change)
+            // colors the name as the panel's git column does.
+            let git_color = app
+                .git_statuses
+                .get(&line.path)
+                .and_then(|&s| git_status_color(t, s));
             let (icon_style, name_style) = if is_cursor || line.is_current {
                 let s = Style::default().fg(t.yellow);
                 (s, s)
+            } else if let Some(c) = git_color {
+                let icon = if line.is_dir { c } else { t.fg_dim };
+                (Style::default().fg(icon), Style::default().fg(c))
             } else if line.is_on_path || line.is_dir {
                 let s = Style::default().fg(t.dir_color);
                 (s, s)
@@ -85,18 +122,20 @@ pub(super) fn render_tree(f: &mut Frame, app: &App, area: Rect) {
 
             // Truncate the name by display width so wide chars don't overflow.
             let fixed_w = display_width(&line.prefix) + display_width(icon);
-            let avail = width.saturating_sub(fixed_w);
+            let avail = text_w.saturating_sub(fixed_w);
             let name_display = if avail == 0 {
                 String::new()
             } else {
                 truncate_to_width(&line.name, avail)
             };
 
-            ListItem::new(Line::from(vec![
+            let mut spans = vec![
                 Span::styled(&line.prefix, Style::default().fg(t.border_inactive)),
                 Span::styled(icon.to_string(), icon_style),
                 Span::styled(name_display, name_style),
-            ]))
+            ];
+            spans.extend(mark);
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -2,6 +2,8 @@ use std::time::SystemTime;
 
 use chrono::{DateTime, Local, Utc};
 use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Span;
 use unicode_width::UnicodeWidthChar;
 
 pub(crate) fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
@@ -50,6 +52,56 @@ pub(crate) fn truncate_to_width(s: &str, max_cols: usize) -> String {
     out
 }
 
+/// Cut a string to at most `max_cols` terminal columns without an ellipsis — for
+/// content lines (previews) where a marker would read as part of the text.
+pub(crate) fn clip_to_width(s: &str, max_cols: usize) -> String {
+    let mut out = String::new();
+    let mut col = 0;
+    for c in s.chars() {
+        let w = UnicodeWidthChar::width(c).unwrap_or(0);
+        if col + w > max_cols {
+            break;
+        }
+        out.push(c);
+        col += w;
+    }
+    out
+}
+
+/// `line` clipped to `max_cols` columns (see [`clip_to_width`]) as spans in
+/// its syntax colors `hl`, char ranges from the highlighter. Text they don't
+/// cover, or all of it without them, is in `base`.
+pub(crate) fn syntax_spans(
+    line: &str,
+    max_cols: usize,
+    hl: Option<&[crate::viewer::HlSpan]>,
+    base: Style,
+) -> Vec<Span<'static>> {
+    let content = clip_to_width(line, max_cols);
+    let Some(hl) = hl.filter(|h| !h.is_empty()) else {
+        return vec![Span::styled(content, base)];
+    };
+    let chars: Vec<char> = content.chars().collect();
+    let text = |range: std::ops::Range<usize>| chars[range].iter().collect::<String>();
+    let mut spans = Vec::with_capacity(hl.len() + 1);
+    let mut pos = 0;
+    for span in hl {
+        if span.start >= chars.len() {
+            break;
+        }
+        if span.start > pos {
+            spans.push(Span::styled(text(pos..span.start), base));
+        }
+        let end = span.end.min(chars.len());
+        spans.push(Span::styled(text(span.start..end), base.fg(span.color)));
+        pos = end;
+    }
+    if pos < chars.len() {
+        spans.push(Span::styled(text(pos..chars.len()), base));
+    }
+    spans
+}
+
 /// Truncate a string to fit within `max_cols` columns, keeping the TAIL (right side).
 /// Prepends `…` if truncated. Useful for paths, where the filename at the end matters
 /// more than the leading directories.
@@ -113,6 +165,80 @@ pub(crate) fn fit_truncated(
     (truncated, pad)
 }
 
+/// Split `text` into spans, styling the chars at `hits` (sorted char indices)
+/// with `hl` and the rest with `base`. Used to show which characters of a name
+/// matched a search, filter or fuzzy query.
+pub(crate) fn highlight_spans(
+    text: &str,
+    hits: &[usize],
+    base: Style,
+    hl: Style,
+) -> Vec<Span<'static>> {
+    if hits.is_empty() {
+        return vec![Span::styled(text.to_string(), base)];
+    }
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_hit = false;
+    let mut hits = hits.iter().peekable();
+    for (i, c) in text.chars().enumerate() {
+        while hits.next_if(|&&h| h < i).is_some() {}
+        let hit = hits.peek() == Some(&&i);
+        if hit != run_hit && !run.is_empty() {
+            let style = if run_hit { hl } else { base };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_hit = hit;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        spans.push(Span::styled(run, if run_hit { hl } else { base }));
+    }
+    spans
+}
+
+/// Re-index `hits` (char indices into `full`) onto `shown`, the result of
+/// truncating `full` with `truncate_to_width` (or `truncate_to_width_left` when
+/// `tail` is set) and possibly padding it. Hits cut off or covered by the `…`
+/// are dropped.
+pub(crate) fn hits_in_truncated(full: &str, shown: &str, hits: &[usize], tail: bool) -> Vec<usize> {
+    if shown.starts_with(full) {
+        return hits.to_vec();
+    }
+    let n = full.chars().count();
+    let m = shown.chars().count();
+    if tail {
+        // `shown` is "…" followed by the last m - 1 chars of `full`.
+        let skipped = (n + 1).saturating_sub(m);
+        hits.iter()
+            .filter(|&&h| h >= skipped)
+            .map(|&h| h - skipped + 1)
+            .collect()
+    } else {
+        // `shown` is the first m - 1 chars of `full` followed by "…".
+        hits.iter().copied().filter(|&h| h + 1 < m).collect()
+    }
+}
+
+/// Scrollbar thumb `(start_row, len)` within a `track` rows tall track, for `total`
+/// rows of content of which `visible` fit and the first shown is `scroll`. `None`
+/// when everything fits (no scrollbar needed). The thumb is at least one row and
+/// touches the bottom exactly when the last page is shown.
+pub(crate) fn scrollbar_thumb(
+    total: usize,
+    visible: usize,
+    scroll: usize,
+    track: usize,
+) -> Option<(usize, usize)> {
+    if total <= visible || track == 0 {
+        return None;
+    }
+    let len = (visible * track / total).clamp(1, track);
+    let max_scroll = total - visible;
+    let start = (scroll.min(max_scroll) * (track - len)).div_ceil(max_scroll);
+    Some((start, len))
+}
+
 pub(crate) fn format_time(time: SystemTime) -> String {
     let dt: DateTime<Local> = DateTime::<Utc>::from(time).into();
     let now = Local::now();
@@ -125,10 +251,99 @@ pub(crate) fn format_time(time: SystemTime) -> String {
     }
 }
 
+/// Age of `time` at `now` for the 6-column date slot: "now", "12m", "5h",
+/// "3d", "5mo", "2y". A time in the future (clock skew) counts as "now".
+pub(crate) fn format_time_relative(time: SystemTime, now: SystemTime) -> String {
+    const MIN: u64 = 60;
+    const HOUR: u64 = 60 * MIN;
+    const DAY: u64 = 24 * HOUR;
+    let secs = now.duration_since(time).map_or(0, |d| d.as_secs());
+    let label = match secs {
+        s if s < MIN => "now".to_string(),
+        s if s < HOUR => format!("{}m", s / MIN),
+        s if s < DAY => format!("{}h", s / HOUR),
+        s if s < 30 * DAY => format!("{}d", s / DAY),
+        s if s < 365 * DAY => format!("{}mo", s / (30 * DAY)),
+        s => format!("{}y", s / (365 * DAY)),
+    };
+    format!("{label:>6}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // ── syntax_spans ───────────────────────────────────────────────
+
+    #[test]
+    fn syntax_spans_color_ranges_and_clip() {
+        use crate::viewer::HlSpan;
+        use ratatui::style::Color;
+        let base = Style::default().fg(Color::White);
+        let hl = [
+            HlSpan {
+                start: 0,
+                end: 2,
+                color: Color::Red,
+            },
+            HlSpan {
+                start: 3,
+                end: 7,
+                color: Color::Blue,
+            },
+        ];
+        let spans = syntax_spans("fn main()", 5, Some(&hl), base);
+        let parts: Vec<(&str, Option<Color>)> = spans
+            .iter()
+            .map(|s| (s.content.as_ref(), s.style.fg))
+            .collect();
+        assert_eq!(
+            parts,
+            [
+                ("fn", Some(Color::Red)),
+                (" ", Some(Color::White)),
+                ("ma", Some(Color::Blue))
+            ]
+        );
+        let plain = syntax_spans("fn main()", 80, None, base);
+        assert_eq!(plain.len(), 1);
+        assert_eq!(plain[0].style, base);
+    }
+
+    // ── highlight_spans ────────────────────────────────────────────
+
+    #[test]
+    fn highlight_spans_groups_runs() {
+        let base = Style::default();
+        let hl = Style::default().fg(ratatui::style::Color::Yellow);
+        let spans = highlight_spans("report.txt", &[0, 1, 7], base, hl);
+        let parts: Vec<(&str, bool)> = spans
+            .iter()
+            .map(|s| (s.content.as_ref(), s.style == hl))
+            .collect();
+        assert_eq!(
+            parts,
+            [("re", true), ("port.", false), ("t", true), ("xt", false)]
+        );
+        assert_eq!(highlight_spans("abc", &[], base, hl).len(), 1);
+    }
+
+    #[test]
+    fn hits_follow_truncation() {
+        let full = "abcdefgh";
+        // Padded: unchanged.
+        assert_eq!(
+            hits_in_truncated(full, "abcdefgh  ", &[0, 7], false),
+            [0, 7]
+        );
+        // Head kept: "abcd…" drops hits at or past the ellipsis.
+        let head = truncate_to_width(full, 5);
+        assert_eq!(hits_in_truncated(full, &head, &[1, 3, 4, 7], false), [1, 3]);
+        // Tail kept: "…efgh" shifts hits right of the cut.
+        let tail = truncate_to_width_left(full, 5);
+        assert_eq!(hits_in_truncated(full, &tail, &[1, 4, 7], true), [1, 4]);
+    }
+
     // ── display_width ──────────────────────────────────────────────
 
     #[test]
@@ -165,6 +380,27 @@ mod tests {
         assert_eq!(display_width("\u{f024}"), 1); // flag icon
     }
 
+    // ── scrollbar_thumb ────────────────────────────────────────────
+
+    #[test]
+    fn scrollbar_none_when_content_fits() {
+        assert_eq!(scrollbar_thumb(10, 10, 0, 10), None);
+        assert_eq!(scrollbar_thumb(5, 10, 0, 10), None);
+    }
+
+    #[test]
+    fn scrollbar_thumb_tracks_position() {
+        // 100 rows, 10 visible, 10-row track: thumb is 1 row.
+        assert_eq!(scrollbar_thumb(100, 10, 0, 10), Some((0, 1)));
+        assert_eq!(scrollbar_thumb(100, 10, 90, 10), Some((9, 1)));
+        // Scroll past the end (e.g. after a resize) is pinned to the bottom.
+        assert_eq!(scrollbar_thumb(100, 10, 500, 10), Some((9, 1)));
+        // Any scroll off the top moves the thumb off row 0.
+        assert_eq!(scrollbar_thumb(100, 10, 1, 10), Some((1, 1)));
+        // 20 rows, 10 visible: half-height thumb.
+        assert_eq!(scrollbar_thumb(20, 10, 5, 10), Some((3, 5)));
+    }
+
     // ── truncate_to_width ──────────────────────────────────────────
 
     #[test]
@@ -200,6 +436,32 @@ mod tests {
         assert_eq!(truncate_to_width("", 5), "");
     }
 
+    #[test]
+    fn truncate_mixed_width_names() {
+        // "日本a😀b.txt": 2+2+1+2+1+4 = 12 columns, 8 chars.
+        let name = "日本a😀b.txt";
+        assert_eq!(display_width(name), 12);
+        assert_eq!(truncate_to_width(name, 12), name);
+        // 6 cols: 5 for text, the emoji would straddle the edge and is dropped.
+        assert_eq!(truncate_to_width(name, 6), "日本a\u{2026}");
+        assert_eq!(truncate_to_width(name, 7), "日本a\u{2026}");
+        assert_eq!(truncate_to_width(name, 8), "日本a😀\u{2026}");
+        for cols in 1..12 {
+            assert!(display_width(&truncate_to_width(name, cols)) <= cols);
+            assert!(display_width(&truncate_to_width_left(name, cols)) <= cols);
+        }
+        let (fitted, pad) = fit_truncated(name, 10, 2);
+        assert_eq!(display_width(&fitted) + pad, 8);
+    }
+
+    #[test]
+    fn clip_never_splits_wide_chars() {
+        assert_eq!(clip_to_width("日本語", 3), "日");
+        assert_eq!(clip_to_width("a😀b", 2), "a");
+        assert_eq!(clip_to_width("a😀b", 3), "a😀");
+        assert_eq!(clip_to_width("abc", 10), "abc");
+    }
+
     // ── truncate_to_width_left ─────────────────────────────────────
 
     #[test]
@@ -381,4 +643,26 @@ mod tests {
         // Should be "Mon YY" format
         assert_eq!(result.len(), 6); // e.g. "Feb 25"
     }
+
+    #[test]
+    fn format_time_relative_buckets() {
+        use std::time::Duration;
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        let ago = |secs: u64| format_time_relative(now - Duration::from_secs(secs), now);
+        const DAY: u64 = 86400;
+        assert_eq!(ago(0), "   now");
+        assert_eq!(ago(59), "   now");
+        assert_eq!(ago(60), "    1m");
+        assert_eq!(ago(3599), "   59m");
+        assert_eq!(ago(3600), "    1h");
+        assert_eq!(ago(DAY - 1), "   23h");
+        assert_eq!(ago(DAY), "    1d");
+        assert_eq!(ago(30 * DAY - 1), "   29d");
+        assert_eq!(ago(30 * DAY), "   1mo");
+        assert_eq!(ago(365 * DAY - 1), "  12mo");
+        assert_eq!(ago(365 * DAY), "    1y");
+        // Clock skew: a future time is "now", not a panic or a huge number.
+        let future = now + Duration::from_secs(120);
+        assert_eq!(format_time_relative(future, now), "   now");
+    }
 }
@@ -1,4 +1,80 @@
+use std::cell::Cell;
+
+thread_local! {
+    // Rendering and command handling both run on the UI thread.
+    static ENABLED: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Turn Nerd Font glyphs on or off (`icons` in the config, `:icons`).
+pub fn set_enabled(on: bool) {
+    ENABLED.with(|e| e.set(on));
+}
+
+pub fn enabled() -> bool {
+    ENABLED.with(Cell::get)
+}
+
+/// Whether `c` is a Nerd Font glyph: they all live in the Private Use Areas,
+/// which a plain font draws as tofu.
+pub fn is_glyph(c: char) -> bool {
+    matches!(c, '\u{e000}'..='\u{f8ff}' | '\u{f0000}'..='\u{ffffd}')
+}
+
+/// `nerd` with icons on, else `ascii`: a marker of the same width where the
+/// glyph means something (a flag, a git status), or "" where it only
+/// decorates.
+pub fn glyph(nerd: &'static str, ascii: &'static str) -> &'static str {
+    if enabled() { nerd } else { ascii }
+}
+
+/// A title, mode name or hint written with its icons, as shown: whole, or
+/// without icons with each glyph and the space after it dropped.
+pub fn label(text: &str) -> String {
+    if enabled() {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if is_glyph(c) {
+            chars.next_if_eq(&' ');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Powerline separator pointing right, or `|` without icons.
+pub fn sep_right() -> &'static str {
+    if enabled() { "\u{e0b0}" } else { "|" }
+}
+
+/// Powerline separator pointing left, or `|` without icons.
+pub fn sep_left() -> &'static str {
+    if enabled() { "\u{e0b2}" } else { "|" }
+}
+
+/// Icon (plus trailing space) shown before a name; an ASCII marker when
+/// icons are off.
 pub fn file_icon(name: &str, is_dir: bool) -> &'static str {
+    if !enabled() {
+        return ascii_icon(name, is_dir);
+    }
+    nerd_icon(name, is_dir)
+}
+
+fn ascii_icon(name: &str, is_dir: bool) -> &'static str {
+    if name == ".." {
+        "^ "
+    } else if is_dir {
+        "/ "
+    } else {
+        "  "
+    }
+}
+
+fn nerd_icon(name: &str, is_dir: bool) -> &'static str {
     if name == ".." {
         return "\u{f005e} "; // 󰁞 arrow up
     }
@@ -145,6 +221,23 @@ mod tests {
         assert_eq!(file_icon("data.xyz", false), "\u{f016} ");
     }
 
+    #[test]
+    fn ascii_mode_uses_plain_markers() {
+        set_enabled(false);
+        assert_eq!(file_icon("..", true), "^ ");
+        assert_eq!(file_icon("src", true), "/ ");
+        assert_eq!(file_icon("main.rs", false), "  ");
+        assert_eq!(sep_right(), "|");
+        assert_eq!(label(" \u{f02e6} Bookmarks (3) "), " Bookmarks (3) ");
+        assert_eq!(label("\u{f0ae}"), "");
+        assert_eq!(glyph("\u{f024}", "*"), "*");
+        set_enabled(true);
+        assert_eq!(label(" \u{f02e6} Bookmarks "), " \u{f02e6} Bookmarks ");
+        assert_eq!(sep_left(), "\u{e0b2}");
+        assert!(is_glyph('\u{f07b}') && is_glyph('\u{f0d09}'));
+        assert!(!is_glyph('\u{2502}') && !is_glyph('a'));
+    }
+
     #[test]
     fn no_extension() {
         assert_eq!(file_icon("Makefile_custom", false), "\u{f016} ");
@@ -1,10 +1,23 @@
 pub mod icons;
 pub mod natsort;
+pub mod pool;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-/// Returns `~/.config/fcmd` as the config directory (XDG-style, cross-platform).
+static CONFIG_DIR: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+
+/// `--config <dir>`: use `dir` in place of `~/.config/fcmd` for this run.
+/// Only the first call has an effect.
+pub fn set_config_dir(dir: PathBuf) {
+    let _ = CONFIG_DIR.set(dir);
+}
+
+/// Returns `~/.config/fcmd` as the config directory (XDG-style, cross-platform),
+/// unless `--config` named another one.
 pub fn config_dir() -> Option<PathBuf> {
+    if let Some(dir) = CONFIG_DIR.get() {
+        return Some(dir.clone());
+    }
     dirs::home_dir().map(|h| h.join(".config").join("fcmd"))
 }
 
@@ -19,6 +32,73 @@ pub fn home_dir_string() -> String {
         .unwrap_or_else(|| "/".to_string())
 }
 
+/// `$VAR` and `${VAR}` in `s` replaced by their environment values, as a shell
+/// would. Unset variables are left as typed so the resulting error names them.
+pub fn expand_env(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('$') {
+        out.push_str(&rest[..i]);
+        let after = &rest[i + 1..];
+        let (name, len) = match after.strip_prefix('{') {
+            Some(inner) => match inner.find('}') {
+                Some(end) => (&inner[..end], end + 2),
+                None => ("", 0),
+            },
+            None => {
+                let end = after
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                (&after[..end], end)
+            }
+        };
+        match std::env::var(name).ok().filter(|_| !name.is_empty()) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[i..i + 1 + len]),
+        }
+        rest = &after[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// A typed path argument as a shell would read it: `$VAR`s expanded, and a
+/// bare `~` or `~/` starting a word (or a quoted word) replaced with the home
+/// directory. `~user` and a `~` inside a name are left alone.
+pub fn expand_path_arg(s: &str) -> String {
+    let s = expand_env(s);
+    let home = home_dir_string();
+    let mut out = String::with_capacity(s.len());
+    let mut word_start = true;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let ends_word = |n: &char| matches!(n, '/' | '"' | '\'') || n.is_whitespace();
+        if c == '~' && word_start && chars.peek().is_none_or(ends_word) {
+            out.push_str(&home);
+        } else {
+            out.push(c);
+        }
+        word_start = c.is_whitespace() || c == '"' || c == '\'';
+    }
+    out
+}
+
+/// `path` split into breadcrumb segments, root first: the label shown for each
+/// segment and the directory it stands for. The root keeps its full form
+/// (`/`, `C:\`) since it has no file name.
+pub fn path_segments(path: &Path) -> Vec<(String, PathBuf)> {
+    let mut segments: Vec<(String, PathBuf)> = path
+        .ancestors()
+        .filter(|p| !p.as_os_str().is_empty())
+        .map(|p| {
+            let label = p.file_name().unwrap_or(p.as_os_str());
+            (label.to_string_lossy().into_owned(), p.to_path_buf())
+        })
+        .collect();
+    segments.reverse();
+    segments
+}
+
 pub fn format_bytes(b: u64) -> String {
     if b < 1024 {
         format!("{b}B")
@@ -128,10 +208,101 @@ pub async fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
     }
 }
 
+/// Hand `path` to the desktop's default application and return without
+/// waiting for it; its output is discarded so it cannot draw over the UI.
+pub fn open_with_system(path: &Path) -> std::io::Result<()> {
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    tokio::process::Command::new(opener)
+        .arg(path)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map(drop)
+}
+
+/// Arguments that open `path` at 1-based `line` in `editor`, in the form the
+/// editor understands (`+N file`, `-g file:N`, `file:N`). An editor not known
+/// here just gets the path.
+pub fn editor_line_args(editor: &str, path: &Path, line: usize) -> Vec<std::ffi::OsString> {
+    let name = Path::new(editor)
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let with_line = || {
+        let mut arg = path.as_os_str().to_owned();
+        arg.push(format!(":{line}"));
+        arg
+    };
+    match name.as_str() {
+        "vi" | "vim" | "nvim" | "gvim" | "view" | "nano" | "pico" | "micro" | "emacs"
+        | "emacsclient" | "kak" | "joe" | "ne" | "mg" | "gedit" => {
+            vec![format!("+{line}").into(), path.into()]
+        }
+        "code" | "code-insiders" | "codium" | "cursor" => vec!["-g".into(), with_line()],
+        "subl" | "sublime_text" | "zed" | "hx" | "helix" => vec![with_line()],
+        _ => vec![path.into()],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn editor_line_args_per_editor() {
+        let path = Path::new("/var/log/app.log");
+        let args = |editor| -> Vec<String> {
+            editor_line_args(editor, path, 123)
+                .into_iter()
+                .map(|a| a.to_string_lossy().into_owned())
+                .collect()
+        };
+        assert_eq!(args("/usr/bin/nvim"), ["+123", "/var/log/app.log"]);
+        assert_eq!(args("code"), ["-g", "/var/log/app.log:123"]);
+        assert_eq!(args("hx"), ["/var/log/app.log:123"]);
+        assert_eq!(args("ed"), ["/var/log/app.log"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_segments_root_first() {
+        let segs = path_segments(Path::new("/home/u"));
+        let labels: Vec<&str> = segs.iter().map(|(l, _)| l.as_str()).collect();
+        assert_eq!(labels, ["/", "home", "u"]);
+        assert_eq!(segs[1].1, PathBuf::from("/home"));
+        assert_eq!(path_segments(Path::new("/")).len(), 1);
+    }
+
+    #[test]
+    fn expand_env_replaces_set_variables() {
+        let path = std::env::var("PATH").unwrap_or_default();
+        assert_eq!(expand_env("$PATH/x"), format!("{path}/x"));
+        assert_eq!(expand_env("a${PATH}b"), format!("a{path}b"));
+        assert_eq!(
+            expand_env("$FCMD_SURELY_UNSET_VAR/x"),
+            "$FCMD_SURELY_UNSET_VAR/x"
+        );
+        assert_eq!(expand_env("cost $ 5 ${unclosed"), "cost $ 5 ${unclosed");
+    }
+
+    #[test]
+    fn expand_path_arg_expands_home_per_word() {
+        let home = home_dir_string();
+        assert_eq!(expand_path_arg("~"), home);
+        assert_eq!(expand_path_arg("~/docs/~old"), format!("{home}/docs/~old"));
+        assert_eq!(expand_path_arg("~/a ~/b"), format!("{home}/a {home}/b"));
+        assert_eq!(expand_path_arg("'~/my log'"), format!("'{home}/my log'"));
+        assert_eq!(expand_path_arg("sub/~x"), "sub/~x");
+        assert_eq!(expand_path_arg("~user/x"), "~user/x");
+        let path = std::env::var("PATH").unwrap_or_default();
+        assert_eq!(expand_path_arg("${PATH}/x"), format!("{path}/x"));
+    }
+
     #[test]
     fn format_bytes_ranges() {
         assert_eq!(format_bytes(0), "0B");
@@ -6,6 +6,25 @@ use std::cmp::Ordering;
 /// embedded numbers are sorted numerically: "file2" < "file10".
 /// Non-ASCII bytes are decoded as UTF-8 codepoints for correct comparison.
 pub fn natsort(left: &[u8], right: &[u8]) -> Ordering {
+    compare(left, right, true)
+}
+
+/// [`natsort`] that tells case apart: uppercase sorts before lowercase, as in
+/// byte order (`Apple`, `Cherry`, `banana`), while numbers stay numeric.
+pub fn natsort_case_sensitive(left: &[u8], right: &[u8]) -> Ordering {
+    compare(left, right, false)
+}
+
+/// Name order for the `case_sensitive_sort` setting.
+pub fn name_order(case_sensitive: bool) -> fn(&[u8], &[u8]) -> Ordering {
+    if case_sensitive {
+        natsort_case_sensitive
+    } else {
+        natsort
+    }
+}
+
+fn compare(left: &[u8], right: &[u8], fold_case: bool) -> Ordering {
     let mut li = 0;
     let mut ri = 0;
 
@@ -33,8 +52,11 @@ pub fn natsort(left: &[u8], right: &[u8]) -> Ordering {
                     }
                 } else if lb.is_ascii() && rb.is_ascii() {
                     // Fast path for ASCII
-                    let la = lb.to_ascii_lowercase();
-                    let ra = rb.to_ascii_lowercase();
+                    let (la, ra) = if fold_case {
+                        (lb.to_ascii_lowercase(), rb.to_ascii_lowercase())
+                    } else {
+                        (lb, rb)
+                    };
                     match la.cmp(&ra) {
                         Ordering::Equal => {}
                         ord => return ord,
@@ -45,9 +67,14 @@ pub fn natsort(left: &[u8], right: &[u8]) -> Ordering {
                     // Decode UTF-8 codepoints for correct multi-byte comparison
                     let (lch, llen) = decode_utf8_char(&left[li..]);
                     let (rch, rlen) = decode_utf8_char(&right[ri..]);
-                    let lo: char = lch.to_lowercase().next().unwrap_or(lch);
-                    let ro: char = rch.to_lowercase().next().unwrap_or(rch);
-                    match lo.cmp(&ro) {
+                    let fold = |c: char| {
+                        if fold_case {
+                            c.to_lowercase().next().unwrap_or(c)
+                        } else {
+                            c
+                        }
+                    };
+                    match fold(lch).cmp(&fold(rch)) {
                         Ordering::Equal => {}
                         ord => return ord,
                     }
@@ -153,6 +180,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn case_sensitive_differs_only_in_case() {
+        let names = ["banana", "Cherry", "file10", "Apple", "file2"];
+        let mut by_name = names.to_vec();
+        by_name.sort_by(|a, b| natsort(a.as_bytes(), b.as_bytes()));
+        assert_eq!(by_name, ["Apple", "banana", "Cherry", "file2", "file10"]);
+
+        let mut cased = names.to_vec();
+        cased.sort_by(|a, b| natsort_case_sensitive(a.as_bytes(), b.as_bytes()));
+        assert_eq!(cased, ["Apple", "Cherry", "banana", "file2", "file10"]);
+        // Raw byte order agrees on case but not on the numbers.
+        let mut bytes = names.to_vec();
+        bytes.sort();
+        assert_eq!(bytes, ["Apple", "Cherry", "banana", "file10", "file2"]);
+    }
+
     #[test]
     fn leading_zeros() {
         assert_eq!(
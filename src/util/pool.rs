@@ -0,0 +1,90 @@
+//! A scoped worker pool for the blocking background jobs (`du`, checksums).
+//! The thread count comes from the `workers` setting.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Run `work` on every item with up to `workers` threads and hand each
+/// result, with the item's index, to `done` on the calling thread as it
+/// comes in (so in completion order, not item order). `done` returning
+/// `false` stops the pool: items not yet started are skipped.
+pub fn for_each_parallel<T: Sync, R: Send>(
+    items: &[T],
+    workers: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(usize, R) -> bool,
+) {
+    let next = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    let workers = workers.clamp(1, items.len().max(1));
+    std::thread::scope(|s| {
+        let (tx, rx) = std::sync::mpsc::channel();
+        for _ in 0..workers {
+            let tx = tx.clone();
+            let (next, stop, work) = (&next, &stop, &work);
+            s.spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+                    if tx.send((i, work(item))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+        for (i, result) in rx {
+            if !done(i, result) {
+                stop.store(true, Ordering::Relaxed);
+                break;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_item_is_done_once() {
+        let items: Vec<u64> = (0..100).collect();
+        let mut seen = vec![None; items.len()];
+        for_each_parallel(
+            &items,
+            4,
+            |n| n * n,
+            |i, sq| {
+                assert!(seen[i].replace(sq).is_none());
+                true
+            },
+        );
+        assert!(
+            seen.iter()
+                .enumerate()
+                .all(|(i, sq)| *sq == Some((i * i) as u64))
+        );
+    }
+
+    #[test]
+    fn done_returning_false_stops_the_pool() {
+        let items: Vec<u32> = (0..1000).collect();
+        let started = AtomicUsize::new(0);
+        let mut count = 0;
+        for_each_parallel(
+            &items,
+            2,
+            |_| {
+                started.fetch_add(1, Ordering::Relaxed);
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            },
+            |_, ()| {
+                count += 1;
+                count < 3
+            },
+        );
+        assert_eq!(count, 3);
+        assert!(started.load(Ordering::Relaxed) < 100);
+    }
+}